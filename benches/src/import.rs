@@ -50,6 +50,9 @@ pub fn provision_import_test(
     let params = Config {
         header_batch_size: header_batch_size as usize,
         block_stream_buffer_size,
+        max_batch_retries: 0,
+        request_full_blocks: false,
+        headers_only: false,
     };
     let p2p = Arc::new(PressurePeerToPeer::new(
         shared_count.clone(),
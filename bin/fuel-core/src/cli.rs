@@ -27,8 +27,14 @@ pub fn default_db_path() -> PathBuf {
     dirs::home_dir().unwrap().join(".fuel").join("db")
 }
 
+#[cfg(feature = "backup")]
+pub mod backup;
+#[cfg(feature = "rocksdb")]
+pub mod db;
 pub mod fee_contract;
 #[cfg(feature = "rocksdb")]
+pub mod reindex;
+#[cfg(feature = "rocksdb")]
 pub mod rollback;
 pub mod run;
 #[cfg(feature = "rocksdb")]
@@ -57,6 +63,12 @@ pub enum Fuel {
     Snapshot(snapshot::Command),
     #[cfg(feature = "rocksdb")]
     Rollback(rollback::Command),
+    #[cfg(feature = "backup")]
+    Backup(backup::Command),
+    #[cfg(feature = "rocksdb")]
+    Db(db::Command),
+    #[cfg(feature = "rocksdb")]
+    Reindex(reindex::Command),
     GenerateFeeContract(fee_contract::Command),
 }
 
@@ -141,6 +153,12 @@ pub async fn run_cli() -> anyhow::Result<()> {
             Fuel::Snapshot(command) => snapshot::exec(command).await,
             Fuel::GenerateFeeContract(command) => fee_contract::exec(command).await,
             Fuel::Rollback(command) => rollback::exec(command).await,
+            #[cfg(feature = "backup")]
+            Fuel::Backup(command) => backup::exec(command).await,
+            #[cfg(feature = "rocksdb")]
+            Fuel::Db(command) => db::exec(command).await,
+            #[cfg(feature = "rocksdb")]
+            Fuel::Reindex(command) => reindex::exec(command).await,
         },
         Err(e) => {
             // Prints the error and exits.
@@ -0,0 +1,186 @@
+use crate::cli::default_db_path;
+use clap::{
+    Parser,
+    Subcommand,
+};
+use fuel_core::combined_database::backup::{
+    Backup,
+    BackupStore,
+};
+use std::{
+    path::PathBuf,
+    time::{
+        Duration,
+        UNIX_EPOCH,
+    },
+};
+
+/// Manages incremental backups of the database.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The sub-command of the backup operation.
+    #[command(subcommand)]
+    pub subcommand: SubCommands,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum SubCommands {
+    /// Creates a new backup of the database.
+    ///
+    /// The database is opened in the read-only mode, so the backup can be created
+    /// while the node is running. Only the files created since the previous backup
+    /// in the same directory are copied.
+    Create {
+        /// The path to the database.
+        #[clap(
+            name = "DB_PATH",
+            long = "db-path",
+            value_parser,
+            default_value = default_db_path().into_os_string()
+        )]
+        database_path: PathBuf,
+        /// The directory with backups.
+        #[clap(long = "backup-path")]
+        backup_path: PathBuf,
+        /// The number of the newest backups to keep after creating the backup.
+        /// All backups are kept if not set.
+        #[clap(long = "retention")]
+        retention: Option<usize>,
+    },
+    /// Lists the backups from the oldest to the newest.
+    List {
+        /// The directory with backups.
+        #[clap(long = "backup-path")]
+        backup_path: PathBuf,
+    },
+    /// Restores the backup into a new database directory.
+    Restore {
+        /// The directory with backups.
+        #[clap(long = "backup-path")]
+        backup_path: PathBuf,
+        /// The path to the restored database. The directory must not exist.
+        #[clap(name = "DB_PATH", long = "db-path", value_parser)]
+        database_path: PathBuf,
+        /// The backup to restore. The latest backup is restored if not set.
+        #[clap(long = "backup-id")]
+        backup_id: Option<u32>,
+    },
+    /// Verifies that the files of the backup are not missing or corrupted.
+    Verify {
+        /// The directory with backups.
+        #[clap(long = "backup-path")]
+        backup_path: PathBuf,
+        /// The backup to verify. The latest backup is verified if not set.
+        #[clap(long = "backup-id")]
+        backup_id: Option<u32>,
+    },
+}
+
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    match command.subcommand {
+        SubCommands::Create {
+            database_path,
+            backup_path,
+            retention,
+        } => {
+            let store = BackupStore::new(backup_path);
+            let backup = store.create_from_path(&database_path)?;
+            tracing::info!("Created the backup {}", describe(&backup));
+
+            if let Some(retention) = retention {
+                for removed in store.retain(retention)? {
+                    tracing::info!("Removed the old backup {}", describe(&removed));
+                }
+            }
+        }
+        SubCommands::List { backup_path } => {
+            let backups = BackupStore::new(backup_path).list()?;
+            if backups.is_empty() {
+                println!("No backups");
+            }
+            for backup in backups {
+                println!("{}", describe(&backup));
+            }
+        }
+        SubCommands::Restore {
+            backup_path,
+            database_path,
+            backup_id,
+        } => {
+            let backup =
+                BackupStore::new(backup_path).restore(backup_id, &database_path)?;
+            tracing::info!(
+                "Restored the backup {} into `{}`",
+                describe(&backup),
+                database_path.display()
+            );
+        }
+        SubCommands::Verify {
+            backup_path,
+            backup_id,
+        } => {
+            let backup = BackupStore::new(backup_path).verify(backup_id)?;
+            tracing::info!("The backup {} is valid", describe(&backup));
+        }
+    }
+
+    Ok(())
+}
+
+fn describe(backup: &Backup) -> String {
+    let height = match backup.height() {
+        Some(height) => format!("height {height}"),
+        None => "no height".to_string(),
+    };
+    let created_at = UNIX_EPOCH
+        .checked_add(Duration::from_secs(backup.created_at))
+        .map(humantime::format_rfc3339_seconds);
+    match created_at {
+        Some(created_at) => format!("{}: {height}, created at {created_at}", backup.id),
+        None => format!("{}: {height}", backup.id),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_restore_command() {
+        // When
+        let command = Command::try_parse_from([
+            "backup",
+            "restore",
+            "--backup-path",
+            "/backups",
+            "--db-path",
+            "/db",
+            "--backup-id",
+            "3",
+        ])
+        .unwrap();
+
+        // Then
+        let SubCommands::Restore {
+            backup_path,
+            database_path,
+            backup_id,
+        } = command.subcommand
+        else {
+            panic!("Expected the restore command")
+        };
+        assert_eq!(backup_path, PathBuf::from("/backups"));
+        assert_eq!(database_path, PathBuf::from("/db"));
+        assert_eq!(backup_id, Some(3));
+    }
+
+    #[test]
+    fn restore_command_requires_db_path() {
+        // When
+        let result =
+            Command::try_parse_from(["backup", "restore", "--backup-path", "/backups"]);
+
+        // Then
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,198 @@
+use crate::cli::default_db_path;
+use anyhow::Context;
+use clap::{
+    Parser,
+    Subcommand,
+};
+use fuel_core::{
+    combined_database::{
+        CombinedDatabase,
+        integrity::{
+            CheckReport,
+            IntegrityReport,
+        },
+    },
+    state::{
+        historical_rocksdb::StateRewindPolicy,
+        rocks_db::{
+            ColumnsPolicy,
+            DatabaseConfig,
+        },
+    },
+};
+use rlimit::{
+    Resource,
+    getrlimit,
+};
+use std::path::PathBuf;
+
+/// Inspects and maintains the database of the stopped node.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The sub-command of the database operation.
+    #[command(subcommand)]
+    pub subcommand: SubCommands,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum SubCommands {
+    /// Verifies the integrity of the database.
+    ///
+    /// Recomputes the Merkle roots of the blocks and contracts, verifies the
+    /// off-chain indexes against the on-chain state and checks that the heights
+    /// of the databases line up. Exits with an error if any issue is found.
+    Check {
+        /// The path to the database.
+        #[clap(
+            name = "DB_PATH",
+            long = "db-path",
+            value_parser,
+            default_value = default_db_path().into_os_string()
+        )]
+        database_path: PathBuf,
+
+        /// Defines a specific number of file descriptors that RocksDB can use.
+        ///
+        /// If defined as -1 no limit will be applied and will use the OS limits.
+        /// If not defined the system default divided by two is used.
+        #[clap(
+            long = "rocksdb-max-fds",
+            env,
+            default_value = get_default_max_fds().to_string()
+        )]
+        rocksdb_max_fds: i32,
+
+        /// Rebuilds the off-chain indexes from the on-chain state
+        /// if the check finds issues in them.
+        #[clap(long = "repair")]
+        repair: bool,
+
+        /// Prints the report in the JSON format.
+        #[clap(long = "json")]
+        json: bool,
+    },
+}
+
+fn get_default_max_fds() -> i32 {
+    getrlimit(Resource::NOFILE)
+        .map(|(_, hard)| i32::try_from(hard.saturating_div(2)).unwrap_or(i32::MAX))
+        .expect("Our supported platforms should return max FD.")
+}
+
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    match command.subcommand {
+        SubCommands::Check {
+            database_path,
+            rocksdb_max_fds,
+            repair,
+            json,
+        } => {
+            let path = database_path.as_path();
+            let db = CombinedDatabase::open(
+                path,
+                StateRewindPolicy::RewindFullRange,
+                DatabaseConfig {
+                    cache_capacity: Some(64 * 1024 * 1024),
+                    max_fds: rocksdb_max_fds,
+                    columns_policy: ColumnsPolicy::Lazy,
+                },
+            )
+            .map_err(Into::<anyhow::Error>::into)
+            .context(format!("failed to open combined database at path {path:?}"))?;
+
+            let mut report = db.check_integrity()?;
+            if repair && !report.is_consistent() {
+                if !report.is_repairable() {
+                    print_report(&report, json)?;
+                    return Err(anyhow::anyhow!(
+                        "The database has issues outside of the off-chain indexes, \
+                        which can't be repaired"
+                    ));
+                }
+                tracing::info!("Rebuilding the off-chain indexes");
+                db.rebuild_indexes()?;
+                report = db.check_integrity()?;
+            }
+
+            print_report(&report, json)?;
+            if !report.is_consistent() {
+                return Err(anyhow::anyhow!("The database is inconsistent"));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn print_report(report: &IntegrityReport, json: bool) -> anyhow::Result<()> {
+    if json {
+        println!("{}", serde_json::to_string_pretty(report)?);
+        return Ok(());
+    }
+
+    for check in &report.checks {
+        println!("{}", describe(check));
+        for issue in &check.issues {
+            println!("    issue: {issue}");
+        }
+        let unlisted = check
+            .issues_count
+            .saturating_sub(u64::try_from(check.issues.len()).unwrap_or(u64::MAX));
+        if unlisted > 0 {
+            println!("    ... and {unlisted} more issues");
+        }
+        for note in &check.notes {
+            println!("    note: {note}");
+        }
+    }
+    Ok(())
+}
+
+fn describe(check: &CheckReport) -> String {
+    let status = if check.issues_count == 0 {
+        "ok".to_string()
+    } else {
+        format!("{} issues", check.issues_count)
+    };
+    format!("{}: {status} ({} checked)", check.name, check.checked)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_check_command() {
+        // When
+        let command = Command::try_parse_from([
+            "db",
+            "check",
+            "--db-path",
+            "/db",
+            "--repair",
+            "--json",
+        ])
+        .unwrap();
+
+        // Then
+        let SubCommands::Check {
+            database_path,
+            repair,
+            json,
+            ..
+        } = command.subcommand;
+        assert_eq!(database_path, PathBuf::from("/db"));
+        assert!(repair);
+        assert!(json);
+    }
+
+    #[test]
+    fn check_command_is_read_only_by_default() {
+        // When
+        let command = Command::try_parse_from(["db", "check"]).unwrap();
+
+        // Then
+        let SubCommands::Check { repair, .. } = command.subcommand;
+        assert!(!repair);
+    }
+}
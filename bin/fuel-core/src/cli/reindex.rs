@@ -0,0 +1,176 @@
+use crate::cli::default_db_path;
+use anyhow::Context;
+use clap::{
+    Parser,
+    ValueEnum,
+};
+use fuel_core::{
+    combined_database::{
+        CombinedDatabase,
+        reindex::ReindexProgress,
+    },
+    database::database_description::IndexationKind,
+    state::{
+        historical_rocksdb::StateRewindPolicy,
+        rocks_db::{
+            ColumnsPolicy,
+            DatabaseConfig,
+        },
+    },
+};
+use rlimit::{
+    Resource,
+    getrlimit,
+};
+use std::path::PathBuf;
+
+/// Rebuilds the off-chain indexes from the on-chain data of the stopped node.
+///
+/// The indexes are rebuilt from the unspent coins and messages and the stored
+/// blocks, without resyncing the chain. The interrupted reindexation continues
+/// from the last batch on the next run. Once an index is rebuilt, the node serves
+/// it after the restart. The off-chain database must be at the on-chain height.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The path to the database.
+    #[clap(
+        name = "DB_PATH",
+        long = "db-path",
+        value_parser,
+        default_value = default_db_path().into_os_string()
+    )]
+    pub database_path: PathBuf,
+
+    /// Defines a specific number of file descriptors that RocksDB can use.
+    ///
+    /// If defined as -1 no limit will be applied and will use the OS limits.
+    /// If not defined the system default divided by two is used.
+    #[clap(
+        long = "rocksdb-max-fds",
+        env,
+        default_value = get_default_max_fds().to_string()
+    )]
+    pub rocksdb_max_fds: i32,
+
+    /// The index to rebuild. Can be repeated to rebuild several indexes in parallel.
+    #[clap(long = "index", value_enum, required = true)]
+    pub indexes: Vec<Index>,
+
+    /// The number of entries indexed in one database commit.
+    #[clap(long = "batch-size", default_value = "1000")]
+    pub batch_size: usize,
+}
+
+/// The off-chain index that can be rebuilt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Index {
+    Balances,
+    #[value(alias = "coins_to_spend")]
+    CoinsToSpend,
+    #[value(alias = "asset_metadata")]
+    AssetMetadata,
+    #[value(alias = "contract_receipts")]
+    ContractReceipts,
+}
+
+impl From<Index> for IndexationKind {
+    fn from(index: Index) -> Self {
+        match index {
+            Index::Balances => IndexationKind::Balances,
+            Index::CoinsToSpend => IndexationKind::CoinsToSpend,
+            Index::AssetMetadata => IndexationKind::AssetMetadata,
+            Index::ContractReceipts => IndexationKind::ContractReceipts,
+        }
+    }
+}
+
+/// The number of batches between progress reports.
+const PROGRESS_INTERVAL: u64 = 10;
+
+fn get_default_max_fds() -> i32 {
+    getrlimit(Resource::NOFILE)
+        .map(|(_, hard)| i32::try_from(hard.saturating_div(2)).unwrap_or(i32::MAX))
+        .expect("Our supported platforms should return max FD.")
+}
+
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    use crate::cli::ShutdownListener;
+
+    let path = command.database_path.as_path();
+    let db = CombinedDatabase::open(
+        path,
+        StateRewindPolicy::RewindFullRange,
+        DatabaseConfig {
+            cache_capacity: Some(64 * 1024 * 1024),
+            max_fds: command.rocksdb_max_fds,
+            columns_policy: ColumnsPolicy::Lazy,
+        },
+    )
+    .map_err(Into::<anyhow::Error>::into)
+    .context(format!("failed to open combined database at path {path:?}"))?;
+
+    let kinds = command
+        .indexes
+        .into_iter()
+        .map(IndexationKind::from)
+        .collect::<Vec<_>>();
+    let batch_size = u64::try_from(command.batch_size).unwrap_or(u64::MAX);
+    let shutdown_listener = ShutdownListener::spawn();
+    db.reindex(&kinds, command.batch_size, &shutdown_listener, |progress| {
+        let batches = progress.processed.div_ceil(batch_size.max(1));
+        if progress.done || batches.checked_rem(PROGRESS_INTERVAL) == Some(0) {
+            report_progress(progress);
+        }
+    })?;
+
+    tracing::info!(
+        "Reindexation is complete, the indexes are served after the node restart"
+    );
+    Ok(())
+}
+
+fn report_progress(progress: &ReindexProgress) {
+    let status = if progress.done { "done" } else { "in progress" };
+    tracing::info!(
+        "Reindexation of the {:?} index from the {:?}: {status}, {} processed",
+        progress.kind,
+        progress.source,
+        progress.processed
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_several_indexes() {
+        // When
+        let command = Command::try_parse_from([
+            "reindex",
+            "--index",
+            "balances",
+            "--index",
+            "coins_to_spend",
+            "--index",
+            "asset-metadata",
+        ])
+        .unwrap();
+
+        // Then
+        assert_eq!(
+            command.indexes,
+            vec![Index::Balances, Index::CoinsToSpend, Index::AssetMetadata]
+        );
+        assert_eq!(command.batch_size, 1000);
+    }
+
+    #[test]
+    fn requires_the_index() {
+        // When
+        let result = Command::try_parse_from(["reindex"]);
+
+        // Then
+        assert!(result.is_err());
+    }
+}
@@ -2,7 +2,11 @@ use crate::cli::default_db_path;
 use anyhow::Context;
 use clap::Parser;
 use fuel_core::{
-    combined_database::CombinedDatabase,
+    combined_database::{
+        CombinedDatabase,
+        DatabaseRollback,
+        RollbackPlan,
+    },
     state::{
         historical_rocksdb::StateRewindPolicy,
         rocks_db::{
@@ -43,8 +47,16 @@ pub struct Command {
     /// The path to the database.
     #[clap(long = "target-block-height")]
     pub target_block_height: u32,
+
+    /// Prints the heights and databases that the rollback would change
+    /// without modifying them.
+    #[clap(long = "dry-run")]
+    pub dry_run: bool,
 }
 
+/// The number of rolled back blocks between progress reports.
+const PROGRESS_INTERVAL: u32 = 100;
+
 fn get_default_max_fds() -> i32 {
     getrlimit(Resource::NOFILE)
         .map(|(_, hard)| i32::try_from(hard.saturating_div(2)).unwrap_or(i32::MAX))
@@ -67,10 +79,76 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
     .map_err(Into::<anyhow::Error>::into)
     .context(format!("failed to open combined database at path {path:?}"))?;
 
-    let mut shutdown_listener = ShutdownListener::spawn();
     let target_block_height = command.target_block_height.into();
 
-    db.rollback_to(target_block_height, &mut shutdown_listener)?;
+    if command.dry_run {
+        let plan = db.rollback_plan(target_block_height)?;
+        print_plan(&plan);
+        return Ok(());
+    }
+
+    let mut shutdown_listener = ShutdownListener::spawn();
+    let mut last_reported: Option<u32> = None;
+    db.rollback_to_with_progress(target_block_height, &mut shutdown_listener, |plan| {
+        let remaining = plan.remaining_blocks();
+        let report = match last_reported {
+            None => true,
+            Some(last) => {
+                plan.is_done() || last.saturating_sub(remaining) >= PROGRESS_INTERVAL
+            }
+        };
+        if report {
+            last_reported = Some(remaining);
+            report_progress(plan);
+        }
+    })?;
+
+    db.verify_tip(target_block_height)
+        .context("The rollback left the databases out of step")?;
+    tracing::info!(
+        "Rollback to the height {target_block_height} is complete, \
+        all databases agree on the tip"
+    );
 
     Ok(())
 }
+
+fn print_plan(plan: &RollbackPlan) {
+    println!(
+        "Dry run of the rollback to the height {}:",
+        plan.target_block_height
+    );
+    for (name, rollback) in plan.databases() {
+        println!("  {name}: {}", describe(&rollback));
+    }
+    match plan.relayer {
+        Some(height) => println!("  relayer: unchanged at DA height {height}"),
+        None => println!("  relayer: unchanged, no DA height"),
+    }
+}
+
+fn report_progress(plan: &RollbackPlan) {
+    for (name, rollback) in plan.databases() {
+        tracing::info!(
+            "Rollback of the {name} database to the height {}: {}",
+            plan.target_block_height,
+            describe(&rollback)
+        );
+    }
+}
+
+fn describe(rollback: &DatabaseRollback) -> String {
+    match rollback {
+        DatabaseRollback::Rollback { from, to } => {
+            format!("{from} -> {to} ({} blocks)", rollback.blocks())
+        }
+        DatabaseRollback::Unchanged {
+            height: Some(height),
+        } => {
+            format!("unchanged at {height}")
+        }
+        DatabaseRollback::Unchanged { height: None } => {
+            "unchanged, no height".to_string()
+        }
+    }
+}
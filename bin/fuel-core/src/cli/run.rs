@@ -8,7 +8,12 @@ use crate::{
         run::{
             consensus::PoATriggerArgs,
             graphql::GraphQLArgs,
-            tx_pool::TxPoolArgs,
+            tx_pool::{
+                TX_POOL_BLACKLIST_FILE,
+                TX_POOL_JOURNAL_FILE,
+                TxPoolArgs,
+                TxSelectionAlgorithmArg,
+            },
             tx_status_manager::TxStatusManagerArgs,
         },
     },
@@ -32,6 +37,8 @@ use fuel_core::{
         RelayerConsensusConfig,
         config::{
             DaCompressionMode,
+            FileLeaderLease,
+            LeaderLeaseConfig,
             Trigger,
         },
         genesis::NotifyCancel,
@@ -46,7 +53,9 @@ use fuel_core::{
         Config as TxPoolConfig,
         HeavyWorkConfig,
         PoolLimits,
+        RbfPolicy,
         ServiceChannelLimits,
+        TxSelectionAlgorithm,
     },
     types::{
         fuel_tx::ContractId,
@@ -65,7 +74,13 @@ use fuel_core_metrics::config::{
 };
 use fuel_core_types::{
     blockchain::header::StateTransitionBytecodeVersion,
-    signer::SignMode,
+    signer::{
+        SignMode,
+        remote::{
+            RemoteSigner,
+            RemoteSignerEndpoint,
+        },
+    },
 };
 use pyroscope::{
     PyroscopeAgent,
@@ -88,6 +103,7 @@ use std::{
     },
     path::PathBuf,
     str::FromStr,
+    sync::Arc,
     time::Duration,
 };
 use tracing::{
@@ -96,6 +112,8 @@ use tracing::{
     warn,
 };
 
+#[cfg(feature = "backup")]
+use fuel_core::service::config::BackupSchedulerConfig;
 #[cfg(feature = "rocksdb")]
 use fuel_core::state::historical_rocksdb::StateRewindPolicy;
 
@@ -180,6 +198,17 @@ pub struct Command {
     #[arg(name = "SNAPSHOT", long = "snapshot", env)]
     pub snapshot: Option<PathBuf>,
 
+    /// Diff snapshots applied on top of the `--snapshot`, in the provided order.
+    /// Each diff must be based on the height of the snapshot it is applied to.
+    #[arg(
+        name = "SNAPSHOT_DIFF",
+        long = "snapshot-diff",
+        env,
+        value_delimiter = ',',
+        requires = "SNAPSHOT"
+    )]
+    pub snapshot_diff: Vec<PathBuf>,
+
     /// Prunes the db. Genesis is done from the provided snapshot or the local testnet
     /// configuration.
     #[arg(name = "DB_PRUNE", long = "db-prune", env, default_value = "false")]
@@ -232,10 +261,33 @@ pub struct Command {
     /// Use [AWS KMS](https://docs.aws.amazon.com/kms/latest/APIReference/Welcome.html)for signing blocks.
     /// Loads the AWS credentials and configuration from the environment.
     /// Takes key_id as an argument, e.g. key ARN works.
-    #[arg(long = "consensus-aws-kms", env, conflicts_with = "consensus_key")]
+    #[arg(
+        long = "consensus-aws-kms",
+        env,
+        conflicts_with_all = ["consensus_key", "consensus_remote_signer"]
+    )]
     #[cfg(feature = "aws-kms")]
     pub consensus_aws_kms: Option<String>,
 
+    /// Use an external signer process for signing blocks and pre-confirmation delegate keys.
+    /// Takes the endpoint of the signer, either `tcp://<address>` or `unix://<path>`.
+    /// TCP is accepted only for the loopback address, because the connection isn't encrypted.
+    #[arg(
+        long = "consensus-remote-signer",
+        env,
+        conflicts_with = "consensus_key",
+        requires = "consensus_remote_signer_token"
+    )]
+    pub consensus_remote_signer: Option<RemoteSignerEndpoint>,
+
+    /// The token presented to the remote signer with every request.
+    /// Setting via the `CONSENSUS_REMOTE_SIGNER_TOKEN` ENV var is preferred.
+    #[arg(
+        long = "consensus-remote-signer-token",
+        env = "CONSENSUS_REMOTE_SIGNER_TOKEN"
+    )]
+    pub consensus_remote_signer_token: Option<String>,
+
     /// If given, the node will produce and store da-compressed blocks
     /// with the given retention time.
     #[arg(long = "da-compression", env)]
@@ -313,6 +365,48 @@ pub struct Command {
     #[clap(long = "production-timeout", default_value = "20s", env)]
     pub production_timeout: humantime::Duration,
 
+    /// The path to the leader lease file shared by the producer nodes.
+    /// Only the node holding the lease produces blocks, others stay in standby
+    /// and take over once the lease lapses. The filesystem must support file locks.
+    #[clap(long = "leader-lease-file", requires = "leader_lease_holder_id", env)]
+    pub leader_lease_file: Option<PathBuf>,
+
+    /// The unique identifier of this node in the leader lease.
+    #[clap(long = "leader-lease-holder-id", requires = "leader_lease_file", env)]
+    pub leader_lease_holder_id: Option<String>,
+
+    /// How long the leader lease stays valid without renewal.
+    #[clap(long = "leader-lease-duration", default_value = "10s", env)]
+    pub leader_lease_duration: humantime::Duration,
+
+    /// How often the leader renews the lease, and the standby tries to acquire it.
+    #[clap(long = "leader-lease-renew-interval", default_value = "3s", env)]
+    pub leader_lease_renew_interval: humantime::Duration,
+
+    /// The directory with incremental backups taken periodically by the node.
+    /// The backups are disabled if not set.
+    #[cfg(feature = "backup")]
+    #[clap(long = "backup-dir", env)]
+    pub backup_dir: Option<PathBuf>,
+
+    /// The interval between the periodic backups.
+    #[cfg(feature = "backup")]
+    #[clap(long = "backup-interval", default_value = "1h", env)]
+    pub backup_interval: humantime::Duration,
+
+    /// The number of the newest periodic backups to keep.
+    /// All backups are kept if not set.
+    #[cfg(feature = "backup")]
+    #[clap(long = "backup-retention", requires = "backup_dir", env)]
+    pub backup_retention: Option<usize>,
+
+    /// The number of the latest blocks which keep their transactions, statuses
+    /// and receipts. The older blocks are pruned in the background, keeping only
+    /// their headers and the block Merkle tree. The state rewind history is limited
+    /// by the same number of blocks. Nothing is pruned if not set.
+    #[clap(long = "block-retention", env)]
+    pub block_retention: Option<u32>,
+
     /// The size of the memory pool in number of `MemoryInstance`s.
     #[clap(long = "memory-pool-size", default_value = "32", env)]
     pub memory_pool_size: usize,
@@ -335,6 +429,7 @@ impl Command {
             state_rewind_duration,
             db_prune,
             snapshot,
+            snapshot_diff,
             continue_on_error,
             vm_backtrace: _,
             debug,
@@ -347,6 +442,8 @@ impl Command {
             consensus_key,
             #[cfg(feature = "aws-kms")]
             consensus_aws_kms,
+            consensus_remote_signer,
+            consensus_remote_signer_token,
             da_compression,
             da_compression_starting_height,
             poa_trigger,
@@ -371,6 +468,17 @@ impl Command {
             min_connected_reserved_peers,
             time_until_synced,
             production_timeout,
+            leader_lease_file,
+            leader_lease_holder_id,
+            leader_lease_duration,
+            leader_lease_renew_interval,
+            #[cfg(feature = "backup")]
+            backup_dir,
+            #[cfg(feature = "backup")]
+            backup_interval,
+            #[cfg(feature = "backup")]
+            backup_retention,
+            block_retention,
             memory_pool_size,
             profiling: _,
         } = self;
@@ -412,10 +520,13 @@ impl Command {
                 SnapshotReader::open(metadata)?
             }
         };
+        let snapshot_reader = snapshot_diff
+            .iter()
+            .try_fold(snapshot_reader, |reader, diff| reader.with_diff(diff))?;
         let chain_config = snapshot_reader.chain_config();
 
         #[cfg(feature = "relayer")]
-        let relayer_cfg = relayer_args.into_config();
+        let relayer_cfg = relayer_args.into_config()?;
 
         #[cfg(feature = "p2p")]
         let p2p_cfg = p2p_args.into_config(
@@ -434,7 +545,8 @@ impl Command {
                     .echo_delegation_interval,
             };
 
-        let trigger: Trigger = poa_trigger.into();
+        let trigger =
+            poa_trigger.into_trigger(chain_config.consensus_parameters.block_gas_limit());
 
         if trigger != Trigger::Never {
             info!("Block production mode: {:?}", &trigger);
@@ -442,6 +554,42 @@ impl Command {
             info!("Block production disabled");
         }
 
+        let leader_lease = match (leader_lease_file, leader_lease_holder_id) {
+            (Some(path), Some(holder_id)) => {
+                if *leader_lease_renew_interval >= *leader_lease_duration {
+                    anyhow::bail!(
+                        "The leader lease renew interval must be shorter than the lease duration"
+                    );
+                }
+                info!("Leader lease is kept in {:?} by {}", path, holder_id);
+                Some(LeaderLeaseConfig {
+                    lease: Arc::new(FileLeaderLease::new(
+                        path,
+                        holder_id,
+                        leader_lease_duration.into(),
+                    )),
+                    renew_interval: leader_lease_renew_interval.into(),
+                })
+            }
+            _ => None,
+        };
+
+        #[cfg(feature = "backup")]
+        let backup = backup_dir.map(|backup_dir| {
+            info!("Backups are taken every {backup_interval} into {backup_dir:?}");
+            BackupSchedulerConfig {
+                backup_dir,
+                interval: backup_interval.into(),
+                retention: backup_retention,
+            }
+        });
+
+        if let Some(block_retention) = block_retention {
+            info!(
+                "The transactions of the blocks older than {block_retention} blocks are pruned"
+            );
+        }
+
         let mut consensus_signer = SignMode::Unavailable;
 
         #[cfg(feature = "aws-kms")]
@@ -465,6 +613,13 @@ impl Command {
             };
         }
 
+        if let (Some(endpoint), Some(token)) =
+            (consensus_remote_signer, consensus_remote_signer_token)
+        {
+            let signer = RemoteSigner::connect(endpoint, token).await?;
+            consensus_signer = SignMode::Remote(signer);
+        }
+
         if matches!(consensus_signer, SignMode::Unavailable) {
             if let Some(consensus_key) = consensus_key {
                 let key = SecretKey::from_str(&consensus_key)
@@ -520,7 +675,17 @@ impl Command {
                 tracing::warn!("State rewind policy is only supported with RocksDB");
             }
 
-            let blocks = state_rewind_duration.as_secs();
+            let mut blocks = state_rewind_duration.as_secs();
+            if let Some(block_retention) = block_retention {
+                let block_retention = u64::from(block_retention);
+                if blocks > block_retention {
+                    tracing::warn!(
+                        "The state rewind history is limited to {block_retention} blocks \
+                        by the block retention"
+                    );
+                    blocks = block_retention;
+                }
+            }
 
             if blocks == 0 {
                 StateRewindPolicy::NoRewind
@@ -538,6 +703,12 @@ impl Command {
             }
         };
 
+        let tx_pool_journal_path =
+            tx_pool_journal.then(|| database_path.join(TX_POOL_JOURNAL_FILE));
+        // The in-memory node doesn't keep anything between restarts.
+        let tx_pool_black_list_path = (database_type == DbType::RocksDb)
+            .then(|| database_path.join(TX_POOL_BLACKLIST_FILE));
+
         let combined_db_config = CombinedDatabaseConfig {
             database_path,
             database_type,
@@ -590,6 +761,12 @@ impl Command {
             tx_max_pending_write_requests,
             tx_pending_pool_ttl,
             tx_pending_pool_size_percentage,
+            tx_selection_algorithm,
+            tx_max_gas_per_owner,
+            tx_rbf_min_tip_bump_percentage,
+            tx_rbf_max_replaced_subtree_size,
+            tx_rbf_require_total_fee_increase,
+            tx_pool_journal,
         } = tx_pool;
 
         let TxStatusManagerArgs {
@@ -623,6 +800,21 @@ impl Command {
             max_pending_write_pool_requests: tx_max_pending_write_requests,
         };
 
+        let rbf_policy = RbfPolicy {
+            min_tip_bump_percentage: tx_rbf_min_tip_bump_percentage,
+            max_replaced_subtree_size: tx_rbf_max_replaced_subtree_size,
+            require_total_fee_increase: tx_rbf_require_total_fee_increase,
+        };
+
+        let tx_selection_algorithm = match tx_selection_algorithm {
+            TxSelectionAlgorithmArg::RatioTipGas => TxSelectionAlgorithm::RatioTipGas,
+            TxSelectionAlgorithmArg::Fifo => TxSelectionAlgorithm::Fifo,
+            TxSelectionAlgorithmArg::FairByOwner => TxSelectionAlgorithm::FairByOwner {
+                max_gas_per_owner: tx_max_gas_per_owner
+                    .context("`--tx-max-gas-per-owner` is required by `fair-by-owner`")?,
+            },
+        };
+
         let gas_price_config = GasPriceConfig {
             starting_exec_gas_price: gas_price.starting_gas_price,
             exec_gas_price_change_percent: gas_price.gas_price_change_percent,
@@ -700,6 +892,7 @@ impl Command {
                 required_fuel_block_height_timeout: graphql
                     .required_fuel_block_height_timeout
                     .into(),
+                admin_api_token: graphql.admin_api_token,
             },
             combined_db_config,
             snapshot_reader,
@@ -718,11 +911,15 @@ impl Command {
                 ttl_check_interval: tx_ttl_check_interval.into(),
                 utxo_validation,
                 black_list,
+                black_list_path: tx_pool_black_list_path,
                 pool_limits,
                 heavy_work: pool_heavy_work_config,
                 service_channel_limits,
                 pending_pool_tx_ttl: tx_pending_pool_ttl.into(),
                 max_pending_pool_size_percentage: tx_pending_pool_size_percentage,
+                selection_algorithm: tx_selection_algorithm,
+                rbf_policy,
+                journal_path: tx_pool_journal_path,
                 metrics: metrics.is_enabled(Module::TxPool),
             },
             block_producer: ProducerConfig {
@@ -748,6 +945,10 @@ impl Command {
             min_connected_reserved_peers,
             time_until_synced: time_until_synced.into(),
             production_timeout: production_timeout.into(),
+            leader_lease,
+            #[cfg(feature = "backup")]
+            backup,
+            block_retention,
             memory_pool_size,
             tx_status_manager: TxStatusManagerConfig {
                 max_tx_update_subscriptions: tx_number_active_subscriptions,
@@ -933,4 +1134,42 @@ mod tests {
             \n\nFor more information, try '--help'.\n"
         );
     }
+
+    #[test]
+    fn parse_consensus_remote_signer__rejects_tcp_on_non_loopback_address() {
+        // Given
+        let args = [
+            "--consensus-remote-signer",
+            "tcp://10.0.0.1:4100",
+            "--consensus-remote-signer-token",
+            "token",
+        ];
+
+        // When
+        let command = parse_command(&args);
+
+        // Then
+        let err = command.expect_err("should fail to parse");
+        assert!(err.to_string().contains("not a loopback address"), "{err}");
+    }
+
+    #[test]
+    fn parse_consensus_remote_signer__accepts_tcp_on_loopback_address() {
+        // Given
+        let args = [
+            "--consensus-remote-signer",
+            "tcp://127.0.0.1:4100",
+            "--consensus-remote-signer-token",
+            "token",
+        ];
+
+        // When
+        let command = parse_command(&args).unwrap();
+
+        // Then
+        assert_eq!(
+            command.consensus_remote_signer,
+            Some(RemoteSignerEndpoint::Tcp("127.0.0.1:4100".to_string()))
+        );
+    }
 }
@@ -15,12 +15,33 @@ pub struct PoATriggerArgs {
     interval: Interval,
     #[clap(flatten)]
     open: Open,
+    #[clap(flatten)]
+    hybrid: Hybrid,
 }
 
-// Convert from arg struct to PoATrigger enum
-impl From<PoATriggerArgs> for PoATrigger {
-    fn from(value: PoATriggerArgs) -> Self {
-        match value {
+impl PoATriggerArgs {
+    /// Converts the arguments into the `PoATrigger`. The `block_gas_limit` is used to
+    /// turn the gas threshold percentage of the hybrid trigger into the absolute value.
+    pub fn into_trigger(self, block_gas_limit: u64) -> PoATrigger {
+        match self {
+            PoATriggerArgs {
+                hybrid:
+                    Hybrid {
+                        max_latency: Some(max_latency),
+                        gas_threshold_percent,
+                        tx_count_threshold,
+                    },
+                ..
+            } => PoATrigger::Hybrid {
+                max_latency: max_latency.into(),
+                gas_threshold: gas_threshold_percent.map(|percent| {
+                    let threshold = u128::from(block_gas_limit)
+                        .saturating_mul(u128::from(percent))
+                        / 100;
+                    u64::try_from(threshold).unwrap_or(u64::MAX)
+                }),
+                tx_count_threshold,
+            },
             PoATriggerArgs {
                 open: Open { period: Some(p) },
                 ..
@@ -44,7 +65,7 @@ impl From<PoATriggerArgs> for PoATrigger {
 
 #[derive(Debug, Clone, clap::Args)]
 #[clap(
-    group = ArgGroup::new("instant-mode").args(&["instant"]).conflicts_with_all(&["interval-mode", "open-mode"]),
+    group = ArgGroup::new("instant-mode").args(&["instant"]).conflicts_with_all(&["interval-mode", "open-mode", "hybrid-mode"]),
 )]
 struct Instant {
     /// Use instant block production mode.
@@ -62,7 +83,7 @@ enum Boolean {
 
 #[derive(Debug, Clone, clap::Args)]
 #[clap(
-    group = ArgGroup::new("interval-mode").args(&["block_time"]).conflicts_with_all(&["instant-mode", "open-mode"]),
+    group = ArgGroup::new("interval-mode").args(&["block_time"]).conflicts_with_all(&["instant-mode", "open-mode", "hybrid-mode"]),
 )]
 struct Interval {
     /// Interval trigger option.
@@ -74,7 +95,7 @@ struct Interval {
 
 #[derive(Debug, Clone, clap::Args)]
 #[clap(
-    group = ArgGroup::new("open-mode").args(&["period"]).conflicts_with_all(&["instant-mode", "interval-mode"]),
+    group = ArgGroup::new("open-mode").args(&["period"]).conflicts_with_all(&["instant-mode", "interval-mode", "hybrid-mode"]),
 )]
 struct Open {
     /// Opens the block production immediately and keeps it open for the specified period.
@@ -84,6 +105,32 @@ struct Open {
     pub period: Option<Duration>,
 }
 
+#[derive(Debug, Clone, clap::Args)]
+#[clap(
+    group = ArgGroup::new("hybrid-mode").args(&["max_latency"]).conflicts_with_all(&["instant-mode", "interval-mode", "open-mode"]),
+)]
+struct Hybrid {
+    /// Hybrid trigger option.
+    /// Produces a block as soon as the txpool reaches one of the thresholds,
+    /// or when the specified latency passes since the previous block.
+    /// Cannot be combined with other poa flags.
+    #[clap(long = "poa-hybrid-max-latency", env)]
+    pub max_latency: Option<Duration>,
+    /// The total gas of the pending transactions, in percents of the block gas limit,
+    /// that triggers the block production in the hybrid mode.
+    #[clap(
+        long = "poa-hybrid-gas-threshold-percent",
+        requires = "max_latency",
+        value_parser = clap::value_parser!(u8).range(1..=100),
+        env
+    )]
+    pub gas_threshold_percent: Option<u8>,
+    /// The number of pending transactions that triggers the block production
+    /// in the hybrid mode.
+    #[clap(long = "poa-hybrid-tx-count-threshold", requires = "max_latency", env)]
+    pub tx_count_threshold: Option<u64>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -92,6 +139,8 @@ mod tests {
     use std::time::Duration as StdDuration;
     use test_case::test_case;
 
+    const BLOCK_GAS_LIMIT: u64 = 30_000_000;
+
     #[derive(Debug, Clone, Parser)]
     pub struct Command {
         #[clap(flatten)]
@@ -104,9 +153,14 @@ mod tests {
     #[test_case(&["", "--poa-open-period=1s"] => Ok(Trigger::Open { period: StdDuration::from_secs(1)}); "uses open mode if set")]
     #[test_case(&["", "--poa-instant=true", "--poa-interval-period=1s"] => Err(()); "can't set interval and instant at the same time")]
     #[test_case(&["", "--poa-open-period=1s", "--poa-interval-period=1s"] => Err(()); "can't set open and interval at the same time")]
+    #[test_case(&["", "--poa-hybrid-max-latency=1s"] => Ok(Trigger::Hybrid { max_latency: StdDuration::from_secs(1), gas_threshold: None, tx_count_threshold: None }); "uses hybrid mode if set")]
+    #[test_case(&["", "--poa-hybrid-max-latency=1s", "--poa-hybrid-gas-threshold-percent=50", "--poa-hybrid-tx-count-threshold=10"] => Ok(Trigger::Hybrid { max_latency: StdDuration::from_secs(1), gas_threshold: Some(BLOCK_GAS_LIMIT / 2), tx_count_threshold: Some(10) }); "uses hybrid mode with thresholds")]
+    #[test_case(&["", "--poa-hybrid-tx-count-threshold=10"] => Err(()); "can't set hybrid thresholds without latency")]
+    #[test_case(&["", "--poa-hybrid-max-latency=1s", "--poa-hybrid-gas-threshold-percent=101"] => Err(()); "can't set gas threshold above 100 percents")]
+    #[test_case(&["", "--poa-hybrid-max-latency=1s", "--poa-interval-period=1s"] => Err(()); "can't set hybrid and interval at the same time")]
     fn parse(args: &[&str]) -> Result<Trigger, ()> {
         Command::try_parse_from(args)
             .map_err(|_| ())
-            .map(|c| c.trigger.into())
+            .map(|c| c.trigger.into_trigger(BLOCK_GAS_LIMIT))
     }
 }
@@ -99,6 +99,12 @@ pub struct GraphQLArgs {
     )]
     pub required_fuel_block_height_timeout: humantime::Duration,
 
+    /// The token that authorizes requests to the admin API, like updates of
+    /// the `TxPool` blacklist. Requests pass it in the `Authorization: Bearer <token>` header.
+    /// The admin API is disabled if the token is not set.
+    #[clap(long = "admin-api-token", env, hide_env_values = true)]
+    pub admin_api_token: Option<String>,
+
     #[clap(flatten)]
     pub costs: QueryCosts,
 }
@@ -219,6 +219,21 @@ pub struct SyncArgs {
     /// The maximum number of headers to request in a single batch.
     #[clap(long = "sync-header-batch-size", default_value = "10", env)]
     pub header_batch_size: u32,
+    /// The maximum number of times a batch of headers or transactions is re-requested
+    /// from other peers after a timeout or a bad response, before the import fails.
+    #[clap(long = "sync-max-batch-retries", default_value = "2", env)]
+    pub max_batch_retries: usize,
+    /// Request full blocks from peers in one round trip instead of requesting
+    /// the headers and the transactions separately.
+    /// Only peers that support the `/fuel/req_res/0.0.3` protocol can serve these requests,
+    /// the headers and the transactions are requested when there is no such peer.
+    #[clap(long = "sync-request-full-blocks", env)]
+    pub request_full_blocks: bool,
+    /// Run the node as a light client that syncs only the block headers signed by
+    /// the block producer, without the transactions and the execution of the blocks.
+    /// The transactions of a block are requested from peers when they are queried.
+    #[clap(long = "sync-headers-only", env, conflicts_with = "request_full_blocks")]
+    pub headers_only: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -253,6 +268,9 @@ impl From<SyncArgs> for fuel_core::sync::Config {
         Self {
             block_stream_buffer_size: value.block_stream_buffer_size,
             header_batch_size: value.header_batch_size as usize,
+            max_batch_retries: value.max_batch_retries,
+            request_full_blocks: value.request_full_blocks,
+            headers_only: value.headers_only,
         }
     }
 }
@@ -1,12 +1,23 @@
+use anyhow::anyhow;
 use clap::{
     Args,
     builder::ArgPredicate::IsPresent,
 };
 use fuel_core::{
-    relayer::Config,
+    relayer::{
+        Config,
+        decoder::{
+            AbiEventDecoder,
+            EventDecoderRegistry,
+        },
+    },
     types::blockchain::primitives::DaBlockHeight,
 };
 use fuel_core_types::fuel_types::Bytes20;
+use std::{
+    str::FromStr,
+    sync::Arc,
+};
 
 #[derive(Debug, Clone, Args)]
 pub struct RelayerArgs {
@@ -24,13 +35,31 @@ pub struct RelayerArgs {
     pub relayer: Option<Vec<url::Url>>,
 
     /// Ethereum contract address. Create EthAddress into fuel_types
-    #[arg(long = "relayer-v2-listening-contracts", value_delimiter = ',', env)]
-    pub eth_v2_listening_contracts: Vec<Bytes20>,
+    /// Additional events watched for the contract can be selected in the format
+    /// `ADDRESS:EVENT+EVENT`, e.g. `0x03E4...6911:deposit`.
+    /// The contract is always watched for messages and forced transactions.
+    #[arg(
+        long = "relayer-v2-listening-contracts",
+        value_delimiter = ',',
+        value_parser = ListeningContract::try_from_string,
+        env
+    )]
+    pub eth_v2_listening_contracts: Vec<ListeningContract>,
+
+    /// Additional events that can be selected for the listening contracts, in the format
+    /// `NAME=DEFINITION`, e.g. `deposit=event Deposit(bytes32 indexed to, uint256 amount)`.
+    #[arg(long = "relayer-event-abi", value_parser = parse_event_abi, env)]
+    pub event_abis: Vec<(String, String)>,
 
     /// Number of da block that the contract is deployed at.
     #[clap(long = "relayer-da-deploy-height", default_value_t = Config::DEFAULT_DA_DEPLOY_HEIGHT, env)]
     pub da_deploy_height: u64,
 
+    /// The number of DA blocks built on top of a DA block before the relayer processes it.
+    /// If not set, the relayer follows the `finalized` block of the DA node.
+    #[clap(long = "relayer-confirmation-depth", env)]
+    pub confirmation_depth: Option<u64>,
+
     /// Number of pages or blocks containing logs that
     /// should be downloaded in a single call to the da layer
     #[clap(long = "relayer-log-page-size", default_value_t = Config::DEFAULT_LOG_PAGE_SIZE, env)]
@@ -50,26 +79,85 @@ pub struct RelayerArgs {
 }
 
 impl RelayerArgs {
-    pub fn into_config(self) -> Option<Config> {
+    pub fn into_config(self) -> anyhow::Result<Option<Config>> {
         if !self.enable_relayer {
             tracing::info!("Relayer service disabled");
-            return None
+            return Ok(None)
         }
 
+        let mut event_decoders = EventDecoderRegistry::default();
+        for (name, definition) in self.event_abis {
+            let decoder = AbiEventDecoder::parse(name, &definition)?;
+            event_decoders.register(Arc::new(decoder))?;
+        }
+        let contract_events = self
+            .eth_v2_listening_contracts
+            .iter()
+            .filter_map(|contract| {
+                let events = contract.events.clone()?;
+                Some((contract.address, events))
+            })
+            .collect();
+
         let config = Config {
             da_deploy_height: DaBlockHeight(self.da_deploy_height),
             relayer: self.relayer,
-            eth_v2_listening_contracts: self.eth_v2_listening_contracts,
+            eth_v2_listening_contracts: self
+                .eth_v2_listening_contracts
+                .into_iter()
+                .map(|contract| contract.address)
+                .collect(),
+            contract_events,
+            event_decoders,
+            confirmation_depth: self.confirmation_depth,
             log_page_size: self.log_page_size,
             sync_minimum_duration: self.sync_minimum_duration.into(),
             syncing_call_frequency: self.syncing_call_frequency.into(),
             syncing_log_frequency: self.syncing_log_frequency.into(),
             metrics: false,
         };
-        Some(config)
+        Ok(Some(config))
     }
 }
 
+/// The listening contract with the optionally selected events.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListeningContract {
+    pub address: Bytes20,
+    pub events: Option<Vec<String>>,
+}
+
+impl ListeningContract {
+    pub fn try_from_string(s: &str) -> anyhow::Result<ListeningContract> {
+        let (address, events) = match s.split_once(':') {
+            Some((address, events)) => {
+                let events: Vec<_> = events
+                    .split('+')
+                    .map(|event| event.trim().to_string())
+                    .collect();
+                if events.iter().any(String::is_empty) {
+                    return Err(anyhow!(
+                        "Empty event name in the listening contract `{s}`"
+                    ))
+                }
+                (address, Some(events))
+            }
+            None => (s, None),
+        };
+        let address = Bytes20::from_str(address.trim()).map_err(|err| {
+            anyhow!("Invalid listening contract address `{address}`: {err}")
+        })?;
+        Ok(ListeningContract { address, events })
+    }
+}
+
+fn parse_event_abi(s: &str) -> anyhow::Result<(String, String)> {
+    let (name, definition) = s
+        .split_once('=')
+        .ok_or_else(|| anyhow!("Expected the event in the format `NAME=DEFINITION`"))?;
+    Ok((name.trim().to_string(), definition.trim().to_string()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -92,4 +180,59 @@ mod tests {
         let args = command.relayer;
         Ok(args.relayer)
     }
+
+    const CONTRACT: &str = "0x03E4538018285e1c03CCce2F92C9538c87606911";
+
+    #[test_case(&[""] => Ok(vec![]); "no contracts")]
+    #[test_case(&["", &format!("--relayer-v2-listening-contracts={CONTRACT}")] => Ok(vec![None]); "default events")]
+    #[test_case(&["", &format!("--relayer-v2-listening-contracts={CONTRACT}:deposit")] => Ok(vec![Some(vec!["deposit".to_string()])]); "one event")]
+    #[test_case(&["", &format!("--relayer-v2-listening-contracts={CONTRACT}:message+deposit,{CONTRACT}")] => Ok(vec![Some(vec!["message".to_string(), "deposit".to_string()]), None]); "several contracts with events")]
+    fn parse_listening_contract_events(
+        args: &[&str],
+    ) -> Result<Vec<Option<Vec<String>>>, String> {
+        let command: Command =
+            Command::try_parse_from(args).map_err(|e| e.to_string())?;
+        let args = command.relayer;
+        Ok(args
+            .eth_v2_listening_contracts
+            .into_iter()
+            .map(|contract| contract.events)
+            .collect())
+    }
+
+    #[test_case(&format!("{CONTRACT}:"); "empty event")]
+    #[test_case(&format!("{CONTRACT}:message+"); "empty second event")]
+    #[test_case("0x03:message"; "invalid address")]
+    fn parse_listening_contract_fails(arg: &str) {
+        let result = Command::try_parse_from([
+            "",
+            format!("--relayer-v2-listening-contracts={arg}").as_str(),
+        ]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn into_config_registers_event_abis() {
+        // Given
+        let command = Command::try_parse_from([
+            "",
+            "--enable-relayer",
+            "--relayer=https://test.com",
+            format!("--relayer-v2-listening-contracts={CONTRACT}:deposit").as_str(),
+            "--relayer-event-abi=deposit=event Deposit(bytes32 indexed to, uint256 amount)",
+        ])
+        .unwrap();
+
+        // When
+        let config = command.relayer.into_config().unwrap().unwrap();
+
+        // Then
+        let contract = Bytes20::from_str(CONTRACT).unwrap();
+        assert_eq!(config.eth_v2_listening_contracts, vec![contract]);
+        assert_eq!(
+            config.contract_events.get(&contract),
+            Some(&vec!["deposit".to_string()])
+        );
+        assert!(config.event_decoders.get("deposit").is_some());
+    }
 }
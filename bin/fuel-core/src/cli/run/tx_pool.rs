@@ -9,6 +9,13 @@ use fuel_core_types::{
     fuel_types::Nonce,
 };
 
+/// The name of the `TxPool` journal file inside of the database directory.
+pub const TX_POOL_JOURNAL_FILE: &str = "txpool.journal";
+
+/// The name of the file inside of the database directory that keeps
+/// the `TxPool` blacklist updated via the admin API.
+pub const TX_POOL_BLACKLIST_FILE: &str = "txpool.blacklist";
+
 #[derive(Debug, Clone, clap::Args)]
 pub struct TxPoolArgs {
     /// The max time to live of the transaction inside of the `TxPool`.
@@ -89,6 +96,55 @@ pub struct TxPoolArgs {
     /// The max percentage of the `TxPool` that can be used by the `PendingPool`.
     #[clap(long = "tx-pending-pool-size-percentage", default_value = "50", env)]
     pub tx_pending_pool_size_percentage: u16,
+
+    /// The algorithm used to select transactions from the `TxPool` for the block.
+    #[clap(
+        long = "tx-selection-algorithm",
+        default_value = "ratio-tip-gas",
+        value_enum,
+        env
+    )]
+    pub tx_selection_algorithm: TxSelectionAlgorithmArg,
+
+    /// The max gas that transactions of the same owner can take in one block.
+    /// Required by the `fair-by-owner` selection algorithm.
+    #[clap(
+        long = "tx-max-gas-per-owner",
+        required_if_eq("tx_selection_algorithm", "fair-by-owner"),
+        env
+    )]
+    pub tx_max_gas_per_owner: Option<u64>,
+
+    /// The minimum increase of the tip, in percent, required to replace
+    /// a colliding transaction in the `TxPool`. Zero disables the rule.
+    #[clap(long = "tx-rbf-min-tip-bump-percentage", default_value = "0", env)]
+    pub tx_rbf_min_tip_bump_percentage: u16,
+
+    /// The max number of transactions, including dependents, that can be removed
+    /// from the `TxPool` when a colliding transaction replaces them.
+    #[clap(long = "tx-rbf-max-replaced-subtree-size", default_value = "100", env)]
+    pub tx_rbf_max_replaced_subtree_size: usize,
+
+    /// Require the tip of the replacing transaction to be higher than
+    /// the total tip of all transactions it removes from the `TxPool`.
+    #[clap(long = "tx-rbf-require-total-fee-increase", env)]
+    pub tx_rbf_require_total_fee_increase: bool,
+
+    /// Keep the journal of accepted transactions in the database directory.
+    /// Transactions from the journal are verified and inserted into the `TxPool`
+    /// again after the restart of the node, unless their time to live is exceeded.
+    #[clap(long = "tx-pool-journal", env)]
+    pub tx_pool_journal: bool,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum TxSelectionAlgorithmArg {
+    /// Selects transactions with the highest tip/gas ratio first.
+    RatioTipGas,
+    /// Selects transactions in the order of their arrival to the `TxPool`.
+    Fifo,
+    /// Same as `fifo`, but limits the gas that one owner can take in one block.
+    FairByOwner,
 }
 
 #[cfg(test)]
@@ -148,4 +204,16 @@ mod tests {
 
         Ok(blacklist)
     }
+
+    #[test_case(&[""] => Ok(TxSelectionAlgorithmArg::RatioTipGas); "defaults to ratio tip gas")]
+    #[test_case(&["", "--tx-selection-algorithm=fifo"] => Ok(TxSelectionAlgorithmArg::Fifo); "fifo works")]
+    #[test_case(&["", "--tx-selection-algorithm=fair-by-owner", "--tx-max-gas-per-owner=1000"] => Ok(TxSelectionAlgorithmArg::FairByOwner); "fair by owner works")]
+    #[test_case(&["", "--tx-selection-algorithm=fair-by-owner"] => matches Err(_); "fair by owner requires max gas per owner")]
+    fn parse_selection_algorithm(
+        args: &[&str],
+    ) -> Result<TxSelectionAlgorithmArg, String> {
+        let command: Command =
+            Command::try_parse_from(args).map_err(|e| e.to_string())?;
+        Ok(command.tx_pool.tx_selection_algorithm)
+    }
 }
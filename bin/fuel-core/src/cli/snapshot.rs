@@ -124,6 +124,27 @@ pub enum SubCommands {
         #[clap(subcommand)]
         encoding_command: Option<EncodingCommand>,
     },
+    /// Creates a snapshot of the entries changed between two block heights.
+    ///
+    /// The diff is applied on top of the snapshot taken at the `--from` height
+    /// with the `--snapshot-diff` argument of the `run` command. Requires the
+    /// database to keep the history of modifications for the whole range.
+    #[command(arg_required_else_help = true)]
+    Diff {
+        /// The height of the base snapshot.
+        #[clap(long = "from")]
+        base_height: u32,
+        /// The height of the snapshot after applying the diff.
+        #[clap(long = "to")]
+        height: u32,
+        /// Specify a path to the chain config. Defaults used if no path
+        /// is provided.
+        #[clap(name = "CHAIN_CONFIG", long = "chain")]
+        chain_config: Option<PathBuf>,
+        /// Encoding format for the chain state files.
+        #[clap(subcommand)]
+        encoding_command: Option<EncodingCommand>,
+    },
     /// Creates a config for the contract.
     #[command(arg_required_else_help = true)]
     Contract {
@@ -144,6 +165,7 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
     use fuel_core::service::genesis::Exporter;
     use fuel_core_chain_config::{
         MAX_GROUP_SIZE,
+        SnapshotDiffMetadata,
         SnapshotWriter,
     };
 
@@ -185,6 +207,42 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
             .write_full_snapshot()
             .await
         }
+        SubCommands::Diff {
+            base_height,
+            height,
+            chain_config,
+            encoding_command,
+        } => {
+            let encoding = encoding_command
+                .map(|f| f.encoding())
+                .unwrap_or_else(|| Encoding::Json);
+
+            let group_size = encoding.group_size().unwrap_or(MAX_GROUP_SIZE);
+            let removed_dir = output_dir.join(SnapshotDiffMetadata::REMOVED_ENTRIES_DIR);
+            let create_writer = move |dir: &Path| match encoding {
+                Encoding::Json => Ok(SnapshotWriter::json(dir)),
+                #[cfg(feature = "parquet")]
+                Encoding::Parquet { compression, .. } => {
+                    SnapshotWriter::parquet(dir, compression.try_into()?)
+                }
+            };
+            let writer = {
+                let output_dir = output_dir.clone();
+                move || create_writer(&output_dir)
+            };
+            let removed_writer = move || create_writer(&removed_dir);
+
+            let metadata = Exporter::new(
+                db,
+                load_chain_config_or_use_testnet(chain_config.as_deref())?,
+                writer,
+                group_size,
+                shutdown_listener,
+            )
+            .write_diff_snapshot(base_height.into(), height.into(), removed_writer)
+            .await?;
+            metadata.write(&output_dir)
+        }
         SubCommands::Contract { contract_id } => {
             let writer = move || Ok(SnapshotWriter::json(output_dir.clone()));
             Exporter::new(
@@ -374,6 +432,7 @@ mod tests {
             fn read<T>(reader: &mut SnapshotReader) -> Vec<TableEntry<T>>
             where
                 T: TableWithBlueprint,
+                T::OwnedKey: Ord,
                 StateConfig: AsTable<T>,
                 TableEntry<T>: serde::de::DeserializeOwned,
             {
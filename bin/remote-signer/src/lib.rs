@@ -0,0 +1,191 @@
+//! A reference implementation of the remote signer used by `SignMode::Remote`.
+//!
+//! The signer keeps the producer key outside of the node process and serves the
+//! protocol described in `fuel_core_types::signer::remote`. It is meant for local
+//! setups and tests, production deployments are expected to use a hardened signer.
+
+#![deny(unused_crate_dependencies)]
+#![deny(missing_docs)]
+#![deny(warnings)]
+
+use clap as _;
+use tracing_subscriber as _;
+
+use fuel_core_types::{
+    fuel_crypto::SecretKey,
+    fuel_vm::Signature,
+    signer::remote::{
+        RemoteSignerEndpoint,
+        RemoteSignerMethod,
+        RemoteSignerRequest,
+        RemoteSignerResponse,
+    },
+};
+use std::sync::Arc;
+use tokio::{
+    io::{
+        AsyncBufReadExt,
+        AsyncRead,
+        AsyncWrite,
+        AsyncWriteExt,
+        BufReader,
+    },
+    task::JoinHandle,
+};
+
+/// The signer answering the requests of the nodes.
+pub struct Signer {
+    secret_key: SecretKey,
+    token: String,
+}
+
+impl Signer {
+    /// Creates the signer with the `secret_key` that accepts requests with the `token`.
+    pub fn new(secret_key: SecretKey, token: String) -> Self {
+        Self { secret_key, token }
+    }
+
+    /// Handles a single request.
+    pub fn handle(&self, request: RemoteSignerRequest) -> RemoteSignerResponse {
+        if request.token != self.token {
+            return RemoteSignerResponse::Error {
+                reason: "invalid token".to_string(),
+            }
+        }
+        match request.method {
+            RemoteSignerMethod::PublicKey => RemoteSignerResponse::PublicKey {
+                public_key: self.secret_key.public_key(),
+            },
+            RemoteSignerMethod::Sign { message } => RemoteSignerResponse::Signature {
+                signature: Signature::sign(&self.secret_key, &message),
+            },
+        }
+    }
+
+    /// Binds to the `endpoint` and serves the requests in the background task.
+    /// The signer is ready to accept connections once the function returns.
+    pub async fn spawn(
+        self,
+        endpoint: RemoteSignerEndpoint,
+    ) -> anyhow::Result<JoinHandle<()>> {
+        let signer = Arc::new(self);
+        let handle = match endpoint {
+            RemoteSignerEndpoint::Tcp(address) => {
+                let listener = tokio::net::TcpListener::bind(address).await?;
+                tokio::spawn(async move {
+                    loop {
+                        match listener.accept().await {
+                            Ok((stream, _)) => spawn_connection(signer.clone(), stream),
+                            Err(err) => {
+                                tracing::error!("Failed to accept a connection: {err}")
+                            }
+                        }
+                    }
+                })
+            }
+            #[cfg(unix)]
+            RemoteSignerEndpoint::Unix(path) => {
+                // The socket file is left behind by the previous run.
+                if path.exists() {
+                    std::fs::remove_file(&path)?;
+                }
+                let listener = tokio::net::UnixListener::bind(path)?;
+                tokio::spawn(async move {
+                    loop {
+                        match listener.accept().await {
+                            Ok((stream, _)) => spawn_connection(signer.clone(), stream),
+                            Err(err) => {
+                                tracing::error!("Failed to accept a connection: {err}")
+                            }
+                        }
+                    }
+                })
+            }
+        };
+        Ok(handle)
+    }
+}
+
+fn spawn_connection<S>(signer: Arc<Signer>, stream: S)
+where
+    S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+{
+    tokio::spawn(async move {
+        if let Err(err) = serve_connection(&signer, stream).await {
+            tracing::warn!("Failed to serve the request: {err}");
+        }
+    });
+}
+
+async fn serve_connection<S>(signer: &Signer, stream: S) -> anyhow::Result<()>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let mut stream = BufReader::new(stream);
+    let mut line = String::new();
+    stream.read_line(&mut line).await?;
+
+    let response = match serde_json::from_str::<RemoteSignerRequest>(&line) {
+        Ok(request) => signer.handle(request),
+        Err(err) => RemoteSignerResponse::Error {
+            reason: format!("malformed request: {err}"),
+        },
+    };
+
+    let mut payload = serde_json::to_vec(&response)?;
+    payload.push(b'\n');
+    stream.get_mut().write_all(&payload).await?;
+    stream.get_mut().flush().await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+    use fuel_core_types::fuel_crypto::Message;
+
+    fn signer() -> Signer {
+        let secret_key = SecretKey::try_from([7u8; 32].as_slice()).unwrap();
+        Signer::new(secret_key, "token".to_string())
+    }
+
+    #[test]
+    fn handle__rejects_invalid_token() {
+        // Given
+        let request = RemoteSignerRequest {
+            token: "wrong".to_string(),
+            method: RemoteSignerMethod::PublicKey,
+        };
+
+        // When
+        let response = signer().handle(request);
+
+        // Then
+        assert!(matches!(response, RemoteSignerResponse::Error { .. }));
+    }
+
+    #[test]
+    fn handle__signs_message() {
+        // Given
+        let signer = signer();
+        let message = Message::new(b"block");
+        let request = RemoteSignerRequest {
+            token: "token".to_string(),
+            method: RemoteSignerMethod::Sign { message },
+        };
+
+        // When
+        let response = signer.handle(request);
+
+        // Then
+        let RemoteSignerResponse::Signature { signature } = response else {
+            panic!("Expected a signature, got {response:?}");
+        };
+        assert_eq!(
+            signature.recover(&message).unwrap(),
+            signer.secret_key.public_key()
+        );
+    }
+}
@@ -0,0 +1,53 @@
+//! A reference remote signer for `fuel-core` block and pre-confirmation signing.
+use clap::Parser;
+use fuel_core_remote_signer::Signer;
+use fuel_core_types::{
+    fuel_crypto::SecretKey,
+    signer::remote::RemoteSignerEndpoint,
+};
+use std::str::FromStr;
+use tracing_subscriber::EnvFilter;
+
+/// Serves signatures to the `fuel-core` nodes started with `--consensus-remote-signer`
+#[derive(Debug, Parser)]
+#[clap(name = "fuel-core-remote-signer", author, version, about)]
+struct Command {
+    /// The endpoint to listen on, either `tcp://<address>` or `unix://<path>`.
+    /// TCP is accepted only for the loopback address.
+    #[arg(long = "endpoint", env)]
+    endpoint: RemoteSignerEndpoint,
+    /// The secret key used for signing.
+    /// Setting via the `REMOTE_SIGNER_KEY_SECRET` ENV var is preferred.
+    #[arg(long = "key", env = "REMOTE_SIGNER_KEY_SECRET")]
+    key: String,
+    /// The token the nodes have to present with every request.
+    /// Setting via the `REMOTE_SIGNER_TOKEN` ENV var is preferred.
+    #[arg(long = "token", env = "REMOTE_SIGNER_TOKEN")]
+    token: String,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::from_default_env())
+        .init();
+
+    let Command {
+        endpoint,
+        key,
+        token,
+    } = Command::parse();
+    let secret_key = SecretKey::from_str(&key)
+        .map_err(|err| anyhow::anyhow!("Failed to parse the secret key: {err:?}"))?;
+    tracing::info!(
+        "Remote signer listens on {endpoint} with the public key {}",
+        secret_key.public_key()
+    );
+
+    let handle = Signer::new(secret_key, token).spawn(endpoint).await?;
+    tokio::select! {
+        result = handle => result?,
+        result = tokio::signal::ctrl_c() => result?,
+    }
+    Ok(())
+}
@@ -18,6 +18,7 @@ use fuel_core_chain_config::default_consensus_dev_key;
 pub enum ConsensusConfig {
     PoA { signing_key: Address },
     PoAV2(PoAV2),
+    RoundRobin(RoundRobinPoA),
 }
 
 impl ConsensusConfig {
@@ -80,6 +81,124 @@ impl PoAV2 {
     }
 }
 
+/// A set of producers that take turns sealing blocks.
+///
+/// The producer scheduled for a block height is `producers[height % producers.len()]`.
+/// If the scheduled producer misses its slot, the next producers in the set may seal
+/// the block: the producer at offset `n` from the scheduled one is allowed to do so once
+/// `n * fallback_timeout_secs` seconds have passed since the previous block.
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(try_from = "UncheckedRoundRobinPoA")]
+pub struct RoundRobinPoA {
+    producers: Vec<Address>,
+    fallback_timeout_secs: u64,
+}
+
+/// The round-robin configuration before the validation.
+#[derive(Deserialize)]
+struct UncheckedRoundRobinPoA {
+    producers: Vec<Address>,
+    fallback_timeout_secs: u64,
+}
+
+impl TryFrom<UncheckedRoundRobinPoA> for RoundRobinPoA {
+    type Error = anyhow::Error;
+
+    fn try_from(value: UncheckedRoundRobinPoA) -> Result<Self, Self::Error> {
+        let round_robin =
+            RoundRobinPoA::new(value.producers, value.fallback_timeout_secs);
+        round_robin.validate()?;
+        Ok(round_robin)
+    }
+}
+
+impl RoundRobinPoA {
+    pub fn new(producers: Vec<Address>, fallback_timeout_secs: u64) -> Self {
+        RoundRobinPoA {
+            producers,
+            fallback_timeout_secs,
+        }
+    }
+
+    /// Checks that the set has producers to schedule.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            !self.producers.is_empty(),
+            "The round-robin PoA requires at least one producer"
+        );
+        Ok(())
+    }
+
+    /// Returns the ordered set of producers.
+    pub fn producers(&self) -> &[Address] {
+        &self.producers
+    }
+
+    /// Returns the number of seconds after which the next producer in line
+    /// may seal the block instead of the scheduled one.
+    pub fn fallback_timeout_secs(&self) -> u64 {
+        self.fallback_timeout_secs
+    }
+
+    /// Returns the producer scheduled to seal the block at `height`.
+    pub fn scheduled_producer(&self, height: BlockHeight) -> Option<Address> {
+        self.producer_at_offset(height, 0)
+    }
+
+    /// Returns the offset of the `producer` from the scheduled producer at `height`.
+    /// The scheduled producer has the offset `0`. Returns `None` if the `producer`
+    /// is not part of the set.
+    pub fn producer_offset(
+        &self,
+        height: BlockHeight,
+        producer: &Address,
+    ) -> Option<u64> {
+        let len = self.producers.len() as u64;
+        (0..len).find(|offset| {
+            self.producer_at_offset(height, *offset).as_ref() == Some(producer)
+        })
+    }
+
+    /// Returns the highest producer offset allowed to seal a block when
+    /// `elapsed_secs` seconds have passed since the previous block.
+    pub fn max_allowed_offset(&self, elapsed_secs: u64) -> u64 {
+        let max_offset = (self.producers.len() as u64).saturating_sub(1);
+        elapsed_secs
+            .checked_div(self.fallback_timeout_secs)
+            .unwrap_or(0)
+            .min(max_offset)
+    }
+
+    /// Returns `true` if the `producer` may seal the block at `height` when
+    /// `elapsed_secs` seconds have passed since the previous block.
+    pub fn is_allowed_to_produce(
+        &self,
+        height: BlockHeight,
+        producer: &Address,
+        elapsed_secs: u64,
+    ) -> bool {
+        self.producer_offset(height, producer)
+            .is_some_and(|offset| offset <= self.max_allowed_offset(elapsed_secs))
+    }
+
+    /// Returns `true` if the `producer` is part of the set.
+    pub fn contains(&self, producer: &Address) -> bool {
+        self.producers.contains(producer)
+    }
+
+    fn producer_at_offset(&self, height: BlockHeight, offset: u64) -> Option<Address> {
+        let len = self.producers.len() as u64;
+        let index = u64::from(*height).saturating_add(offset).checked_rem(len)?;
+        let index = usize::try_from(index).ok()?;
+        self.producers.get(index).copied()
+    }
+
+    #[cfg(feature = "test-helpers")]
+    pub fn set_producers(&mut self, producers: Vec<Address>) {
+        self.producers = producers;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(non_snake_case)]
@@ -119,4 +238,84 @@ mod tests {
             signing_key_after_30
         );
     }
+
+    #[test]
+    fn scheduled_producer__rotates_by_height() {
+        // Given
+        let producers = vec![
+            Address::from([1; 32]),
+            Address::from([2; 32]),
+            Address::from([3; 32]),
+        ];
+        let poa = RoundRobinPoA::new(producers.clone(), 5);
+
+        // When/Then
+        assert_eq!(poa.scheduled_producer(0u32.into()), Some(producers[0]));
+        assert_eq!(poa.scheduled_producer(1u32.into()), Some(producers[1]));
+        assert_eq!(poa.scheduled_producer(2u32.into()), Some(producers[2]));
+        assert_eq!(poa.scheduled_producer(3u32.into()), Some(producers[0]));
+        assert_eq!(poa.scheduled_producer(u32::MAX.into()), Some(producers[0]));
+    }
+
+    #[test]
+    fn scheduled_producer__returns_none_for_empty_set() {
+        // Given
+        let poa = RoundRobinPoA::new(vec![], 5);
+
+        // When/Then
+        assert_eq!(poa.scheduled_producer(1u32.into()), None);
+        assert_eq!(
+            poa.producer_offset(1u32.into(), &Address::from([1; 32])),
+            None
+        );
+    }
+
+    #[test]
+    fn deserialize__rejects_empty_set_of_producers() {
+        // Given
+        let empty = serde_json::to_string(&RoundRobinPoA::new(vec![], 5)).unwrap();
+        let single =
+            serde_json::to_string(&RoundRobinPoA::new(vec![Address::from([1; 32])], 5))
+                .unwrap();
+
+        // When
+        let empty = serde_json::from_str::<RoundRobinPoA>(&empty);
+        let single = serde_json::from_str::<RoundRobinPoA>(&single);
+
+        // Then
+        assert!(empty.is_err());
+        assert!(single.is_ok());
+    }
+
+    #[test]
+    fn is_allowed_to_produce__next_producer_takes_over_after_timeout() {
+        // Given
+        let first = Address::from([1; 32]);
+        let second = Address::from([2; 32]);
+        let third = Address::from([3; 32]);
+        let outsider = Address::from([4; 32]);
+        let poa = RoundRobinPoA::new(vec![first, second, third], 5);
+        let height = 3u32.into();
+
+        // When/Then
+        assert!(poa.is_allowed_to_produce(height, &first, 0));
+        assert!(!poa.is_allowed_to_produce(height, &second, 4));
+        assert!(poa.is_allowed_to_produce(height, &second, 5));
+        assert!(!poa.is_allowed_to_produce(height, &third, 9));
+        assert!(poa.is_allowed_to_produce(height, &third, 10));
+        assert!(poa.is_allowed_to_produce(height, &third, 1_000));
+        assert!(!poa.is_allowed_to_produce(height, &outsider, 1_000));
+    }
+
+    #[test]
+    fn is_allowed_to_produce__zero_timeout_disables_fallback() {
+        // Given
+        let first = Address::from([1; 32]);
+        let second = Address::from([2; 32]);
+        let poa = RoundRobinPoA::new(vec![first, second], 0);
+
+        // When/Then
+        assert!(poa.is_allowed_to_produce(0u32.into(), &first, 100));
+        assert!(!poa.is_allowed_to_produce(0u32.into(), &second, 100));
+    }
 }
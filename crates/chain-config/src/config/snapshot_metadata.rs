@@ -1,4 +1,5 @@
 use anyhow::Context;
+use fuel_core_types::fuel_types::BlockHeight;
 use std::{
     io::Read,
     path::{
@@ -102,6 +103,39 @@ impl SnapshotMetadata {
     }
 }
 
+/// Metadata of a diff snapshot. The diff snapshot contains the entries changed
+/// by the blocks in the `(base_block_height, block_height]` range.
+///
+/// The changed entries are stored as a regular snapshot in the diff directory.
+/// The removed entries, with their values at the `base_block_height`,
+/// are stored as a regular snapshot in the [`Self::REMOVED_ENTRIES_DIR`] subdirectory.
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+pub struct SnapshotDiffMetadata {
+    /// The height of the snapshot on top of which the diff is applied.
+    pub base_block_height: BlockHeight,
+    /// The height of the state after applying the diff.
+    pub block_height: BlockHeight,
+}
+
+impl SnapshotDiffMetadata {
+    const METADATA_FILENAME: &'static str = "diff_metadata.json";
+    pub const REMOVED_ENTRIES_DIR: &'static str = "removed";
+
+    pub fn read(dir: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = dir.as_ref().join(Self::METADATA_FILENAME);
+        let file = std::fs::File::open(&path)
+            .with_context(|| format!("Could not open snapshot diff file: {path:?}"))?;
+        Ok(serde_json::from_reader(file)?)
+    }
+
+    pub fn write(&self, dir: &Path) -> anyhow::Result<()> {
+        let path = dir.join(Self::METADATA_FILENAME);
+        let file = std::fs::File::create(path)?;
+        serde_json::to_writer_pretty(file, self)?;
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -678,6 +678,7 @@ mod tests {
     use crate::{
         ChainConfig,
         Randomize,
+        SnapshotDiffMetadata,
     };
 
     use rand::{
@@ -858,12 +859,132 @@ mod tests {
         assert_eq!(coins.into_iter().count(), 0);
     }
 
+    #[test_case::test_case(given_parquet_writer)]
+    #[test_case::test_case(given_json_writer)]
+    fn diff_applies_changed_and_removed_entries(
+        writer: impl Fn(&Path) -> SnapshotWriter,
+    ) {
+        // given
+        let mut rng = StdRng::seed_from_u64(0);
+        let coins: Vec<TableEntry<Coins>> =
+            std::iter::repeat_with(|| TableEntry::randomize(&mut rng))
+                .take(4)
+                .collect();
+        let block_config = |block_height: u32| LastBlockConfig {
+            block_height: block_height.into(),
+            da_block_height: 0u64.into(),
+            consensus_parameters_version: 0,
+            state_transition_version: 0,
+            blocks_root: Default::default(),
+        };
+
+        let base_dir = tempfile::tempdir().unwrap();
+        let mut base_writer = writer(base_dir.path());
+        base_writer.write(coins[..3].to_vec()).unwrap();
+        let base = base_writer
+            .close(Some(block_config(10)), &ChainConfig::local_testnet())
+            .unwrap();
+
+        let changed_coin = TableEntry {
+            key: coins[1].key,
+            value: TableEntry::<Coins>::randomize(&mut rng).value,
+        };
+        let diff_dir = tempfile::tempdir().unwrap();
+        let mut changed_writer = writer(diff_dir.path());
+        changed_writer
+            .write(vec![changed_coin.clone(), coins[3].clone()])
+            .unwrap();
+        changed_writer
+            .close(Some(block_config(12)), &ChainConfig::local_testnet())
+            .unwrap();
+        let mut removed_writer = writer(
+            &diff_dir
+                .path()
+                .join(SnapshotDiffMetadata::REMOVED_ENTRIES_DIR),
+        );
+        removed_writer.write(vec![coins[0].clone()]).unwrap();
+        removed_writer
+            .close(None, &ChainConfig::local_testnet())
+            .unwrap();
+        SnapshotDiffMetadata {
+            base_block_height: 10u32.into(),
+            block_height: 12u32.into(),
+        }
+        .write(diff_dir.path())
+        .unwrap();
+
+        // when
+        let reader = SnapshotReader::open(base)
+            .unwrap()
+            .with_diff(diff_dir.path())
+            .unwrap();
+
+        // then
+        let read_coins: Vec<_> = reader
+            .read::<Coins>()
+            .unwrap()
+            .into_iter()
+            .flatten_ok()
+            .try_collect()
+            .unwrap();
+        let expected_coins = vec![coins[2].clone(), changed_coin, coins[3].clone()];
+        assert_eq!(
+            read_coins
+                .into_iter()
+                .sorted_by_key(|entry| entry.key)
+                .collect_vec(),
+            expected_coins
+                .into_iter()
+                .sorted_by_key(|entry| entry.key)
+                .collect_vec()
+        );
+        assert_eq!(
+            reader.last_block_config().map(|block| block.block_height),
+            Some(12u32.into())
+        );
+    }
+
+    #[test_case::test_case(given_parquet_writer)]
+    #[test_case::test_case(given_json_writer)]
+    fn diff_fails_if_based_on_another_height(writer: impl Fn(&Path) -> SnapshotWriter) {
+        // given
+        let base_dir = tempfile::tempdir().unwrap();
+        let base = writer(base_dir.path())
+            .close(
+                Some(LastBlockConfig {
+                    block_height: 10u32.into(),
+                    da_block_height: 0u64.into(),
+                    consensus_parameters_version: 0,
+                    state_transition_version: 0,
+                    blocks_root: Default::default(),
+                }),
+                &ChainConfig::local_testnet(),
+            )
+            .unwrap();
+        let diff_dir = tempfile::tempdir().unwrap();
+        SnapshotDiffMetadata {
+            base_block_height: 11u32.into(),
+            block_height: 12u32.into(),
+        }
+        .write(diff_dir.path())
+        .unwrap();
+
+        // when
+        let result = SnapshotReader::open(base)
+            .unwrap()
+            .with_diff(diff_dir.path());
+
+        // then
+        assert!(result.is_err());
+    }
+
     fn assert_roundtrip<T>(
         writer: impl FnOnce(&Path) -> SnapshotWriter,
         reader: impl FnOnce(SnapshotMetadata, usize) -> SnapshotReader,
     ) where
         T: TableWithBlueprint,
         T::OwnedKey: Randomize
+            + Ord
             + serde::Serialize
             + serde::de::DeserializeOwned
             + core::fmt::Debug
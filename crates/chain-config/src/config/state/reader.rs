@@ -1,4 +1,7 @@
-use std::fmt::Debug;
+use std::{
+    collections::BTreeSet,
+    fmt::Debug,
+};
 
 use fuel_core_storage::{
     Mappable,
@@ -24,11 +27,7 @@ where
     T: Mappable,
 {
     pub fn len(&self) -> usize {
-        match &self.iter {
-            GroupIter::InMemory { groups } => groups.len(),
-            #[cfg(feature = "parquet")]
-            GroupIter::Parquet { decoder } => decoder.num_groups(),
-        }
+        self.iter.len()
     }
 
     pub fn is_empty(&self) -> bool {
@@ -60,12 +59,59 @@ where
     Parquet {
         decoder: super::parquet::decode::Decoder<std::fs::File>,
     },
+    /// The groups of the base snapshot without the entries changed or removed
+    /// by the diff, followed by the groups of the changed entries.
+    #[cfg(feature = "std")]
+    Diff {
+        base: Box<GroupIter<T>>,
+        excluded: BTreeSet<T::OwnedKey>,
+        changed: std::vec::IntoIter<anyhow::Result<Vec<TableEntry<T>>>>,
+    },
+}
+
+impl<T> GroupIter<T>
+where
+    T: Mappable,
+{
+    fn len(&self) -> usize {
+        match self {
+            GroupIter::InMemory { groups } => groups.len(),
+            #[cfg(feature = "parquet")]
+            GroupIter::Parquet { decoder } => decoder.num_groups(),
+            #[cfg(feature = "std")]
+            GroupIter::Diff { base, changed, .. } => {
+                base.len().saturating_add(changed.len())
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn next_diff_group(
+        base: &mut GroupIter<T>,
+        excluded: &BTreeSet<T::OwnedKey>,
+        changed: &mut std::vec::IntoIter<anyhow::Result<Vec<TableEntry<T>>>>,
+    ) -> Option<anyhow::Result<Vec<TableEntry<T>>>>
+    where
+        T::OwnedKey: Ord,
+        GroupIter<T>: Iterator<Item = anyhow::Result<Vec<TableEntry<T>>>>,
+    {
+        match base.next() {
+            Some(group) => Some(group.map(|group| {
+                group
+                    .into_iter()
+                    .filter(|entry| !excluded.contains::<T::OwnedKey>(&entry.key))
+                    .collect()
+            })),
+            None => changed.next(),
+        }
+    }
 }
 
 #[cfg(feature = "parquet")]
 impl<T> Iterator for GroupIter<T>
 where
     T: Mappable,
+    T::OwnedKey: Ord,
     TableEntry<T>: serde::de::DeserializeOwned,
 {
     type Item = anyhow::Result<Vec<TableEntry<T>>>;
@@ -84,6 +130,12 @@ where
                 });
                 Some(group)
             }
+            #[cfg(feature = "std")]
+            GroupIter::Diff {
+                base,
+                excluded,
+                changed,
+            } => Self::next_diff_group(base, excluded, changed),
         }
     }
 }
@@ -92,12 +144,19 @@ where
 impl<T> Iterator for GroupIter<T>
 where
     T: Mappable,
+    T::OwnedKey: Ord,
 {
     type Item = anyhow::Result<Vec<TableEntry<T>>>;
 
     fn next(&mut self) -> Option<Self::Item> {
         match self {
             GroupIter::InMemory { groups } => groups.next(),
+            #[cfg(feature = "std")]
+            GroupIter::Diff {
+                base,
+                excluded,
+                changed,
+            } => Self::next_diff_group(base, excluded, changed),
         }
     }
 }
@@ -113,6 +172,13 @@ enum DataSource {
         state: StateConfig,
         group_size: usize,
     },
+    /// The base snapshot with the diff snapshot applied on top of it.
+    #[cfg(feature = "std")]
+    Diff {
+        base: Box<SnapshotReader>,
+        changed: Box<SnapshotReader>,
+        removed: Box<SnapshotReader>,
+    },
 }
 
 #[derive(Clone, Debug)]
@@ -241,9 +307,43 @@ impl SnapshotReader {
         }
     }
 
+    /// Applies the diff snapshot stored in the `diff_dir` on top of this snapshot.
+    /// The diff must be based on the last block of this snapshot.
+    #[cfg(feature = "std")]
+    pub fn with_diff(self, diff_dir: &std::path::Path) -> anyhow::Result<Self> {
+        use crate::config::{
+            SnapshotDiffMetadata,
+            SnapshotMetadata,
+        };
+
+        let diff = SnapshotDiffMetadata::read(diff_dir)?;
+        let base_block_height = self.last_block_config().map(|block| block.block_height);
+        anyhow::ensure!(
+            base_block_height == Some(diff.base_block_height),
+            "The snapshot diff is based on the height {}, \
+            but the snapshot is at the height {base_block_height:?}",
+            diff.base_block_height,
+        );
+
+        let changed = Self::open(SnapshotMetadata::read(diff_dir)?)?;
+        let removed = Self::open(SnapshotMetadata::read(
+            diff_dir.join(SnapshotDiffMetadata::REMOVED_ENTRIES_DIR),
+        )?)?;
+
+        Ok(Self {
+            chain_config: self.chain_config.clone(),
+            data_source: DataSource::Diff {
+                base: Box::new(self),
+                changed: Box::new(changed),
+                removed: Box::new(removed),
+            },
+        })
+    }
+
     pub fn read<T>(&self) -> anyhow::Result<Groups<T>>
     where
         T: TableWithBlueprint,
+        T::OwnedKey: Ord,
         StateConfig: AsTable<T>,
         TableEntry<T>: serde::de::DeserializeOwned,
     {
@@ -280,6 +380,27 @@ impl SnapshotReader {
                     groups: collection.into_iter(),
                 }
             }
+            #[cfg(feature = "std")]
+            DataSource::Diff {
+                base,
+                changed,
+                removed,
+            } => {
+                let changed: Vec<_> = changed.read::<T>()?.into_iter().try_collect()?;
+                let removed: Vec<_> = removed.read::<T>()?.into_iter().try_collect()?;
+                let excluded = changed
+                    .iter()
+                    .chain(removed.iter())
+                    .flatten()
+                    .map(|entry| entry.key.clone())
+                    .collect();
+
+                GroupIter::Diff {
+                    base: Box::new(base.read::<T>()?.iter),
+                    excluded,
+                    changed: changed.into_iter().map(Ok).collect_vec().into_iter(),
+                }
+            }
         };
 
         Ok(Groups { iter })
@@ -297,6 +418,8 @@ impl SnapshotReader {
                 latest_block_config: block,
                 ..
             } => block.as_ref(),
+            #[cfg(feature = "std")]
+            DataSource::Diff { changed, .. } => changed.last_block_config(),
         }
     }
 }
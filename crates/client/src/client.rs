@@ -112,7 +112,10 @@ use schema::{
     U32,
     U64,
     assets::AssetInfoArg,
-    balance::BalanceArgs,
+    balance::{
+        BalanceArgs,
+        BalanceAtHeightArgs,
+    },
     blob::BlobByIdArgs,
     block::BlockByIdArgs,
     coins::{
@@ -122,6 +125,7 @@ use schema::{
     contract::{
         ContractBalancesConnectionArgs,
         ContractByIdArgs,
+        ContractReceiptsConnectionArgs,
     },
     da_compressed::DaCompressedBlockByHeightArgs,
     gas_price::BlockHorizonArgs,
@@ -153,6 +157,7 @@ use std::{
         Arc,
         Mutex,
     },
+    time::Duration,
 };
 use tai64::Tai64;
 use tracing as _;
@@ -409,15 +414,40 @@ impl FuelClient {
         &self,
         q: Operation<ResponseData, Vars>,
     ) -> io::Result<ResponseData>
+    where
+        Vars: serde::Serialize,
+        ResponseData: serde::de::DeserializeOwned + 'static,
+    {
+        self.send_query(self.client.post(self.url.clone()), q).await
+    }
+
+    /// Send the GraphQL query to the admin API of the node.
+    /// The `admin_token` is passed in the `Authorization` header.
+    async fn admin_query<ResponseData, Vars>(
+        &self,
+        admin_token: &str,
+        q: Operation<ResponseData, Vars>,
+    ) -> io::Result<ResponseData>
+    where
+        Vars: serde::Serialize,
+        ResponseData: serde::de::DeserializeOwned + 'static,
+    {
+        let request = self.client.post(self.url.clone()).bearer_auth(admin_token);
+        self.send_query(request, q).await
+    }
+
+    async fn send_query<ResponseData, Vars>(
+        &self,
+        request: reqwest::RequestBuilder,
+        q: Operation<ResponseData, Vars>,
+    ) -> io::Result<ResponseData>
     where
         Vars: serde::Serialize,
         ResponseData: serde::de::DeserializeOwned + 'static,
     {
         let required_fuel_block_height = self.required_block_height();
         let fuel_operation = FuelOperation::new(q, required_fuel_block_height);
-        let response = self
-            .client
-            .post(self.url.clone())
+        let response = request
             .run_fuel_graphql(fuel_operation)
             .await
             .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
@@ -611,6 +641,80 @@ impl FuelClient {
         self.query(query).await.map(|r| r.node_info.into())
     }
 
+    /// Returns the blacklist of the transaction pool.
+    /// Requires the admin API token of the node.
+    pub async fn tx_pool_blacklist(
+        &self,
+        admin_token: &str,
+    ) -> io::Result<types::TxPoolBlacklist> {
+        let query = schema::admin::TxPoolBlacklistQuery::build(());
+        self.admin_query(admin_token, query)
+            .await
+            .map(|r| r.tx_pool_blacklist.into())
+    }
+
+    /// Removes the `remove` entries from the blacklist of the transaction pool
+    /// and adds the `add` entries to it. Returns the ids of transactions
+    /// removed from the pool because of the update.
+    /// Requires the admin API token of the node.
+    pub async fn update_tx_pool_blacklist(
+        &self,
+        admin_token: &str,
+        add: types::TxPoolBlacklist,
+        remove: types::TxPoolBlacklist,
+    ) -> io::Result<Vec<TxId>> {
+        let query = schema::admin::UpdateTxPoolBlacklistMutation::build(
+            schema::admin::UpdateTxPoolBlacklistArgs {
+                add: Some(add.into()),
+                remove: Some(remove.into()),
+            },
+        );
+        self.admin_query(admin_token, query).await.map(|r| {
+            r.update_tx_pool_blacklist
+                .into_iter()
+                .map(Into::into)
+                .collect()
+        })
+    }
+
+    /// Returns the active bans of peers and IP addresses in the p2p network.
+    /// Requires the admin API token of the node.
+    pub async fn peer_bans(&self, admin_token: &str) -> io::Result<Vec<types::PeerBan>> {
+        let query = schema::admin::PeerBansQuery::build(());
+        self.admin_query(admin_token, query)
+            .await
+            .map(|r| r.peer_bans.into_iter().map(Into::into).collect())
+    }
+
+    /// Bans the peer id or the IP address in the p2p network for the `duration`,
+    /// or until it is unbanned if the `duration` is not set.
+    /// Requires the admin API token of the node.
+    pub async fn ban_peer(
+        &self,
+        admin_token: &str,
+        target: &str,
+        duration: Option<Duration>,
+    ) -> io::Result<()> {
+        let query = schema::admin::BanPeerMutation::build(schema::admin::BanPeerArgs {
+            target: target.to_string(),
+            duration_seconds: duration.map(|duration| duration.as_secs().into()),
+        });
+        self.admin_query(admin_token, query).await.map(|_| ())
+    }
+
+    /// Removes the ban of the peer id or the IP address in the p2p network.
+    /// Returns `true` if the target was banned.
+    /// Requires the admin API token of the node.
+    pub async fn unban_peer(&self, admin_token: &str, target: &str) -> io::Result<bool> {
+        let query =
+            schema::admin::UnbanPeerMutation::build(schema::admin::UnbanPeerArgs {
+                target: target.to_string(),
+            });
+        self.admin_query(admin_token, query)
+            .await
+            .map(|r| r.unban_peer)
+    }
+
     pub async fn latest_gas_price(&self) -> io::Result<LatestGasPrice> {
         let query = schema::gas_price::QueryLatestGasPrice::build(());
         self.query(query).await.map(|r| r.latest_gas_price.into())
@@ -1427,6 +1531,73 @@ impl FuelClient {
         Ok(block)
     }
 
+    /// Subscribes to the blocks starting from the `from_height`.
+    /// If `from_height` is `None`, the stream returns only new blocks.
+    #[cfg(feature = "subscriptions")]
+    pub async fn subscribe_blocks(
+        &self,
+        from_height: Option<BlockHeight>,
+    ) -> io::Result<impl Stream<Item = io::Result<types::Block>> + '_> {
+        use cynic::SubscriptionBuilder;
+        use schema::block::BlocksSubscriptionArgs;
+        let s = schema::block::BlocksSubscription::build(BlocksSubscriptionArgs {
+            from_height: from_height.map(|height| U32(height.into())),
+        });
+
+        let stream = self.subscribe(s).await?.map(
+            |result: io::Result<schema::block::BlocksSubscription>| {
+                let block: types::Block = result?.blocks.try_into()?;
+                Result::<_, io::Error>::Ok(block)
+            },
+        );
+
+        Ok(stream)
+    }
+
+    /// Subscribes to the new blocks.
+    #[cfg(feature = "subscriptions")]
+    pub async fn subscribe_new_blocks(
+        &self,
+    ) -> io::Result<impl Stream<Item = io::Result<types::Block>> + '_> {
+        use cynic::SubscriptionBuilder;
+        let s = schema::block::NewBlocksSubscription::build(());
+
+        let stream = self.subscribe(s).await?.map(
+            |result: io::Result<schema::block::NewBlocksSubscription>| {
+                let block: types::Block = result?.new_blocks.try_into()?;
+                Result::<_, io::Error>::Ok(block)
+            },
+        );
+
+        Ok(stream)
+    }
+
+    /// Subscribes to the receipts emitted by the contracts from the `filter`.
+    /// If `from_height` of the filter is `None`, the stream returns only new receipts.
+    #[cfg(feature = "subscriptions")]
+    pub async fn subscribe_contract_receipts(
+        &self,
+        filter: types::ContractReceiptsFilter,
+    ) -> io::Result<impl Stream<Item = io::Result<types::ContractReceipt>> + '_> {
+        use cynic::SubscriptionBuilder;
+        use schema::contract::ContractReceiptsSubscriptionArgs;
+        let s = schema::contract::ContractReceiptsSubscription::build(
+            ContractReceiptsSubscriptionArgs {
+                filter: filter.into(),
+            },
+        );
+
+        let stream = self.subscribe(s).await?.map(
+            |result: io::Result<schema::contract::ContractReceiptsSubscription>| {
+                let receipt: types::ContractReceipt =
+                    result?.contract_receipts.try_into()?;
+                Result::<_, io::Error>::Ok(receipt)
+            },
+        );
+
+        Ok(stream)
+    }
+
     pub async fn da_compressed_block(
         &self,
         height: BlockHeight,
@@ -1572,6 +1743,28 @@ impl FuelClient {
         Ok(balance.amount)
     }
 
+    /// Retrieve the balance of the owner at the past `height`.
+    /// Requires the node to keep the state history for the `height`.
+    pub async fn balance_at(
+        &self,
+        owner: &Address,
+        asset_id: Option<&AssetId>,
+        height: BlockHeight,
+    ) -> io::Result<u128> {
+        let owner: schema::Address = (*owner).into();
+        let asset_id: schema::AssetId = match asset_id {
+            Some(asset_id) => (*asset_id).into(),
+            None => schema::AssetId::default(),
+        };
+        let query = schema::balance::BalanceAtHeightQuery::build(BalanceAtHeightArgs {
+            owner,
+            asset_id,
+            at_height: height.into(),
+        });
+        let balance: types::Balance = self.query(query).await?.balance.into();
+        Ok(balance.amount)
+    }
+
     // Retrieve a page of balances by their owner
     pub async fn balances(
         &self,
@@ -1600,6 +1793,21 @@ impl FuelClient {
         Ok(balances)
     }
 
+    /// Returns receipts emitted by the contracts from the `filter`.
+    /// Requires the contract receipts indexation to be enabled on the node.
+    pub async fn contract_receipts(
+        &self,
+        filter: types::ContractReceiptsFilter,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::ContractReceipt, String>> {
+        let args = ContractReceiptsConnectionArgs::from((filter.into(), request));
+        let query = schema::contract::ContractReceiptsQuery::build(args);
+
+        let receipts = self.query(query).await?.contract_receipts.try_into()?;
+
+        Ok(receipts)
+    }
+
     // Retrieve a message by its nonce
     pub async fn message(&self, nonce: &Nonce) -> io::Result<Option<types::Message>> {
         let query = schema::message::MessageQuery::build(NonceArgs {
@@ -0,0 +1,148 @@
+use crate::client::schema::{
+    Address,
+    ContractId,
+    Nonce,
+    Tai64Timestamp,
+    TransactionId,
+    U64,
+    UtxoId,
+    schema,
+};
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TxPoolBlacklist {
+    pub owners: Vec<Address>,
+    pub coins: Vec<UtxoId>,
+    pub messages: Vec<Nonce>,
+    pub contracts: Vec<ContractId>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct TxPoolBlacklistQuery {
+    pub tx_pool_blacklist: TxPoolBlacklist,
+}
+
+#[derive(cynic::InputObject, Clone, Debug, Default)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TxPoolBlacklistInput {
+    pub owners: Vec<Address>,
+    pub coins: Vec<UtxoId>,
+    pub messages: Vec<Nonce>,
+    pub contracts: Vec<ContractId>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct UpdateTxPoolBlacklistArgs {
+    pub add: Option<TxPoolBlacklistInput>,
+    pub remove: Option<TxPoolBlacklistInput>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "UpdateTxPoolBlacklistArgs"
+)]
+pub struct UpdateTxPoolBlacklistMutation {
+    #[arguments(add: $add, remove: $remove)]
+    pub update_tx_pool_blacklist: Vec<TransactionId>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PeerBan {
+    pub target: String,
+    pub expires_at: Option<Tai64Timestamp>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct PeerBansQuery {
+    pub peer_bans: Vec<PeerBan>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct BanPeerArgs {
+    pub target: String,
+    pub duration_seconds: Option<U64>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "BanPeerArgs"
+)]
+pub struct BanPeerMutation {
+    #[arguments(target: $target, durationSeconds: $duration_seconds)]
+    pub ban_peer: bool,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct UnbanPeerArgs {
+    pub target: String,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "UnbanPeerArgs"
+)]
+pub struct UnbanPeerMutation {
+    #[arguments(target: $target)]
+    pub unban_peer: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tx_pool_blacklist_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = TxPoolBlacklistQuery::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn update_tx_pool_blacklist_mutation_gql_output() {
+        use cynic::MutationBuilder;
+        let operation = UpdateTxPoolBlacklistMutation::build(UpdateTxPoolBlacklistArgs {
+            add: Some(TxPoolBlacklistInput {
+                owners: vec![Address::default()],
+                ..Default::default()
+            }),
+            remove: None,
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn peer_bans_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = PeerBansQuery::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn ban_peer_mutation_gql_output() {
+        use cynic::MutationBuilder;
+        let operation = BanPeerMutation::build(BanPeerArgs {
+            target: "127.0.0.1".to_string(),
+            duration_seconds: Some(60u64.into()),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn unban_peer_mutation_gql_output() {
+        use cynic::MutationBuilder;
+        let operation = UnbanPeerMutation::build(UnbanPeerArgs {
+            target: "127.0.0.1".to_string(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+}
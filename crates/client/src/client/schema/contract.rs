@@ -7,8 +7,15 @@ use crate::client::{
         HexString,
         PageInfo,
         Salt,
+        TransactionId,
+        U16,
+        U32,
         U64,
         schema,
+        tx::transparent_receipt::{
+            Receipt,
+            ReceiptType,
+        },
     },
 };
 
@@ -135,6 +142,106 @@ impl From<(ContractId, PaginationRequest<String>)> for ContractBalancesConnectio
     }
 }
 
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractReceiptsFilterInput {
+    /// Filter receipts based on the contract that emitted them
+    pub contract_ids: Vec<ContractId>,
+    /// Filter receipts based on their type
+    pub receipt_types: Option<Vec<ReceiptType>>,
+    /// Filter receipts emitted below the height
+    pub from_height: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractReceipt {
+    pub block_height: U32,
+    pub transaction_id: TransactionId,
+    pub transaction_index: U16,
+    pub receipt_index: U16,
+    pub receipt: Receipt,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ContractReceiptsConnectionArgs {
+    /// Filter receipts based on a filter
+    filter: ContractReceiptsFilterInput,
+    /// Skip until receipt cursor (forward pagination)
+    pub after: Option<String>,
+    /// Skip until receipt cursor (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n receipts in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n receipts in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractReceiptEdge {
+    pub cursor: String,
+    pub node: ContractReceipt,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractReceiptConnection {
+    pub edges: Vec<ContractReceiptEdge>,
+    pub page_info: PageInfo,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ContractReceiptsConnectionArgs"
+)]
+pub struct ContractReceiptsQuery {
+    #[arguments(filter: $filter, after: $after, before: $before, first: $first, last: $last)]
+    pub contract_receipts: ContractReceiptConnection,
+}
+
+impl From<(ContractReceiptsFilterInput, PaginationRequest<String>)>
+    for ContractReceiptsConnectionArgs
+{
+    fn from(r: (ContractReceiptsFilterInput, PaginationRequest<String>)) -> Self {
+        match r.1.direction {
+            PageDirection::Forward => ContractReceiptsConnectionArgs {
+                filter: r.0,
+                after: r.1.cursor,
+                before: None,
+                first: Some(r.1.results),
+                last: None,
+            },
+            PageDirection::Backward => ContractReceiptsConnectionArgs {
+                filter: r.0,
+                after: None,
+                before: r.1.cursor,
+                first: None,
+                last: Some(r.1.results),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ContractReceiptsSubscriptionArgs {
+    pub filter: ContractReceiptsFilterInput,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "ContractReceiptsSubscriptionArgs"
+)]
+pub struct ContractReceiptsSubscription {
+    #[arguments(filter: $filter)]
+    pub contract_receipts: ContractReceipt,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -147,4 +254,21 @@ mod tests {
         });
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn contract_receipts_connection_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = ContractReceiptsQuery::build(ContractReceiptsConnectionArgs {
+            filter: ContractReceiptsFilterInput {
+                contract_ids: vec![ContractId::default()],
+                receipt_types: Some(vec![ReceiptType::Log]),
+                from_height: Some(U32(1)),
+            },
+            after: None,
+            before: None,
+            first: None,
+            last: None,
+        });
+        insta::assert_snapshot!(operation.query)
+    }
 }
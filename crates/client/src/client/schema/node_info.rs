@@ -7,6 +7,7 @@ use crate::client::schema::{
 use fuel_core_types::services::p2p::{
     HeartbeatData,
     PeerId,
+    PeerRequestStats,
 };
 #[cfg(feature = "std")]
 use std::{
@@ -61,6 +62,10 @@ pub struct PeerInfo {
     pub block_height: Option<U32>,
     pub last_heartbeat_ms: U64,
     pub app_score: f64,
+    pub successful_requests: U64,
+    pub failed_requests: U64,
+    pub avg_latency_ms: Option<U64>,
+    pub bytes_served: U64,
 }
 
 #[cfg(feature = "std")]
@@ -77,6 +82,12 @@ impl From<PeerInfo> for fuel_core_types::services::p2p::PeerInfo {
                     .unwrap_or(UNIX_EPOCH),
             },
             app_score: info.app_score,
+            request_stats: PeerRequestStats {
+                successful_requests: info.successful_requests.0,
+                failed_requests: info.failed_requests.0,
+                avg_latency: info.avg_latency_ms.map(|ms| Duration::from_millis(ms.0)),
+                bytes_served: info.bytes_served.0,
+            },
         }
     }
 }
@@ -95,6 +106,7 @@ pub struct IndexationFlags {
     pub balances: bool,
     pub coins_to_spend: bool,
     pub asset_metadata: bool,
+    pub contract_receipts: bool,
 }
 
 #[cfg(test)]
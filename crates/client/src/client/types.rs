@@ -1,3 +1,4 @@
+pub mod admin;
 pub mod balance;
 pub mod blob;
 pub mod block;
@@ -14,6 +15,10 @@ pub mod merkle_proof;
 pub mod message;
 pub mod node_info;
 
+pub use admin::{
+    PeerBan,
+    TxPoolBlacklist,
+};
 pub use balance::Balance;
 pub use blob::Blob;
 pub use block::{
@@ -29,6 +34,8 @@ pub use coins::{
 pub use contract::{
     Contract,
     ContractBalance,
+    ContractReceipt,
+    ContractReceiptsFilter,
 };
 pub use gas_costs::{
     DependentCost,
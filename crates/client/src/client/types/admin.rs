@@ -0,0 +1,63 @@
+use crate::client::{
+    schema,
+    types::primitives::{
+        Address,
+        ContractId,
+        Nonce,
+        UtxoId,
+    },
+};
+use tai64::Tai64;
+
+/// The blacklist of the transaction pool.
+/// Transactions that use blacklisted inputs are not accepted by the pool.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TxPoolBlacklist {
+    /// Owners of coins, and senders and recipients of messages.
+    pub owners: Vec<Address>,
+    pub coins: Vec<UtxoId>,
+    pub messages: Vec<Nonce>,
+    pub contracts: Vec<ContractId>,
+}
+
+/// The ban of a peer or an IP address in the p2p network.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PeerBan {
+    /// The banned peer id or IP address.
+    pub target: String,
+    /// The time when the ban is lifted. `None` means the ban lasts until it is removed.
+    pub expires_at: Option<Tai64>,
+}
+
+// GraphQL Translation
+
+impl From<schema::admin::TxPoolBlacklist> for TxPoolBlacklist {
+    fn from(value: schema::admin::TxPoolBlacklist) -> Self {
+        Self {
+            owners: value.owners.into_iter().map(Into::into).collect(),
+            coins: value.coins.into_iter().map(Into::into).collect(),
+            messages: value.messages.into_iter().map(Into::into).collect(),
+            contracts: value.contracts.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<TxPoolBlacklist> for schema::admin::TxPoolBlacklistInput {
+    fn from(value: TxPoolBlacklist) -> Self {
+        Self {
+            owners: value.owners.into_iter().map(Into::into).collect(),
+            coins: value.coins.into_iter().map(Into::into).collect(),
+            messages: value.messages.into_iter().map(Into::into).collect(),
+            contracts: value.contracts.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<schema::admin::PeerBan> for PeerBan {
+    fn from(value: schema::admin::PeerBan) -> Self {
+        Self {
+            target: value.target,
+            expires_at: value.expires_at.map(|expires_at| expires_at.0),
+        }
+    }
+}
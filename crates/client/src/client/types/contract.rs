@@ -1,13 +1,22 @@
 use crate::client::{
     PaginatedResult,
-    schema,
+    schema::{
+        self,
+        ConversionError,
+        tx::transparent_receipt::ReceiptType,
+    },
     types::primitives::{
         AssetId,
         Bytes,
         ContractId,
         Salt,
+        TransactionId,
     },
 };
+use fuel_core_types::{
+    fuel_tx::Receipt,
+    fuel_types::BlockHeight,
+};
 
 pub struct Contract {
     pub id: ContractId,
@@ -22,6 +31,25 @@ pub struct ContractBalance {
     pub asset_id: AssetId,
 }
 
+#[derive(Clone, Debug, Default)]
+pub struct ContractReceiptsFilter {
+    /// Returns receipts emitted by any of these contracts.
+    pub contract_ids: Vec<ContractId>,
+    /// Returns receipts only of these types. All types are returned if `None`.
+    pub receipt_types: Option<Vec<ReceiptType>>,
+    /// Returns receipts only from blocks at or above this height.
+    pub from_height: Option<BlockHeight>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContractReceipt {
+    pub block_height: BlockHeight,
+    pub transaction_id: TransactionId,
+    pub transaction_index: u16,
+    pub receipt_index: u16,
+    pub receipt: Receipt,
+}
+
 // GraphQL Translation
 
 impl From<schema::contract::Contract> for Contract {
@@ -56,3 +84,48 @@ impl From<schema::contract::ContractBalanceConnection>
         }
     }
 }
+
+impl From<ContractReceiptsFilter> for schema::contract::ContractReceiptsFilterInput {
+    fn from(value: ContractReceiptsFilter) -> Self {
+        Self {
+            contract_ids: value.contract_ids.into_iter().map(Into::into).collect(),
+            receipt_types: value.receipt_types,
+            from_height: value.from_height.map(Into::into),
+        }
+    }
+}
+
+impl TryFrom<schema::contract::ContractReceipt> for ContractReceipt {
+    type Error = ConversionError;
+
+    fn try_from(value: schema::contract::ContractReceipt) -> Result<Self, Self::Error> {
+        Ok(Self {
+            block_height: value.block_height.into(),
+            transaction_id: value.transaction_id.into(),
+            transaction_index: value.transaction_index.into(),
+            receipt_index: value.receipt_index.into(),
+            receipt: value.receipt.try_into()?,
+        })
+    }
+}
+
+impl TryFrom<schema::contract::ContractReceiptConnection>
+    for PaginatedResult<ContractReceipt, String>
+{
+    type Error = ConversionError;
+
+    fn try_from(
+        conn: schema::contract::ContractReceiptConnection,
+    ) -> Result<Self, Self::Error> {
+        Ok(PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: conn
+                .edges
+                .into_iter()
+                .map(|e| e.node.try_into())
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
@@ -36,9 +36,18 @@ use fuel_core_storage::tables::{
     ContractsState,
     Messages,
 };
-use fuel_core_types::fuel_types::BlockHeight;
+use fuel_core_types::{
+    blockchain::primitives::DaBlockHeight,
+    fuel_types::BlockHeight,
+};
 use std::path::PathBuf;
 
+#[cfg(feature = "backup")]
+pub mod backup;
+pub mod integrity;
+pub mod pruning;
+pub mod reindex;
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct CombinedDatabaseConfig {
     pub database_path: PathBuf,
@@ -399,6 +408,26 @@ impl CombinedDatabase {
         Ok(state_config)
     }
 
+    /// Returns the latest heights of all databases.
+    pub fn heights(&self) -> StorageResult<CombinedDatabaseHeights> {
+        Ok(CombinedDatabaseHeights {
+            on_chain: self.on_chain().latest_height_from_metadata()?,
+            off_chain: self.off_chain().latest_height_from_metadata()?,
+            relayer: self.relayer().latest_height_from_metadata()?,
+            gas_price: self.gas_price().latest_height_from_metadata()?,
+            compression: self.compression().latest_height_from_metadata()?,
+        })
+    }
+
+    /// Returns the changes that `rollback_to` would make to the databases
+    /// to rollback them to the `target_block_height`, without modifying them.
+    pub fn rollback_plan(
+        &self,
+        target_block_height: BlockHeight,
+    ) -> anyhow::Result<RollbackPlan> {
+        RollbackPlan::new(target_block_height, &self.heights()?)
+    }
+
     /// Rollbacks the state of the blockchain to a specific block height.
     pub fn rollback_to<S>(
         &self,
@@ -407,73 +436,93 @@ impl CombinedDatabase {
     ) -> anyhow::Result<()>
     where
         S: ShutdownListener,
+    {
+        self.rollback_to_with_progress(target_block_height, shutdown_listener, |_| {})
+    }
+
+    /// Rollbacks the state of the blockchain to a specific block height.
+    ///
+    /// The databases are rolled back one block at a time. Before each step,
+    /// the `progress` is called with the changes that are left to be done.
+    pub fn rollback_to_with_progress<S, P>(
+        &self,
+        target_block_height: BlockHeight,
+        shutdown_listener: &mut S,
+        mut progress: P,
+    ) -> anyhow::Result<()>
+    where
+        S: ShutdownListener,
+        P: FnMut(&RollbackPlan),
     {
         while !shutdown_listener.is_cancelled() {
-            let on_chain_height = self
-                .on_chain()
-                .latest_height_from_metadata()?
-                .ok_or(anyhow::anyhow!("on-chain database doesn't have height"))?;
-
-            let off_chain_height = self
-                .off_chain()
-                .latest_height_from_metadata()?
-                .ok_or(anyhow::anyhow!("off-chain database doesn't have height"))?;
-
-            let gas_price_chain_height =
-                self.gas_price().latest_height_from_metadata()?;
-
-            let gas_price_rolled_back = gas_price_chain_height.is_none()
-                || gas_price_chain_height.expect("We checked height before")
-                    == target_block_height;
-
-            if on_chain_height == target_block_height
-                && off_chain_height == target_block_height
-                && gas_price_rolled_back
-            {
+            let plan = self.rollback_plan(target_block_height)?;
+            progress(&plan);
+
+            if plan.is_done() {
                 break;
             }
 
-            if on_chain_height < target_block_height {
-                return Err(anyhow::anyhow!(
-                    "on-chain database height({on_chain_height}) \
-                    is less than target height({target_block_height})"
-                ));
+            if plan.on_chain.is_rollback() {
+                self.on_chain().rollback_last_block()?;
             }
 
-            if off_chain_height < target_block_height {
-                return Err(anyhow::anyhow!(
-                    "off-chain database height({off_chain_height}) \
-                    is less than target height({target_block_height})"
-                ));
+            if plan.off_chain.is_rollback() {
+                self.off_chain().rollback_last_block()?;
             }
 
-            if let Some(gas_price_chain_height) = gas_price_chain_height {
-                if gas_price_chain_height < target_block_height {
-                    return Err(anyhow::anyhow!(
-                        "gas-price-chain database height({gas_price_chain_height}) \
-                        is less than target height({target_block_height})"
-                    ));
-                }
+            if plan.gas_price.is_rollback() {
+                self.gas_price().rollback_last_block()?;
             }
 
-            if on_chain_height > target_block_height {
-                self.on_chain().rollback_last_block()?;
+            if plan.compression.is_rollback() {
+                self.compression().rollback_last_block()?;
             }
+        }
 
-            if off_chain_height > target_block_height {
-                self.off_chain().rollback_last_block()?;
+        if shutdown_listener.is_cancelled() {
+            return Err(anyhow::anyhow!(
+                "Stop the rollback due to shutdown signal received"
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Checks that all databases agree on the `tip` of the chain.
+    ///
+    /// The on-chain and off-chain databases must be at the `tip`.
+    /// The gas-price database must be at the `tip` or not have a height.
+    /// The compression database can be behind the `tip`,
+    /// because it catches up on the next start of the node.
+    pub fn verify_tip(&self, tip: BlockHeight) -> anyhow::Result<()> {
+        let heights = self.heights()?;
+        let mut mismatches = vec![];
+
+        for (name, height) in [
+            ("on-chain", heights.on_chain),
+            ("off-chain", heights.off_chain),
+        ] {
+            if height != Some(tip) {
+                mismatches.push(format!("{name} database height({height:?})"));
             }
+        }
 
-            if let Some(gas_price_chain_height) = gas_price_chain_height {
-                if gas_price_chain_height > target_block_height {
-                    self.gas_price().rollback_last_block()?;
-                }
+        if let Some(height) = heights.gas_price {
+            if height != tip {
+                mismatches.push(format!("gas-price-chain database height({height})"));
             }
         }
 
-        if shutdown_listener.is_cancelled() {
+        if let Some(height) = heights.compression {
+            if height > tip {
+                mismatches.push(format!("compression database height({height})"));
+            }
+        }
+
+        if !mismatches.is_empty() {
             return Err(anyhow::anyhow!(
-                "Stop the rollback due to shutdown signal received"
+                "The databases don't agree on the tip({tip}): {}",
+                mismatches.join(", ")
             ));
         }
 
@@ -533,6 +582,138 @@ impl CombinedDatabase {
     }
 }
 
+/// The latest heights of the databases of the [`CombinedDatabase`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CombinedDatabaseHeights {
+    pub on_chain: Option<BlockHeight>,
+    pub off_chain: Option<BlockHeight>,
+    pub relayer: Option<DaBlockHeight>,
+    pub gas_price: Option<BlockHeight>,
+    pub compression: Option<BlockHeight>,
+}
+
+/// The change that the rollback makes to one of the databases.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DatabaseRollback {
+    /// The database is rolled back from the `from` height to the `to` height.
+    Rollback { from: BlockHeight, to: BlockHeight },
+    /// The database is left as is. The `height` is `None` if
+    /// the database doesn't have a height.
+    Unchanged { height: Option<BlockHeight> },
+}
+
+impl DatabaseRollback {
+    fn new(height: Option<BlockHeight>, target_block_height: BlockHeight) -> Self {
+        match height {
+            Some(from) if from > target_block_height => Self::Rollback {
+                from,
+                to: target_block_height,
+            },
+            height => Self::Unchanged { height },
+        }
+    }
+
+    /// Returns `true` if the database has blocks to rollback.
+    pub fn is_rollback(&self) -> bool {
+        matches!(self, Self::Rollback { .. })
+    }
+
+    /// Returns the number of blocks to rollback.
+    pub fn blocks(&self) -> u32 {
+        match self {
+            Self::Rollback { from, to } => (**from).saturating_sub(**to),
+            Self::Unchanged { .. } => 0,
+        }
+    }
+}
+
+/// The changes that the rollback makes to the databases of the [`CombinedDatabase`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RollbackPlan {
+    pub target_block_height: BlockHeight,
+    pub on_chain: DatabaseRollback,
+    pub off_chain: DatabaseRollback,
+    pub gas_price: DatabaseRollback,
+    pub compression: DatabaseRollback,
+    /// The relayer database follows the DA height, so the rollback doesn't affect it.
+    pub relayer: Option<DaBlockHeight>,
+}
+
+impl RollbackPlan {
+    fn new(
+        target_block_height: BlockHeight,
+        heights: &CombinedDatabaseHeights,
+    ) -> anyhow::Result<Self> {
+        let on_chain_height = heights
+            .on_chain
+            .ok_or(anyhow::anyhow!("on-chain database doesn't have height"))?;
+
+        let off_chain_height = heights
+            .off_chain
+            .ok_or(anyhow::anyhow!("off-chain database doesn't have height"))?;
+
+        if on_chain_height < target_block_height {
+            return Err(anyhow::anyhow!(
+                "on-chain database height({on_chain_height}) \
+                is less than target height({target_block_height})"
+            ));
+        }
+
+        if off_chain_height < target_block_height {
+            return Err(anyhow::anyhow!(
+                "off-chain database height({off_chain_height}) \
+                is less than target height({target_block_height})"
+            ));
+        }
+
+        if let Some(gas_price_chain_height) = heights.gas_price {
+            if gas_price_chain_height < target_block_height {
+                return Err(anyhow::anyhow!(
+                    "gas-price-chain database height({gas_price_chain_height}) \
+                    is less than target height({target_block_height})"
+                ));
+            }
+        }
+
+        Ok(Self {
+            target_block_height,
+            on_chain: DatabaseRollback::new(heights.on_chain, target_block_height),
+            off_chain: DatabaseRollback::new(heights.off_chain, target_block_height),
+            gas_price: DatabaseRollback::new(heights.gas_price, target_block_height),
+            // The compression database may be behind the on-chain database,
+            // it catches up on the next start of the node.
+            compression: DatabaseRollback::new(heights.compression, target_block_height),
+            relayer: heights.relayer,
+        })
+    }
+
+    /// Returns the changes for each database that follows the block height.
+    pub fn databases(&self) -> [(&'static str, DatabaseRollback); 4] {
+        [
+            ("on-chain", self.on_chain),
+            ("off-chain", self.off_chain),
+            ("gas-price", self.gas_price),
+            ("compression", self.compression),
+        ]
+    }
+
+    /// Returns the largest number of blocks left to rollback among the databases.
+    pub fn remaining_blocks(&self) -> u32 {
+        self.databases()
+            .iter()
+            .map(|(_, rollback)| rollback.blocks())
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Returns `true` if all databases are rolled back to the target height.
+    pub fn is_done(&self) -> bool {
+        self.databases()
+            .iter()
+            .all(|(_, rollback)| !rollback.is_rollback())
+    }
+}
+
 /// A trait for listening to shutdown signals.
 pub trait ShutdownListener {
     /// Returns true if the shutdown signal has been received.
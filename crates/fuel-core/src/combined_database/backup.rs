@@ -0,0 +1,591 @@
+//! Incremental backups of the [`CombinedDatabase`].
+//!
+//! Each database has its own RocksDB backup engine inside the backup directory.
+//! The backups of the same engine share the files, so every new backup only copies
+//! the files created since the previous one. The manifest of the backup directory
+//! groups the backups of all databases taken together into one [`Backup`].
+
+use crate::{
+    combined_database::CombinedDatabase,
+    database::{
+        Database,
+        Error as DatabaseError,
+        Result as DatabaseResult,
+        database_description::{
+            DatabaseDescription,
+            DatabaseHeight,
+            compression::CompressionDatabase,
+            gas_price::GasPriceDatabase,
+            off_chain::OffChain,
+            on_chain::OnChain,
+            relayer::Relayer,
+        },
+        metadata::MetadataTable,
+    },
+    state::rocks_db::{
+        ColumnsPolicy,
+        DatabaseConfig,
+        RocksDb,
+    },
+};
+use fuel_core_storage::{
+    StorageAsRef,
+    structured_storage::StructuredStorage,
+};
+use fuel_core_types::fuel_types::BlockHeight;
+use std::{
+    collections::BTreeMap,
+    path::{
+        Path,
+        PathBuf,
+    },
+    time::{
+        SystemTime,
+        UNIX_EPOCH,
+    },
+};
+
+/// The name of the manifest file in the backup directory.
+pub const MANIFEST_FILE_NAME: &str = "backups.json";
+
+/// The backup of all databases of the [`CombinedDatabase`] taken together.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Backup {
+    /// The identifier of the backup in the backup directory.
+    pub id: u32,
+    /// The unix timestamp of the backup creation in seconds.
+    pub created_at: u64,
+    /// The backups of the databases, keyed by the name of the database.
+    pub databases: BTreeMap<String, DatabaseBackup>,
+}
+
+impl Backup {
+    /// Returns the height of the on-chain database in the backup.
+    pub fn height(&self) -> Option<BlockHeight> {
+        self.databases
+            .get(&OnChain::name())
+            .and_then(|backup| backup.height)
+            .and_then(|height| u32::try_from(height).ok())
+            .map(BlockHeight::from)
+    }
+}
+
+/// The backup of a single database.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct DatabaseBackup {
+    /// The identifier of the backup in the backup engine of the database.
+    pub backup_id: u32,
+    /// The height of the database in the backup.
+    pub height: Option<u64>,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+struct Manifest {
+    backups: Vec<Backup>,
+}
+
+/// The directory with incremental backups of the [`CombinedDatabase`].
+///
+/// The store expects to be the only writer of the directory at a time.
+#[derive(Debug, Clone)]
+pub struct BackupStore {
+    dir: PathBuf,
+}
+
+impl BackupStore {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Returns the path to the backup directory.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Returns all backups from the oldest to the newest.
+    pub fn list(&self) -> DatabaseResult<Vec<Backup>> {
+        Ok(self.read_manifest()?.backups)
+    }
+
+    /// Returns the backup with the `id`, or the latest backup if the `id` is not set.
+    pub fn get(&self, id: Option<u32>) -> DatabaseResult<Backup> {
+        let backups = self.list()?;
+        let backup = match id {
+            Some(id) => backups.into_iter().find(|backup| backup.id == id),
+            None => backups.into_iter().last(),
+        };
+        backup.ok_or_else(|| {
+            backup_error(format!(
+                "The backup {} is not found in `{}`",
+                id.map_or("latest".to_string(), |id| id.to_string()),
+                self.dir.display()
+            ))
+        })
+    }
+
+    /// Creates a new backup of the running node's `database`.
+    ///
+    /// The import of blocks continues during the backup. Each database is backed up
+    /// at the block boundary. The auxiliary databases are backed up before the on-chain
+    /// database, so they are never ahead of it, and the node brings them up to the
+    /// on-chain height on start.
+    pub fn create(&self, database: &CombinedDatabase) -> DatabaseResult<Backup> {
+        self.create_dir()?;
+
+        let databases = BTreeMap::from([
+            backup_database(database.relayer(), &self.dir)?,
+            backup_database(database.compression(), &self.dir)?,
+            backup_database(database.gas_price(), &self.dir)?,
+            backup_database(database.off_chain(), &self.dir)?,
+            backup_database(database.on_chain(), &self.dir)?,
+        ]);
+
+        self.add(databases)
+    }
+
+    /// Creates a new backup of the databases at the `db_dir`.
+    ///
+    /// The databases are opened in the read-only mode, so the backup can be created
+    /// while the node is running.
+    pub fn create_from_path(&self, db_dir: &Path) -> DatabaseResult<Backup> {
+        self.create_dir()?;
+
+        let databases = BTreeMap::from([
+            backup_database_at::<Relayer>(db_dir, &self.dir)?,
+            backup_database_at::<CompressionDatabase>(db_dir, &self.dir)?,
+            backup_database_at::<GasPriceDatabase>(db_dir, &self.dir)?,
+            backup_database_at::<OffChain>(db_dir, &self.dir)?,
+            backup_database_at::<OnChain>(db_dir, &self.dir)?,
+        ]);
+
+        self.add(databases)
+    }
+
+    /// Verifies the files of the backup with the `id`, or of the latest backup
+    /// if the `id` is not set.
+    pub fn verify(&self, id: Option<u32>) -> DatabaseResult<Backup> {
+        let backup = self.get(id)?;
+
+        verify_database::<OnChain>(&backup, &self.dir)?;
+        verify_database::<OffChain>(&backup, &self.dir)?;
+        verify_database::<Relayer>(&backup, &self.dir)?;
+        verify_database::<GasPriceDatabase>(&backup, &self.dir)?;
+        verify_database::<CompressionDatabase>(&backup, &self.dir)?;
+
+        Ok(backup)
+    }
+
+    /// Restores the backup with the `id`, or the latest backup if the `id` is not set,
+    /// into the `restore_to` directory. The directory must not exist.
+    pub fn restore(&self, id: Option<u32>, restore_to: &Path) -> DatabaseResult<Backup> {
+        use tempfile::TempDir;
+
+        if restore_to.exists() {
+            return Err(backup_error(format!(
+                "The restore directory `{}` already exists",
+                restore_to.display()
+            )));
+        }
+        let backup = self.get(id)?;
+
+        // The temporary directory is created next to the target directory,
+        // so it can be renamed into the target directory.
+        let parent = match restore_to.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent,
+            _ => Path::new("."),
+        };
+        std::fs::create_dir_all(parent).map_err(backup_error)?;
+        let temp_restore_dir = TempDir::new_in(parent).map_err(backup_error)?;
+
+        restore_database::<OnChain>(&backup, &self.dir, temp_restore_dir.path())?;
+        restore_database::<OffChain>(&backup, &self.dir, temp_restore_dir.path())?;
+        restore_database::<Relayer>(&backup, &self.dir, temp_restore_dir.path())?;
+        restore_database::<GasPriceDatabase>(
+            &backup,
+            &self.dir,
+            temp_restore_dir.path(),
+        )?;
+        restore_database::<CompressionDatabase>(
+            &backup,
+            &self.dir,
+            temp_restore_dir.path(),
+        )?;
+
+        std::fs::rename(temp_restore_dir.keep(), restore_to).map_err(backup_error)?;
+
+        Ok(backup)
+    }
+
+    /// Removes the oldest backups, keeping `num_backups_to_keep` newest ones.
+    /// Returns the removed backups.
+    pub fn retain(&self, num_backups_to_keep: usize) -> DatabaseResult<Vec<Backup>> {
+        if num_backups_to_keep == 0 {
+            return Err(backup_error("At least one backup must be kept"));
+        }
+
+        let mut manifest = self.read_manifest()?;
+        let num_backups_to_remove =
+            manifest.backups.len().saturating_sub(num_backups_to_keep);
+        if num_backups_to_remove == 0 {
+            return Ok(vec![]);
+        }
+        let removed = manifest
+            .backups
+            .drain(..num_backups_to_remove)
+            .collect::<Vec<_>>();
+        // The manifest is updated first, so it never refers to the purged backups.
+        self.write_manifest(&manifest)?;
+
+        purge_database::<OnChain>(&manifest.backups, &self.dir)?;
+        purge_database::<OffChain>(&manifest.backups, &self.dir)?;
+        purge_database::<Relayer>(&manifest.backups, &self.dir)?;
+        purge_database::<GasPriceDatabase>(&manifest.backups, &self.dir)?;
+        purge_database::<CompressionDatabase>(&manifest.backups, &self.dir)?;
+
+        Ok(removed)
+    }
+
+    fn create_dir(&self) -> DatabaseResult<()> {
+        std::fs::create_dir_all(&self.dir).map_err(backup_error)
+    }
+
+    fn add(&self, databases: BTreeMap<String, DatabaseBackup>) -> DatabaseResult<Backup> {
+        let mut manifest = self.read_manifest()?;
+        let id = manifest
+            .backups
+            .last()
+            .map_or(1, |backup| backup.id.saturating_add(1));
+        let created_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+        let backup = Backup {
+            id,
+            created_at,
+            databases,
+        };
+        manifest.backups.push(backup.clone());
+        self.write_manifest(&manifest)?;
+
+        Ok(backup)
+    }
+
+    fn manifest_path(&self) -> PathBuf {
+        self.dir.join(MANIFEST_FILE_NAME)
+    }
+
+    fn read_manifest(&self) -> DatabaseResult<Manifest> {
+        let path = self.manifest_path();
+        if !path.exists() {
+            return Ok(Manifest::default());
+        }
+        let bytes = std::fs::read(&path).map_err(backup_error)?;
+        serde_json::from_slice(&bytes).map_err(|e| {
+            backup_error(format!(
+                "Failed to parse the backup manifest `{}`: {e}",
+                path.display()
+            ))
+        })
+    }
+
+    fn write_manifest(&self, manifest: &Manifest) -> DatabaseResult<()> {
+        let bytes = serde_json::to_vec_pretty(manifest).map_err(backup_error)?;
+        // The manifest is replaced atomically, so an interrupted write
+        // doesn't corrupt the list of backups.
+        let temp_path = self.manifest_path().with_extension("json.tmp");
+        std::fs::write(&temp_path, bytes).map_err(backup_error)?;
+        std::fs::rename(&temp_path, self.manifest_path()).map_err(backup_error)?;
+        Ok(())
+    }
+}
+
+fn backup_error(error: impl core::fmt::Display) -> DatabaseError {
+    DatabaseError::BackupError(anyhow::anyhow!("{error}"))
+}
+
+fn backup_database<Description>(
+    database: &Database<Description>,
+    backup_dir: &Path,
+) -> DatabaseResult<(String, DatabaseBackup)>
+where
+    Description: DatabaseDescription,
+{
+    let (backup_id, height) = database.backup(backup_dir).map_err(|e| {
+        backup_error(format!(
+            "Failed to backup the {} database: {e}",
+            Description::name()
+        ))
+    })?;
+    let backup = DatabaseBackup {
+        backup_id,
+        height: height.as_ref().map(DatabaseHeight::as_u64),
+    };
+    Ok((Description::name(), backup))
+}
+
+fn backup_database_at<Description>(
+    db_dir: &Path,
+    backup_dir: &Path,
+) -> DatabaseResult<(String, DatabaseBackup)>
+where
+    Description: DatabaseDescription,
+{
+    let db = RocksDb::<Description>::open_read_only(
+        db_dir,
+        enum_iterator::all::<Description::Column>().collect::<Vec<_>>(),
+        false,
+        DatabaseConfig {
+            cache_capacity: None,
+            max_fds: -1,
+            columns_policy: ColumnsPolicy::Lazy,
+        },
+    )?;
+    let storage = StructuredStorage::new(db);
+    let height = storage
+        .storage::<MetadataTable<Description>>()
+        .get(&())
+        .map_err(backup_error)?
+        .map(|metadata| metadata.height().as_u64());
+    let backup_id = storage.as_ref().create_backup(backup_dir, false)?;
+    let backup = DatabaseBackup { backup_id, height };
+    Ok((Description::name(), backup))
+}
+
+fn database_backup<Description>(backup: &Backup) -> DatabaseResult<DatabaseBackup>
+where
+    Description: DatabaseDescription,
+{
+    backup
+        .databases
+        .get(&Description::name())
+        .copied()
+        .ok_or_else(|| {
+            backup_error(format!(
+                "The backup {} doesn't contain the {} database",
+                backup.id,
+                Description::name()
+            ))
+        })
+}
+
+fn verify_database<Description>(backup: &Backup, backup_dir: &Path) -> DatabaseResult<()>
+where
+    Description: DatabaseDescription,
+{
+    let database_backup = database_backup::<Description>(backup)?;
+    RocksDb::<Description>::verify_backup(backup_dir, database_backup.backup_id)
+}
+
+fn restore_database<Description>(
+    backup: &Backup,
+    backup_dir: &Path,
+    restore_to: &Path,
+) -> DatabaseResult<()>
+where
+    Description: DatabaseDescription,
+{
+    let database_backup = database_backup::<Description>(backup)?;
+    RocksDb::<Description>::restore_backup(
+        restore_to,
+        backup_dir,
+        Some(database_backup.backup_id),
+    )
+}
+
+/// Removes the backups of the database that are older than
+/// the oldest backup referenced by the `kept` backups.
+fn purge_database<Description>(kept: &[Backup], backup_dir: &Path) -> DatabaseResult<()>
+where
+    Description: DatabaseDescription,
+{
+    let oldest_kept = kept
+        .iter()
+        .filter_map(|backup| backup.databases.get(&Description::name()))
+        .map(|database_backup| database_backup.backup_id)
+        .min();
+    let Some(oldest_kept) = oldest_kept else {
+        return Ok(());
+    };
+    let num_backups_to_keep = RocksDb::<Description>::backups(backup_dir)?
+        .iter()
+        .filter(|info| info.backup_id >= oldest_kept)
+        .count();
+    RocksDb::<Description>::purge_old_backups(backup_dir, num_backups_to_keep)
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::state::historical_rocksdb::StateRewindPolicy;
+    use fuel_core_storage::{
+        StorageAsMut,
+        tables::Coins,
+    };
+    use fuel_core_types::{
+        entities::coins::coin::CompressedCoin,
+        fuel_tx::UtxoId,
+    };
+    use tempfile::TempDir;
+
+    fn open(path: &Path) -> CombinedDatabase {
+        CombinedDatabase::open(
+            path,
+            StateRewindPolicy::NoRewind,
+            DatabaseConfig::config_for_tests(),
+        )
+        .unwrap()
+    }
+
+    fn insert_coin(database: &mut CombinedDatabase, key: UtxoId) {
+        database
+            .on_chain_mut()
+            .storage_as_mut::<Coins>()
+            .insert(&key, &CompressedCoin::default())
+            .unwrap();
+    }
+
+    fn has_coin(path: &Path, key: &UtxoId) -> bool {
+        let database = open(path);
+        let exists = database
+            .on_chain()
+            .storage::<Coins>()
+            .contains_key(key)
+            .unwrap();
+        database.shutdown();
+        exists
+    }
+
+    #[test]
+    fn create__adds_backups_to_the_manifest() {
+        // Given
+        let db_dir = TempDir::new().unwrap();
+        let backup_dir = TempDir::new().unwrap();
+        let store = BackupStore::new(backup_dir.path());
+        let database = open(db_dir.path());
+
+        // When
+        let first = store.create(&database).unwrap();
+        let second = store.create(&database).unwrap();
+
+        // Then
+        assert_eq!(first.id, 1);
+        assert_eq!(second.id, 2);
+        assert_eq!(first.databases.len(), 5);
+        assert_eq!(store.list().unwrap(), vec![first, second]);
+    }
+
+    #[test]
+    fn restore__restores_the_selected_backup() {
+        // Given
+        let db_dir = TempDir::new().unwrap();
+        let backup_dir = TempDir::new().unwrap();
+        let store = BackupStore::new(backup_dir.path());
+        let mut database = open(db_dir.path());
+        let first_coin = UtxoId::new([1; 32].into(), 0);
+        let second_coin = UtxoId::new([2; 32].into(), 0);
+        insert_coin(&mut database, first_coin);
+        let first = store.create(&database).unwrap();
+        insert_coin(&mut database, second_coin);
+        store.create(&database).unwrap();
+        database.shutdown();
+
+        // When
+        let restore_dir = TempDir::new().unwrap();
+        let restore_to = restore_dir.path().join("db");
+        store.restore(Some(first.id), &restore_to).unwrap();
+
+        // Then
+        assert!(has_coin(&restore_to, &first_coin));
+        assert!(!has_coin(&restore_to, &second_coin));
+    }
+
+    #[test]
+    fn restore__fails_when_the_target_directory_exists() {
+        // Given
+        let db_dir = TempDir::new().unwrap();
+        let backup_dir = TempDir::new().unwrap();
+        let store = BackupStore::new(backup_dir.path());
+        let database = open(db_dir.path());
+        store.create(&database).unwrap();
+
+        // When
+        let restore_to = TempDir::new().unwrap();
+        let result = store.restore(None, restore_to.path());
+
+        // Then
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn create_from_path__backups_the_database_opened_by_another_instance() {
+        // Given
+        let db_dir = TempDir::new().unwrap();
+        let backup_dir = TempDir::new().unwrap();
+        let store = BackupStore::new(backup_dir.path());
+        let mut database = open(db_dir.path());
+        let coin = UtxoId::new([1; 32].into(), 0);
+        insert_coin(&mut database, coin);
+
+        // When
+        let backup = store.create_from_path(db_dir.path()).unwrap();
+
+        // Then
+        database.shutdown();
+        let restore_dir = TempDir::new().unwrap();
+        let restore_to = restore_dir.path().join("db");
+        store.restore(Some(backup.id), &restore_to).unwrap();
+        assert!(has_coin(&restore_to, &coin));
+    }
+
+    #[test]
+    fn verify__succeeds_for_the_existing_backup() {
+        // Given
+        let db_dir = TempDir::new().unwrap();
+        let backup_dir = TempDir::new().unwrap();
+        let store = BackupStore::new(backup_dir.path());
+        let database = open(db_dir.path());
+        let backup = store.create(&database).unwrap();
+
+        // When
+        let result = store.verify(Some(backup.id));
+
+        // Then
+        assert_eq!(result.unwrap(), backup);
+    }
+
+    #[test]
+    fn verify__fails_for_unknown_backup() {
+        // Given
+        let backup_dir = TempDir::new().unwrap();
+        let store = BackupStore::new(backup_dir.path());
+
+        // When
+        let result = store.verify(Some(1));
+
+        // Then
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn retain__removes_the_oldest_backups() {
+        // Given
+        let db_dir = TempDir::new().unwrap();
+        let backup_dir = TempDir::new().unwrap();
+        let store = BackupStore::new(backup_dir.path());
+        let database = open(db_dir.path());
+        let backups = (0..3)
+            .map(|_| store.create(&database).unwrap())
+            .collect::<Vec<_>>();
+
+        // When
+        let removed = store.retain(2).unwrap();
+
+        // Then
+        assert_eq!(removed, backups[..1].to_vec());
+        assert_eq!(store.list().unwrap(), backups[1..].to_vec());
+        let on_chain_backups = RocksDb::<OnChain>::backups(backup_dir.path()).unwrap();
+        assert_eq!(on_chain_backups.len(), 2);
+        store.verify(Some(backups[1].id)).unwrap();
+    }
+}
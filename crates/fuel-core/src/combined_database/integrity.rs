@@ -0,0 +1,1132 @@
+//! Integrity checks of the [`CombinedDatabase`].
+//!
+//! The checks recompute the data derived from the on-chain state, like the Merkle
+//! roots and the off-chain indexes, and compare it with the stored data. The checks
+//! are read-only and are intended for the stopped node. The off-chain indexes can be
+//! rebuilt from the on-chain state with [`CombinedDatabase::rebuild_indexes`].
+
+use crate::{
+    combined_database::{
+        CombinedDatabase,
+        pruning::commit_without_height,
+    },
+    database::{
+        Database,
+        database_description::{
+            DatabaseDescription,
+            IndexationKind,
+            off_chain::OffChain,
+        },
+    },
+    graphql_api::{
+        storage::{
+            balances::{
+                CoinBalances,
+                MessageBalance,
+                MessageBalances,
+            },
+            blocks::FuelBlockIdsToHeights,
+            coins::{
+                CoinsToSpendIndex,
+                CoinsToSpendIndexKey,
+                OwnedCoins,
+                owner_coin_id_key,
+            },
+            messages::OwnedMessageIds,
+            old::OldFuelBlocks,
+        },
+        worker_service,
+    },
+};
+use fuel_core_storage::{
+    MerkleRoot,
+    Result as StorageResult,
+    StorageAsMut,
+    StorageAsRef,
+    iter::{
+        IterDirection,
+        IterableStore,
+        IteratorOverTable,
+    },
+    kv_store::KeyValueMutate,
+    not_found,
+    structured_storage::TableWithBlueprint,
+    tables::{
+        Coins,
+        ConsensusParametersVersions,
+        ContractsAssets,
+        ContractsState,
+        FuelBlocks,
+        Messages,
+        merkle::{
+            ContractsAssetsMerkleMetadata,
+            ContractsStateMerkleMetadata,
+            DenseMerkleMetadata,
+            DenseMetadataKey,
+            FuelBlockMerkleMetadata,
+        },
+    },
+    transactional::{
+        ConflictPolicy,
+        StorageTransaction,
+    },
+};
+use fuel_core_types::{
+    blockchain::block::CompressedBlock,
+    entities::{
+        Message,
+        coins::coin::Coin,
+    },
+    fuel_merkle::{
+        binary::root_calculator::MerkleRootCalculator,
+        sparse::{
+            self,
+            MerkleTreeKey,
+        },
+    },
+    fuel_tx::{
+        Address,
+        AssetId,
+        Bytes32,
+        ContractId,
+    },
+    fuel_types::BlockHeight,
+    services::executor::Event,
+};
+use std::{
+    borrow::Cow,
+    collections::{
+        BTreeMap,
+        BTreeSet,
+    },
+};
+
+/// The maximum number of issues listed by one check. The rest of the issues
+/// are only counted, so a badly broken database doesn't flood the report.
+const MAX_LISTED_ISSUES: usize = 100;
+
+/// The result of [`CombinedDatabase::check_integrity`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize)]
+pub struct IntegrityReport {
+    pub checks: Vec<CheckReport>,
+}
+
+impl IntegrityReport {
+    /// Returns `true` if none of the checks found issues.
+    pub fn is_consistent(&self) -> bool {
+        self.checks.iter().all(|check| check.issues_count == 0)
+    }
+
+    /// Returns `true` if only the off-chain indexes have issues, so
+    /// [`CombinedDatabase::rebuild_indexes`] can fix the database.
+    pub fn is_repairable(&self) -> bool {
+        self.checks
+            .iter()
+            .all(|check| check.issues_count == 0 || check.is_index)
+    }
+
+    /// Returns the check with the `name`.
+    pub fn check(&self, name: &str) -> Option<&CheckReport> {
+        self.checks.iter().find(|check| check.name == name)
+    }
+}
+
+/// The result of one integrity check.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct CheckReport {
+    /// The name of the check.
+    pub name: &'static str,
+    /// `true` if the check verifies an off-chain index that can be rebuilt.
+    pub is_index: bool,
+    /// The number of verified entries.
+    pub checked: u64,
+    /// The total number of found issues.
+    pub issues_count: u64,
+    /// The description of the first found issues.
+    pub issues: Vec<String>,
+    /// The remarks that don't make the database inconsistent.
+    pub notes: Vec<String>,
+}
+
+impl CheckReport {
+    fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            is_index: false,
+            checked: 0,
+            issues_count: 0,
+            issues: vec![],
+            notes: vec![],
+        }
+    }
+
+    fn index(name: &'static str) -> Self {
+        Self {
+            is_index: true,
+            ..Self::new(name)
+        }
+    }
+
+    fn checked_one(&mut self) {
+        self.checked = self.checked.saturating_add(1);
+    }
+
+    fn issue(&mut self, issue: String) {
+        self.issues_count = self.issues_count.saturating_add(1);
+        if self.issues.len() < MAX_LISTED_ISSUES {
+            self.issues.push(issue);
+        }
+    }
+
+    fn note(&mut self, note: String) {
+        self.notes.push(note);
+    }
+}
+
+impl CombinedDatabase {
+    /// Verifies the consistency of the databases:
+    ///
+    /// - The heights of the databases agree with the on-chain database.
+    /// - The block Merkle tree matches the stored blocks.
+    /// - The Merkle roots of the contracts' balances and state match their entries.
+    /// - The off-chain indexes match the on-chain coins, messages and blocks.
+    ///
+    /// The checks keep the expected indexes in memory, so they are intended for
+    /// the offline use by the `db check` command.
+    pub fn check_integrity(&self) -> StorageResult<IntegrityReport> {
+        let checks = vec![
+            self.check_heights()?,
+            self.check_block_merkle_tree()?,
+            self.check_contract_assets_roots()?,
+            self.check_contract_state_roots()?,
+            self.check_owned_coins()?,
+            self.check_owned_messages()?,
+            self.check_coin_balances()?,
+            self.check_message_balances()?,
+            self.check_coins_to_spend()?,
+            self.check_block_ids_to_heights()?,
+        ];
+        Ok(IntegrityReport { checks })
+    }
+
+    /// Rebuilds the off-chain indexes derived from the on-chain state: the owned
+    /// coins and messages, the balances, the coins to spend and the block ids.
+    /// The indexes disabled in the off-chain database are left untouched.
+    ///
+    /// The indexes are rebuilt with the same logic that the off-chain worker
+    /// uses for the new blocks. The off-chain database must be at the on-chain
+    /// height, otherwise the worker would apply the missing blocks on top of
+    /// the indexes that already include them.
+    pub fn rebuild_indexes(&self) -> anyhow::Result<()> {
+        let heights = self.heights()?;
+        if heights.off_chain != heights.on_chain {
+            return Err(anyhow::anyhow!(
+                "The off-chain database height({:?}) doesn't match the on-chain \
+                height({:?}), start the node to let the off-chain database catch up \
+                before rebuilding the indexes",
+                heights.off_chain,
+                heights.on_chain
+            ))
+        }
+
+        let off_chain = self.off_chain();
+        let balances = off_chain.indexation_available(IndexationKind::Balances)?;
+        let coins_to_spend =
+            off_chain.indexation_available(IndexationKind::CoinsToSpend)?;
+        let base_asset_id = self.base_asset_id()?;
+
+        let mut transaction = StorageTransaction::transaction(
+            off_chain.clone(),
+            ConflictPolicy::Overwrite,
+            Default::default(),
+        );
+        clear_column(off_chain, &mut transaction, OwnedCoins::column())?;
+        clear_column(off_chain, &mut transaction, OwnedMessageIds::column())?;
+        clear_column(off_chain, &mut transaction, FuelBlockIdsToHeights::column())?;
+        if balances {
+            clear_column(off_chain, &mut transaction, CoinBalances::column())?;
+            clear_column(off_chain, &mut transaction, MessageBalances::column())?;
+        }
+        if coins_to_spend {
+            clear_column(off_chain, &mut transaction, CoinsToSpendIndex::column())?;
+        }
+
+        let coins = self.coins().map(|coin| coin.map(Event::CoinCreated));
+        let messages = self
+            .messages()
+            .map(|message| message.map(Event::MessageImported));
+        for event in coins.chain(messages) {
+            worker_service::process_executor_events(
+                core::iter::once(Cow::Owned(event?)),
+                &mut transaction,
+                balances,
+                coins_to_spend,
+                &base_asset_id,
+            )?;
+        }
+
+        for block in self.blocks() {
+            let (height, block) = block?;
+            transaction
+                .storage_as_mut::<FuelBlockIdsToHeights>()
+                .insert(&block.id(), &height)?;
+        }
+
+        commit_without_height(off_chain, transaction.into_changes())?;
+        Ok(())
+    }
+
+    fn check_heights(&self) -> StorageResult<CheckReport> {
+        let mut report = CheckReport::new("heights");
+        let heights = self.heights()?;
+        let latest_block = self
+            .on_chain()
+            .iter_all::<FuelBlocks>(Some(IterDirection::Reverse))
+            .next()
+            .transpose()?;
+
+        report.checked_one();
+        let latest_height = latest_block.as_ref().map(|(height, _)| *height);
+        if heights.on_chain != latest_height {
+            report.issue(format!(
+                "The on-chain database height({:?}) doesn't match the latest block({:?})",
+                heights.on_chain, latest_height
+            ));
+        }
+
+        let Some(tip) = heights.on_chain else {
+            report.note("The on-chain database doesn't have a height".to_string());
+            return Ok(report)
+        };
+
+        // The indexes can be rebuilt only at the on-chain height.
+        report.checked_one();
+        match heights.off_chain {
+            Some(height) if height > tip => report.issue(format!(
+                "The off-chain database height({height}) is above the on-chain height({tip})"
+            )),
+            Some(height) if height == tip => {}
+            height => report.issue(format!(
+                "The off-chain database height({height:?}) is below the on-chain \
+                height({tip}), start the node to let it catch up before the repair"
+            )),
+        }
+
+        for (name, height) in [
+            ("gas-price", heights.gas_price),
+            ("compression", heights.compression),
+        ] {
+            let Some(height) = height else { continue };
+            report.checked_one();
+            if height > tip {
+                report.issue(format!(
+                    "The {name} database height({height}) is above the on-chain height({tip})"
+                ));
+            } else if height < tip {
+                report.note(format!(
+                    "The {name} database height({height}) is below the on-chain \
+                    height({tip}), it catches up when the node starts"
+                ));
+            }
+        }
+
+        if let (Some((_, block)), Some(relayer)) = (latest_block, heights.relayer) {
+            report.checked_one();
+            let da_height = block.header().da_height();
+            if relayer < da_height {
+                report.issue(format!(
+                    "The relayer database height({relayer}) is below the DA height({da_height}) \
+                    of the latest block"
+                ));
+            }
+        }
+
+        Ok(report)
+    }
+
+    fn check_block_merkle_tree(&self) -> StorageResult<CheckReport> {
+        let mut report = CheckReport::new("block_merkle_tree");
+        let on_chain = self.on_chain();
+        let mut calculator: Option<MerkleRootCalculator> = None;
+        let mut previous_height: Option<BlockHeight> = None;
+        let mut previous_metadata: Option<DenseMerkleMetadata> = None;
+
+        for block in on_chain.iter_all::<FuelBlocks>(Some(IterDirection::Forward)) {
+            let (height, block) = block?;
+            report.checked_one();
+            let metadata = on_chain
+                .storage::<FuelBlockMerkleMetadata>()
+                .get(&DenseMetadataKey::Primary(height))?
+                .map(Cow::into_owned);
+
+            match previous_height {
+                None => {
+                    // The tree of the regenesis contains the blocks of the previous
+                    // network, which are not stored, so the roots can't be recomputed.
+                    if metadata.as_ref().is_some_and(|m| m.version() == 1) {
+                        calculator = Some(MerkleRootCalculator::new());
+                    } else {
+                        report.note(format!(
+                            "The block tree starts before the block {height}, \
+                            so only the links between the blocks are verified"
+                        ));
+                    }
+                }
+                Some(previous_height) => {
+                    if previous_height.succ() != Some(height) {
+                        report.issue(format!(
+                            "The blocks between {previous_height} and {height} are missing"
+                        ));
+                    }
+                    if let Some(previous_metadata) = &previous_metadata {
+                        let previous_root = Bytes32::from(*previous_metadata.root());
+                        if block.header().prev_root() != &previous_root {
+                            report.issue(format!(
+                                "The previous root({}) of the block {height} doesn't match \
+                                the root({previous_root}) of the block tree",
+                                block.header().prev_root()
+                            ));
+                        }
+                    }
+                }
+            }
+
+            if let Some(calculator) = calculator.as_mut() {
+                let block_id: Bytes32 = block.id().into();
+                calculator.push(block_id.as_ref());
+            }
+
+            let Some(metadata) = metadata else {
+                report.issue(format!(
+                    "The block {height} doesn't have the Merkle metadata"
+                ));
+                previous_height = Some(height);
+                previous_metadata = None;
+                continue
+            };
+
+            if let Some(previous_metadata) = &previous_metadata {
+                let expected_version = previous_metadata.version().saturating_add(1);
+                if metadata.version() != expected_version {
+                    report.issue(format!(
+                        "The block tree of the block {height} has {} leaves instead of {expected_version}",
+                        metadata.version()
+                    ));
+                }
+            }
+
+            if let Some(calculator) = &calculator {
+                let root = calculator.clone().root();
+                if root != *metadata.root() {
+                    report.issue(format!(
+                        "The root({}) of the block tree at the block {height} doesn't \
+                        match the recomputed root({})",
+                        Bytes32::from(*metadata.root()),
+                        Bytes32::from(root)
+                    ));
+                }
+            }
+
+            previous_height = Some(height);
+            previous_metadata = Some(metadata);
+        }
+
+        if let Some(previous_metadata) = previous_metadata {
+            let latest = on_chain
+                .storage::<FuelBlockMerkleMetadata>()
+                .get(&DenseMetadataKey::Latest)?;
+            let is_latest = latest.is_some_and(|latest| {
+                latest.root() == previous_metadata.root()
+                    && latest.version() == previous_metadata.version()
+            });
+            if !is_latest {
+                report.issue(
+                    "The latest metadata of the block tree doesn't match the latest block"
+                        .to_string(),
+                );
+            }
+        }
+
+        Ok(report)
+    }
+
+    fn check_contract_assets_roots(&self) -> StorageResult<CheckReport> {
+        let on_chain = self.on_chain();
+        let leaves = on_chain
+            .iter_all::<ContractsAssets>(Some(IterDirection::Forward))
+            .map(|entry| {
+                entry.map(|(key, value)| {
+                    (
+                        *key.contract_id(),
+                        MerkleTreeKey::new(key),
+                        value.to_be_bytes(),
+                    )
+                })
+            });
+        let roots = on_chain
+            .iter_all::<ContractsAssetsMerkleMetadata>(Some(IterDirection::Forward))
+            .map(|entry| {
+                entry.map(|(contract_id, metadata)| (contract_id, *metadata.root()))
+            });
+        check_contract_roots("contract_assets_roots", leaves, roots)
+    }
+
+    fn check_contract_state_roots(&self) -> StorageResult<CheckReport> {
+        let on_chain = self.on_chain();
+        let leaves = on_chain
+            .iter_all::<ContractsState>(Some(IterDirection::Forward))
+            .map(|entry| {
+                entry.map(|(key, value)| {
+                    (*key.contract_id(), MerkleTreeKey::new(key), value)
+                })
+            });
+        let roots = on_chain
+            .iter_all::<ContractsStateMerkleMetadata>(Some(IterDirection::Forward))
+            .map(|entry| {
+                entry.map(|(contract_id, metadata)| (contract_id, *metadata.root()))
+            });
+        check_contract_roots("contract_state_roots", leaves, roots)
+    }
+
+    fn check_owned_coins(&self) -> StorageResult<CheckReport> {
+        let mut report = CheckReport::index("owned_coins");
+        let mut expected = BTreeMap::new();
+        for coin in self.coins() {
+            let coin = coin?;
+            expected.insert(owner_coin_id_key(&coin.owner, &coin.utxo_id), coin.utxo_id);
+        }
+
+        for key in self
+            .off_chain()
+            .iter_all_keys::<OwnedCoins>(Some(IterDirection::Forward))
+        {
+            let key = key?;
+            report.checked_one();
+            if expected.remove(&key).is_none() {
+                report.issue(format!(
+                    "The owned coins index has the entry {} of an unknown coin",
+                    hex::encode(key)
+                ));
+            }
+        }
+
+        for utxo_id in expected.into_values() {
+            report.issue(format!(
+                "The coin {utxo_id} is missing in the owned coins index"
+            ));
+        }
+        Ok(report)
+    }
+
+    fn check_owned_messages(&self) -> StorageResult<CheckReport> {
+        let mut report = CheckReport::index("owned_messages");
+        let mut expected = BTreeSet::new();
+        for message in self.messages() {
+            let message = message?;
+            expected.insert((*message.recipient(), *message.nonce()));
+        }
+
+        for key in self
+            .off_chain()
+            .iter_all_keys::<OwnedMessageIds>(Some(IterDirection::Forward))
+        {
+            let key = key?;
+            report.checked_one();
+            if !expected.remove(&(*key.address(), *key.nonce())) {
+                report.issue(format!(
+                    "The owned messages index has the unknown message {} of {}",
+                    key.nonce(),
+                    key.address()
+                ));
+            }
+        }
+
+        for (recipient, nonce) in expected {
+            report.issue(format!(
+                "The message {nonce} of {recipient} is missing in the owned messages index"
+            ));
+        }
+        Ok(report)
+    }
+
+    fn check_coin_balances(&self) -> StorageResult<CheckReport> {
+        let mut report = CheckReport::index("coin_balances");
+        if !self.index_enabled(IndexationKind::Balances, &mut report)? {
+            return Ok(report)
+        }
+
+        let mut expected = BTreeMap::<(Address, AssetId), u128>::new();
+        for coin in self.coins() {
+            let coin = coin?;
+            let balance = expected.entry((coin.owner, coin.asset_id)).or_default();
+            *balance = balance.saturating_add(u128::from(coin.amount));
+        }
+
+        for entry in self
+            .off_chain()
+            .iter_all::<CoinBalances>(Some(IterDirection::Forward))
+        {
+            let (key, amount) = entry?;
+            report.checked_one();
+            let expected_amount = expected
+                .remove(&(*key.address(), *key.asset_id()))
+                .unwrap_or_default();
+            if amount != expected_amount {
+                report.issue(format!(
+                    "The balance of {key} is {amount} instead of {expected_amount}"
+                ));
+            }
+        }
+
+        for ((owner, asset_id), amount) in expected {
+            if amount != 0 {
+                report.issue(format!(
+                    "The balance {amount} of address={owner} asset_id={asset_id} is missing"
+                ));
+            }
+        }
+        Ok(report)
+    }
+
+    fn check_message_balances(&self) -> StorageResult<CheckReport> {
+        let mut report = CheckReport::index("message_balances");
+        if !self.index_enabled(IndexationKind::Balances, &mut report)? {
+            return Ok(report)
+        }
+
+        let mut expected = BTreeMap::<Address, MessageBalance>::new();
+        for message in self.messages() {
+            let message = message?;
+            let balance = expected.entry(*message.recipient()).or_default();
+            let amount = u128::from(message.amount());
+            if message.is_retryable_message() {
+                balance.retryable = balance.retryable.saturating_add(amount);
+            } else {
+                balance.non_retryable = balance.non_retryable.saturating_add(amount);
+            }
+        }
+
+        for entry in self
+            .off_chain()
+            .iter_all::<MessageBalances>(Some(IterDirection::Forward))
+        {
+            let (owner, balance) = entry?;
+            report.checked_one();
+            let expected_balance = expected.remove(&owner).unwrap_or_default();
+            if balance != expected_balance {
+                report.issue(format!(
+                    "The message balance of {owner} is {balance:?} instead of {expected_balance:?}"
+                ));
+            }
+        }
+
+        for (owner, balance) in expected {
+            if balance != MessageBalance::default() {
+                report.issue(format!(
+                    "The message balance {balance:?} of {owner} is missing"
+                ));
+            }
+        }
+        Ok(report)
+    }
+
+    fn check_coins_to_spend(&self) -> StorageResult<CheckReport> {
+        let mut report = CheckReport::index("coins_to_spend");
+        if !self.index_enabled(IndexationKind::CoinsToSpend, &mut report)? {
+            return Ok(report)
+        }
+
+        let base_asset_id = self.base_asset_id()?;
+        let mut expected = BTreeSet::new();
+        for coin in self.coins() {
+            expected.insert(CoinsToSpendIndexKey::from_coin(&coin?));
+        }
+        for message in self.messages() {
+            expected.insert(CoinsToSpendIndexKey::from_message(
+                &message?,
+                &base_asset_id,
+            ));
+        }
+
+        for key in self
+            .off_chain()
+            .iter_all_keys::<CoinsToSpendIndex>(Some(IterDirection::Forward))
+        {
+            let key = key?;
+            report.checked_one();
+            if !expected.remove(&key) {
+                report.issue(format!(
+                    "The coins to spend index has the unknown entry {key}"
+                ));
+            }
+        }
+
+        for key in expected {
+            report.issue(format!(
+                "The entry {key} is missing in the coins to spend index"
+            ));
+        }
+        Ok(report)
+    }
+
+    fn check_block_ids_to_heights(&self) -> StorageResult<CheckReport> {
+        let mut report = CheckReport::index("block_ids_to_heights");
+        let off_chain = self.off_chain();
+
+        for block in self.blocks() {
+            let (height, block) = block?;
+            report.checked_one();
+            let block_id = block.id();
+            let indexed_height = off_chain
+                .storage::<FuelBlockIdsToHeights>()
+                .get(&block_id)?
+                .map(Cow::into_owned);
+            if indexed_height != Some(height) {
+                report.issue(format!(
+                    "The block {block_id} at the height {height} is indexed at the height {indexed_height:?}"
+                ));
+            }
+        }
+
+        for entry in
+            off_chain.iter_all::<FuelBlockIdsToHeights>(Some(IterDirection::Forward))
+        {
+            let (block_id, height) = entry?;
+            let block = self.block_at(&height)?;
+            if block.is_none_or(|block| block.id() != block_id) {
+                report.issue(format!(
+                    "The block {block_id} is indexed at the height {height}, \
+                    but there is no such block"
+                ));
+            }
+        }
+        Ok(report)
+    }
+
+    /// Returns `true` if the index is enabled in the off-chain database.
+    /// Otherwise, notes in the `report` that the index is not verified.
+    fn index_enabled(
+        &self,
+        kind: IndexationKind,
+        report: &mut CheckReport,
+    ) -> StorageResult<bool> {
+        let enabled = self.off_chain().indexation_available(kind)?;
+        if !enabled {
+            report.note(format!("The {kind:?} indexation is disabled"));
+        }
+        Ok(enabled)
+    }
+
+    /// Returns the base asset id from the latest consensus parameters.
+    pub(crate) fn base_asset_id(&self) -> StorageResult<AssetId> {
+        let (_, consensus_parameters) = self
+            .on_chain()
+            .iter_all::<ConsensusParametersVersions>(Some(IterDirection::Reverse))
+            .next()
+            .ok_or(not_found!(ConsensusParametersVersions))??;
+        Ok(*consensus_parameters.base_asset_id())
+    }
+
+    fn coins(&self) -> impl Iterator<Item = StorageResult<Coin>> + '_ {
+        self.on_chain()
+            .iter_all::<Coins>(Some(IterDirection::Forward))
+            .map(|entry| entry.map(|(utxo_id, coin)| coin.uncompress(utxo_id)))
+    }
+
+    fn messages(&self) -> impl Iterator<Item = StorageResult<Message>> + '_ {
+        self.on_chain()
+            .iter_all::<Messages>(Some(IterDirection::Forward))
+            .map(|entry| entry.map(|(_, message)| message))
+    }
+
+    /// Returns all blocks known to the node, including the blocks from before
+    /// the regenesis.
+    fn blocks(
+        &self,
+    ) -> impl Iterator<Item = StorageResult<(BlockHeight, CompressedBlock)>> + '_ {
+        let old_blocks = self
+            .off_chain()
+            .iter_all::<OldFuelBlocks>(Some(IterDirection::Forward));
+        let blocks = self
+            .on_chain()
+            .iter_all::<FuelBlocks>(Some(IterDirection::Forward));
+        old_blocks.chain(blocks)
+    }
+}
+
+/// Compares the roots of the contracts' sparse Merkle trees with the roots
+/// recomputed from the `leaves`. Both iterators are sorted by the contract id.
+fn check_contract_roots<Leaves, Roots, Value>(
+    name: &'static str,
+    leaves: Leaves,
+    roots: Roots,
+) -> StorageResult<CheckReport>
+where
+    Leaves: Iterator<Item = StorageResult<(ContractId, MerkleTreeKey, Value)>>,
+    Roots: Iterator<Item = StorageResult<(ContractId, MerkleRoot)>>,
+    Value: AsRef<[u8]>,
+{
+    let mut report = CheckReport::new(name);
+    let mut computed_roots = BTreeMap::new();
+    let mut current: Option<(ContractId, Vec<(MerkleTreeKey, Value)>)> = None;
+
+    for leaf in leaves {
+        let (contract_id, key, value) = leaf?;
+        let is_current = current.as_ref().is_some_and(|(id, _)| *id == contract_id);
+        if !is_current {
+            if let Some((id, set)) = current.replace((contract_id, vec![])) {
+                computed_roots.insert(
+                    id,
+                    sparse::in_memory::MerkleTree::root_from_set(set.into_iter()),
+                );
+            }
+        }
+        if let Some((_, set)) = current.as_mut() {
+            set.push((key, value));
+        }
+    }
+    if let Some((id, set)) = current {
+        computed_roots.insert(
+            id,
+            sparse::in_memory::MerkleTree::root_from_set(set.into_iter()),
+        );
+    }
+
+    for root in roots {
+        let (contract_id, root) = root?;
+        report.checked_one();
+        let computed_root = computed_roots
+            .remove(&contract_id)
+            .unwrap_or(*sparse::empty_sum());
+        if root != computed_root {
+            report.issue(format!(
+                "The root({}) of the contract {contract_id} doesn't match the recomputed root({})",
+                Bytes32::from(root),
+                Bytes32::from(computed_root)
+            ));
+        }
+    }
+
+    for contract_id in computed_roots.into_keys() {
+        report.issue(format!(
+            "The contract {contract_id} has entries, but doesn't have the Merkle metadata"
+        ));
+    }
+    Ok(report)
+}
+
+/// Removes all entries of the `column` from the off-chain database within
+/// the `transaction`.
+fn clear_column(
+    database: &Database<OffChain>,
+    transaction: &mut StorageTransaction<Database<OffChain>>,
+    column: <OffChain as DatabaseDescription>::Column,
+) -> StorageResult<()> {
+    for key in database.iter_store_keys(column, None, None, IterDirection::Forward) {
+        transaction.delete(&key?, column)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::graphql_api::storage::{
+        balances::CoinBalancesKey,
+        messages::OwnedMessageKey,
+    };
+    use fuel_core_storage::{
+        ContractsAssetKey,
+        ContractsStateKey,
+        tables::merkle::SparseMerkleMetadata,
+    };
+    use fuel_core_types::{
+        blockchain::block::Block,
+        entities::relayer::message::MessageV1,
+        fuel_tx::{
+            ConsensusParameters,
+            UtxoId,
+        },
+        fuel_types::{
+            ChainId,
+            Nonce,
+        },
+    };
+
+    const BLOCKS: u32 = 3;
+
+    fn owner() -> Address {
+        Address::from([1; 32])
+    }
+
+    fn contract_id() -> ContractId {
+        ContractId::from([2; 32])
+    }
+
+    fn coin(output_index: u16, amount: u64) -> Coin {
+        Coin {
+            utxo_id: UtxoId::new(Default::default(), output_index),
+            owner: owner(),
+            amount,
+            asset_id: AssetId::BASE,
+            tx_pointer: Default::default(),
+        }
+    }
+
+    fn message(nonce: u8, data: Vec<u8>) -> Message {
+        Message::V1(MessageV1 {
+            sender: Default::default(),
+            recipient: owner(),
+            nonce: Nonce::from([nonce; 32]),
+            amount: 10,
+            data,
+            da_height: Default::default(),
+        })
+    }
+
+    /// Creates the database with coins, messages, a contract and a chain of blocks,
+    /// with the off-chain indexes built from them.
+    fn consistent_database() -> CombinedDatabase {
+        let mut database = CombinedDatabase::in_memory();
+
+        let on_chain = database.on_chain_mut();
+        on_chain
+            .storage_as_mut::<ConsensusParametersVersions>()
+            .insert(&0, &ConsensusParameters::default())
+            .unwrap();
+        for coin in [coin(0, 10), coin(1, 20)] {
+            on_chain
+                .storage_as_mut::<Coins>()
+                .insert(&coin.utxo_id, &coin.clone().compress())
+                .unwrap();
+        }
+        for message in [message(1, vec![]), message(2, vec![1])] {
+            on_chain
+                .storage_as_mut::<Messages>()
+                .insert(message.nonce(), &message)
+                .unwrap();
+        }
+        on_chain
+            .storage_as_mut::<ContractsAssets>()
+            .insert(
+                &ContractsAssetKey::new(&contract_id(), &AssetId::BASE),
+                &100,
+            )
+            .unwrap();
+        on_chain
+            .storage_as_mut::<ContractsState>()
+            .insert(
+                &ContractsStateKey::new(&contract_id(), &Bytes32::zeroed()),
+                [1u8, 2, 3].as_slice(),
+            )
+            .unwrap();
+
+        // The blocks are inserted last, because each of them moves the height
+        // of the database, and the database doesn't accept changes without it.
+        for height in 0..BLOCKS {
+            push_block(&mut database, height, true);
+        }
+
+        database.rebuild_indexes().unwrap();
+        database
+    }
+
+    /// Appends the block at the `height` to the on-chain database. The off-chain
+    /// database follows it only if `index` is set.
+    fn push_block(database: &mut CombinedDatabase, height: u32, index: bool) {
+        let chain_id = ChainId::default();
+        let mut block = Block::default();
+        block.header_mut().set_block_height(height.into());
+        let latest = database
+            .on_chain()
+            .storage::<FuelBlockMerkleMetadata>()
+            .get(&DenseMetadataKey::Latest)
+            .unwrap();
+        if let Some(latest) = latest {
+            block
+                .header_mut()
+                .set_previous_root(Bytes32::from(*latest.root()));
+        }
+        let block = block.compress(&chain_id);
+        database
+            .on_chain_mut()
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&height.into(), &block)
+            .unwrap();
+        if index {
+            database
+                .off_chain_mut()
+                .storage_as_mut::<FuelBlockIdsToHeights>()
+                .insert(&block.id(), &height.into())
+                .unwrap();
+        }
+    }
+
+    fn modify<Description>(
+        database: &Database<Description>,
+        f: impl FnOnce(&mut StorageTransaction<&Database<Description>>),
+    ) where
+        Description: DatabaseDescription,
+    {
+        let mut transaction = StorageTransaction::transaction(
+            database,
+            ConflictPolicy::Overwrite,
+            Default::default(),
+        );
+        f(&mut transaction);
+        commit_without_height(database, transaction.into_changes()).unwrap();
+    }
+
+    fn issues_count(report: &IntegrityReport, name: &str) -> u64 {
+        report
+            .check(name)
+            .expect("The check should exist")
+            .issues_count
+    }
+
+    #[test]
+    fn check_integrity__passes_for_consistent_database() {
+        // Given
+        let database = consistent_database();
+
+        // When
+        let report = database.check_integrity().unwrap();
+
+        // Then
+        assert!(report.is_consistent(), "{report:#?}");
+        let blocks = report.check("block_merkle_tree").unwrap();
+        assert_eq!(blocks.checked, u64::from(BLOCKS));
+        assert!(blocks.notes.is_empty(), "{blocks:#?}");
+        assert_eq!(report.check("owned_coins").unwrap().checked, 2);
+        assert_eq!(report.check("coins_to_spend").unwrap().checked, 4);
+    }
+
+    #[test]
+    fn check_integrity__detects_corrupted_indexes() {
+        // Given
+        let database = consistent_database();
+        let coin = coin(0, 10);
+        modify(database.off_chain(), |transaction| {
+            transaction
+                .storage_as_mut::<OwnedCoins>()
+                .remove(&owner_coin_id_key(&coin.owner, &coin.utxo_id))
+                .unwrap();
+            transaction
+                .storage_as_mut::<CoinBalances>()
+                .insert(&CoinBalancesKey::new(&owner(), &AssetId::BASE), &1)
+                .unwrap();
+        });
+
+        // When
+        let report = database.check_integrity().unwrap();
+
+        // Then
+        assert!(!report.is_consistent());
+        assert!(report.is_repairable());
+        assert_eq!(issues_count(&report, "owned_coins"), 1);
+        assert_eq!(issues_count(&report, "coin_balances"), 1);
+        assert_eq!(issues_count(&report, "coins_to_spend"), 0);
+    }
+
+    #[test]
+    fn check_integrity__detects_wrong_contract_root() {
+        // Given
+        let database = consistent_database();
+        modify(database.on_chain(), |transaction| {
+            transaction
+                .storage_as_mut::<ContractsAssetsMerkleMetadata>()
+                .insert(&contract_id(), &SparseMerkleMetadata::new([0; 32]))
+                .unwrap();
+        });
+
+        // When
+        let report = database.check_integrity().unwrap();
+
+        // Then
+        assert!(!report.is_repairable());
+        assert_eq!(issues_count(&report, "contract_assets_roots"), 1);
+        assert_eq!(issues_count(&report, "contract_state_roots"), 0);
+    }
+
+    #[test]
+    fn check_integrity__detects_broken_block_tree() {
+        // Given
+        let database = consistent_database();
+        modify(database.on_chain(), |transaction| {
+            transaction
+                .storage_as_mut::<FuelBlockMerkleMetadata>()
+                .insert(
+                    &DenseMetadataKey::Primary(1.into()),
+                    &DenseMerkleMetadata::new([0; 32], 2),
+                )
+                .unwrap();
+        });
+
+        // When
+        let report = database.check_integrity().unwrap();
+
+        // Then
+        // The root of the block 1 differs from the recomputed one,
+        // and the block 2 doesn't link to it.
+        assert!(!report.is_repairable());
+        assert_eq!(issues_count(&report, "block_merkle_tree"), 2);
+    }
+
+    #[test]
+    fn rebuild_indexes__repairs_the_corrupted_indexes() {
+        // Given
+        let database = consistent_database();
+        let coin = coin(1, 20);
+        modify(database.off_chain(), |transaction| {
+            transaction
+                .storage_as_mut::<CoinsToSpendIndex>()
+                .remove(&CoinsToSpendIndexKey::from_coin(&coin))
+                .unwrap();
+            transaction
+                .storage_as_mut::<OwnedMessageIds>()
+                .remove(&OwnedMessageKey::new(&owner(), &Nonce::from([1; 32])))
+                .unwrap();
+            transaction
+                .storage_as_mut::<FuelBlockIdsToHeights>()
+                .insert(&Default::default(), &7.into())
+                .unwrap();
+        });
+        assert!(!database.check_integrity().unwrap().is_consistent());
+
+        // When
+        database.rebuild_indexes().unwrap();
+
+        // Then
+        let report = database.check_integrity().unwrap();
+        assert!(report.is_consistent(), "{report:#?}");
+    }
+
+    #[test]
+    fn check_integrity__off_chain_database_behind_blocks_the_repair() {
+        // Given
+        let mut database = consistent_database();
+        push_block(&mut database, BLOCKS, false);
+
+        // When
+        let report = database.check_integrity().unwrap();
+
+        // Then
+        assert_eq!(issues_count(&report, "heights"), 1);
+        assert!(!report.is_repairable());
+    }
+
+    #[test]
+    fn rebuild_indexes__fails_when_off_chain_database_is_behind() {
+        // Given
+        let mut database = consistent_database();
+        push_block(&mut database, BLOCKS, false);
+
+        // When
+        let result = database.rebuild_indexes();
+
+        // Then
+        let err = result.expect_err("The indexes shouldn't be rebuilt");
+        assert!(err.to_string().contains("doesn't match"), "{err}");
+    }
+}
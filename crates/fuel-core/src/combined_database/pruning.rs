@@ -0,0 +1,538 @@
+//! Pruning of the old blocks of the [`CombinedDatabase`].
+//!
+//! The pruning removes the transactions of the blocks together with their statuses,
+//! receipts and the indexes built from them. The headers of the blocks, their
+//! consensus and the block Merkle tree are kept, so the proofs keep working for
+//! the pruned blocks. The ids of the pruned transactions stay in the
+//! `ProcessedTransactions` table, which protects the chain from replays and allows
+//! telling pruned transactions apart from unknown ones.
+
+use crate::{
+    combined_database::CombinedDatabase,
+    database::{
+        Database,
+        database_description::{
+            DatabaseDescription,
+            off_chain::OffChain,
+            on_chain::OnChain,
+        },
+    },
+    graphql_api::{
+        storage::{
+            PRUNED_HEIGHT,
+            PRUNING_HEIGHT,
+            old::{
+                OldFuelBlocks,
+                OldTransactions,
+            },
+            receipts::{
+                ContractReceiptKey,
+                ContractReceipts,
+                ReceiptCursor,
+            },
+            statistic::StatisticTable,
+            transactions::{
+                OwnedTransactionIndexKey,
+                OwnedTransactions,
+                TransactionStatuses,
+            },
+        },
+        worker_service::transaction_owners,
+    },
+};
+use fuel_core_storage::{
+    Result as StorageResult,
+    StorageAsMut,
+    StorageAsRef,
+    iter::{
+        IterDirection,
+        IteratorOverTable,
+    },
+    tables::{
+        FuelBlocks,
+        Transactions,
+    },
+    transactional::{
+        Changes,
+        ConflictPolicy,
+        StorageTransaction,
+    },
+};
+use fuel_core_types::{
+    blockchain::block::CompressedBlock,
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
+    fuel_types::BlockHeight,
+    services::transaction_status::TransactionExecutionStatus,
+};
+use std::borrow::Cow;
+
+impl CombinedDatabase {
+    /// Returns the height of the last block with pruned transactions, if any.
+    pub fn pruned_height(&self) -> StorageResult<Option<BlockHeight>> {
+        let height = self
+            .off_chain()
+            .storage::<StatisticTable<BlockHeight>>()
+            .get(PRUNED_HEIGHT)?
+            .map(Cow::into_owned);
+        Ok(height)
+    }
+
+    /// Returns the height of the last block that may miss its transactions,
+    /// including the blocks that are being pruned at the moment.
+    pub fn pruning_height(&self) -> StorageResult<Option<BlockHeight>> {
+        let pruning_height = self
+            .off_chain()
+            .storage::<StatisticTable<BlockHeight>>()
+            .get(PRUNING_HEIGHT)?
+            .map(Cow::into_owned);
+        Ok(pruning_height.max(self.pruned_height()?))
+    }
+
+    /// Prunes the transactions, statuses and receipts of the blocks up to the
+    /// `up_to` height inclusively. At most `max_blocks` blocks are pruned per call,
+    /// so the caller can prune a big range in small batches.
+    ///
+    /// Returns the height of the last pruned block, or `None` if there was
+    /// nothing to prune.
+    pub fn prune_blocks(
+        &self,
+        up_to: BlockHeight,
+        max_blocks: u32,
+    ) -> StorageResult<Option<BlockHeight>> {
+        let from = match self.pruned_height()? {
+            Some(pruned_height) => pruned_height.succ(),
+            None => self.first_block_height()?,
+        };
+        let Some(from) = from else { return Ok(None) };
+        if from > up_to || max_blocks == 0 {
+            return Ok(None)
+        }
+        let to = u32::from(from)
+            .saturating_add(max_blocks.saturating_sub(1))
+            .min(u32::from(up_to));
+        let to = BlockHeight::from(to);
+
+        // The readers learn about the pruned blocks before their transactions
+        // disappear. The pruned height is updated only after the removal, so
+        // the interrupted batch is pruned again by the next call. The marker
+        // is never lowered, the interrupted batch could go further than this one.
+        if self.pruning_height()? < Some(to) {
+            self.set_height_marker(PRUNING_HEIGHT, &to)?;
+        }
+
+        let mut on_chain = StorageTransaction::transaction(
+            self.on_chain(),
+            ConflictPolicy::Overwrite,
+            Default::default(),
+        );
+        let mut off_chain = StorageTransaction::transaction(
+            self.off_chain(),
+            ConflictPolicy::Overwrite,
+            Default::default(),
+        );
+        for height in u32::from(from)..=u32::from(to) {
+            let height = BlockHeight::from(height);
+            let Some(block) = self.block_at(&height)? else {
+                continue
+            };
+
+            for (tx_idx, tx_id) in block.transactions().iter().enumerate() {
+                let tx_idx = u16::try_from(tx_idx).map_err(|e| {
+                    anyhow::anyhow!(
+                        "The block has more than `u16::MAX` transactions, {}",
+                        e
+                    )
+                })?;
+                self.prune_transaction(
+                    height,
+                    tx_idx,
+                    tx_id,
+                    &mut on_chain,
+                    &mut off_chain,
+                )?;
+            }
+        }
+
+        // The off-chain indexes are removed before the transactions, because the
+        // owners of the transaction are known only while the transaction exists.
+        // It makes the pruning idempotent if the node stops between the commits.
+        commit_without_height(self.off_chain(), off_chain.into_changes())?;
+        commit_without_height(self.on_chain(), on_chain.into_changes())?;
+
+        self.set_height_marker(PRUNED_HEIGHT, &to)?;
+
+        Ok(Some(to))
+    }
+
+    fn set_height_marker(&self, key: &str, height: &BlockHeight) -> StorageResult<()> {
+        let mut marker = StorageTransaction::transaction(
+            self.off_chain(),
+            ConflictPolicy::Overwrite,
+            Default::default(),
+        );
+        marker
+            .storage_as_mut::<StatisticTable<BlockHeight>>()
+            .insert(key, height)?;
+        commit_without_height(self.off_chain(), marker.into_changes())
+    }
+
+    /// Returns the height of the first block known to the node, including the
+    /// blocks from before the regenesis.
+    pub(crate) fn first_block_height(&self) -> StorageResult<Option<BlockHeight>> {
+        let old = self
+            .off_chain()
+            .iter_all_keys::<OldFuelBlocks>(Some(IterDirection::Forward))
+            .next()
+            .transpose()?;
+        if old.is_some() {
+            return Ok(old)
+        }
+
+        self.on_chain()
+            .iter_all_keys::<FuelBlocks>(Some(IterDirection::Forward))
+            .next()
+            .transpose()
+    }
+
+    pub(crate) fn block_at(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<Option<CompressedBlock>> {
+        let block = self.on_chain().storage::<FuelBlocks>().get(height)?;
+        if let Some(block) = block {
+            return Ok(Some(block.into_owned()))
+        }
+
+        let old_block = self.off_chain().storage::<OldFuelBlocks>().get(height)?;
+        Ok(old_block.map(Cow::into_owned))
+    }
+
+    fn prune_transaction(
+        &self,
+        height: BlockHeight,
+        tx_idx: u16,
+        tx_id: &TxId,
+        on_chain: &mut StorageTransaction<&Database<OnChain>>,
+        off_chain: &mut StorageTransaction<&Database<OffChain>>,
+    ) -> StorageResult<()> {
+        let tx = self.find_transaction(tx_id)?;
+        if let Some(tx) = &tx {
+            for owner in transaction_owners(tx) {
+                off_chain
+                    .storage_as_mut::<OwnedTransactions>()
+                    .remove(&OwnedTransactionIndexKey::new(owner, height, tx_idx))?;
+            }
+        }
+
+        let status = off_chain
+            .storage_as_mut::<TransactionStatuses>()
+            .take(tx_id)?;
+        if let Some(
+            TransactionExecutionStatus::Success { receipts, .. }
+            | TransactionExecutionStatus::Failed { receipts, .. },
+        ) = status
+        {
+            for (receipt_idx, receipt) in receipts.iter().enumerate() {
+                let Some(contract_id) = receipt.id() else {
+                    continue
+                };
+                let Ok(receipt_idx) = u16::try_from(receipt_idx) else {
+                    break
+                };
+                let key = ContractReceiptKey::new(
+                    *contract_id,
+                    ReceiptCursor::new(height, tx_idx, receipt_idx),
+                );
+                off_chain
+                    .storage_as_mut::<ContractReceipts>()
+                    .remove(&key)?;
+            }
+        }
+
+        off_chain
+            .storage_as_mut::<OldTransactions>()
+            .remove(tx_id)?;
+        on_chain.storage_as_mut::<Transactions>().remove(tx_id)?;
+        Ok(())
+    }
+
+    fn find_transaction(&self, tx_id: &TxId) -> StorageResult<Option<Transaction>> {
+        let tx = self.on_chain().storage::<Transactions>().get(tx_id)?;
+        if let Some(tx) = tx {
+            return Ok(Some(tx.into_owned()))
+        }
+
+        let old_tx = self.off_chain().storage::<OldTransactions>().get(tx_id)?;
+        Ok(old_tx.map(Cow::into_owned))
+    }
+}
+
+/// The pruning doesn't belong to any block, so the changes are committed
+/// without updating the height of the database.
+pub(super) fn commit_without_height<Description>(
+    database: &Database<Description>,
+    changes: Changes,
+) -> StorageResult<()>
+where
+    Description: DatabaseDescription,
+{
+    database.commit_without_height(changes)
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::graphql_api::storage::receipts::ContractReceipt;
+    use fuel_core_storage::tables::ProcessedTransactions;
+    use fuel_core_types::{
+        blockchain::block::Block,
+        fuel_tx::{
+            Address,
+            AssetId,
+            ContractId,
+            Output,
+            Receipt,
+            TransactionBuilder,
+            UniqueIdentifier,
+        },
+        fuel_types::ChainId,
+        tai64::Tai64,
+    };
+
+    const BLOCKS: u32 = 5;
+
+    fn owner() -> Address {
+        Address::from([1; 32])
+    }
+
+    fn contract_id() -> ContractId {
+        ContractId::from([2; 32])
+    }
+
+    fn transaction(height: u32) -> Transaction {
+        TransactionBuilder::script(vec![], height.to_be_bytes().to_vec())
+            .add_output(Output::coin(owner(), 10, AssetId::BASE))
+            .finalize_as_transaction()
+    }
+
+    /// Creates the database with one transaction in each block, fully indexed
+    /// by the off-chain database.
+    fn database_with_blocks() -> (CombinedDatabase, Vec<TxId>) {
+        let mut database = CombinedDatabase::in_memory();
+        let chain_id = ChainId::default();
+        let mut tx_ids = vec![];
+        let mut blocks = vec![];
+
+        for height in 0..BLOCKS {
+            let tx = transaction(height);
+            let tx_id = tx.id(&chain_id);
+            let block_height = BlockHeight::from(height);
+            let receipt = Receipt::log(contract_id(), 1, 0, 0, 0, 0, 0);
+
+            let on_chain = database.on_chain_mut();
+            on_chain
+                .storage_as_mut::<Transactions>()
+                .insert(&tx_id, &tx)
+                .unwrap();
+            on_chain
+                .storage_as_mut::<ProcessedTransactions>()
+                .insert(&tx_id, &())
+                .unwrap();
+
+            let off_chain = database.off_chain_mut();
+            off_chain
+                .storage_as_mut::<OwnedTransactions>()
+                .insert(
+                    &OwnedTransactionIndexKey::new(&owner(), block_height, 0),
+                    &tx_id,
+                )
+                .unwrap();
+            off_chain
+                .storage_as_mut::<ContractReceipts>()
+                .insert(
+                    &ContractReceiptKey::new(
+                        contract_id(),
+                        ReceiptCursor::new(block_height, 0, 0),
+                    ),
+                    &ContractReceipt {
+                        tx_id,
+                        receipt: receipt.clone(),
+                    },
+                )
+                .unwrap();
+            off_chain
+                .storage_as_mut::<TransactionStatuses>()
+                .insert(
+                    &tx_id,
+                    &TransactionExecutionStatus::Success {
+                        block_height,
+                        time: Tai64::UNIX_EPOCH,
+                        result: None,
+                        receipts: vec![receipt],
+                        total_gas: 0,
+                        total_fee: 0,
+                    },
+                )
+                .unwrap();
+
+            let mut block = Block::default();
+            block.header_mut().set_block_height(block_height);
+            block.transactions_mut().push(tx);
+            blocks.push(block.compress(&chain_id));
+            tx_ids.push(tx_id);
+        }
+
+        // The blocks are inserted last, because each of them moves the height
+        // of the database, and the database doesn't accept changes without it.
+        for block in blocks {
+            database
+                .on_chain_mut()
+                .storage_as_mut::<FuelBlocks>()
+                .insert(block.header().height(), &block)
+                .unwrap();
+        }
+
+        (database, tx_ids)
+    }
+
+    fn is_pruned(database: &CombinedDatabase, height: u32, tx_id: &TxId) -> bool {
+        let block_height = BlockHeight::from(height);
+        let on_chain = database.on_chain();
+        let off_chain = database.off_chain();
+
+        let tx = on_chain
+            .storage::<Transactions>()
+            .contains_key(tx_id)
+            .unwrap();
+        let status = off_chain
+            .storage::<TransactionStatuses>()
+            .contains_key(tx_id)
+            .unwrap();
+        let owned = off_chain
+            .storage::<OwnedTransactions>()
+            .contains_key(&OwnedTransactionIndexKey::new(&owner(), block_height, 0))
+            .unwrap();
+        let receipt = off_chain
+            .storage::<ContractReceipts>()
+            .contains_key(&ContractReceiptKey::new(
+                contract_id(),
+                ReceiptCursor::new(block_height, 0, 0),
+            ))
+            .unwrap();
+
+        assert_eq!(tx, status);
+        assert_eq!(tx, owned);
+        assert_eq!(tx, receipt);
+        !tx
+    }
+
+    #[test]
+    fn prune_blocks__removes_the_transactions_up_to_the_height() {
+        // Given
+        let (database, tx_ids) = database_with_blocks();
+
+        // When
+        let pruned = database.prune_blocks(2.into(), 100).unwrap();
+
+        // Then
+        assert_eq!(pruned, Some(2.into()));
+        assert_eq!(database.pruned_height().unwrap(), Some(2.into()));
+        for (height, tx_id) in (0..BLOCKS).zip(tx_ids.iter()) {
+            assert_eq!(is_pruned(&database, height, tx_id), height <= 2);
+        }
+    }
+
+    #[test]
+    fn prune_blocks__keeps_the_blocks_and_the_processed_transactions() {
+        // Given
+        let (database, tx_ids) = database_with_blocks();
+
+        // When
+        database.prune_blocks(BLOCKS.into(), 100).unwrap();
+
+        // Then
+        for (height, tx_id) in (0..BLOCKS).zip(tx_ids.iter()) {
+            let block = database
+                .on_chain()
+                .storage::<FuelBlocks>()
+                .get(&height.into())
+                .unwrap()
+                .expect("The block should be kept");
+            assert_eq!(block.transactions(), &[*tx_id]);
+            assert!(
+                database
+                    .on_chain()
+                    .storage::<ProcessedTransactions>()
+                    .contains_key(tx_id)
+                    .unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn prune_blocks__prunes_at_most_max_blocks_per_call() {
+        // Given
+        let (database, tx_ids) = database_with_blocks();
+
+        // When
+        let first = database.prune_blocks(3.into(), 2).unwrap();
+        let second = database.prune_blocks(3.into(), 2).unwrap();
+        let third = database.prune_blocks(3.into(), 2).unwrap();
+
+        // Then
+        assert_eq!(first, Some(1.into()));
+        assert_eq!(second, Some(3.into()));
+        assert_eq!(third, None);
+        for (height, tx_id) in (0..BLOCKS).zip(tx_ids.iter()) {
+            assert_eq!(is_pruned(&database, height, tx_id), height <= 3);
+        }
+    }
+
+    #[cfg(feature = "p2p")]
+    #[test]
+    fn prune_blocks__p2p_does_not_serve_transactions_of_the_pruned_blocks() {
+        use crate::service::adapters::p2p::P2PViewProvider;
+        use fuel_core_p2p::ports::P2pDb;
+        use fuel_core_storage::transactional::AtomicView;
+
+        // Given
+        let (database, _) = database_with_blocks();
+        database.prune_blocks(2.into(), 100).unwrap();
+        let view = P2PViewProvider::new(database).latest_view().unwrap();
+
+        // When
+        let pruned = view.get_transactions(2..4).unwrap();
+        let not_pruned = view.get_transactions(3..5).unwrap();
+
+        // Then
+        assert!(pruned.is_none());
+        let not_pruned = not_pruned.expect("The transactions should be served");
+        assert_eq!(not_pruned.len(), 2);
+    }
+
+    #[test]
+    fn prune_blocks__repeats_the_interrupted_batch() {
+        // Given
+        let (database, tx_ids) = database_with_blocks();
+        database
+            .set_height_marker(PRUNING_HEIGHT, &BlockHeight::from(2))
+            .unwrap();
+
+        // When
+        let pruning_height = database.pruning_height().unwrap();
+        let pruned = database.prune_blocks(1.into(), 100).unwrap();
+
+        // Then
+        assert_eq!(pruning_height, Some(2.into()));
+        assert_eq!(pruned, Some(1.into()));
+        assert_eq!(database.pruned_height().unwrap(), Some(1.into()));
+        assert_eq!(database.pruning_height().unwrap(), Some(2.into()));
+        for (height, tx_id) in (0..BLOCKS).zip(tx_ids.iter()) {
+            assert_eq!(is_pruned(&database, height, tx_id), height <= 1);
+        }
+    }
+}
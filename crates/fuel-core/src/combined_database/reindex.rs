@@ -0,0 +1,912 @@
+//! Offline reindexation of the off-chain indexes of the [`CombinedDatabase`].
+//!
+//! The reindexation rebuilds an index disabled in the off-chain database from the
+//! on-chain tables and the stored blocks, with the same logic that the off-chain
+//! worker uses for the new blocks. Each source of the index is processed by its own
+//! thread in batches. Every batch is committed together with the cursor of its
+//! source, so the interrupted reindexation continues from the last committed batch.
+//! Once all sources are processed, the index is marked as available in the metadata
+//! of the off-chain database, and the node serves it after the next start.
+
+use crate::{
+    combined_database::{
+        CombinedDatabase,
+        ShutdownListener,
+        pruning::commit_without_height,
+    },
+    database::{
+        Database,
+        database_description::{
+            DatabaseDescription,
+            DatabaseMetadata,
+            IndexationKind,
+            off_chain::OffChain,
+        },
+        metadata::MetadataTable,
+    },
+    graphql_api::{
+        indexation,
+        storage::{
+            REINDEX_PROGRESS,
+            assets::AssetsInfo,
+            balances::{
+                CoinBalances,
+                MessageBalances,
+            },
+            coins::CoinsToSpendIndex,
+            receipts::ContractReceipts,
+            statistic::StatisticTable,
+            transactions::TransactionStatuses,
+        },
+    },
+};
+use fuel_core_storage::{
+    Result as StorageResult,
+    StorageAsMut,
+    StorageAsRef,
+    iter::{
+        IterDirection,
+        IterableStore,
+        IteratorOverTable,
+    },
+    kv_store::KeyValueMutate,
+    structured_storage::TableWithBlueprint,
+    tables::{
+        Coins,
+        Messages,
+    },
+    transactional::{
+        ConflictPolicy,
+        StorageTransaction,
+    },
+};
+use fuel_core_types::{
+    fuel_tx::{
+        AssetId,
+        Receipt,
+        TxId,
+        UtxoId,
+    },
+    fuel_types::{
+        BlockHeight,
+        Nonce,
+    },
+    services::{
+        executor::Event,
+        transaction_status::TransactionExecutionStatus,
+    },
+};
+use std::{
+    borrow::Cow,
+    collections::HashSet,
+    sync::atomic::{
+        AtomicBool,
+        Ordering,
+    },
+};
+
+/// The on-chain data the index is rebuilt from.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ReindexSource {
+    /// The unspent coins from the `Coins` table.
+    Coins,
+    /// The unspent messages from the `Messages` table.
+    Messages,
+    /// The receipts of the stored blocks' transactions.
+    Blocks,
+}
+
+/// The progress of one source of the index, reported after each batch.
+#[derive(Copy, Clone, Debug)]
+pub struct ReindexProgress {
+    /// The index being rebuilt.
+    pub kind: IndexationKind,
+    /// The source of the index.
+    pub source: ReindexSource,
+    /// The number of entries of the source processed during this run.
+    pub processed: u64,
+    /// `true` if the source is fully processed.
+    pub done: bool,
+}
+
+/// The state of the reindexation of one index.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+struct ReindexState {
+    /// The height of the on-chain database when the reindexation started.
+    /// The reindexation starts from the beginning if the height changes.
+    on_chain_height: Option<BlockHeight>,
+    /// `true` once the old entries of the index are removed.
+    cleared: bool,
+}
+
+/// The last entry of the source included into the index.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+enum ReindexCursor {
+    Coin(UtxoId),
+    Message(Nonce),
+    Block(BlockHeight),
+    Done,
+}
+
+impl CombinedDatabase {
+    /// Rebuilds the off-chain indexes of the given `kinds` from the on-chain data
+    /// and marks them as available. The indexes that are already available are
+    /// skipped.
+    ///
+    /// The sources of the indexes are processed in parallel, in batches of
+    /// `batch_size` entries. After each batch, the `progress` is called with
+    /// the progress of the source. The reindexation stops between the batches
+    /// when the shutdown signal is received and continues from the last batch
+    /// on the next call.
+    pub fn reindex<S, P>(
+        &self,
+        kinds: &[IndexationKind],
+        batch_size: usize,
+        shutdown_listener: &S,
+        progress: P,
+    ) -> anyhow::Result<()>
+    where
+        S: ShutdownListener + Sync,
+        P: Fn(&ReindexProgress) + Sync,
+    {
+        if batch_size == 0 {
+            return Err(anyhow::anyhow!("The batch size must be positive"));
+        }
+
+        let mut pending = vec![];
+        for kind in kinds.iter().copied() {
+            if pending.contains(&kind) {
+                continue
+            }
+            if self.off_chain().indexation_available(kind)? {
+                tracing::info!("The {kind:?} index is already available");
+            } else {
+                pending.push(kind);
+            }
+        }
+        if pending.is_empty() {
+            return Ok(())
+        }
+
+        let base_asset_id = self.base_asset_id()?;
+        for kind in &pending {
+            self.prepare_reindex(*kind, batch_size)?;
+        }
+
+        let failed = AtomicBool::new(false);
+        let results = std::thread::scope(|scope| {
+            let tasks = pending
+                .iter()
+                .flat_map(|kind| {
+                    sources(*kind).iter().map(move |source| (*kind, *source))
+                })
+                .map(|(kind, source)| {
+                    let database = self.clone();
+                    let failed = &failed;
+                    let progress = &progress;
+                    scope.spawn(move || {
+                        let is_cancelled = || {
+                            shutdown_listener.is_cancelled()
+                                || failed.load(Ordering::Relaxed)
+                        };
+                        let result = database.reindex_source(
+                            kind,
+                            source,
+                            batch_size,
+                            &base_asset_id,
+                            is_cancelled,
+                            progress,
+                        );
+                        if result.is_err() {
+                            // Stops other tasks, the progress is kept for the next run.
+                            failed.store(true, Ordering::Relaxed);
+                        }
+                        result
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            tasks
+                .into_iter()
+                .map(|task| {
+                    task.join().unwrap_or_else(|_| {
+                        Err(anyhow::anyhow!("The reindexation task panicked"))
+                    })
+                })
+                .collect::<Vec<_>>()
+        });
+        for result in results {
+            result?;
+        }
+
+        if shutdown_listener.is_cancelled() {
+            return Err(anyhow::anyhow!(
+                "Stop the reindexation due to shutdown signal received, \
+                it continues from the last batch on the next run"
+            ));
+        }
+
+        for kind in pending {
+            self.finish_reindex(kind)?;
+        }
+        Ok(())
+    }
+
+    /// Validates that the index can be rebuilt and removes its old entries.
+    /// The removal is skipped if the previous run already did it for the
+    /// same on-chain height.
+    ///
+    /// The off-chain database must be at the on-chain height, otherwise the
+    /// transaction statuses of the latest blocks are missing, and the off-chain
+    /// worker would index the same blocks again after the reindexation.
+    fn prepare_reindex(
+        &self,
+        kind: IndexationKind,
+        batch_size: usize,
+    ) -> anyhow::Result<()> {
+        let on_chain_height = self.on_chain().latest_height_from_metadata()?;
+        let off_chain_height = self.off_chain().latest_height_from_metadata()?;
+        if off_chain_height != on_chain_height {
+            return Err(anyhow::anyhow!(
+                "The off-chain database height({off_chain_height:?}) doesn't match \
+                the on-chain height({on_chain_height:?}), start the node to let the \
+                off-chain database catch up before rebuilding the {kind:?} index"
+            ))
+        }
+
+        if sources(kind).contains(&ReindexSource::Blocks) {
+            if let Some(pruned_height) = self.pruned_height()? {
+                return Err(anyhow::anyhow!(
+                    "The transactions of the blocks up to the height {pruned_height} \
+                    are pruned, the {kind:?} index can't be rebuilt from them"
+                ));
+            }
+        }
+
+        let key = state_key(kind);
+        let state = self
+            .off_chain()
+            .storage::<StatisticTable<ReindexState>>()
+            .get(key.as_str())?
+            .map(Cow::into_owned);
+
+        let mut state = match state {
+            Some(state) if state.on_chain_height == on_chain_height => {
+                tracing::info!("Continuing the reindexation of the {kind:?} index");
+                state
+            }
+            state => {
+                if state.is_some() {
+                    tracing::warn!(
+                        "The on-chain database has changed since the previous \
+                        reindexation of the {kind:?} index, starting from the beginning"
+                    );
+                }
+                let state = ReindexState {
+                    on_chain_height,
+                    cleared: false,
+                };
+                let mut transaction = self.off_chain_transaction();
+                transaction
+                    .storage_as_mut::<StatisticTable<ReindexState>>()
+                    .insert(key.as_str(), &state)?;
+                for source in sources(kind) {
+                    transaction
+                        .storage_as_mut::<StatisticTable<ReindexCursor>>()
+                        .remove(cursor_key(kind, *source).as_str())?;
+                }
+                commit_without_height(self.off_chain(), transaction.into_changes())?;
+                state
+            }
+        };
+
+        if !state.cleared {
+            for column in columns(kind) {
+                self.clear_off_chain_column(column, batch_size)?;
+            }
+            state.cleared = true;
+            let mut transaction = self.off_chain_transaction();
+            transaction
+                .storage_as_mut::<StatisticTable<ReindexState>>()
+                .insert(key.as_str(), &state)?;
+            commit_without_height(self.off_chain(), transaction.into_changes())?;
+        }
+        Ok(())
+    }
+
+    /// Marks the index as available and removes the progress of its reindexation.
+    fn finish_reindex(&self, kind: IndexationKind) -> StorageResult<()> {
+        let mut transaction = self.off_chain_transaction();
+        let metadata = transaction
+            .storage::<MetadataTable<OffChain>>()
+            .get(&())?
+            .map(Cow::into_owned);
+        let metadata = match metadata {
+            Some(DatabaseMetadata::V1 { version, height }) => {
+                Some(DatabaseMetadata::V2 {
+                    version,
+                    height,
+                    indexation_availability: HashSet::from([kind]),
+                })
+            }
+            Some(DatabaseMetadata::V2 {
+                version,
+                height,
+                mut indexation_availability,
+            }) => {
+                indexation_availability.insert(kind);
+                Some(DatabaseMetadata::V2 {
+                    version,
+                    height,
+                    indexation_availability,
+                })
+            }
+            // The database without metadata has all indexes available.
+            None => None,
+        };
+        if let Some(metadata) = metadata {
+            transaction
+                .storage_as_mut::<MetadataTable<OffChain>>()
+                .insert(&(), &metadata)?;
+        }
+
+        transaction
+            .storage_as_mut::<StatisticTable<ReindexState>>()
+            .remove(state_key(kind).as_str())?;
+        for source in sources(kind) {
+            transaction
+                .storage_as_mut::<StatisticTable<ReindexCursor>>()
+                .remove(cursor_key(kind, *source).as_str())?;
+        }
+        commit_without_height(self.off_chain(), transaction.into_changes())?;
+
+        tracing::info!("The {kind:?} index is rebuilt and available");
+        Ok(())
+    }
+
+    /// Processes the `source` of the index batch by batch until it is done
+    /// or the reindexation is cancelled.
+    fn reindex_source<C, P>(
+        &self,
+        kind: IndexationKind,
+        source: ReindexSource,
+        batch_size: usize,
+        base_asset_id: &AssetId,
+        is_cancelled: C,
+        progress: &P,
+    ) -> anyhow::Result<()>
+    where
+        C: Fn() -> bool,
+        P: Fn(&ReindexProgress),
+    {
+        let key = cursor_key(kind, source);
+        let mut cursor = self
+            .off_chain()
+            .storage::<StatisticTable<ReindexCursor>>()
+            .get(key.as_str())?
+            .map(Cow::into_owned);
+        let mut processed = 0u64;
+
+        while cursor != Some(ReindexCursor::Done) {
+            if is_cancelled() {
+                return Ok(())
+            }
+
+            let mut transaction = self.off_chain_transaction();
+            let (count, next) = match source {
+                ReindexSource::Coins => self.reindex_coins(
+                    kind,
+                    cursor.as_ref(),
+                    batch_size,
+                    base_asset_id,
+                    &mut transaction,
+                )?,
+                ReindexSource::Messages => self.reindex_messages(
+                    kind,
+                    cursor.as_ref(),
+                    batch_size,
+                    base_asset_id,
+                    &mut transaction,
+                )?,
+                ReindexSource::Blocks => self.reindex_blocks(
+                    kind,
+                    cursor.as_ref(),
+                    batch_size,
+                    &mut transaction,
+                )?,
+            };
+            transaction
+                .storage_as_mut::<StatisticTable<ReindexCursor>>()
+                .insert(key.as_str(), &next)?;
+            commit_without_height(self.off_chain(), transaction.into_changes())?;
+
+            processed = processed.saturating_add(count);
+            progress(&ReindexProgress {
+                kind,
+                source,
+                processed,
+                done: next == ReindexCursor::Done,
+            });
+            cursor = Some(next);
+        }
+        Ok(())
+    }
+
+    /// Indexes the next batch of coins after the `cursor`.
+    fn reindex_coins(
+        &self,
+        kind: IndexationKind,
+        cursor: Option<&ReindexCursor>,
+        batch_size: usize,
+        base_asset_id: &AssetId,
+        transaction: &mut StorageTransaction<Database<OffChain>>,
+    ) -> anyhow::Result<(u64, ReindexCursor)> {
+        let last = match cursor {
+            Some(ReindexCursor::Coin(utxo_id)) => Some(*utxo_id),
+            _ => None,
+        };
+        let coins = self
+            .on_chain()
+            .iter_all_by_start::<Coins>(last.as_ref(), Some(IterDirection::Forward))
+            .filter(|entry| {
+                !matches!(entry, Ok((utxo_id, _)) if Some(utxo_id) == last.as_ref())
+            })
+            .take(batch_size)
+            .collect::<StorageResult<Vec<_>>>()?;
+
+        let next = match coins.last() {
+            Some((utxo_id, _)) => ReindexCursor::Coin(*utxo_id),
+            None => ReindexCursor::Done,
+        };
+        let count = u64::try_from(coins.len()).unwrap_or(u64::MAX);
+        for (utxo_id, coin) in coins {
+            let event = Event::CoinCreated(coin.uncompress(utxo_id));
+            index_event(kind, &event, transaction, base_asset_id)?;
+        }
+        Ok((count, next))
+    }
+
+    /// Indexes the next batch of messages after the `cursor`.
+    fn reindex_messages(
+        &self,
+        kind: IndexationKind,
+        cursor: Option<&ReindexCursor>,
+        batch_size: usize,
+        base_asset_id: &AssetId,
+        transaction: &mut StorageTransaction<Database<OffChain>>,
+    ) -> anyhow::Result<(u64, ReindexCursor)> {
+        let last = match cursor {
+            Some(ReindexCursor::Message(nonce)) => Some(*nonce),
+            _ => None,
+        };
+        let messages = self
+            .on_chain()
+            .iter_all_by_start::<Messages>(last.as_ref(), Some(IterDirection::Forward))
+            .filter(
+                |entry| !matches!(entry, Ok((nonce, _)) if Some(nonce) == last.as_ref()),
+            )
+            .take(batch_size)
+            .collect::<StorageResult<Vec<_>>>()?;
+
+        let next = match messages.last() {
+            Some((nonce, _)) => ReindexCursor::Message(*nonce),
+            None => ReindexCursor::Done,
+        };
+        let count = u64::try_from(messages.len()).unwrap_or(u64::MAX);
+        for (_, message) in messages {
+            let event = Event::MessageImported(message);
+            index_event(kind, &event, transaction, base_asset_id)?;
+        }
+        Ok((count, next))
+    }
+
+    /// Indexes the receipts of the next batch of blocks after the `cursor`.
+    /// The blocks are processed in order, because the asset metadata
+    /// accumulates the minted and burned amounts.
+    fn reindex_blocks(
+        &self,
+        kind: IndexationKind,
+        cursor: Option<&ReindexCursor>,
+        batch_size: usize,
+        transaction: &mut StorageTransaction<Database<OffChain>>,
+    ) -> anyhow::Result<(u64, ReindexCursor)> {
+        let from = match cursor {
+            Some(ReindexCursor::Block(last)) => last.succ(),
+            _ => self.first_block_height()?,
+        };
+        let latest = self.on_chain().latest_height_from_metadata()?;
+        let (Some(from), Some(latest)) = (from, latest) else {
+            return Ok((0, ReindexCursor::Done))
+        };
+        if from > latest {
+            return Ok((0, ReindexCursor::Done))
+        }
+        let batch_size = u32::try_from(batch_size).unwrap_or(u32::MAX);
+        let to = u32::from(from)
+            .saturating_add(batch_size.saturating_sub(1))
+            .min(u32::from(latest));
+
+        let mut count = 0u64;
+        for height in u32::from(from)..=to {
+            let height = BlockHeight::from(height);
+            let Some(block) = self.block_at(&height)? else {
+                continue
+            };
+
+            for (tx_idx, tx_id) in block.transactions().iter().enumerate() {
+                let tx_idx = u16::try_from(tx_idx).map_err(|e| {
+                    anyhow::anyhow!(
+                        "The block has more than `u16::MAX` transactions, {}",
+                        e
+                    )
+                })?;
+                let status = self
+                    .off_chain()
+                    .storage::<TransactionStatuses>()
+                    .get(tx_id)?
+                    .ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "The status of the transaction {tx_id} from the block \
+                            {height} is missing, the {kind:?} index can't be rebuilt"
+                        )
+                    })?;
+                // The off-chain worker indexes only the receipts of the successful
+                // transactions.
+                if let TransactionExecutionStatus::Success { receipts, .. } = &*status {
+                    index_receipts(
+                        kind,
+                        height,
+                        tx_idx,
+                        tx_id,
+                        receipts.as_slice(),
+                        transaction,
+                    )?;
+                }
+            }
+            count = count.saturating_add(1);
+        }
+        Ok((count, ReindexCursor::Block(to.into())))
+    }
+
+    /// Removes all entries of the `column` from the off-chain database,
+    /// committing every `batch_size` removals.
+    fn clear_off_chain_column(
+        &self,
+        column: <OffChain as DatabaseDescription>::Column,
+        batch_size: usize,
+    ) -> StorageResult<()> {
+        loop {
+            let keys = self
+                .off_chain()
+                .iter_store_keys(column, None, None, IterDirection::Forward)
+                .take(batch_size)
+                .collect::<StorageResult<Vec<_>>>()?;
+            if keys.is_empty() {
+                return Ok(())
+            }
+
+            let mut transaction = self.off_chain_transaction();
+            for key in keys {
+                transaction.delete(&key, column)?;
+            }
+            commit_without_height(self.off_chain(), transaction.into_changes())?;
+        }
+    }
+
+    fn off_chain_transaction(&self) -> StorageTransaction<Database<OffChain>> {
+        StorageTransaction::transaction(
+            self.off_chain().clone(),
+            ConflictPolicy::Overwrite,
+            Default::default(),
+        )
+    }
+}
+
+/// Returns the sources the index is built from.
+fn sources(kind: IndexationKind) -> &'static [ReindexSource] {
+    match kind {
+        IndexationKind::Balances | IndexationKind::CoinsToSpend => {
+            &[ReindexSource::Coins, ReindexSource::Messages]
+        }
+        IndexationKind::AssetMetadata | IndexationKind::ContractReceipts => {
+            &[ReindexSource::Blocks]
+        }
+    }
+}
+
+/// Returns the columns of the off-chain database that store the index.
+fn columns(kind: IndexationKind) -> Vec<<OffChain as DatabaseDescription>::Column> {
+    match kind {
+        IndexationKind::Balances => {
+            vec![CoinBalances::column(), MessageBalances::column()]
+        }
+        IndexationKind::CoinsToSpend => vec![CoinsToSpendIndex::column()],
+        IndexationKind::AssetMetadata => vec![AssetsInfo::column()],
+        IndexationKind::ContractReceipts => vec![ContractReceipts::column()],
+    }
+}
+
+fn state_key(kind: IndexationKind) -> String {
+    format!("{REINDEX_PROGRESS}/{kind:?}")
+}
+
+fn cursor_key(kind: IndexationKind, source: ReindexSource) -> String {
+    format!("{REINDEX_PROGRESS}/{kind:?}/{source:?}")
+}
+
+fn index_event(
+    kind: IndexationKind,
+    event: &Event,
+    transaction: &mut StorageTransaction<Database<OffChain>>,
+    base_asset_id: &AssetId,
+) -> anyhow::Result<()> {
+    match kind {
+        IndexationKind::Balances => {
+            indexation::balances::update(event, transaction, true)?
+        }
+        IndexationKind::CoinsToSpend => {
+            indexation::coins_to_spend::update(event, transaction, true, base_asset_id)?
+        }
+        IndexationKind::AssetMetadata | IndexationKind::ContractReceipts => {}
+    }
+    Ok(())
+}
+
+fn index_receipts(
+    kind: IndexationKind,
+    height: BlockHeight,
+    tx_idx: u16,
+    tx_id: &TxId,
+    receipts: &[Receipt],
+    transaction: &mut StorageTransaction<Database<OffChain>>,
+) -> anyhow::Result<()> {
+    match kind {
+        IndexationKind::AssetMetadata => {
+            indexation::asset_metadata::update(receipts, transaction, true)?
+        }
+        IndexationKind::ContractReceipts => indexation::receipts::update(
+            height,
+            tx_idx,
+            tx_id,
+            receipts,
+            transaction,
+            true,
+        )?,
+        IndexationKind::Balances | IndexationKind::CoinsToSpend => {}
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::graphql_api::storage::{
+        PRUNED_HEIGHT,
+        balances::CoinBalancesKey,
+    };
+    use crate::database::database_description::on_chain::OnChain;
+    use fuel_core_storage::tables::ConsensusParametersVersions;
+    use fuel_core_types::{
+        entities::{
+            Message,
+            coins::coin::Coin,
+            relayer::message::MessageV1,
+        },
+        fuel_tx::{
+            Address,
+            ConsensusParameters,
+        },
+    };
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct Listener(AtomicBool);
+
+    impl ShutdownListener for Listener {
+        fn is_cancelled(&self) -> bool {
+            self.0.load(Ordering::Relaxed)
+        }
+    }
+
+    fn owner() -> Address {
+        Address::from([1; 32])
+    }
+
+    fn set_on_chain_height(database: &CombinedDatabase, height: BlockHeight) {
+        let mut transaction = StorageTransaction::transaction(
+            database.on_chain().clone(),
+            ConflictPolicy::Overwrite,
+            Default::default(),
+        );
+        transaction
+            .storage_as_mut::<MetadataTable<OnChain>>()
+            .insert(
+                &(),
+                &DatabaseMetadata::V1 {
+                    version: OnChain::version(),
+                    height,
+                },
+            )
+            .unwrap();
+        commit_without_height(database.on_chain(), transaction.into_changes()).unwrap();
+    }
+
+    /// Creates the database with two coins and two messages of the `owner`,
+    /// where the off-chain indexes are disabled. Both databases are at the
+    /// genesis height.
+    fn database_without_indexes() -> CombinedDatabase {
+        let mut database = CombinedDatabase::in_memory();
+
+        let on_chain = database.on_chain_mut();
+        on_chain
+            .storage_as_mut::<ConsensusParametersVersions>()
+            .insert(&0, &ConsensusParameters::default())
+            .unwrap();
+        for (output_index, amount) in [(0, 10), (1, 20)] {
+            let coin = Coin {
+                utxo_id: UtxoId::new(Default::default(), output_index),
+                owner: owner(),
+                amount,
+                asset_id: AssetId::BASE,
+                tx_pointer: Default::default(),
+            };
+            on_chain
+                .storage_as_mut::<Coins>()
+                .insert(&coin.utxo_id, &coin.clone().compress())
+                .unwrap();
+        }
+        for nonce in [1, 2] {
+            let message = Message::V1(MessageV1 {
+                sender: Default::default(),
+                recipient: owner(),
+                nonce: Nonce::from([nonce; 32]),
+                amount: 5,
+                data: vec![],
+                da_height: Default::default(),
+            });
+            on_chain
+                .storage_as_mut::<Messages>()
+                .insert(message.nonce(), &message)
+                .unwrap();
+        }
+
+        let mut transaction = database.off_chain_transaction();
+        transaction
+            .storage_as_mut::<MetadataTable<OffChain>>()
+            .insert(
+                &(),
+                &DatabaseMetadata::V2 {
+                    version: OffChain::version(),
+                    height: Default::default(),
+                    indexation_availability: HashSet::new(),
+                },
+            )
+            .unwrap();
+        commit_without_height(database.off_chain(), transaction.into_changes()).unwrap();
+        set_on_chain_height(&database, Default::default());
+        database
+    }
+
+    fn coin_balance(database: &CombinedDatabase) -> Option<u128> {
+        database
+            .off_chain()
+            .storage::<CoinBalances>()
+            .get(&CoinBalancesKey::new(&owner(), &AssetId::BASE))
+            .unwrap()
+            .map(Cow::into_owned)
+    }
+
+    #[test]
+    fn reindex__rebuilds_the_index_and_marks_it_available() {
+        // Given
+        let database = database_without_indexes();
+        let kinds = [IndexationKind::Balances, IndexationKind::CoinsToSpend];
+
+        // When
+        database
+            .reindex(&kinds, 1, &Listener::default(), |_| {})
+            .unwrap();
+
+        // Then
+        assert_eq!(coin_balance(&database), Some(30));
+        for kind in kinds {
+            assert!(database.off_chain().indexation_available(kind).unwrap());
+        }
+        let report = database.check_integrity().unwrap();
+        for check in ["coin_balances", "message_balances", "coins_to_spend"] {
+            let check = report.check(check).unwrap();
+            assert_eq!(check.issues_count, 0, "{check:#?}");
+            assert!(check.notes.is_empty(), "{check:#?}");
+        }
+    }
+
+    #[test]
+    fn reindex__continues_after_interruption() {
+        // Given
+        let database = database_without_indexes();
+        let kinds = [IndexationKind::Balances];
+        let listener = Listener::default();
+        let result = database.reindex(&kinds, 1, &listener, |_| {
+            listener.0.store(true, Ordering::Relaxed);
+        });
+        assert!(result.is_err());
+        assert!(
+            !database
+                .off_chain()
+                .indexation_available(IndexationKind::Balances)
+                .unwrap()
+        );
+
+        // When
+        let processed = Mutex::new(0u64);
+        database
+            .reindex(&kinds, 1, &Listener::default(), |progress| {
+                if progress.done {
+                    let mut processed = processed.lock().unwrap();
+                    *processed = processed.saturating_add(progress.processed);
+                }
+            })
+            .unwrap();
+
+        // Then
+        assert!(*processed.lock().unwrap() < 4);
+        assert_eq!(coin_balance(&database), Some(30));
+        assert!(
+            database
+                .off_chain()
+                .indexation_available(IndexationKind::Balances)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn reindex__fails_for_receipt_based_index_when_blocks_are_pruned() {
+        // Given
+        let database = database_without_indexes();
+        let mut transaction = database.off_chain_transaction();
+        transaction
+            .storage_as_mut::<StatisticTable<BlockHeight>>()
+            .insert(PRUNED_HEIGHT, &BlockHeight::from(1))
+            .unwrap();
+        commit_without_height(database.off_chain(), transaction.into_changes()).unwrap();
+
+        // When
+        let result = database.reindex(
+            &[IndexationKind::AssetMetadata],
+            1,
+            &Listener::default(),
+            |_| {},
+        );
+
+        // Then
+        let err = result.expect_err("The pruned blocks can't be reindexed");
+        assert!(err.to_string().contains("pruned"), "{err}");
+    }
+
+    #[test]
+    fn reindex__fails_when_off_chain_database_is_behind() {
+        // Given
+        let database = database_without_indexes();
+        set_on_chain_height(&database, 1.into());
+
+        // When
+        let result = database.reindex(
+            &[IndexationKind::Balances],
+            1,
+            &Listener::default(),
+            |_| {},
+        );
+
+        // Then
+        let err = result.expect_err("The off-chain database is behind");
+        assert!(err.to_string().contains("catch up"), "{err}");
+        assert_eq!(coin_balance(&database), None);
+        assert!(
+            !database
+                .off_chain()
+                .indexation_available(IndexationKind::Balances)
+                .unwrap()
+        );
+    }
+}
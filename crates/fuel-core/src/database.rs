@@ -15,7 +15,7 @@ use crate::{
     state::{
         ColumnType,
         IterableKeyValueView,
-        KeyValueView,
+        ModifiedKeys,
         data_source::{
             DataSource,
             DataSourceType,
@@ -132,10 +132,8 @@ where
 
 pub type Database<Description = OnChain, Stage = RegularStage<Description>> =
     GenericDatabase<DataSource<Description, Stage>, Empty>;
-pub type OnChainKeyValueView = KeyValueView<ColumnType<OnChain>, HeightType<OnChain>>;
 pub type OnChainIterableKeyValueView =
     IterableKeyValueView<ColumnType<OnChain>, HeightType<OnChain>>;
-pub type OffChainKeyValueView = KeyValueView<ColumnType<OffChain>, HeightType<OffChain>>;
 pub type OffChainIterableKeyValueView =
     IterableKeyValueView<ColumnType<OffChain>, HeightType<OffChain>>;
 pub type RelayerIterableKeyValueView =
@@ -354,6 +352,48 @@ where
         Ok(())
     }
 
+    /// Commits the changes that don't belong to any block, without updating
+    /// the height of the database.
+    ///
+    /// The height lock is held during the commit, so it is not interleaved with
+    /// the commits of the blocks, the creation of the views and the backups.
+    pub(crate) fn commit_without_height(&self, changes: Changes) -> StorageResult<()> {
+        let _lock = self.inner_storage().stage.height.lock();
+        self.inner_storage()
+            .data
+            .commit_changes(None, changes.into())
+    }
+
+    /// Returns the keys modified by the blocks in the `(from, to]` height range,
+    /// grouped by the column id. Requires the history of modifications.
+    pub fn modified_keys(
+        &self,
+        from: &Description::Height,
+        to: &Description::Height,
+    ) -> StorageResult<ModifiedKeys> {
+        self.inner_storage().data.modified_keys(from, to)
+    }
+
+    /// Creates a new backup of the database in the `backup_dir`.
+    ///
+    /// The commits of new blocks wait only until the checkpoint of the database
+    /// is taken, so the backup contains the state at the block boundary. The files
+    /// are copied into the backup after that. Returns the identifier of the
+    /// backup and the height of the database in it.
+    #[cfg(feature = "backup")]
+    pub fn backup(
+        &self,
+        backup_dir: &Path,
+    ) -> StorageResult<(u32, Option<Description::Height>)> {
+        let (checkpoint, height) = {
+            let lock = self.inner_storage().stage.height.lock();
+            let checkpoint = self.inner_storage().data.checkpoint()?;
+            (checkpoint, *lock)
+        };
+        let backup_id = checkpoint.backup(backup_dir)?;
+        Ok((backup_id, height))
+    }
+
     fn latest_view_with_height(
         &self,
         height: Option<Description::Height>,
@@ -386,7 +426,8 @@ where
     Description: DatabaseDescription,
 {
     type Height = Description::Height;
-    type ViewAtHeight = KeyValueView<ColumnType<Description>, Description::Height>;
+    type ViewAtHeight =
+        IterableKeyValueView<ColumnType<Description>, Description::Height>;
 
     fn latest_height(&self) -> Option<Self::Height> {
         *self.inner_storage().stage.height.lock()
@@ -396,15 +437,9 @@ where
         let lock = self.inner_storage().stage.height.lock();
 
         match *lock {
-            None => {
-                return self
-                    .latest_view_with_height(None)
-                    .map(|view| view.into_key_value_view())
-            }
+            None => return self.latest_view_with_height(None),
             Some(current_height) if &current_height == height => {
-                return self
-                    .latest_view_with_height(Some(current_height))
-                    .map(|view| view.into_key_value_view())
+                return self.latest_view_with_height(Some(current_height))
             }
             _ => {}
         };
@@ -63,6 +63,8 @@ impl OnChainIterableKeyValueView {
     }
 
     /// Retrieve the full block and all associated transactions
+    ///
+    /// Fails with the not found error if the transactions of the block are pruned.
     pub fn get_full_block(&self, height: &BlockHeight) -> StorageResult<Option<Block>> {
         let db_block = self.storage::<FuelBlocks>().get(height)?;
         if let Some(block) = db_block {
@@ -85,6 +85,7 @@ pub enum IndexationKind {
     Balances,
     CoinsToSpend,
     AssetMetadata,
+    ContractReceipts,
 }
 
 impl IndexationKind {
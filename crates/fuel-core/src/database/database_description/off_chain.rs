@@ -31,6 +31,10 @@ impl DatabaseDescription for OffChain {
                 // prefix is address length
                 Some(32)
             }
+            Self::Column::ContractReceipts => {
+                // prefix is contract id length
+                Some(32)
+            }
             _ => None,
         }
     }
@@ -56,6 +56,9 @@ pub struct ServiceConfig {
     pub assemble_tx_estimate_predicates_limit: usize,
     /// Configurable cost parameters to limit graphql queries complexity
     pub costs: Costs,
+    /// The token that authorizes requests to the admin API.
+    /// The admin API is disabled if it is not set.
+    pub admin_api_token: Option<String>,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -8,7 +8,6 @@ use crate::{
             ConsensusModulePort,
             GasPriceEstimate,
             OffChainDatabase,
-            OffChainDatabaseAt,
             OnChainDatabase,
             P2pPort,
             TxPoolPort,
@@ -27,6 +26,7 @@ use crate::{
     schema::{
         CoreSchema,
         CoreSchemaBuilder,
+        admin::AuthorizationToken,
     },
     service::{
         adapters::SharedMemoryPool,
@@ -46,11 +46,13 @@ use axum::{
         Extension,
     },
     http::{
+        HeaderMap,
         HeaderValue,
         header::{
             ACCESS_CONTROL_ALLOW_HEADERS,
             ACCESS_CONTROL_ALLOW_METHODS,
             ACCESS_CONTROL_ALLOW_ORIGIN,
+            AUTHORIZATION,
         },
     },
     response::{
@@ -103,7 +105,6 @@ use super::{
     block_height_subscription,
     ports::{
         DatabaseDaCompressedBlocks,
-        OnChainDatabaseAt,
         worker,
     },
 };
@@ -258,8 +259,8 @@ where
     OffChain: HistoricalView<Height = BlockHeight> + worker::OffChainDatabase + 'static,
     OnChain::LatestView: OnChainDatabase,
     OffChain::LatestView: OffChainDatabase,
-    OnChain::ViewAtHeight: OnChainDatabaseAt,
-    OffChain::ViewAtHeight: OffChainDatabaseAt,
+    OnChain::ViewAtHeight: OnChainDatabase,
+    OffChain::ViewAtHeight: OffChainDatabase,
 {
     let balances_indexation_enabled = off_database.balances_indexation_enabled()?;
 
@@ -421,14 +422,25 @@ async fn health() -> Json<serde_json::Value> {
 
 async fn graphql_handler(
     schema: Extension<CoreSchema>,
+    headers: HeaderMap,
     req: Json<Request>,
 ) -> Json<Response> {
-    let response = schema.execute(req.0).await;
+    let mut request = req.0;
+    if let Some(token) = bearer_token(&headers) {
+        request = request.data(AuthorizationToken(token));
+    }
+    let response = schema.execute(request).await;
     let response = unify_response(response);
 
     response.into()
 }
 
+fn bearer_token(headers: &HeaderMap) -> Option<String> {
+    let value = headers.get(AUTHORIZATION)?.to_str().ok()?;
+    let token = value.strip_prefix("Bearer ")?;
+    Some(token.trim().to_string())
+}
+
 async fn graphql_subscription_handler(
     schema: Extension<CoreSchema>,
     req: Json<Request>,
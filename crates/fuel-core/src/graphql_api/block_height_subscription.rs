@@ -4,28 +4,54 @@ use std::{
     sync::Arc,
 };
 
-use fuel_core_types::fuel_types::BlockHeight;
+use fuel_core_types::{
+    fuel_types::BlockHeight,
+    services::block_importer::SharedImportResult,
+};
 use parking_lot::RwLock;
-use tokio::sync::oneshot;
+use tokio::sync::{
+    broadcast,
+    oneshot,
+};
+
+/// The number of imported blocks buffered for slow subscribers of the blocks stream.
+const BLOCKS_CHANNEL_CAPACITY: usize = 128;
 
-#[derive(Default)]
 pub struct Handler {
     inner: Arc<RwLock<HandlersMapInner>>,
+    blocks: broadcast::Sender<SharedImportResult>,
+}
+
+impl Default for Handler {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
 }
 
 impl Handler {
     pub fn new(block_height: BlockHeight) -> Handler {
+        let (blocks, _) = broadcast::channel(BLOCKS_CHANNEL_CAPACITY);
         Self {
             inner: Arc::new(RwLock::new(HandlersMapInner::new(block_height))),
+            blocks,
         }
     }
 
     pub fn subscribe(&self) -> Subscriber {
         Subscriber {
             inner: self.inner.clone(),
+            blocks: self.blocks.clone(),
         }
     }
 
+    /// Notifies subscribers of the blocks stream about the processed block.
+    /// It should be called after `notify_and_update`, so subscribers can rely on
+    /// the block being available in the database.
+    pub fn notify_block_processed(&self, result: SharedImportResult) {
+        // The error means that there are no active subscribers.
+        let _ = self.blocks.send(result);
+    }
+
     pub fn notify_and_update(&self, block_height: BlockHeight) {
         let to_notify = {
             let mut inner_map = self.inner.write();
@@ -49,6 +75,7 @@ impl Handler {
 #[derive(Clone, Debug)]
 pub struct Subscriber {
     inner: Arc<RwLock<HandlersMapInner>>,
+    blocks: broadcast::Sender<SharedImportResult>,
 }
 
 impl Subscriber {
@@ -80,6 +107,11 @@ impl Subscriber {
     pub fn current_block_height(&self) -> BlockHeight {
         self.inner.read().current_block_height
     }
+
+    /// Subscribes to the blocks processed by the off-chain worker.
+    pub fn subscribe_to_blocks(&self) -> broadcast::Receiver<SharedImportResult> {
+        self.blocks.subscribe()
+    }
 }
 
 #[derive(Debug, Default)]
@@ -4,9 +4,7 @@ use crate::{
         database::arc_wrapper::ArcWrapper,
         ports::{
             OffChainDatabase,
-            OffChainDatabaseAt,
             OnChainDatabase,
-            OnChainDatabaseAt,
         },
     },
 };
@@ -83,11 +81,6 @@ pub type OnChainView = Arc<dyn OnChainDatabase>;
 /// The off-chain view of the database used by the [`ReadView`] to fetch off-chain data.
 pub type OffChainView = Arc<dyn OffChainDatabase>;
 
-/// The on-chain view of the database used by the [`ReadViewAt`] to fetch on-chain data.
-pub type OnChainViewAt = Arc<dyn OnChainDatabaseAt>;
-/// The off-chain view of the database used by the [`ReadViewAt`] to fetch off-chain data.
-pub type OffChainViewAt = Arc<dyn OffChainDatabaseAt>;
-
 /// The container of the on-chain and off-chain database view provides.
 /// It is used only by `ViewExtension` to create a [`ReadView`].
 pub struct ReadDatabase {
@@ -100,7 +93,7 @@ pub struct ReadDatabase {
         dyn HistoricalView<
                 LatestView = OnChainView,
                 Height = BlockHeight,
-                ViewAtHeight = OnChainViewAt,
+                ViewAtHeight = OnChainView,
             >,
     >,
     /// The off-chain database view provider.
@@ -108,7 +101,7 @@ pub struct ReadDatabase {
         dyn HistoricalView<
                 LatestView = OffChainView,
                 Height = BlockHeight,
-                ViewAtHeight = OffChainViewAt,
+                ViewAtHeight = OffChainView,
             >,
     >,
     /// The flag indicating which indexation is enabled.
@@ -152,8 +145,8 @@ impl ReadDatabase {
             HistoricalView<Height = BlockHeight> + worker::OffChainDatabase + 'static,
         OnChain::LatestView: OnChainDatabase,
         OffChain::LatestView: OffChainDatabase,
-        OnChain::ViewAtHeight: OnChainDatabaseAt,
-        OffChain::ViewAtHeight: OffChainDatabaseAt,
+        OnChain::ViewAtHeight: OnChainDatabase,
+        OffChain::ViewAtHeight: OffChainDatabase,
     {
         let mut indexation_flags = IndexationFlags::new();
         for kind in IndexationKind::iter() {
@@ -173,6 +166,11 @@ impl ReadDatabase {
                         indexation_flags.insert(kind);
                     }
                 }
+                IndexationKind::ContractReceipts => {
+                    if off_chain.contract_receipts_indexation_enabled()? {
+                        indexation_flags.insert(kind);
+                    }
+                }
             }
         }
         Ok(Self {
@@ -199,11 +197,11 @@ impl ReadDatabase {
     }
 
     /// Creates a consistent view of the database at specific block height.
-    pub fn view_at(&self, block_height: BlockHeight) -> StorageResult<ReadViewAt> {
-        Ok(ReadViewAt {
+    /// The view at the height outside the retained history is an error.
+    pub fn view_at(&self, block_height: BlockHeight) -> StorageResult<ReadView> {
+        Ok(ReadView {
             batch_size: self.batch_size,
             genesis_height: self.genesis_height,
-            view_block_height: block_height,
             on_chain: self.on_chain.view_at(&block_height)?,
             off_chain: self.off_chain.view_at(&block_height)?,
             indexation_flags: self.indexation_flags.clone(),
@@ -231,13 +229,34 @@ impl ReadView {
         if result.is_not_found() {
             match self.off_chain.old_transaction(tx_id)? {
                 Some(tx) => Ok(tx),
-                _ => Err(not_found!(Transactions)),
+                _ => Err(self.missing_transaction_error(tx_id, not_found!(Transactions))),
             }
         } else {
             result
         }
     }
 
+    /// Returns the error for the transaction missing in the database. The pruned
+    /// transactions are reported with the height up to which the node pruned them,
+    /// because the chain still remembers them as processed.
+    fn missing_transaction_error(
+        &self,
+        tx_id: &TxId,
+        not_found: StorageError,
+    ) -> StorageError {
+        let pruned_height = self.off_chain.pruned_height();
+        let processed = self.on_chain.transaction_processed(tx_id);
+        match (pruned_height, processed) {
+            (Ok(Some(pruned_height)), Ok(true)) => anyhow::anyhow!(
+                "The transaction {tx_id} was pruned: the node keeps the transactions \
+                only for the blocks above the height {pruned_height}"
+            )
+            .into(),
+            (Err(err), _) | (_, Err(err)) => err,
+            _ => not_found,
+        }
+    }
+
     pub async fn transactions(
         &self,
         tx_ids: Vec<TxId>,
@@ -430,7 +449,17 @@ impl ReadView {
     }
 
     pub fn tx_status(&self, tx_id: &TxId) -> StorageResult<TransactionExecutionStatus> {
-        self.off_chain.tx_status(tx_id)
+        let result = self.off_chain.tx_status(tx_id);
+        if result.is_not_found() {
+            // The status is pruned together with the transaction,
+            // so the pruned transaction explains the missing status.
+            if let Err(err) = self.transaction(tx_id) {
+                if !err.is_not_found() {
+                    return Err(err)
+                }
+            }
+        }
+        result
     }
 
     pub fn owned_coins_ids(
@@ -483,20 +512,7 @@ impl ReadView {
     pub fn message_is_spent(&self, nonce: &Nonce) -> StorageResult<bool> {
         self.off_chain.message_is_spent(nonce)
     }
-}
-
-#[derive(Clone)]
-#[allow(dead_code)]
-pub struct ReadViewAt {
-    pub(crate) batch_size: usize,
-    pub(crate) genesis_height: BlockHeight,
-    pub(crate) view_block_height: BlockHeight,
-    pub(crate) on_chain: OnChainViewAt,
-    pub(crate) off_chain: OffChainViewAt,
-    pub(crate) indexation_flags: IndexationFlags,
-}
 
-impl ReadViewAt {
     pub fn contract_slot_values(
         &self,
         contract_id: ContractId,
@@ -2,17 +2,13 @@ use crate::{
     fuel_core_graphql_api::{
         database::{
             OffChainView,
-            OffChainViewAt,
             OnChainView,
         },
         ports::{
             OffChainDatabase,
-            OffChainDatabaseAt,
             OnChainDatabase,
-            OnChainDatabaseAt,
         },
     },
-    graphql_api::database::OnChainViewAt,
 };
 use fuel_core_storage::{
     Result as StorageResult,
@@ -56,9 +52,9 @@ impl<Provider> HistoricalView for ArcWrapper<Provider, OnChainView>
 where
     Provider: HistoricalView<Height = BlockHeight>,
     Provider::LatestView: OnChainDatabase + 'static,
-    Provider::ViewAtHeight: OnChainDatabaseAt + 'static,
+    Provider::ViewAtHeight: OnChainDatabase + 'static,
 {
-    type ViewAtHeight = OnChainViewAt;
+    type ViewAtHeight = OnChainView;
 
     type Height = BlockHeight;
 
@@ -87,9 +83,9 @@ impl<Provider> HistoricalView for ArcWrapper<Provider, OffChainView>
 where
     Provider: HistoricalView<Height = BlockHeight>,
     Provider::LatestView: OffChainDatabase + 'static,
-    Provider::ViewAtHeight: OffChainDatabaseAt + 'static,
+    Provider::ViewAtHeight: OffChainDatabase + 'static,
 {
-    type ViewAtHeight = OffChainViewAt;
+    type ViewAtHeight = OffChainView;
 
     type Height = BlockHeight;
 
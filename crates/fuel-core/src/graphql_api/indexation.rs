@@ -2,5 +2,6 @@ pub(crate) mod asset_metadata;
 pub(crate) mod balances;
 pub(crate) mod coins_to_spend;
 pub(crate) mod error;
+pub(crate) mod receipts;
 #[cfg(test)]
 pub(crate) mod test_utils;
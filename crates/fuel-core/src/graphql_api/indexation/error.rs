@@ -4,6 +4,7 @@ use fuel_core_types::{
     fuel_tx::{
         Address,
         AssetId,
+        TxId,
         UtxoId,
     },
     fuel_types::Nonce,
@@ -112,6 +113,12 @@ pub enum IndexationError {
         receipt
     )]
     UnexpectedReceipt { receipt: String },
+    #[display(
+        fmt = "Transaction {} has more than `u16::MAX` receipts: {}",
+        tx_id,
+        receipts_count
+    )]
+    TooManyReceipts { tx_id: TxId, receipts_count: usize },
     #[from]
     StorageError(StorageError),
 }
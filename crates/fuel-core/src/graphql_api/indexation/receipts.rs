@@ -0,0 +1,221 @@
+use fuel_core_types::{
+    fuel_tx::{
+        ContractId,
+        Receipt,
+        TxId,
+    },
+    fuel_types::BlockHeight,
+};
+
+use fuel_core_storage::StorageAsMut;
+
+use crate::graphql_api::{
+    ports::worker::OffChainDatabaseTransaction,
+    storage::receipts::{
+        ContractReceipt,
+        ContractReceiptKey,
+        ContractReceipts,
+        ReceiptCursor,
+    },
+};
+
+use super::error::IndexationError;
+
+/// Indexes all receipts of the transaction that are emitted by a contract.
+/// The receipts emitted by the script have the zero contract id and are skipped.
+pub(crate) fn update<T>(
+    block_height: BlockHeight,
+    tx_idx: u16,
+    tx_id: &TxId,
+    receipts: &[Receipt],
+    block_st_transaction: &mut T,
+    enabled: bool,
+) -> Result<(), IndexationError>
+where
+    T: OffChainDatabaseTransaction,
+{
+    if !enabled {
+        return Ok(());
+    }
+
+    for (receipt_idx, receipt) in receipts.iter().enumerate() {
+        let Some(contract_id) = receipt.id() else {
+            continue
+        };
+        if *contract_id == ContractId::zeroed() {
+            continue
+        }
+        let receipt_idx =
+            u16::try_from(receipt_idx).map_err(|_| IndexationError::TooManyReceipts {
+                tx_id: *tx_id,
+                receipts_count: receipts.len(),
+            })?;
+        let key = ContractReceiptKey::new(
+            *contract_id,
+            ReceiptCursor::new(block_height, tx_idx, receipt_idx),
+        );
+
+        block_st_transaction.storage::<ContractReceipts>().insert(
+            &key,
+            &ContractReceipt {
+                tx_id: *tx_id,
+                receipt: receipt.clone(),
+            },
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_core_storage::{
+        StorageAsRef,
+        iter::{
+            IterDirection,
+            IteratorOverTable,
+        },
+        transactional::WriteTransaction,
+    };
+    use fuel_core_types::fuel_tx::{
+        ContractId,
+        Receipt,
+        ScriptExecutionResult,
+        TxId,
+    };
+
+    use crate::{
+        database::{
+            Database,
+            database_description::off_chain::OffChain,
+        },
+        graphql_api::{
+            indexation::receipts::update,
+            storage::receipts::{
+                ContractReceiptKey,
+                ContractReceipts,
+                ReceiptCursor,
+            },
+        },
+    };
+
+    #[test]
+    fn receipts_index_is_correctly_updated() {
+        let mut db: Database<OffChain> = Database::in_memory();
+        let mut tx = db.write_transaction();
+
+        const RECEIPTS_INDEXATION_IS_ENABLED: bool = true;
+
+        let contract_a = ContractId::from([1u8; 32]);
+        let contract_b = ContractId::from([2u8; 32]);
+        let tx_id = TxId::from([3u8; 32]);
+        let height = 5u32.into();
+
+        let receipts: Vec<Receipt> = vec![
+            Receipt::log(contract_a, 1, 0, 0, 0, 0, 0),
+            Receipt::log(contract_b, 2, 0, 0, 0, 0, 0),
+            Receipt::script_result(ScriptExecutionResult::Success, 0),
+            Receipt::ret(contract_a, 3, 0, 0),
+        ];
+
+        update(
+            height,
+            7,
+            &tx_id,
+            &receipts,
+            &mut tx,
+            RECEIPTS_INDEXATION_IS_ENABLED,
+        )
+        .expect("should process receipts");
+        tx.commit().expect("should commit");
+
+        let indexed = db
+            .iter_all_filtered::<ContractReceipts, _>(
+                Some(contract_a),
+                None,
+                Some(IterDirection::Forward),
+            )
+            .map(|result| result.expect("should read the index"))
+            .collect::<Vec<_>>();
+
+        assert_eq!(indexed.len(), 2);
+        assert_eq!(indexed[0].0.cursor, ReceiptCursor::new(height, 7, 0));
+        assert_eq!(indexed[0].1.tx_id, tx_id);
+        assert_eq!(indexed[0].1.receipt, receipts[0]);
+        assert_eq!(indexed[1].0.cursor, ReceiptCursor::new(height, 7, 3));
+        assert_eq!(indexed[1].1.receipt, receipts[3]);
+
+        let key = ContractReceiptKey::new(contract_b, ReceiptCursor::new(height, 7, 1));
+        let receipt = db
+            .storage::<ContractReceipts>()
+            .get(&key)
+            .expect("should correctly query db")
+            .expect("should have receipt");
+        assert_eq!(receipt.receipt, receipts[1]);
+    }
+
+    #[test]
+    fn receipts_indexation_enabled_flag_is_respected() {
+        let mut db: Database<OffChain> = Database::in_memory();
+        let mut tx = db.write_transaction();
+
+        const RECEIPTS_INDEXATION_IS_DISABLED: bool = false;
+
+        let contract_id = ContractId::from([1u8; 32]);
+        let receipts: Vec<Receipt> = vec![Receipt::log(contract_id, 1, 0, 0, 0, 0, 0)];
+
+        update(
+            5u32.into(),
+            0,
+            &TxId::default(),
+            &receipts,
+            &mut tx,
+            RECEIPTS_INDEXATION_IS_DISABLED,
+        )
+        .expect("should process receipts");
+        tx.commit().expect("should commit");
+
+        let indexed = db
+            .iter_all_filtered::<ContractReceipts, _>(
+                Some(contract_id),
+                None,
+                Some(IterDirection::Forward),
+            )
+            .count();
+
+        assert_eq!(indexed, 0);
+    }
+
+    #[test]
+    fn receipts_emitted_by_the_script_are_not_indexed() {
+        let mut db: Database<OffChain> = Database::in_memory();
+        let mut tx = db.write_transaction();
+
+        let contract_id = ContractId::from([1u8; 32]);
+        let receipts: Vec<Receipt> = vec![
+            Receipt::log(ContractId::zeroed(), 1, 0, 0, 0, 0, 0),
+            Receipt::log(contract_id, 2, 0, 0, 0, 0, 0),
+        ];
+
+        update(5u32.into(), 0, &TxId::default(), &receipts, &mut tx, true)
+            .expect("should process receipts");
+        tx.commit().expect("should commit");
+
+        let script_receipts = db
+            .iter_all_filtered::<ContractReceipts, _>(
+                Some(ContractId::zeroed()),
+                None,
+                Some(IterDirection::Forward),
+            )
+            .count();
+        assert_eq!(script_receipts, 0);
+
+        let contract_receipts = db
+            .iter_all_filtered::<ContractReceipts, _>(
+                Some(contract_id),
+                None,
+                Some(IterDirection::Forward),
+            )
+            .count();
+        assert_eq!(contract_receipts, 1);
+    }
+}
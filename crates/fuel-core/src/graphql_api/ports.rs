@@ -1,6 +1,10 @@
 use super::storage::{
     assets::AssetDetails,
     balances::TotalBalanceAmount,
+    receipts::{
+        ContractReceipt,
+        ReceiptCursor,
+    },
 };
 use crate::fuel_core_graphql_api::storage::coins::CoinsToSpendIndexKey;
 use async_trait::async_trait;
@@ -25,7 +29,10 @@ use fuel_core_storage::{
     },
 };
 use fuel_core_tx_status_manager::TxStatusMessage;
-use fuel_core_txpool::TxPoolStats;
+use fuel_core_txpool::{
+    TxPoolStats,
+    config::BlackList,
+};
 use fuel_core_types::{
     blockchain::{
         block::CompressedBlock,
@@ -69,7 +76,11 @@ use fuel_core_types::{
             StorageReadReplayEvent,
         },
         graphql_api::ContractBalance,
-        p2p::PeerInfo,
+        p2p::{
+            BanTarget,
+            PeerBan,
+            PeerInfo,
+        },
         transaction_status::{
             self,
             TransactionStatus,
@@ -77,7 +88,10 @@ use fuel_core_types::{
     },
     tai64::Tai64,
 };
-use std::sync::Arc;
+use std::{
+    sync::Arc,
+    time::Duration,
+};
 
 pub struct CoinsToSpendIndexIter<'a> {
     pub big_coins_iter: BoxedIter<'a, Result<CoinsToSpendIndexKey, StorageError>>,
@@ -128,6 +142,13 @@ pub trait OffChainDatabase: Send + Sync {
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<(TxPointer, TxId)>>;
 
+    fn contract_receipts(
+        &self,
+        contract_id: &ContractId,
+        start: Option<ReceiptCursor>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(ReceiptCursor, ContractReceipt)>>;
+
     fn coins_to_spend_index(
         &self,
         owner: &Address,
@@ -148,6 +169,9 @@ pub trait OffChainDatabase: Send + Sync {
 
     fn old_transaction(&self, id: &TxId) -> StorageResult<Option<Transaction>>;
 
+    /// Returns the height of the last block with pruned transactions, if any.
+    fn pruned_height(&self) -> StorageResult<Option<BlockHeight>>;
+
     fn relayed_tx_status(
         &self,
         id: Bytes32,
@@ -179,6 +203,10 @@ pub trait DatabaseBlocks {
     /// Get a transaction by its id.
     fn transaction(&self, tx_id: &TxId) -> StorageResult<Transaction>;
 
+    /// Returns `true` if the transaction was included into a block.
+    /// It remains `true` after the transaction is pruned.
+    fn transaction_processed(&self, tx_id: &TxId) -> StorageResult<bool>;
+
     /// Get a block by its height.
     fn block(&self, height: &BlockHeight) -> StorageResult<CompressedBlock>;
 
@@ -239,6 +267,18 @@ pub trait DatabaseContracts:
         &self,
         contract: ContractId,
     ) -> BoxedIter<StorageResult<ContractBalance>>;
+
+    fn contract_slot_values(
+        &self,
+        contract_id: ContractId,
+        storage_slots: Vec<Bytes32>,
+    ) -> BoxedIter<StorageResult<(Bytes32, Vec<u8>)>>;
+
+    fn contract_balance_values(
+        &self,
+        contract_id: ContractId,
+        assets: Vec<AssetId>,
+    ) -> BoxedIter<StorageResult<ContractBalance>>;
 }
 
 /// Trait that specifies all the getters required for chain metadata.
@@ -253,6 +293,17 @@ pub trait TxPoolPort: Send + Sync {
     async fn insert(&self, txs: Transaction) -> anyhow::Result<()>;
 
     fn latest_pool_stats(&self) -> TxPoolStats;
+
+    /// Returns the current blacklist of the pool.
+    fn black_list(&self) -> BlackList;
+
+    /// Updates the blacklist of the pool and returns
+    /// the ids of transactions removed from the pool.
+    async fn update_black_list(
+        &self,
+        add: BlackList,
+        remove: BlackList,
+    ) -> anyhow::Result<Vec<TxId>>;
 }
 
 #[async_trait]
@@ -306,6 +357,25 @@ pub trait DatabaseMessageProof: Send + Sync {
 #[async_trait::async_trait]
 pub trait P2pPort: Send + Sync {
     async fn all_peer_info(&self) -> anyhow::Result<Vec<PeerInfo>>;
+
+    /// Bans the peer or the IP address for the `duration`,
+    /// or until it is unbanned if the `duration` is not set.
+    async fn ban_peer(
+        &self,
+        target: BanTarget,
+        duration: Option<Duration>,
+    ) -> anyhow::Result<()>;
+
+    /// Removes the ban. Returns `true` if the `target` was banned.
+    async fn unban_peer(&self, target: BanTarget) -> anyhow::Result<bool>;
+
+    async fn peer_bans(&self) -> anyhow::Result<Vec<PeerBan>>;
+
+    /// Requests the transactions of the block at the `height` from the peers.
+    async fn block_transactions(
+        &self,
+        height: BlockHeight,
+    ) -> anyhow::Result<Vec<Transaction>>;
 }
 
 /// Trait for defining how to estimate gas price for future blocks
@@ -346,6 +416,7 @@ pub mod worker {
                 OldFuelBlocks,
                 OldTransactions,
             },
+            receipts::ContractReceipts,
             relayed_transactions::RelayedTransactionStatuses,
         },
     };
@@ -395,6 +466,9 @@ pub mod worker {
 
         /// Checks if AssetMetadata indexation functionality is available.
         fn asset_metadata_indexation_enabled(&self) -> StorageResult<bool>;
+
+        /// Checks if ContractReceipts indexation functionality is available.
+        fn contract_receipts_indexation_enabled(&self) -> StorageResult<bool>;
     }
 
     /// Represents either the Genesis Block or a block at a specific height
@@ -420,6 +494,7 @@ pub mod worker {
         + StorageMutate<MessageBalances, Error = StorageError>
         + StorageMutate<CoinsToSpendIndex, Error = StorageError>
         + StorageMutate<AssetsInfo, Error = StorageError>
+        + StorageMutate<ContractReceipts, Error = StorageError>
     {
         fn record_tx_id_owner(
             &mut self,
@@ -485,19 +560,3 @@ pub trait ChainStateProvider: Send + Sync {
     /// Returns the current state transition bytecode version.
     fn current_stf_version(&self) -> StateTransitionBytecodeVersion;
 }
-
-pub trait OnChainDatabaseAt: Send + Sync {
-    fn contract_slot_values(
-        &self,
-        contract_id: ContractId,
-        storage_slots: Vec<Bytes32>,
-    ) -> BoxedIter<StorageResult<(Bytes32, Vec<u8>)>>;
-
-    fn contract_balance_values(
-        &self,
-        contract_id: ContractId,
-        assets: Vec<AssetId>,
-    ) -> BoxedIter<StorageResult<ContractBalance>>;
-}
-
-pub trait OffChainDatabaseAt: Send + Sync {}
@@ -43,6 +43,7 @@ pub mod coins;
 pub mod contracts;
 pub mod messages;
 pub mod old;
+pub mod receipts;
 pub mod statistic;
 pub mod transactions;
 
@@ -51,6 +52,19 @@ pub mod relayed_transactions;
 /// It's useful for analyzing TPS or other metrics.
 const TX_COUNT: &str = "total_tx_count";
 
+/// The height of the last block with pruned transactions, statuses and receipts.
+/// The value is stored in the [`StatisticTable`] as a [`BlockHeight`].
+pub const PRUNED_HEIGHT: &str = "pruned_block_height";
+
+/// The height up to which the transactions are being pruned. It is written before
+/// the transactions are removed, so the blocks up to it may already miss them
+/// while [`PRUNED_HEIGHT`] is lower.
+pub const PRUNING_HEIGHT: &str = "pruning_block_height";
+
+/// The prefix of the keys tracking the progress of the offline reindexation.
+/// The entries are stored in the [`StatisticTable`] and removed once the index is rebuilt.
+pub const REINDEX_PROGRESS: &str = "reindex_progress";
+
 /// GraphQL database tables column ids to the corresponding [`fuel_core_storage::Mappable`] table.
 #[repr(u32)]
 #[derive(
@@ -103,6 +117,8 @@ pub enum Column {
     AssetsInfo = 25,
     /// Index of the coins that are available to spend.
     CoinsToSpend = 26,
+    /// See [`ContractReceipts`](receipts::ContractReceipts)
+    ContractReceipts = 27,
 }
 
 impl Column {
@@ -0,0 +1,165 @@
+use fuel_core_storage::{
+    Mappable,
+    blueprint::plain::Plain,
+    codec::{
+        Decode,
+        Encode,
+        manual::Manual,
+        postcard::Postcard,
+    },
+    structured_storage::TableWithBlueprint,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        ContractId,
+        Receipt,
+        TxId,
+    },
+    fuel_types::BlockHeight,
+};
+use std::mem::size_of;
+
+/// The table of receipts emitted by contracts. It allows iteration over
+/// all receipts of the contract, sorted by the block height, the index of
+/// the transaction in the block and the index of the receipt in the transaction.
+pub struct ContractReceipts;
+
+impl Mappable for ContractReceipts {
+    type Key = ContractReceiptKey;
+    type OwnedKey = Self::Key;
+    type Value = ContractReceipt;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for ContractReceipts {
+    type Blueprint = Plain<Manual<ContractReceiptKey>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::ContractReceipts
+    }
+}
+
+/// The receipt emitted by the contract with the id of the transaction that emitted it.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ContractReceipt {
+    pub tx_id: TxId,
+    pub receipt: Receipt,
+}
+
+const BLOCK_HEIGHT_SIZE: usize = size_of::<BlockHeight>();
+const TX_INDEX_SIZE: usize = size_of::<u16>();
+const RECEIPT_INDEX_SIZE: usize = size_of::<u16>();
+const CURSOR_SIZE: usize = BLOCK_HEIGHT_SIZE + TX_INDEX_SIZE + RECEIPT_INDEX_SIZE;
+const KEY_SIZE: usize = ContractId::LEN + CURSOR_SIZE;
+
+/// The position of the receipt in the chain.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ReceiptCursor {
+    pub block_height: BlockHeight,
+    pub tx_idx: u16,
+    pub receipt_idx: u16,
+}
+
+impl ReceiptCursor {
+    pub fn new(block_height: BlockHeight, tx_idx: u16, receipt_idx: u16) -> Self {
+        Self {
+            block_height,
+            tx_idx,
+            receipt_idx,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ContractReceiptKey {
+    pub contract_id: ContractId,
+    pub cursor: ReceiptCursor,
+}
+
+impl ContractReceiptKey {
+    pub fn new(contract_id: ContractId, cursor: ReceiptCursor) -> Self {
+        Self {
+            contract_id,
+            cursor,
+        }
+    }
+}
+
+impl From<[u8; KEY_SIZE]> for ContractReceiptKey {
+    fn from(bytes: [u8; KEY_SIZE]) -> Self {
+        let mut contract_id = [0u8; ContractId::LEN];
+        contract_id.copy_from_slice(&bytes[..ContractId::LEN]);
+        let bytes = &bytes[ContractId::LEN..];
+
+        let mut block_height = [0u8; BLOCK_HEIGHT_SIZE];
+        block_height.copy_from_slice(&bytes[..BLOCK_HEIGHT_SIZE]);
+        let bytes = &bytes[BLOCK_HEIGHT_SIZE..];
+
+        let mut tx_idx = [0u8; TX_INDEX_SIZE];
+        tx_idx.copy_from_slice(&bytes[..TX_INDEX_SIZE]);
+        let bytes = &bytes[TX_INDEX_SIZE..];
+
+        let mut receipt_idx = [0u8; RECEIPT_INDEX_SIZE];
+        receipt_idx.copy_from_slice(bytes);
+
+        Self {
+            contract_id: contract_id.into(),
+            cursor: ReceiptCursor {
+                block_height: u32::from_be_bytes(block_height).into(),
+                tx_idx: u16::from_be_bytes(tx_idx),
+                receipt_idx: u16::from_be_bytes(receipt_idx),
+            },
+        }
+    }
+}
+
+impl Encode<ContractReceiptKey> for Manual<ContractReceiptKey> {
+    type Encoder<'a> = [u8; KEY_SIZE];
+
+    fn encode(key: &ContractReceiptKey) -> Self::Encoder<'_> {
+        let mut bytes = [0u8; KEY_SIZE];
+        let (contract_id, cursor) = bytes.split_at_mut(ContractId::LEN);
+        contract_id.copy_from_slice(key.contract_id.as_ref());
+        let (block_height, indexes) = cursor.split_at_mut(BLOCK_HEIGHT_SIZE);
+        block_height.copy_from_slice(&key.cursor.block_height.to_bytes());
+        let (tx_idx, receipt_idx) = indexes.split_at_mut(TX_INDEX_SIZE);
+        tx_idx.copy_from_slice(&key.cursor.tx_idx.to_be_bytes());
+        receipt_idx.copy_from_slice(&key.cursor.receipt_idx.to_be_bytes());
+        bytes
+    }
+}
+
+impl Decode<ContractReceiptKey> for Manual<ContractReceiptKey> {
+    fn decode(bytes: &[u8]) -> anyhow::Result<ContractReceiptKey> {
+        let bytes: [u8; KEY_SIZE] = bytes
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("Unable to decode bytes"))?;
+        Ok(bytes.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn generate_key(rng: &mut impl rand::Rng) -> <ContractReceipts as Mappable>::Key {
+        let mut bytes = [0u8; KEY_SIZE];
+        rng.fill(bytes.as_mut());
+        bytes.into()
+    }
+
+    fuel_core_storage::basic_storage_tests!(
+        ContractReceipts,
+        [1u8; KEY_SIZE].into(),
+        ContractReceipt {
+            tx_id: Default::default(),
+            receipt: Receipt::log(Default::default(), 1, 2, 3, 4, 5, 6),
+        },
+        ContractReceipt {
+            tx_id: Default::default(),
+            receipt: Receipt::log(Default::default(), 1, 2, 3, 4, 5, 6),
+        },
+        generate_key
+    );
+}
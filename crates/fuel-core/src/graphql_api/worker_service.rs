@@ -83,6 +83,7 @@ use fuel_core_types::{
         },
     },
     fuel_types::{
+        Address,
         BlockHeight,
         Bytes32,
         ChainId,
@@ -143,6 +144,7 @@ pub struct Task<TxStatusManager, D> {
     balances_indexation_enabled: bool,
     coins_to_spend_indexation_enabled: bool,
     asset_metadata_indexation_enabled: bool,
+    contract_receipts_indexation_enabled: bool,
     base_asset_id: AssetId,
     block_height_subscription_handler: block_height_subscription::Handler,
 }
@@ -159,6 +161,7 @@ where
         persist_transaction_status(
             &result,
             self.asset_metadata_indexation_enabled,
+            self.contract_receipts_indexation_enabled,
             &mut transaction,
         )?;
 
@@ -199,6 +202,8 @@ where
         // Notify subscribers and update last seen block height
         self.block_height_subscription_handler
             .notify_and_update(*height);
+        self.block_height_subscription_handler
+            .notify_block_processed(result.clone());
         // Get all the subscribers that need to be notified that the block height
         // has been reached.
 
@@ -316,9 +321,13 @@ where
 }
 
 fn update_receipt_based_indexation<T>(
+    block_height: BlockHeight,
+    tx_idx: u16,
+    tx_id: &TxId,
     receipts: &[Receipt],
     block_st_transaction: &mut T,
     asset_metadata_indexation_enabled: bool,
+    contract_receipts_indexation_enabled: bool,
 ) -> Result<(), IndexationError>
 where
     T: OffChainDatabaseTransaction,
@@ -329,6 +338,15 @@ where
         asset_metadata_indexation_enabled,
     )?;
 
+    indexation::receipts::update(
+        block_height,
+        tx_idx,
+        tx_id,
+        receipts,
+        block_st_transaction,
+        contract_receipts_indexation_enabled,
+    )?;
+
     Ok(())
 }
 
@@ -342,44 +360,15 @@ where
     T: OffChainDatabaseTransaction,
 {
     for (tx_idx, tx) in block.transactions().iter().enumerate() {
+        if let Transaction::Mint(_) = tx {
+            continue
+        }
         let block_height = *block.header().height();
-        let inputs;
-        let outputs;
         let tx_idx = u16::try_from(tx_idx).map_err(|e| {
             anyhow::anyhow!("The block has more than `u16::MAX` transactions, {}", e)
         })?;
         let tx_id = tx.id(chain_id);
-        match tx {
-            Transaction::Script(tx) => {
-                inputs = tx.inputs().as_slice();
-                outputs = tx.outputs().as_slice();
-            }
-            Transaction::Create(tx) => {
-                inputs = tx.inputs().as_slice();
-                outputs = tx.outputs().as_slice();
-            }
-            Transaction::Mint(_) => continue,
-            Transaction::Upgrade(tx) => {
-                inputs = tx.inputs().as_slice();
-                outputs = tx.outputs().as_slice();
-            }
-            Transaction::Upload(tx) => {
-                inputs = tx.inputs().as_slice();
-                outputs = tx.outputs().as_slice();
-            }
-            Transaction::Blob(tx) => {
-                inputs = tx.inputs().as_slice();
-                outputs = tx.outputs().as_slice();
-            }
-        }
-        persist_owners_index(
-            block_height,
-            inputs,
-            outputs,
-            &tx_id,
-            tx_idx,
-            block_st_transaction,
-        )?;
+        persist_owners_index(block_height, tx, &tx_id, tx_idx, block_st_transaction)?;
     }
     Ok(())
 }
@@ -387,8 +376,7 @@ where
 /// Index the tx id by owner for all of the inputs and outputs
 fn persist_owners_index<T>(
     block_height: BlockHeight,
-    inputs: &[Input],
-    outputs: &[Output],
+    tx: &Transaction,
     tx_id: &Bytes32,
     tx_idx: u16,
     db: &mut T,
@@ -396,6 +384,42 @@ fn persist_owners_index<T>(
 where
     T: OffChainDatabaseTransaction,
 {
+    for owner in transaction_owners(tx) {
+        db.record_tx_id_owner(owner, block_height, tx_idx, tx_id)?;
+    }
+
+    Ok(())
+}
+
+/// Returns the owners of the inputs and outputs of the transaction without duplicates.
+/// These are the owners used to index the transaction in the `OwnedTransactions` table.
+pub(crate) fn transaction_owners(tx: &Transaction) -> Vec<&Address> {
+    let inputs;
+    let outputs;
+    match tx {
+        Transaction::Script(tx) => {
+            inputs = tx.inputs().as_slice();
+            outputs = tx.outputs().as_slice();
+        }
+        Transaction::Create(tx) => {
+            inputs = tx.inputs().as_slice();
+            outputs = tx.outputs().as_slice();
+        }
+        Transaction::Mint(_) => return vec![],
+        Transaction::Upgrade(tx) => {
+            inputs = tx.inputs().as_slice();
+            outputs = tx.outputs().as_slice();
+        }
+        Transaction::Upload(tx) => {
+            inputs = tx.inputs().as_slice();
+            outputs = tx.outputs().as_slice();
+        }
+        Transaction::Blob(tx) => {
+            inputs = tx.inputs().as_slice();
+            outputs = tx.outputs().as_slice();
+        }
+    }
+
     let mut owners = vec![];
     for input in inputs {
         if let Input::CoinSigned(CoinSigned { owner, .. })
@@ -419,23 +443,22 @@ where
     // dedupe owners from inputs and outputs prior to indexing
     owners.sort();
     owners.dedup();
-
-    for owner in owners {
-        db.record_tx_id_owner(owner, block_height, tx_idx, tx_id)?;
-    }
-
-    Ok(())
+    owners
 }
 
 fn persist_transaction_status<T>(
     import_result: &ImportResult,
     asset_metadata_indexation_enabled: bool,
+    contract_receipts_indexation_enabled: bool,
     db: &mut T,
 ) -> StorageResult<()>
 where
     T: OffChainDatabaseTransaction,
 {
-    for TransactionExecutionStatus { id, result } in import_result.tx_status.iter() {
+    let block_height = *import_result.sealed_block.entity.header().height();
+    for (tx_idx, TransactionExecutionStatus { id, result }) in
+        import_result.tx_status.iter().enumerate()
+    {
         let status =
             from_executor_to_status(&import_result.sealed_block.entity, result.clone());
 
@@ -451,7 +474,18 @@ where
             continue
         };
 
-        update_receipt_based_indexation(receipts, db, asset_metadata_indexation_enabled)?;
+        let tx_idx = u16::try_from(tx_idx).map_err(|e| {
+            anyhow::anyhow!("The block has more than `u16::MAX` transactions, {}", e)
+        })?;
+        update_receipt_based_indexation(
+            block_height,
+            tx_idx,
+            id,
+            receipts,
+            db,
+            asset_metadata_indexation_enabled,
+            contract_receipts_indexation_enabled,
+        )?;
     }
     Ok(())
 }
@@ -578,10 +612,14 @@ where
         let asset_metadata_indexation_enabled = self
             .off_chain_database
             .asset_metadata_indexation_enabled()?;
+        let contract_receipts_indexation_enabled = self
+            .off_chain_database
+            .contract_receipts_indexation_enabled()?;
         tracing::info!(
             balances_indexation_enabled,
             coins_to_spend_indexation_enabled,
             asset_metadata_indexation_enabled,
+            contract_receipts_indexation_enabled,
             "Indexation availability status"
         );
         tracing::debug!(
@@ -610,6 +648,7 @@ where
             balances_indexation_enabled,
             coins_to_spend_indexation_enabled,
             asset_metadata_indexation_enabled,
+            contract_receipts_indexation_enabled,
             base_asset_id,
             block_height_subscription_handler,
         };
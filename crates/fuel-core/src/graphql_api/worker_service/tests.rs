@@ -88,6 +88,7 @@ fn worker_task_with_block_importer_and_db<D: ports::worker::OffChainDatabase>(
         balances_indexation_enabled: true,
         coins_to_spend_indexation_enabled: true,
         asset_metadata_indexation_enabled: true,
+        contract_receipts_indexation_enabled: true,
         base_asset_id: Default::default(),
         block_height_subscription_handler: Default::default(),
     }
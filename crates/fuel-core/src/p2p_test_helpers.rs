@@ -30,7 +30,7 @@ use fuel_core_p2p::{
     p2p_service::FuelP2PEvent,
     request_response::messages::{
         RequestMessage,
-        V2ResponseMessage,
+        V3ResponseMessage,
     },
     service::to_message_acceptance,
 };
@@ -225,7 +225,7 @@ impl Bootstrap {
                                 if request_message == RequestMessage::TxPoolAllTransactionsIds {
                                     let _ = bootstrap.send_response_msg(
                                         request_id,
-                                        V2ResponseMessage::TxPoolAllTransactionsIds(Ok(vec![])),
+                                        V3ResponseMessage::TxPoolAllTransactionsIds(Ok(vec![])),
                                     );
                                 }
                             }
@@ -480,6 +480,9 @@ fn update_signing_key(config: &mut Config, key: Address) {
         ConsensusConfig::PoAV2(poa) => {
             poa.set_genesis_signing_key(key);
         }
+        ConsensusConfig::RoundRobin(round_robin) => {
+            round_robin.set_producers(vec![key]);
+        }
     }
     config.snapshot_reader = snapshot_reader.clone().with_chain_config(chain_config)
 }
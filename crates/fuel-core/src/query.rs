@@ -5,6 +5,7 @@ mod block;
 mod coin;
 mod contract;
 mod message;
+mod receipts;
 mod subscriptions;
 mod tx;
 mod upgrades;
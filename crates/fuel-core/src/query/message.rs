@@ -151,6 +151,49 @@ impl MessageProofData for ReadView {
     }
 }
 
+/// The [`MessageProofData`] that uses the `message_block` instead of the stored one.
+/// Used when the node doesn't store the transactions of the message block,
+/// e.g. the light client that keeps only the headers.
+pub struct WithMessageBlock<'a, T: ?Sized> {
+    database: &'a T,
+    message_block: CompressedBlock,
+}
+
+impl<'a, T: ?Sized> WithMessageBlock<'a, T> {
+    pub fn new(database: &'a T, message_block: CompressedBlock) -> Self {
+        Self {
+            database,
+            message_block,
+        }
+    }
+}
+
+impl<T: MessageProofData + ?Sized> MessageProofData for WithMessageBlock<'_, T> {
+    fn block(&self, id: &BlockHeight) -> StorageResult<CompressedBlock> {
+        if self.message_block.header().height() == id {
+            Ok(self.message_block.clone())
+        } else {
+            self.database.block(id)
+        }
+    }
+
+    fn transaction_status(
+        &self,
+        transaction_id: &TxId,
+    ) -> StorageResult<TransactionExecutionStatus> {
+        self.database.transaction_status(transaction_id)
+    }
+
+    fn block_history_proof(
+        &self,
+        message_block_height: &BlockHeight,
+        commit_block_height: &BlockHeight,
+    ) -> StorageResult<MerkleProof> {
+        self.database
+            .block_history_proof(message_block_height, commit_block_height)
+    }
+}
+
 /// Generate an output proof.
 pub fn message_proof<T: MessageProofData + ?Sized>(
     database: &T,
@@ -5,6 +5,7 @@ use fuel_core_types::{
         block::CompressedBlock,
         header::{
             ApplicationHeader,
+            BlockHeader,
             ConsensusHeader,
             PartialBlockHeader,
         },
@@ -215,3 +216,31 @@ async fn can_build_message_proof() {
     );
     assert_eq!(proof.block_proof, block_proof);
 }
+
+#[test]
+fn with_message_block__replaces_only_the_message_block() {
+    // Given
+    let mut stored_header = BlockHeader::default();
+    stored_header.set_block_height(2u32.into());
+    let stored_block = CompressedBlock::test(stored_header, vec![]);
+    let mut message_header = BlockHeader::default();
+    message_header.set_block_height(1u32.into());
+    let message_block = CompressedBlock::test(message_header, vec![txn_id(1)]);
+    let mut data = MockProofDataStorage::new();
+    data.expect_block()
+        .once()
+        .with(mockall::predicate::eq(BlockHeight::from(2u32)))
+        .returning({
+            let stored_block = stored_block.clone();
+            move |_| Ok(stored_block.clone())
+        });
+    let data = WithMessageBlock::new(&data, message_block.clone());
+
+    // When
+    let message_block_result = data.block(&1u32.into()).unwrap();
+    let stored_block_result = data.block(&2u32.into()).unwrap();
+
+    // Then
+    assert_eq!(message_block_result, message_block);
+    assert_eq!(stored_block_result, stored_block);
+}
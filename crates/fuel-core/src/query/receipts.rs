@@ -0,0 +1,93 @@
+use crate::{
+    database::database_description::IndexationKind,
+    fuel_core_graphql_api::database::ReadView,
+    graphql_api::storage::receipts::{
+        ContractReceipt,
+        ReceiptCursor,
+    },
+};
+use fuel_core_storage::{
+    Result as StorageResult,
+    iter::IterDirection,
+};
+use fuel_core_types::fuel_types::ContractId;
+use itertools::Itertools;
+
+/// The maximum number of receipts that one query may scan.
+pub const MAX_SCANNED_CONTRACT_RECEIPTS: usize = 10_000;
+
+impl ReadView {
+    /// Returns an error if the contract receipts index is not available.
+    pub fn ensure_contract_receipts_indexation(&self) -> StorageResult<()> {
+        if self
+            .indexation_flags
+            .contains(&IndexationKind::ContractReceipts)
+        {
+            Ok(())
+        } else {
+            Err(anyhow::anyhow!("Contract receipts index is not available").into())
+        }
+    }
+
+    /// Returns receipts emitted by any of the `contract_ids` and accepted by the
+    /// `filter`, sorted by their position in the chain according to the `direction`.
+    ///
+    /// The iteration fails after scanning [`MAX_SCANNED_CONTRACT_RECEIPTS`] receipts,
+    /// so a selective `filter` can't make one query scan the whole history.
+    pub fn contract_receipts<'a, F>(
+        &'a self,
+        contract_ids: &[ContractId],
+        filter: F,
+        start: Option<ReceiptCursor>,
+        direction: IterDirection,
+    ) -> StorageResult<
+        impl Iterator<Item = StorageResult<(ReceiptCursor, ContractReceipt)>>
+        + 'a
+        + use<'a, F>,
+    >
+    where
+        F: Fn(&ContractReceipt) -> bool + 'a,
+    {
+        self.ensure_contract_receipts_indexation()?;
+
+        let iters = contract_ids
+            .iter()
+            .unique()
+            .map(|contract_id| {
+                self.off_chain
+                    .contract_receipts(contract_id, start, direction)
+            })
+            .collect::<Vec<_>>();
+
+        // Errors are returned as soon as possible to stop the iteration.
+        let receipts = iters.into_iter().kmerge_by(move |a, b| match (a, b) {
+            (Ok((a, _)), Ok((b, _))) => match direction {
+                IterDirection::Forward => a < b,
+                IterDirection::Reverse => a > b,
+            },
+            (Err(_), _) => true,
+            (_, Err(_)) => false,
+        });
+
+        let receipts = receipts
+            .scan(Some(0usize), |scanned, result| {
+                let count = (*scanned)?;
+                if count >= MAX_SCANNED_CONTRACT_RECEIPTS {
+                    *scanned = None;
+                    return Some(Err(anyhow::anyhow!(
+                        "The query scanned more than {MAX_SCANNED_CONTRACT_RECEIPTS} \
+                        receipts, narrow it down with the `from_height` or the cursor"
+                    )
+                    .into()))
+                }
+                *scanned = Some(count.saturating_add(1));
+                Some(result)
+            })
+            .filter(move |result| match result {
+                Ok((_, receipt)) => filter(receipt),
+                Err(_) => true,
+            });
+
+        Ok(receipts)
+    }
+}
@@ -2,15 +2,28 @@ use crate::schema::tx::types::TransactionStatus as ApiTxStatus;
 use fuel_core_storage::Result as StorageResult;
 use fuel_core_tx_status_manager::TxStatusMessage;
 use fuel_core_types::{
-    fuel_types::Bytes32,
-    services::transaction_status::TransactionStatus,
+    blockchain::block::{
+        Block,
+        CompressedBlock,
+    },
+    fuel_types::{
+        BlockHeight,
+        Bytes32,
+    },
+    services::{
+        block_importer::SharedImportResult,
+        transaction_status::TransactionStatus,
+    },
 };
 use futures::{
     Stream,
     StreamExt,
     stream::BoxStream,
 };
+use tokio::sync::broadcast;
 
+#[cfg(test)]
+mod blocks_test;
 #[cfg(test)]
 mod test;
 
@@ -24,6 +37,81 @@ pub(crate) trait TxnStatusChangeState {
     ) -> StorageResult<Option<TransactionStatus>>;
 }
 
+#[cfg_attr(test, mockall::automock)]
+pub(crate) trait BlocksSubscriptionState {
+    /// Return the height of the latest block processed by the off-chain worker.
+    fn latest_processed_height(&self) -> BlockHeight;
+
+    /// Return the block at the given height from the database.
+    fn block(&self, height: &BlockHeight) -> StorageResult<CompressedBlock>;
+
+    /// Compress the imported block.
+    fn compress(&self, block: &Block) -> CompressedBlock;
+}
+
+/// Returns a stream of blocks processed by the off-chain worker.
+///
+/// If `from_height` is set, the stream first replays the blocks from the database
+/// starting from this height and then continues with the imported blocks.
+/// Otherwise, the stream starts from the next imported block.
+///
+/// If the subscriber is too slow and misses some imported blocks,
+/// the missing blocks are fetched from the database, so the stream
+/// never skips a height once started.
+pub(crate) fn blocks_subscription<'a, State>(
+    state: State,
+    receiver: broadcast::Receiver<SharedImportResult>,
+    from_height: Option<BlockHeight>,
+) -> impl Stream<Item = anyhow::Result<CompressedBlock>> + 'a
+where
+    State: BlocksSubscriptionState + Send + Sync + 'a,
+{
+    let initial_state = Some((state, receiver, from_height));
+
+    futures::stream::unfold(initial_state, |inner| async move {
+        let (state, mut receiver, next_height) = inner?;
+
+        loop {
+            if let Some(height) = next_height {
+                if height <= state.latest_processed_height() {
+                    return match state.block(&height) {
+                        Ok(block) => {
+                            Some((Ok(block), Some((state, receiver, height.succ()))))
+                        }
+                        Err(err) => Some((Err(err.into()), None)),
+                    }
+                }
+            }
+
+            match receiver.recv().await {
+                Ok(result) => {
+                    let block = &result.sealed_block.entity;
+                    let height = *block.header().height();
+
+                    match next_height {
+                        // The block was already sent, or some blocks were missed,
+                        // and they will be fetched from the database.
+                        Some(next_height) if next_height != height => continue,
+                        _ => {
+                            let block = state.compress(block);
+                            return Some((
+                                Ok(block),
+                                Some((state, receiver, height.succ())),
+                            ))
+                        }
+                    }
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    tracing::debug!(
+                        "The blocks subscriber lagged behind by {skipped} blocks"
+                    );
+                }
+                Err(broadcast::error::RecvError::Closed) => return None,
+            }
+        }
+    })
+}
+
 #[tracing::instrument(skip(state, stream), fields(transaction_id = %transaction_id))]
 pub(crate) async fn transaction_status_change<'a, State>(
     state: State,
@@ -0,0 +1,128 @@
+#![allow(non_snake_case)]
+
+use super::{
+    MockBlocksSubscriptionState,
+    blocks_subscription,
+};
+use fuel_core_types::{
+    blockchain::{
+        SealedBlock,
+        block::{
+            Block,
+            CompressedBlock,
+        },
+    },
+    fuel_types::BlockHeight,
+    services::block_importer::{
+        ImportResult,
+        SharedImportResult,
+    },
+};
+use futures::StreamExt;
+use std::sync::Arc;
+use tokio::sync::broadcast;
+
+fn block(height: u32) -> Block {
+    let mut block = Block::default();
+    block.header_mut().set_block_height(height.into());
+    block
+}
+
+fn compressed_block(height: &BlockHeight) -> CompressedBlock {
+    block(**height).compress(&Default::default())
+}
+
+fn import_result(height: u32) -> SharedImportResult {
+    let sealed_block = SealedBlock {
+        entity: block(height),
+        consensus: Default::default(),
+    };
+    Arc::new(ImportResult::new_from_local(sealed_block, vec![], vec![]).wrap())
+}
+
+fn send(sender: &broadcast::Sender<SharedImportResult>, height: u32) {
+    let result = sender.send(import_result(height));
+    assert!(result.is_ok());
+}
+
+fn state(latest_processed_height: u32) -> MockBlocksSubscriptionState {
+    let mut state = MockBlocksSubscriptionState::new();
+    state
+        .expect_latest_processed_height()
+        .returning(move || latest_processed_height.into());
+    state
+        .expect_block()
+        .returning(|height| Ok(compressed_block(height)));
+    state
+        .expect_compress()
+        .returning(|block| block.compress(&Default::default()));
+    state
+}
+
+async fn heights<S>(stream: S, count: usize) -> Vec<u32>
+where
+    S: futures::Stream<Item = anyhow::Result<CompressedBlock>>,
+{
+    stream
+        .take(count)
+        .map(|block| **block.unwrap().header().height())
+        .collect()
+        .await
+}
+
+#[tokio::test]
+async fn blocks_subscription__replays_blocks_from_database_before_imported_blocks() {
+    // Given
+    let (sender, receiver) = broadcast::channel(4);
+    send(&sender, 2);
+    send(&sender, 3);
+
+    // When
+    let stream = blocks_subscription(state(2), receiver, Some(1.into()));
+
+    // Then
+    assert_eq!(heights(stream, 3).await, vec![1, 2, 3]);
+}
+
+#[tokio::test]
+async fn blocks_subscription__without_start_height_streams_only_imported_blocks() {
+    // Given
+    let (sender, receiver) = broadcast::channel(4);
+    send(&sender, 6);
+    send(&sender, 7);
+
+    // When
+    let stream = blocks_subscription(state(5), receiver, None);
+
+    // Then
+    assert_eq!(heights(stream, 2).await, vec![6, 7]);
+}
+
+#[tokio::test]
+async fn blocks_subscription__fetches_missed_blocks_from_database() {
+    // Given
+    let (sender, receiver) = broadcast::channel(4);
+    send(&sender, 1);
+    send(&sender, 4);
+
+    // When
+    let stream = blocks_subscription(state(4), receiver, None);
+
+    // Then
+    assert_eq!(heights(stream, 4).await, vec![1, 2, 3, 4]);
+}
+
+#[tokio::test]
+async fn blocks_subscription__continues_with_latest_block_after_lagging_behind() {
+    // Given
+    let (sender, receiver) = broadcast::channel(1);
+    for height in 1..=3 {
+        send(&sender, height);
+    }
+
+    // When
+    let stream = blocks_subscription(state(3), receiver, None);
+
+    // Then
+    assert_eq!(heights(stream, 1).await, vec![3]);
+}
@@ -1,6 +1,10 @@
-use crate::fuel_core_graphql_api::{
-    api_service::ReadDatabase,
-    database::ReadView,
+use crate::{
+    fuel_core_graphql_api::{
+        Config as GraphQLConfig,
+        api_service::ReadDatabase,
+        database::ReadView,
+    },
+    schema::scalars::U32,
 };
 use anyhow::anyhow;
 use async_graphql::{
@@ -23,6 +27,7 @@ use fuel_core_storage::{
     Result as StorageResult,
     iter::IterDirection,
 };
+use fuel_core_types::fuel_types::BlockHeight;
 use futures::{
     Stream,
     TryStreamExt,
@@ -30,6 +35,7 @@ use futures::{
 use std::borrow::Cow;
 use tokio_stream::StreamExt;
 
+pub mod admin;
 pub mod assets;
 pub mod balance;
 pub mod blob;
@@ -42,6 +48,7 @@ pub mod dap;
 pub mod health;
 pub mod message;
 pub mod node_info;
+pub mod receipts;
 pub mod upgrades;
 
 pub mod gas_price;
@@ -72,13 +79,25 @@ pub struct Query(
     relayed_tx::RelayedTransactionQuery,
     upgrades::UpgradeQuery,
     storage::StorageQuery,
+    receipts::ContractReceiptsQuery,
+    admin::AdminQuery,
 );
 
 #[derive(MergedObject, Default)]
-pub struct Mutation(dap::DapMutation, tx::TxMutation, block::BlockMutation);
+pub struct Mutation(
+    dap::DapMutation,
+    tx::TxMutation,
+    block::BlockMutation,
+    admin::AdminMutation,
+);
 
 #[derive(MergedSubscription, Default)]
-pub struct Subscription(tx::TxStatusSubscription, storage::StorageSubscription);
+pub struct Subscription(
+    tx::TxStatusSubscription,
+    storage::StorageSubscription,
+    block::BlockSubscription,
+    receipts::ContractReceiptsSubscription,
+);
 
 pub type CoreSchema = Schema<Query, Mutation, Subscription>;
 pub type CoreSchemaBuilder = SchemaBuilder<Query, Mutation, Subscription>;
@@ -220,6 +239,13 @@ where
 pub trait ReadViewProvider {
     /// Returns the read view for the current operation.
     fn read_view(&self) -> StorageResult<Cow<ReadView>>;
+
+    /// Returns the read view of the state at the `at_height`.
+    /// Returns the read view for the current operation if the height is not set.
+    fn read_view_at(
+        &self,
+        at_height: Option<U32>,
+    ) -> async_graphql::Result<Cow<ReadView>>;
 }
 
 impl<'a> ReadViewProvider for Context<'a> {
@@ -236,4 +262,45 @@ impl<'a> ReadViewProvider for Context<'a> {
             Ok(Cow::Borrowed(read_view))
         }
     }
+
+    fn read_view_at(
+        &self,
+        at_height: Option<U32>,
+    ) -> async_graphql::Result<Cow<'a, ReadView>> {
+        let Some(at_height) = at_height else {
+            return Ok(self.read_view()?)
+        };
+
+        if !self.data_unchecked::<GraphQLConfig>().historical_execution {
+            return Err(anyhow!(
+                "The `atHeight` parameter requires the `--historical-execution` option"
+            )
+            .into())
+        }
+
+        let height: BlockHeight = at_height.0.into();
+        let read_view = self.read_view()?;
+        let latest_height = read_view.latest_height()?;
+
+        if height > latest_height {
+            return Err(anyhow!(
+                "The requested height {height} is above the latest block height {latest_height}"
+            )
+            .into())
+        }
+
+        if height == latest_height {
+            return Ok(read_view)
+        }
+
+        let database: &ReadDatabase = self.data_unchecked();
+        let view = database.view_at(height).map_err(|err| {
+            anyhow!(
+                "The state at height {height} is outside the retained history range \
+                of the node (see `--state-rewind-duration`): {err}"
+            )
+        })?;
+
+        Ok(Cow::Owned(view))
+    }
 }
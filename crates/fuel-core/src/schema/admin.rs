@@ -0,0 +1,231 @@
+use crate::{
+    fuel_core_graphql_api::{
+        Config as GraphQLConfig,
+        api_service::{
+            P2pService,
+            TxPool,
+        },
+    },
+    schema::scalars::{
+        Address,
+        ContractId,
+        Nonce,
+        Tai64Timestamp,
+        TransactionId,
+        U64,
+        UtxoId,
+    },
+};
+use async_graphql::{
+    Context,
+    InputObject,
+    Object,
+};
+use fuel_core_txpool::config::BlackList;
+use fuel_core_types::{
+    services::p2p::BanTarget,
+    tai64::Tai64N,
+};
+use std::{
+    collections::HashSet,
+    time::Duration,
+};
+
+/// The token from the `Authorization: Bearer <token>` header of the request.
+pub struct AuthorizationToken(pub String);
+
+/// Checks that the request is authorized to use the admin API.
+pub fn require_admin(ctx: &Context<'_>) -> async_graphql::Result<()> {
+    let config = ctx.data_unchecked::<GraphQLConfig>();
+    let Some(admin_api_token) = config.config.admin_api_token.as_ref() else {
+        return Err(async_graphql::Error::new("The admin API is disabled"))
+    };
+
+    match ctx.data_opt::<AuthorizationToken>() {
+        Some(token)
+            if constant_time_eq(token.0.as_bytes(), admin_api_token.as_bytes()) =>
+        {
+            Ok(())
+        }
+        _ => Err(async_graphql::Error::new(
+            "The request is not authorized to use the admin API",
+        )),
+    }
+}
+
+/// Compares the tokens without leaking the length of the matching prefix through timing.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (a, b)| acc | (a ^ b)) == 0
+}
+
+pub struct TxPoolBlacklist(BlackList);
+
+#[Object]
+impl TxPoolBlacklist {
+    /// Blacklisted owners of coins, and senders and recipients of messages.
+    async fn owners(&self) -> Vec<Address> {
+        sorted(&self.0.owners)
+    }
+
+    /// Blacklisted coins.
+    async fn coins(&self) -> Vec<UtxoId> {
+        sorted(&self.0.coins)
+    }
+
+    /// Blacklisted messages.
+    async fn messages(&self) -> Vec<Nonce> {
+        sorted(&self.0.messages)
+    }
+
+    /// Blacklisted contracts.
+    async fn contracts(&self) -> Vec<ContractId> {
+        sorted(&self.0.contracts)
+    }
+}
+
+fn sorted<T, S>(entries: &HashSet<T>) -> Vec<S>
+where
+    T: Copy + Ord,
+    S: From<T>,
+{
+    let mut entries = entries.iter().copied().collect::<Vec<_>>();
+    entries.sort();
+    entries.into_iter().map(S::from).collect()
+}
+
+#[derive(InputObject, Default)]
+pub struct TxPoolBlacklistInput {
+    #[graphql(default)]
+    owners: Vec<Address>,
+    #[graphql(default)]
+    coins: Vec<UtxoId>,
+    #[graphql(default)]
+    messages: Vec<Nonce>,
+    #[graphql(default)]
+    contracts: Vec<ContractId>,
+}
+
+impl From<TxPoolBlacklistInput> for BlackList {
+    fn from(input: TxPoolBlacklistInput) -> Self {
+        BlackList::new(
+            input.owners.into_iter().map(Into::into).collect(),
+            input.coins.into_iter().map(Into::into).collect(),
+            input.messages.into_iter().map(Into::into).collect(),
+            input.contracts.into_iter().map(Into::into).collect(),
+        )
+    }
+}
+
+pub struct PeerBan(fuel_core_types::services::p2p::PeerBan);
+
+#[Object]
+impl PeerBan {
+    /// The banned peer id or IP address.
+    async fn target(&self) -> String {
+        self.0.target.to_string()
+    }
+
+    /// The time when the ban is lifted. Not set if the ban lasts until it is removed.
+    async fn expires_at(&self) -> Option<Tai64Timestamp> {
+        self.0
+            .expires_at
+            .map(|expires_at| Tai64Timestamp(Tai64N::from_system_time(&expires_at).0))
+    }
+}
+
+fn parse_ban_target(target: &str) -> async_graphql::Result<BanTarget> {
+    target.parse().map_err(|e| {
+        async_graphql::Error::new(format!(
+            "`{target}` is neither an IP address nor a peer id: {e}"
+        ))
+    })
+}
+
+#[derive(Default)]
+pub struct AdminQuery;
+
+#[Object]
+impl AdminQuery {
+    /// Returns the blacklist of the transaction pool.
+    /// Requires the admin API token.
+    async fn tx_pool_blacklist(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<TxPoolBlacklist> {
+        require_admin(ctx)?;
+        let txpool = ctx.data_unchecked::<TxPool>();
+        Ok(TxPoolBlacklist(txpool.black_list()))
+    }
+
+    /// Returns the active bans of peers and IP addresses in the p2p network.
+    /// Requires the admin API token.
+    async fn peer_bans(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<PeerBan>> {
+        require_admin(ctx)?;
+        let p2p = ctx.data_unchecked::<P2pService>();
+        let bans = p2p.peer_bans().await?;
+        Ok(bans.into_iter().map(PeerBan).collect())
+    }
+}
+
+#[derive(Default)]
+pub struct AdminMutation;
+
+#[Object]
+impl AdminMutation {
+    /// Removes the `remove` entries from the blacklist of the transaction pool
+    /// and adds the `add` entries to it. If the node keeps the blacklist on disk,
+    /// the update survives restarts.
+    /// Transactions in the pool that use newly blacklisted inputs are removed
+    /// together with their dependents and reported as squeezed out.
+    ///
+    /// Returns the ids of the removed transactions. Requires the admin API token.
+    async fn update_tx_pool_blacklist(
+        &self,
+        ctx: &Context<'_>,
+        add: Option<TxPoolBlacklistInput>,
+        remove: Option<TxPoolBlacklistInput>,
+    ) -> async_graphql::Result<Vec<TransactionId>> {
+        require_admin(ctx)?;
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let removed = txpool
+            .update_black_list(
+                add.unwrap_or_default().into(),
+                remove.unwrap_or_default().into(),
+            )
+            .await?;
+        Ok(removed.into_iter().map(Into::into).collect())
+    }
+
+    /// Bans the peer id or the IP address in the p2p network for `duration_seconds`,
+    /// or until it is unbanned if the duration is not set. Connected peers
+    /// affected by the ban are disconnected. The ban is not kept after the restart
+    /// of the node. Requires the admin API token.
+    async fn ban_peer(
+        &self,
+        ctx: &Context<'_>,
+        target: String,
+        duration_seconds: Option<U64>,
+    ) -> async_graphql::Result<bool> {
+        require_admin(ctx)?;
+        let target = parse_ban_target(&target)?;
+        let duration = duration_seconds.map(|seconds| Duration::from_secs(seconds.0));
+        let p2p = ctx.data_unchecked::<P2pService>();
+        p2p.ban_peer(target, duration).await?;
+        Ok(true)
+    }
+
+    /// Removes the ban of the peer id or the IP address in the p2p network.
+    /// The unbanned peer starts over with the default reputation.
+    ///
+    /// Returns `true` if the target was banned. Requires the admin API token.
+    async fn unban_peer(
+        &self,
+        ctx: &Context<'_>,
+        target: String,
+    ) -> async_graphql::Result<bool> {
+        require_admin(ctx)?;
+        let target = parse_ban_target(&target)?;
+        let p2p = ctx.data_unchecked::<P2pService>();
+        Ok(p2p.unban_peer(target).await?)
+    }
+}
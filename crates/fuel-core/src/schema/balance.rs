@@ -9,6 +9,7 @@ use crate::{
         scalars::{
             Address,
             AssetId,
+            U32,
             U128,
         },
     },
@@ -67,8 +68,13 @@ impl BalanceQuery {
         ctx: &Context<'_>,
         #[graphql(desc = "address of the owner")] owner: Address,
         #[graphql(desc = "asset_id of the coin")] asset_id: AssetId,
+        #[graphql(
+            desc = "The height of the state. The latest state is used if not set. \
+            Requires historical execution."
+        )]
+        at_height: Option<U32>,
     ) -> async_graphql::Result<Balance> {
-        let query = ctx.read_view()?;
+        let query = ctx.read_view_at(at_height)?;
         let base_asset_id = *ctx
             .data_unchecked::<ChainInfoProvider>()
             .current_consensus_params()
@@ -7,11 +7,20 @@ use crate::{
     fuel_core_graphql_api::{
         Config as GraphQLConfig,
         IntoApiResult,
-        api_service::ConsensusModule,
+        api_service::{
+            ChainInfoProvider,
+            ConsensusModule,
+            P2pService,
+            ReadDatabase,
+        },
         block_height_subscription,
         database::ReadView,
         query_costs,
     },
+    query::{
+        BlocksSubscriptionState,
+        blocks_subscription,
+    },
     schema::{
         ReadViewProvider,
         scalars::{
@@ -30,6 +39,7 @@ use async_graphql::{
     Enum,
     Object,
     SimpleObject,
+    Subscription,
     Union,
     connection::{
         Connection,
@@ -42,13 +52,21 @@ use fuel_core_storage::{
 };
 use fuel_core_types::{
     blockchain::{
-        block::CompressedBlock,
+        block::{
+            Block as FuelBlock,
+            CompressedBlock,
+        },
         header::BlockHeader,
     },
-    fuel_tx::TxId,
+    fuel_tx::{
+        Transaction as FuelTransaction,
+        TxId,
+        UniqueIdentifier,
+    },
     fuel_types::{
         self,
         BlockHeight,
+        ChainId,
     },
 };
 use futures::{
@@ -139,6 +157,11 @@ impl Block {
         &self,
         ctx: &Context<'_>,
     ) -> async_graphql::Result<Vec<Transaction>> {
+        if self.0.transactions().is_empty() && self.0.header().transactions_count() > 0 {
+            // Light clients store only the headers of the blocks.
+            return self.transactions_from_peers(ctx).await
+        }
+
         let query = ctx.read_view()?;
         let tx_ids = futures::stream::iter(self.0.transactions().iter().copied());
 
@@ -164,6 +187,42 @@ impl Block {
     }
 }
 
+impl Block {
+    async fn transactions_from_peers(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<Transaction>> {
+        let transactions = transactions_from_peers(ctx, self.0.header()).await?;
+        let chain_id = ctx
+            .data_unchecked::<ChainInfoProvider>()
+            .current_consensus_params()
+            .chain_id();
+        Ok(transactions
+            .into_iter()
+            .map(|tx| Transaction::from_tx(tx.id(&chain_id), tx))
+            .collect())
+    }
+}
+
+/// Requests the transactions of the block from the peers
+/// and checks that they match the block header.
+pub(crate) async fn transactions_from_peers(
+    ctx: &Context<'_>,
+    header: &BlockHeader,
+) -> async_graphql::Result<Vec<FuelTransaction>> {
+    let p2p = ctx.data_unchecked::<P2pService>();
+    let transactions = p2p.block_transactions(*header.height()).await?;
+
+    if !header.validate_transactions(&transactions) {
+        return Err(anyhow!(
+            "The transactions received from the peers don't match the block header"
+        )
+        .into())
+    }
+
+    Ok(transactions)
+}
+
 #[derive(Clone, Copy, Debug, Enum, Eq, PartialEq)]
 pub enum HeaderVersion {
     V1,
@@ -414,6 +473,83 @@ impl BlockMutation {
     }
 }
 
+#[derive(Default)]
+pub struct BlockSubscription;
+
+#[Subscription]
+impl BlockSubscription {
+    /// Returns a stream of blocks.
+    /// If `from_height` is set, the stream first returns all blocks
+    /// starting from this height and then continues with the new blocks.
+    /// Otherwise, the stream returns only the new blocks.
+    ///
+    /// The stream never skips a block, even if the subscriber is polling
+    /// slower than the blocks are imported.
+    #[graphql(complexity = "query_costs().block_header + child_complexity")]
+    async fn blocks<'a>(
+        &self,
+        ctx: &'a Context<'a>,
+        #[graphql(desc = "The height of the first block in the stream")]
+        from_height: Option<U32>,
+    ) -> async_graphql::Result<
+        impl Stream<Item = async_graphql::Result<Block>> + 'a + use<'a>,
+    > {
+        let state = BlocksState::from_context(ctx);
+        // Subscribe before reading the database to not miss any block.
+        let receiver = state.block_height_subscriber.subscribe_to_blocks();
+        let from_height = from_height.map(|height| BlockHeight::from(height.0));
+
+        Ok(blocks_subscription(state, receiver, from_height)
+            .map(|result| result.map(Block).map_err(async_graphql::Error::from)))
+    }
+
+    /// Returns a stream of the new blocks.
+    #[graphql(complexity = "query_costs().block_header + child_complexity")]
+    async fn new_blocks<'a>(
+        &self,
+        ctx: &'a Context<'a>,
+    ) -> async_graphql::Result<
+        impl Stream<Item = async_graphql::Result<Block>> + 'a + use<'a>,
+    > {
+        self.blocks(ctx, None).await
+    }
+}
+
+pub(crate) struct BlocksState<'a> {
+    database: &'a ReadDatabase,
+    pub(crate) block_height_subscriber: &'a block_height_subscription::Subscriber,
+    chain_id: ChainId,
+}
+
+impl<'a> BlocksState<'a> {
+    pub(crate) fn from_context(ctx: &Context<'a>) -> Self {
+        let chain_id = ctx
+            .data_unchecked::<ChainInfoProvider>()
+            .current_consensus_params()
+            .chain_id();
+
+        Self {
+            database: ctx.data_unchecked(),
+            block_height_subscriber: ctx.data_unchecked(),
+            chain_id,
+        }
+    }
+}
+
+impl BlocksSubscriptionState for BlocksState<'_> {
+    fn latest_processed_height(&self) -> BlockHeight {
+        self.block_height_subscriber.current_block_height()
+    }
+
+    fn block(&self, height: &BlockHeight) -> StorageResult<CompressedBlock> {
+        self.database.view()?.block(height)
+    }
+
+    fn compress(&self, block: &FuelBlock) -> CompressedBlock {
+        block.compress(&self.chain_id)
+    }
+}
+
 impl From<CompressedBlock> for Block {
     fn from(block: CompressedBlock) -> Self {
         Block(block)
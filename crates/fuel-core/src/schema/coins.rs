@@ -231,6 +231,7 @@ impl CoinQuery {
     }
 
     /// Gets all unspent coins of some `owner` maybe filtered with by `asset_id` per page.
+    /// Uses the state at `at_height` if provided, which requires historical execution.
     #[graphql(complexity = "{\
         query_costs().storage_iterator\
         + (query_costs().storage_read + first.unwrap_or_default() as usize) * child_complexity \
@@ -244,8 +245,9 @@ impl CoinQuery {
         after: Option<String>,
         last: Option<i32>,
         before: Option<String>,
+        at_height: Option<U32>,
     ) -> async_graphql::Result<Connection<UtxoId, Coin, EmptyFields, EmptyFields>> {
-        let query = ctx.read_view()?;
+        let query = ctx.read_view_at(at_height)?;
         let owner: fuel_tx::Address = filter.owner.into();
         crate::schema::query_pagination(after, before, first, last, |start, direction| {
             let coins = query
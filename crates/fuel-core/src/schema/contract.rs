@@ -10,6 +10,7 @@ use crate::{
             ContractId,
             HexString,
             Salt,
+            U32,
             U64,
         },
     },
@@ -33,34 +34,42 @@ use fuel_core_types::{
 };
 use futures::StreamExt;
 
-pub struct Contract(pub(crate) fuel_types::ContractId);
+pub struct Contract {
+    id: fuel_types::ContractId,
+    /// The height of the state used to resolve the fields.
+    /// The latest state is used if not set.
+    at_height: Option<U32>,
+}
 
 impl From<fuel_types::ContractId> for Contract {
     fn from(id: fuel_types::ContractId) -> Self {
-        Self(id)
+        Self {
+            id,
+            at_height: None,
+        }
     }
 }
 
 #[Object]
 impl Contract {
     async fn id(&self) -> ContractId {
-        self.0.into()
+        self.id.into()
     }
 
     #[graphql(complexity = "query_costs().bytecode_read")]
     async fn bytecode(&self, ctx: &Context<'_>) -> async_graphql::Result<HexString> {
-        let query = ctx.read_view()?;
+        let query = ctx.read_view_at(self.at_height)?;
         query
-            .contract_bytecode(self.0)
+            .contract_bytecode(self.id)
             .map(HexString)
             .map_err(Into::into)
     }
 
     #[graphql(complexity = "query_costs().storage_read")]
     async fn salt(&self, ctx: &Context<'_>) -> async_graphql::Result<Salt> {
-        let query = ctx.read_view()?;
+        let query = ctx.read_view_at(self.at_height)?;
         query
-            .contract_salt(&self.0)
+            .contract_salt(&self.id)
             .map(Into::into)
             .map_err(Into::into)
     }
@@ -76,13 +85,21 @@ impl ContractQuery {
         &self,
         ctx: &Context<'_>,
         #[graphql(desc = "ID of the Contract")] id: ContractId,
+        #[graphql(
+            desc = "The height of the state. The latest state is used if not set. \
+            Requires historical execution."
+        )]
+        at_height: Option<U32>,
     ) -> async_graphql::Result<Option<Contract>> {
-        let query = ctx.read_view()?;
+        let query = ctx.read_view_at(at_height)?;
         query
             .contract_exists(id.0)
             .and_then(|contract_exists| {
                 if contract_exists {
-                    Ok(id.0)
+                    Ok(Contract {
+                        id: id.0,
+                        at_height,
+                    })
                 } else {
                     Err(not_found!(ContractsRawCode))
                 }
@@ -119,16 +136,19 @@ pub struct ContractBalanceQuery;
 
 #[Object]
 impl ContractBalanceQuery {
+    /// Gets the balance of the contract.
+    /// Uses the state at `at_height` if provided, which requires historical execution.
     #[graphql(complexity = "query_costs().storage_read")]
     async fn contract_balance(
         &self,
         ctx: &Context<'_>,
         contract: ContractId,
         asset: AssetId,
+        at_height: Option<U32>,
     ) -> async_graphql::Result<ContractBalance> {
         let contract_id = contract.into();
         let asset_id = asset.into();
-        let query = ctx.read_view()?;
+        let query = ctx.read_view_at(at_height)?;
         query
             .contract_balance(contract_id, asset_id)
             .into_api_result()
@@ -1,6 +1,9 @@
 use super::{
     ReadViewProvider,
-    block::Header,
+    block::{
+        Header,
+        transactions_from_peers,
+    },
     scalars::{
         Address,
         Bytes32,
@@ -11,8 +14,13 @@ use super::{
     },
 };
 use crate::{
-    fuel_core_graphql_api::query_costs,
+    fuel_core_graphql_api::{
+        api_service::ChainInfoProvider,
+        database::ReadView,
+        query_costs,
+    },
     graphql_api::IntoApiResult,
+    query::WithMessageBlock,
     schema::scalars::{
         BlockId,
         U32,
@@ -29,7 +37,15 @@ use async_graphql::{
     },
 };
 use fuel_core_services::stream::IntoBoxStream;
-use fuel_core_types::entities;
+use fuel_core_types::{
+    blockchain::block::{
+        Block as FuelBlock,
+        CompressedBlock,
+    },
+    entities,
+    fuel_tx::TxId,
+    services::transaction_status::TransactionExecutionStatus,
+};
 use futures::StreamExt;
 
 pub struct Message(pub(crate) entities::relayer::message::Message);
@@ -146,12 +162,23 @@ impl MessageQuery {
             ))?,
         };
 
-        let proof = crate::query::message_proof(
-            query.as_ref(),
-            transaction_id.into(),
-            nonce.into(),
-            height,
-        )?;
+        let transaction_id: TxId = transaction_id.into();
+        let message_block =
+            message_block_from_peers(ctx, query.as_ref(), &transaction_id).await?;
+        let proof = match message_block {
+            Some(message_block) => crate::query::message_proof(
+                &WithMessageBlock::new(query.as_ref(), message_block),
+                transaction_id,
+                nonce.into(),
+                height,
+            )?,
+            None => crate::query::message_proof(
+                query.as_ref(),
+                transaction_id,
+                nonce.into(),
+                height,
+            )?,
+        };
 
         Ok(MessageProof(proof))
     }
@@ -167,6 +194,37 @@ impl MessageQuery {
         Ok(status.into())
     }
 }
+/// Returns the block of the transaction with the transactions requested from the peers
+/// if the node doesn't store them, like the light client that keeps only the headers.
+/// The transactions are checked against the transactions root of the block header.
+async fn message_block_from_peers(
+    ctx: &Context<'_>,
+    query: &ReadView,
+    transaction_id: &TxId,
+) -> async_graphql::Result<Option<CompressedBlock>> {
+    // The errors of the status are reported by the proof itself.
+    let Ok(TransactionExecutionStatus::Success { block_height, .. }) =
+        query.tx_status(transaction_id)
+    else {
+        return Ok(None)
+    };
+    let block = query.block(&block_height)?;
+    if !block.transactions().is_empty() || block.header().transactions_count() == 0 {
+        return Ok(None)
+    }
+
+    let header = block.header().clone();
+    let transactions = transactions_from_peers(ctx, &header).await?;
+    let chain_id = ctx
+        .data_unchecked::<ChainInfoProvider>()
+        .current_consensus_params()
+        .chain_id();
+    let block = FuelBlock::try_from_executed(header, transactions).ok_or_else(|| {
+        anyhow!("The transactions received from the peers don't match the block header")
+    })?;
+    Ok(Some(block.compress(&chain_id)))
+}
+
 pub struct MerkleProof(pub(crate) entities::relayer::message::MerkleProof);
 
 #[Object]
@@ -162,6 +162,29 @@ impl PeerInfo {
     async fn app_score(&self) -> f64 {
         self.0.app_score
     }
+
+    /// The number of requests to this peer answered with the expected data
+    async fn successful_requests(&self) -> U64 {
+        self.0.request_stats.successful_requests.into()
+    }
+
+    /// The number of requests to this peer that failed or were answered with an error
+    async fn failed_requests(&self) -> U64 {
+        self.0.request_stats.failed_requests.into()
+    }
+
+    /// The moving average of the response latency of this peer in ms
+    async fn avg_latency_ms(&self) -> Option<U64> {
+        self.0
+            .request_stats
+            .avg_latency
+            .map(|latency| U64(latency.as_millis().try_into().unwrap_or(u64::MAX)))
+    }
+
+    /// The total size of the responses received from this peer in bytes
+    async fn bytes_served(&self) -> U64 {
+        self.0.request_stats.bytes_served.into()
+    }
 }
 
 struct TxPoolStats(fuel_core_txpool::TxPoolStats);
@@ -200,4 +223,9 @@ impl IndexationFlags {
     async fn asset_metadata(&self) -> bool {
         self.contains(&IndexationKind::AssetMetadata)
     }
+
+    /// Is contract receipts indexation enabled
+    async fn contract_receipts(&self) -> bool {
+        self.contains(&IndexationKind::ContractReceipts)
+    }
 }
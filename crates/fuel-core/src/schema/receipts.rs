@@ -0,0 +1,240 @@
+use crate::{
+    fuel_core_graphql_api::{
+        api_service::ReadDatabase,
+        query_costs,
+    },
+    graphql_api::storage::receipts::{
+        self,
+        ContractReceipt as StorageContractReceipt,
+    },
+    query::blocks_subscription,
+    schema::{
+        ReadViewProvider,
+        block::BlocksState,
+        scalars::{
+            ContractId,
+            ReceiptCursor,
+            TransactionId,
+            U16,
+            U32,
+        },
+        tx::receipt::{
+            Receipt,
+            ReceiptType,
+        },
+    },
+};
+use async_graphql::{
+    Context,
+    InputObject,
+    Object,
+    Subscription,
+    connection::{
+        Connection,
+        EmptyFields,
+    },
+};
+use fuel_core_storage::{
+    Result as StorageResult,
+    iter::IterDirection,
+};
+use fuel_core_types::fuel_types::{
+    self,
+    BlockHeight,
+};
+use futures::{
+    Stream,
+    StreamExt,
+};
+
+pub struct ContractReceipt {
+    cursor: receipts::ReceiptCursor,
+    receipt: StorageContractReceipt,
+}
+
+#[Object]
+impl ContractReceipt {
+    /// The height of the block that contains the receipt.
+    async fn block_height(&self) -> U32 {
+        self.cursor.block_height.into()
+    }
+
+    /// The id of the transaction that emitted the receipt.
+    async fn transaction_id(&self) -> TransactionId {
+        self.receipt.tx_id.into()
+    }
+
+    /// The index of the transaction in the block.
+    async fn transaction_index(&self) -> U16 {
+        self.cursor.tx_idx.into()
+    }
+
+    /// The index of the receipt in the transaction.
+    async fn receipt_index(&self) -> U16 {
+        self.cursor.receipt_idx.into()
+    }
+
+    async fn receipt(&self) -> Receipt {
+        Receipt(self.receipt.receipt.clone())
+    }
+}
+
+#[derive(InputObject)]
+struct ContractReceiptsFilterInput {
+    /// Returns receipts emitted by any of the `contract_ids`.
+    contract_ids: Vec<ContractId>,
+    /// Returns receipts only of the `receipt_types`. All types are returned if not set.
+    receipt_types: Option<Vec<ReceiptType>>,
+    /// Returns receipts only from blocks at or above the `from_height`.
+    from_height: Option<U32>,
+}
+
+impl ContractReceiptsFilterInput {
+    fn contract_ids(&self) -> Vec<fuel_types::ContractId> {
+        self.contract_ids.iter().map(|id| id.0).collect()
+    }
+
+    fn start_height(&self) -> Option<BlockHeight> {
+        self.from_height.map(Into::into)
+    }
+
+    fn matches(&self, receipt: &StorageContractReceipt) -> bool {
+        match &self.receipt_types {
+            Some(types) => types.contains(&ReceiptType::from(&receipt.receipt)),
+            None => true,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct ContractReceiptsQuery;
+
+#[Object]
+impl ContractReceiptsQuery {
+    /// Returns receipts emitted by the contracts from the `filter`.
+    /// Requires the contract receipts indexation to be enabled.
+    #[graphql(complexity = "{\
+        query_costs().storage_iterator * filter.contract_ids.len() \
+        + (query_costs().storage_read + first.unwrap_or_default() as usize) * child_complexity \
+        + (query_costs().storage_read + last.unwrap_or_default() as usize) * child_complexity\
+    }")]
+    async fn contract_receipts(
+        &self,
+        ctx: &Context<'_>,
+        filter: ContractReceiptsFilterInput,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<ReceiptCursor, ContractReceipt, EmptyFields, EmptyFields>,
+    > {
+        let query = ctx.read_view()?;
+        let contract_ids = filter.contract_ids();
+        let from_height = filter.start_height().unwrap_or_default();
+
+        crate::schema::query_pagination(after, before, first, last, |start, direction| {
+            let start = (*start).map(receipts::ReceiptCursor::from);
+            // Receipts below the `from_height` are never returned,
+            // so the forward iteration can start from it.
+            let start = match direction {
+                IterDirection::Forward => {
+                    let lowest = receipts::ReceiptCursor::new(from_height, 0, 0);
+                    Some(start.map_or(lowest, |start| start.max(lowest)))
+                }
+                IterDirection::Reverse => start,
+            };
+
+            let receipts = query
+                .contract_receipts(
+                    &contract_ids,
+                    |receipt| filter.matches(receipt),
+                    start,
+                    direction,
+                )?
+                .take_while(move |result| match result {
+                    Ok((cursor, _)) => cursor.block_height >= from_height,
+                    Err(_) => true,
+                })
+                .map(|result| {
+                    result.map(|(cursor, receipt)| {
+                        (cursor.into(), ContractReceipt { cursor, receipt })
+                    })
+                });
+
+            Ok(futures::stream::iter(receipts))
+        })
+        .await
+    }
+}
+
+#[derive(Default)]
+pub struct ContractReceiptsSubscription;
+
+#[Subscription]
+impl ContractReceiptsSubscription {
+    /// Returns a stream of receipts emitted by the contracts from the `filter`.
+    /// If `from_height` is set, the stream first returns all receipts
+    /// starting from this height and then continues with the new receipts.
+    /// Otherwise, the stream returns only the new receipts.
+    ///
+    /// Requires the contract receipts indexation to be enabled.
+    #[graphql(complexity = "{\
+        query_costs().storage_iterator * filter.contract_ids.len() + child_complexity\
+    }")]
+    async fn contract_receipts<'a>(
+        &self,
+        ctx: &'a Context<'a>,
+        filter: ContractReceiptsFilterInput,
+    ) -> async_graphql::Result<
+        impl Stream<Item = async_graphql::Result<ContractReceipt>> + 'a + use<'a>,
+    > {
+        let database: &ReadDatabase = ctx.data_unchecked();
+        // Fail early if the index is not available.
+        database.view()?.ensure_contract_receipts_indexation()?;
+
+        let state = BlocksState::from_context(ctx);
+        // Subscribe before reading the database to not miss any block.
+        let receiver = state.block_height_subscriber.subscribe_to_blocks();
+        let contract_ids = filter.contract_ids();
+
+        let stream = blocks_subscription(state, receiver, filter.start_height())
+            .map(move |block| {
+                let height = *block?.header().height();
+                let receipts =
+                    receipts_at_height(database, &contract_ids, &filter, height)?;
+                Ok(receipts)
+            })
+            .flat_map(|result: anyhow::Result<Vec<ContractReceipt>>| {
+                let items = match result {
+                    Ok(receipts) => receipts.into_iter().map(Ok).collect(),
+                    Err(err) => vec![Err(async_graphql::Error::from(err))],
+                };
+                futures::stream::iter(items)
+            });
+
+        Ok(stream)
+    }
+}
+
+fn receipts_at_height(
+    database: &ReadDatabase,
+    contract_ids: &[fuel_types::ContractId],
+    filter: &ContractReceiptsFilterInput,
+    height: BlockHeight,
+) -> StorageResult<Vec<ContractReceipt>> {
+    let view = database.view()?;
+    let start = receipts::ReceiptCursor::new(height, 0, 0);
+    view.contract_receipts(
+        contract_ids,
+        |receipt| filter.matches(receipt),
+        Some(start),
+        IterDirection::Forward,
+    )?
+    .take_while(|result| match result {
+        Ok((cursor, _)) => cursor.block_height == height,
+        Err(_) => true,
+    })
+    .map(|result| result.map(|(cursor, receipt)| ContractReceipt { cursor, receipt }))
+    .collect()
+}
@@ -1,3 +1,4 @@
+use crate::graphql_api::storage::receipts;
 use async_graphql::{
     InputValueError,
     InputValueResult,
@@ -160,6 +161,59 @@ impl CursorType for SortedTxCursor {
     }
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ReceiptCursor {
+    pub block_height: BlockHeight,
+    pub tx_idx: u16,
+    pub receipt_idx: u16,
+}
+
+impl ReceiptCursor {
+    pub fn new(block_height: BlockHeight, tx_idx: u16, receipt_idx: u16) -> Self {
+        Self {
+            block_height,
+            tx_idx,
+            receipt_idx,
+        }
+    }
+}
+
+impl CursorType for ReceiptCursor {
+    type Error = String;
+
+    fn decode_cursor(s: &str) -> Result<Self, Self::Error> {
+        let mut parts = s.split('#');
+        let (Some(block_height), Some(tx_idx), Some(receipt_idx), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err("Incorrect format provided".to_string())
+        };
+
+        Ok(Self::new(
+            BlockHeight::from_str(block_height)
+                .map_err(|_| "Failed to decode block_height")?,
+            u16::from_str(tx_idx).map_err(|_| "Failed to decode tx_idx")?,
+            u16::from_str(receipt_idx).map_err(|_| "Failed to decode receipt_idx")?,
+        ))
+    }
+
+    fn encode_cursor(&self) -> String {
+        format!("{}#{}#{}", self.block_height, self.tx_idx, self.receipt_idx)
+    }
+}
+
+impl From<receipts::ReceiptCursor> for ReceiptCursor {
+    fn from(cursor: receipts::ReceiptCursor) -> Self {
+        Self::new(cursor.block_height, cursor.tx_idx, cursor.receipt_idx)
+    }
+}
+
+impl From<ReceiptCursor> for receipts::ReceiptCursor {
+    fn from(cursor: ReceiptCursor) -> Self {
+        Self::new(cursor.block_height, cursor.tx_idx, cursor.receipt_idx)
+    }
+}
+
 #[derive(Clone, Debug, derive_more::Into, derive_more::From, PartialEq, Eq)]
 pub struct HexString(pub(crate) Vec<u8>);
 
@@ -361,4 +415,18 @@ mod tests {
         let res = HexString::from_str(hex_data);
         assert!(res.is_err());
     }
+
+    #[test]
+    fn receipt_cursor_roundtrips() {
+        let cursor = ReceiptCursor::new(10u32.into(), 2, 3);
+        let encoded = cursor.encode_cursor();
+        assert_eq!(encoded, "0000000a#2#3");
+        assert_eq!(ReceiptCursor::decode_cursor(&encoded), Ok(cursor));
+    }
+
+    #[test]
+    fn receipt_cursor_only_parses_three_parts() {
+        assert!(ReceiptCursor::decode_cursor("10#2").is_err());
+        assert!(ReceiptCursor::decode_cursor("10#2#3#4").is_err());
+    }
 }
@@ -1,10 +1,8 @@
 use crate::{
     fuel_core_graphql_api::database::ReadView,
-    graphql_api::{
-        api_service::ReadDatabase,
-        require_historical_execution,
-    },
+    graphql_api::require_historical_execution,
     schema::{
+        ReadViewProvider,
         contract::ContractBalance,
         scalars::{
             AssetId,
@@ -34,25 +32,19 @@ pub struct StorageQuery;
 impl StorageQuery {
     /// Get storage slot values for a contract at a specific block height.
     /// Use the latest block height if not provided.
+    /// The `atHeight` takes precedence over the `blockHeight` kept for compatibility.
     /// Requires historical execution config to be enabled.
     async fn contract_slot_values(
         &self,
         ctx: &Context<'_>,
         contract_id: ContractId,
         block_height: Option<U32>,
+        at_height: Option<U32>,
         storage_slots: Vec<Bytes32>,
     ) -> async_graphql::Result<Vec<StorageSlot>> {
         require_historical_execution(ctx)?;
 
-        let view_block_height = if let Some(block_height) = block_height {
-            block_height.0.into()
-        } else {
-            let read_view: &ReadView = ctx.data_unchecked();
-            read_view.latest_height()?
-        };
-
-        let read_database: &ReadDatabase = ctx.data_unchecked();
-        let view_at = read_database.view_at(view_block_height)?;
+        let view_at = ctx.read_view_at(at_height.or(block_height))?;
         let storage_slots = storage_slots
             .into_iter()
             .map(|x| x.into())
@@ -69,25 +61,19 @@ impl StorageQuery {
 
     /// Get balance values for a contract at a specific block height.
     /// Use the latest block height if not provided.
+    /// The `atHeight` takes precedence over the `blockHeight` kept for compatibility.
     /// Requires historical execution config to be enabled.
     async fn contract_balance_values(
         &self,
         ctx: &Context<'_>,
         contract_id: ContractId,
         block_height: Option<U32>,
+        at_height: Option<U32>,
         assets: Vec<AssetId>,
     ) -> async_graphql::Result<Vec<ContractBalance>> {
         require_historical_execution(ctx)?;
 
-        let view_block_height = if let Some(block_height) = block_height {
-            block_height.0.into()
-        } else {
-            let read_view: &ReadView = ctx.data_unchecked();
-            read_view.latest_height()?
-        };
-
-        let read_database: &ReadDatabase = ctx.data_unchecked();
-        let view_at = read_database.view_at(view_block_height)?;
+        let view_at = ctx.read_view_at(at_height.or(block_height))?;
         let assets = assets.into_iter().map(|x| x.into()).collect::<Vec<_>>();
 
         let stream = view_at
@@ -63,6 +63,9 @@ use crate::{
 use self::adapters::BlockImporterAdapter;
 
 pub mod adapters;
+#[cfg(feature = "backup")]
+pub mod backup;
+pub mod block_pruner;
 pub mod config;
 pub mod genesis;
 pub mod metrics;
@@ -256,8 +259,9 @@ impl FuelService {
 
         let mut found_override_height = None;
         match start_up_consensus_config {
-            ConsensusConfig::PoA { .. } => {
-                // We don't support overriding of the heights for PoA version 1.
+            ConsensusConfig::PoA { .. } | ConsensusConfig::RoundRobin(_) => {
+                // We don't support overriding of the heights for PoA version 1
+                // and for the round-robin PoA.
             }
             ConsensusConfig::PoAV2(poa) => {
                 let on_chain_view = combined_database.on_chain().latest_view()?;
@@ -45,6 +45,7 @@ use fuel_core_txpool::ports::{
 use fuel_core_types::{
     blockchain::{
         SealedBlock,
+        SealedBlockHeader,
         block::Block,
         consensus::Consensus,
     },
@@ -82,6 +83,11 @@ impl BlockImporterAdapter {
         self.block_importer.execute_and_commit(sealed_block).await?;
         Ok(())
     }
+
+    pub async fn commit_header(&self, header: SealedBlockHeader) -> anyhow::Result<()> {
+        self.block_importer.commit_header(header).await?;
+        Ok(())
+    }
 }
 
 impl BlockVerifier for VerifierAdapter {
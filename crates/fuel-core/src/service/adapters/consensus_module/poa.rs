@@ -13,6 +13,7 @@ use fuel_core_poa::{
     ports::{
         BlockImporter,
         P2pPort,
+        PendingTransactions,
         PredefinedBlocks,
         TransactionPool,
         TransactionsSource,
@@ -85,6 +86,14 @@ impl TransactionPool for TxPoolAdapter {
     fn new_txs_watcher(&self) -> watch::Receiver<()> {
         self.service.get_new_executable_txs_notifier()
     }
+
+    fn pending_transactions(&self) -> PendingTransactions {
+        let stats = self.service.latest_stats();
+        PendingTransactions {
+            tx_count: stats.tx_count,
+            total_gas: stats.total_gas,
+        }
+    }
 }
 
 #[async_trait::async_trait]
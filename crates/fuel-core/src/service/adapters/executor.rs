@@ -75,7 +75,11 @@ impl fuel_core_executor::ports::RelayerPort for RelayerIterableKeyValueView {
                 .storage::<fuel_core_relayer::storage::EventsHistory>()
                 .get(da_height)?
                 .map(|cow| cow.into_owned())
-                .unwrap_or_default();
+                .unwrap_or_default()
+                .into_iter()
+                // The executors of previous versions don't know about custom events.
+                .filter(Event::is_consensus_event)
+                .collect();
             Ok(events)
         }
         #[cfg(not(feature = "relayer"))]
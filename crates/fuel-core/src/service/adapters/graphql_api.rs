@@ -51,7 +51,10 @@ use fuel_core_storage::{
     structured_storage::TableWithBlueprint,
 };
 use fuel_core_tx_status_manager::TxStatusMessage;
-use fuel_core_txpool::TxPoolStats;
+use fuel_core_txpool::{
+    TxPoolStats,
+    config::BlackList,
+};
 use fuel_core_types::{
     blockchain::header::{
         ConsensusParametersVersion,
@@ -71,7 +74,11 @@ use fuel_core_types::{
             DryRunResult,
             StorageReadReplayEvent,
         },
-        p2p::PeerInfo,
+        p2p::{
+            BanTarget,
+            PeerBan,
+            PeerInfo,
+        },
         transaction_status::TransactionStatus,
     },
     tai64::Tai64,
@@ -79,6 +86,7 @@ use fuel_core_types::{
 use std::{
     ops::Deref,
     sync::Arc,
+    time::Duration,
 };
 
 mod off_chain;
@@ -119,6 +127,21 @@ impl TxPoolPort for TxPoolAdapter {
     fn latest_pool_stats(&self) -> TxPoolStats {
         self.service.latest_stats()
     }
+
+    fn black_list(&self) -> BlackList {
+        self.service.black_list()
+    }
+
+    async fn update_black_list(
+        &self,
+        add: BlackList,
+        remove: BlackList,
+    ) -> anyhow::Result<Vec<TxId>> {
+        self.service
+            .update_black_list(add, remove)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+    }
 }
 
 impl DatabaseMessageProof for OnChainIterableKeyValueView {
@@ -167,7 +190,10 @@ impl P2pPort for P2PAdapter {
     async fn all_peer_info(&self) -> anyhow::Result<Vec<PeerInfo>> {
         #[cfg(feature = "p2p")]
         {
-            use fuel_core_types::services::p2p::HeartbeatData;
+            use fuel_core_types::services::p2p::{
+                HeartbeatData,
+                PeerRequestStats,
+            };
             match &self.service {
                 Some(service) => {
                     let peers = service.get_all_peers().await?;
@@ -190,6 +216,14 @@ impl P2pPort for P2PAdapter {
                                     .last_heartbeat_sys,
                             },
                             app_score: peer_info.score,
+                            request_stats: PeerRequestStats {
+                                successful_requests: peer_info
+                                    .request_stats
+                                    .successful_requests,
+                                failed_requests: peer_info.request_stats.failed_requests,
+                                avg_latency: peer_info.request_stats.avg_latency,
+                                bytes_served: peer_info.request_stats.bytes_served,
+                            },
                         })
                         .collect())
                 }
@@ -201,6 +235,58 @@ impl P2pPort for P2PAdapter {
             Ok(vec![])
         }
     }
+
+    async fn ban_peer(
+        &self,
+        target: BanTarget,
+        duration: Option<Duration>,
+    ) -> anyhow::Result<()> {
+        #[cfg(feature = "p2p")]
+        if let Some(service) = &self.service {
+            return service.ban_peer(target, duration).await
+        }
+        let _ = (target, duration);
+        Err(anyhow::anyhow!("The p2p service is disabled"))
+    }
+
+    async fn unban_peer(&self, target: BanTarget) -> anyhow::Result<bool> {
+        #[cfg(feature = "p2p")]
+        if let Some(service) = &self.service {
+            return service.unban_peer(target).await
+        }
+        let _ = target;
+        Err(anyhow::anyhow!("The p2p service is disabled"))
+    }
+
+    async fn peer_bans(&self) -> anyhow::Result<Vec<PeerBan>> {
+        #[cfg(feature = "p2p")]
+        if let Some(service) = &self.service {
+            return service.get_peer_bans().await
+        }
+        Ok(vec![])
+    }
+
+    async fn block_transactions(
+        &self,
+        height: BlockHeight,
+    ) -> anyhow::Result<Vec<Transaction>> {
+        #[cfg(feature = "p2p")]
+        if let Some(service) = &self.service {
+            let height = u32::from(height);
+            let range = height..height.saturating_add(1);
+            let (_, transactions) = service.get_transactions(range, vec![]).await?;
+            let transactions = transactions
+                .and_then(|transactions| transactions.into_iter().next())
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "The peer didn't return the transactions of the block"
+                    )
+                })?;
+            return Ok(transactions.0)
+        }
+        let _ = height;
+        Err(anyhow::anyhow!("The p2p service is disabled"))
+    }
 }
 
 impl worker::TxStatusCompletion for TxStatusManagerAdapter {
@@ -2,7 +2,6 @@ use crate::{
     database::{
         Database,
         OffChainIterableKeyValueView,
-        OffChainKeyValueView,
         database_description::{
             IndexationKind,
             off_chain::OffChain,
@@ -11,7 +10,6 @@ use crate::{
     fuel_core_graphql_api::{
         ports::{
             OffChainDatabase,
-            OffChainDatabaseAt,
             worker,
         },
         storage::{
@@ -24,6 +22,7 @@ use crate::{
         indexation::coins_to_spend::NON_RETRYABLE_BYTE,
         ports::CoinsToSpendIndexIter,
         storage::{
+            PRUNED_HEIGHT,
             assets::{
                 AssetDetails,
                 AssetsInfo,
@@ -41,6 +40,13 @@ use crate::{
                 OldFuelBlocks,
                 OldTransactions,
             },
+            receipts::{
+                ContractReceipt,
+                ContractReceiptKey,
+                ContractReceipts,
+                ReceiptCursor,
+            },
+            statistic::StatisticTable,
         },
     },
 };
@@ -135,6 +141,22 @@ impl OffChainDatabase for OffChainIterableKeyValueView {
             .into_boxed()
     }
 
+    fn contract_receipts(
+        &self,
+        contract_id: &ContractId,
+        start: Option<ReceiptCursor>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(ReceiptCursor, ContractReceipt)>> {
+        let start = start.map(|cursor| ContractReceiptKey::new(*contract_id, cursor));
+        self.iter_all_filtered::<ContractReceipts, _>(
+            Some(*contract_id),
+            start.as_ref(),
+            Some(direction),
+        )
+        .map(|res| res.map(|(key, receipt)| (key.cursor, receipt)))
+        .into_boxed()
+    }
+
     fn contract_salt(&self, contract_id: &ContractId) -> StorageResult<Salt> {
         let salt = *self
             .storage_as_ref::<ContractsInfo>()
@@ -179,6 +201,12 @@ impl OffChainDatabase for OffChainIterableKeyValueView {
             .map(|tx| tx.map(|tx| tx.into_owned()))
     }
 
+    fn pruned_height(&self) -> StorageResult<Option<BlockHeight>> {
+        self.storage_as_ref::<StatisticTable<BlockHeight>>()
+            .get(PRUNED_HEIGHT)
+            .map(|height| height.map(|height| height.into_owned()))
+    }
+
     fn relayed_tx_status(
         &self,
         id: Bytes32,
@@ -410,6 +438,8 @@ impl worker::OffChainDatabase for Database<OffChain> {
     fn asset_metadata_indexation_enabled(&self) -> StorageResult<bool> {
         self.indexation_available(IndexationKind::AssetMetadata)
     }
-}
 
-impl OffChainDatabaseAt for OffChainKeyValueView {}
+    fn contract_receipts_indexation_enabled(&self) -> StorageResult<bool> {
+        self.indexation_available(IndexationKind::ContractReceipts)
+    }
+}
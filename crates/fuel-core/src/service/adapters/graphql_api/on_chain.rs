@@ -2,7 +2,6 @@ use crate::{
     database::{
         Database,
         OnChainIterableKeyValueView,
-        OnChainKeyValueView,
         database_description::on_chain::OnChain,
     },
     fuel_core_graphql_api::ports::{
@@ -11,7 +10,6 @@ use crate::{
         DatabaseContracts,
         DatabaseMessages,
         OnChainDatabase,
-        OnChainDatabaseAt,
     },
     graphql_api::ports::worker,
 };
@@ -31,6 +29,7 @@ use fuel_core_storage::{
         ContractsAssets,
         ContractsState,
         FuelBlocks,
+        ProcessedTransactions,
         SealedBlockConsensus,
         Transactions,
     },
@@ -66,6 +65,10 @@ impl DatabaseBlocks for OnChainIterableKeyValueView {
             .into_owned())
     }
 
+    fn transaction_processed(&self, tx_id: &TxId) -> StorageResult<bool> {
+        self.storage::<ProcessedTransactions>().contains_key(tx_id)
+    }
+
     fn block(&self, height: &BlockHeight) -> StorageResult<CompressedBlock> {
         let block = self
             .storage_as_ref::<FuelBlocks>()
@@ -152,25 +155,7 @@ impl DatabaseContracts for OnChainIterableKeyValueView {
             })
             .into_boxed()
     }
-}
-
-impl DatabaseChain for OnChainIterableKeyValueView {
-    fn da_height(&self) -> StorageResult<DaBlockHeight> {
-        self.latest_compressed_block()?
-            .map(|block| block.header().da_height())
-            .ok_or(not_found!("DaBlockHeight"))
-    }
-}
 
-impl OnChainDatabase for OnChainIterableKeyValueView {}
-
-impl worker::OnChainDatabase for Database<OnChain> {
-    fn latest_height(&self) -> StorageResult<Option<BlockHeight>> {
-        Ok(fuel_core_storage::transactional::HistoricalView::latest_height(self))
-    }
-}
-
-impl OnChainDatabaseAt for OnChainKeyValueView {
     fn contract_slot_values(
         &self,
         contract_id: ContractId,
@@ -215,3 +200,19 @@ impl OnChainDatabaseAt for OnChainKeyValueView {
             .into_boxed()
     }
 }
+
+impl DatabaseChain for OnChainIterableKeyValueView {
+    fn da_height(&self) -> StorageResult<DaBlockHeight> {
+        self.latest_compressed_block()?
+            .map(|block| block.header().da_height())
+            .ok_or(not_found!("DaBlockHeight"))
+    }
+}
+
+impl OnChainDatabase for OnChainIterableKeyValueView {}
+
+impl worker::OnChainDatabase for Database<OnChain> {
+    fn latest_height(&self) -> StorageResult<Option<BlockHeight>> {
+        Ok(fuel_core_storage::transactional::HistoricalView::latest_height(self))
+    }
+}
@@ -2,14 +2,20 @@ use super::{
     BlockImporterAdapter,
     TxPoolAdapter,
 };
-use crate::database::OnChainIterableKeyValueView;
+use crate::{
+    combined_database::CombinedDatabase,
+    database::OnChainIterableKeyValueView,
+};
 use fuel_core_p2p::ports::{
     BlockHeightImporter,
     P2pDb,
     TxPool,
 };
 use fuel_core_services::stream::BoxStream;
-use fuel_core_storage::Result as StorageResult;
+use fuel_core_storage::{
+    Result as StorageResult,
+    transactional::AtomicView,
+};
 use fuel_core_types::{
     blockchain::{
         SealedBlockHeader,
@@ -17,30 +23,75 @@ use fuel_core_types::{
     },
     fuel_tx::TxId,
     fuel_types::BlockHeight,
-    services::p2p::{
-        NetworkableTransactionPool,
-        Transactions,
+    services::{
+        block_importer::Source,
+        p2p::{
+            NetworkableTransactionPool,
+            Transactions,
+        },
     },
 };
 use std::ops::Range;
 
-impl P2pDb for OnChainIterableKeyValueView {
+/// Provides the views of the database to the p2p service.
+#[derive(Clone)]
+pub struct P2PViewProvider {
+    database: CombinedDatabase,
+}
+
+impl P2PViewProvider {
+    pub fn new(database: CombinedDatabase) -> Self {
+        Self { database }
+    }
+}
+
+/// The view of the database for the p2p service. The transactions of the pruned
+/// blocks are not served to the peers.
+pub struct P2PView {
+    on_chain: OnChainIterableKeyValueView,
+    pruned_height: Option<BlockHeight>,
+}
+
+impl AtomicView for P2PViewProvider {
+    type LatestView = P2PView;
+
+    fn latest_view(&self) -> StorageResult<Self::LatestView> {
+        // The pruning height is committed before the transactions are removed.
+        // Reading it after the on-chain view is taken covers all blocks whose
+        // transactions are missing in the view.
+        let on_chain = self.database.on_chain().latest_view()?;
+        let pruned_height = self.database.pruning_height()?;
+        Ok(P2PView {
+            on_chain,
+            pruned_height,
+        })
+    }
+}
+
+impl P2pDb for P2PView {
     fn get_sealed_headers(
         &self,
         block_height_range: Range<u32>,
     ) -> StorageResult<Option<Vec<SealedBlockHeader>>> {
-        self.get_sealed_block_headers(block_height_range)
+        self.on_chain.get_sealed_block_headers(block_height_range)
     }
 
     fn get_transactions(
         &self,
         block_height_range: Range<u32>,
     ) -> StorageResult<Option<Vec<Transactions>>> {
-        self.get_transactions_on_blocks(block_height_range)
+        let is_pruned = self
+            .pruned_height
+            .is_some_and(|height| block_height_range.start <= u32::from(height));
+        if is_pruned {
+            return Ok(None)
+        }
+
+        self.on_chain.get_transactions_on_blocks(block_height_range)
     }
 
     fn get_genesis(&self) -> StorageResult<Genesis> {
-        self.get_genesis()
+        self.on_chain.get_genesis()
     }
 }
 
@@ -56,6 +107,22 @@ impl BlockHeightImporter for BlockImporterAdapter {
                 .map(|result| *result.sealed_block.entity.header().height()),
         )
     }
+
+    fn locally_produced_headers(&self) -> BoxStream<SealedBlockHeader> {
+        use tokio_stream::{
+            StreamExt,
+            wrappers::BroadcastStream,
+        };
+        Box::pin(
+            BroadcastStream::new(self.block_importer.subscribe())
+                .filter_map(|result| result.ok())
+                .filter(|result| result.source == Source::Local)
+                .map(|result| SealedBlockHeader {
+                    entity: result.sealed_block.entity.header().clone(),
+                    consensus: result.sealed_block.consensus.clone(),
+                }),
+        )
+    }
 }
 
 impl TxPool for TxPoolAdapter {
@@ -192,7 +192,10 @@ impl fuel_core_producer::ports::Relayer for MaybeRelayerAdapter {
                 .fold((0u64, 0u64), |(gas_cost, tx_count), event| {
                     let gas_cost = gas_cost.saturating_add(event.cost());
                     let tx_count = match event {
-                        fuel_core_types::services::relayer::Event::Message(_) => tx_count,
+                        fuel_core_types::services::relayer::Event::Message(_)
+                        | fuel_core_types::services::relayer::Event::Custom(_) => {
+                            tx_count
+                        }
                         fuel_core_types::services::relayer::Event::Transaction(_) => {
                             tx_count.saturating_add(1)
                         }
@@ -19,6 +19,9 @@ use fuel_core_types::{
     },
     fuel_types::BlockHeight,
     services::p2p::{
+        GossipsubMessageAcceptance,
+        GossipsubMessageInfo,
+        NewBlockGossipData,
         PeerId,
         SourcePeer,
         Transactions,
@@ -47,12 +50,44 @@ impl PeerToPeerPort for P2PAdapter {
         }
     }
 
+    fn new_block_stream(&self) -> BoxStream<NewBlockGossipData> {
+        use tokio_stream::{
+            StreamExt,
+            wrappers::BroadcastStream,
+        };
+        match &self.service {
+            Some(service) => Box::pin(
+                BroadcastStream::new(service.subscribe_new_blocks())
+                    .filter_map(|result| result.ok()),
+            ),
+            _ => Box::pin(fuel_core_services::stream::pending()),
+        }
+    }
+
+    fn notify_gossip_block_validity(
+        &self,
+        message_info: GossipsubMessageInfo,
+        validity: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()> {
+        match &self.service {
+            Some(service) => {
+                service.notify_gossip_transaction_validity(message_info, validity)
+            }
+            _ => Ok(()),
+        }
+    }
+
     async fn get_sealed_block_headers(
         &self,
         block_height_range: Range<u32>,
+        excluded_peers: Vec<PeerId>,
     ) -> anyhow::Result<SourcePeer<Option<Vec<SealedBlockHeader>>>> {
         let result = match &self.service {
-            Some(service) => service.get_sealed_block_headers(block_height_range).await,
+            Some(service) => {
+                service
+                    .get_sealed_block_headers(block_height_range, excluded_peers)
+                    .await
+            }
             _ => Err(anyhow::anyhow!("No P2P service available")),
         };
         match result {
@@ -65,12 +100,36 @@ impl PeerToPeerPort for P2PAdapter {
         }
     }
 
+    async fn get_sealed_blocks(
+        &self,
+        block_height_range: Range<u32>,
+        excluded_peers: Vec<PeerId>,
+    ) -> anyhow::Result<SourcePeer<Option<Vec<SealedBlock>>>> {
+        let result = match &self.service {
+            Some(service) => {
+                service
+                    .get_sealed_blocks(block_height_range, excluded_peers)
+                    .await
+            }
+            _ => Err(anyhow::anyhow!("No P2P service available")),
+        };
+        match result {
+            Ok((peer_id, blocks)) => {
+                let peer_id: PeerId = peer_id.into();
+                let blocks = peer_id.bind(blocks);
+                Ok(blocks)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
     async fn get_transactions(
         &self,
         block_ids: Range<u32>,
+        excluded_peers: Vec<PeerId>,
     ) -> anyhow::Result<SourcePeer<Option<Vec<Transactions>>>> {
         let result = match &self.service {
-            Some(service) => service.get_transactions(block_ids).await,
+            Some(service) => service.get_transactions(block_ids, excluded_peers).await,
             _ => Err(anyhow::anyhow!("No P2P service available")),
         };
         match result {
@@ -156,6 +215,10 @@ impl BlockImporterPort for BlockImporterAdapter {
     async fn execute_and_commit(&self, block: SealedBlock) -> anyhow::Result<()> {
         self.execute_and_commit(block).await
     }
+
+    async fn commit_header(&self, header: SealedBlockHeader) -> anyhow::Result<()> {
+        self.commit_header(header).await
+    }
 }
 
 impl ConsensusPort for ConsensusAdapter {
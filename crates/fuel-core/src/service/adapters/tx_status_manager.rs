@@ -80,6 +80,11 @@ impl ProtocolPublicKey for ConsensusConfigProtocolPublicKey {
         match &self.inner {
             ConsensusConfig::PoA { signing_key } => *signing_key,
             ConsensusConfig::PoAV2(poa_v2) => poa_v2.latest_address(),
+            // Pre-confirmations are only accepted from the first producer of the set,
+            // which is the producer expected to run the pre-confirmation service.
+            ConsensusConfig::RoundRobin(round_robin) => {
+                round_robin.producers().first().copied().unwrap_or_default()
+            }
         }
     }
 }
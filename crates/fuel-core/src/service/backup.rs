@@ -0,0 +1,146 @@
+//! The service periodically creating incremental backups of the running node.
+
+use crate::combined_database::{
+    CombinedDatabase,
+    backup::BackupStore,
+};
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+    TaskNextAction,
+};
+use std::{
+    path::PathBuf,
+    time::Duration,
+};
+use tokio::time::{
+    Interval,
+    MissedTickBehavior,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BackupSchedulerConfig {
+    /// The directory with incremental backups.
+    pub backup_dir: PathBuf,
+    /// The interval between backups.
+    pub interval: Duration,
+    /// The number of the newest backups to keep. All backups are kept if not set.
+    pub retention: Option<usize>,
+}
+
+pub struct UninitializedTask {
+    database: CombinedDatabase,
+    store: BackupStore,
+    retention: Option<usize>,
+    period: Duration,
+}
+
+pub struct Task {
+    database: CombinedDatabase,
+    store: BackupStore,
+    retention: Option<usize>,
+    interval: Interval,
+}
+
+impl Task {
+    async fn backup(&self) -> anyhow::Result<()> {
+        let database = self.database.clone();
+        let store = self.store.clone();
+        let retention = self.retention;
+
+        // The backup blocks the thread while RocksDB copies the files.
+        tokio::task::spawn_blocking(move || {
+            let backup = store.create(&database)?;
+            tracing::info!(
+                "Created the backup {} at height {:?} in `{}`",
+                backup.id,
+                backup.height(),
+                store.dir().display()
+            );
+
+            if let Some(retention) = retention {
+                for removed in store.retain(retention)? {
+                    tracing::info!("Removed the old backup {}", removed.id);
+                }
+            }
+
+            Ok(())
+        })
+        .await?
+    }
+}
+
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> TaskNextAction {
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                TaskNextAction::Stop
+            }
+
+            _ = self.interval.tick() => {
+                if let Err(err) = self.backup().await {
+                    tracing::error!("Failed to create the backup: {err:?}");
+                }
+                TaskNextAction::Continue
+            }
+        }
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableService for UninitializedTask {
+    const NAME: &'static str = "BackupScheduler";
+    type SharedData = ();
+    type Task = Task;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {}
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        // The first backup is created after the period, so the start of the node
+        // isn't slowed down by the backup.
+        let start = tokio::time::Instant::now()
+            .checked_add(self.period)
+            .ok_or_else(|| anyhow::anyhow!("The backup interval is too large"))?;
+        let mut interval = tokio::time::interval_at(start, self.period);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        Ok(Task {
+            database: self.database,
+            store: self.store,
+            retention: self.retention,
+            interval,
+        })
+    }
+}
+
+pub fn new_service(
+    config: &BackupSchedulerConfig,
+    database: CombinedDatabase,
+) -> anyhow::Result<ServiceRunner<UninitializedTask>> {
+    if config.interval.is_zero() {
+        return Err(anyhow::anyhow!("The backup interval must be positive"));
+    }
+    if config.retention == Some(0) {
+        return Err(anyhow::anyhow!("The backup retention must be positive"));
+    }
+
+    Ok(ServiceRunner::new(UninitializedTask {
+        database,
+        store: BackupStore::new(config.backup_dir.clone()),
+        retention: config.retention,
+        period: config.interval,
+    }))
+}
@@ -0,0 +1,158 @@
+//! The service pruning the transactions, statuses and receipts of the blocks
+//! older than the configured retention.
+
+use crate::combined_database::CombinedDatabase;
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+    TaskNextAction,
+    stream::BoxStream,
+};
+use fuel_core_storage::{
+    Result as StorageResult,
+    transactional::HistoricalView,
+};
+use fuel_core_types::{
+    fuel_types::BlockHeight,
+    services::block_importer::SharedImportResult,
+};
+use futures::StreamExt;
+
+/// The maximum number of blocks pruned in one database commit.
+const BATCH_SIZE: u32 = 100;
+
+pub struct UninitializedTask {
+    database: CombinedDatabase,
+    retention: u32,
+    blocks: BoxStream<SharedImportResult>,
+}
+
+pub struct Task {
+    database: CombinedDatabase,
+    retention: u32,
+    blocks: BoxStream<SharedImportResult>,
+    /// `true` if there are more blocks to prune without waiting for a new block.
+    behind: bool,
+}
+
+impl Task {
+    async fn prune(&mut self) -> TaskNextAction {
+        let database = self.database.clone();
+        let retention = self.retention;
+
+        // The pruning blocks the thread while the database removes the entries.
+        let result =
+            tokio::task::spawn_blocking(move || prune_batch(&database, retention)).await;
+
+        match result {
+            Ok(Ok(behind)) => {
+                self.behind = behind;
+            }
+            Ok(Err(err)) => {
+                tracing::error!("Failed to prune the blocks: {err:?}");
+                self.behind = false;
+            }
+            Err(err) => {
+                tracing::error!("The pruning of the blocks panicked: {err:?}");
+                self.behind = false;
+            }
+        }
+        TaskNextAction::Continue
+    }
+}
+
+/// Prunes the next batch of blocks outside of the retention.
+/// Returns `true` if more blocks are left to prune.
+fn prune_batch(database: &CombinedDatabase, retention: u32) -> StorageResult<bool> {
+    let Some(up_to) = prune_up_to(database, retention) else {
+        return Ok(false)
+    };
+
+    let pruned = database.prune_blocks(up_to, BATCH_SIZE)?;
+    if let Some(pruned) = pruned {
+        tracing::debug!("Pruned the blocks up to the height {pruned}");
+    }
+    Ok(pruned.is_some_and(|pruned| pruned < up_to))
+}
+
+/// Returns the height of the last block outside of the retention.
+///
+/// The height is based on the off-chain database as well, so the blocks are
+/// pruned only after the off-chain worker indexes them.
+fn prune_up_to(database: &CombinedDatabase, retention: u32) -> Option<BlockHeight> {
+    let on_chain = database.on_chain().latest_height()?;
+    let off_chain = database.off_chain().latest_height()?;
+    let indexed = on_chain.min(off_chain);
+    u32::from(indexed)
+        .checked_sub(retention)
+        .map(BlockHeight::from)
+}
+
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> TaskNextAction {
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                TaskNextAction::Stop
+            }
+
+            _ = core::future::ready(()), if self.behind => {
+                self.prune().await
+            }
+
+            block = self.blocks.next() => {
+                match block {
+                    Some(_) => self.prune().await,
+                    None => TaskNextAction::Stop,
+                }
+            }
+        }
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableService for UninitializedTask {
+    const NAME: &'static str = "BlockPruner";
+    type SharedData = ();
+    type Task = Task;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {}
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        Ok(Task {
+            database: self.database,
+            retention: self.retention,
+            blocks: self.blocks,
+            // Prunes the blocks accumulated while the node was stopped.
+            behind: true,
+        })
+    }
+}
+
+pub fn new_service(
+    retention: u32,
+    database: CombinedDatabase,
+    blocks: BoxStream<SharedImportResult>,
+) -> anyhow::Result<ServiceRunner<UninitializedTask>> {
+    if retention == 0 {
+        return Err(anyhow::anyhow!("The block retention must be positive"));
+    }
+
+    Ok(ServiceRunner::new(UninitializedTask {
+        database,
+        retention,
+        blocks,
+    }))
+}
@@ -13,7 +13,10 @@ use strum_macros::{
     EnumVariantNames,
 };
 
-use fuel_core_chain_config::SnapshotReader;
+use fuel_core_chain_config::{
+    ConsensusConfig,
+    SnapshotReader,
+};
 #[cfg(feature = "test-helpers")]
 use fuel_core_chain_config::{
     ChainConfig,
@@ -26,7 +29,13 @@ use fuel_core_p2p::config::{
     Config as P2PConfig,
     NotInitialized,
 };
-pub use fuel_core_poa::Trigger;
+pub use fuel_core_poa::{
+    Trigger,
+    leader_lease::{
+        FileLeaderLease,
+        LeaderLeaseConfig,
+    },
+};
 #[cfg(feature = "relayer")]
 use fuel_core_relayer::Config as RelayerConfig;
 use fuel_core_tx_status_manager::config::Config as TxStatusManagerConfig;
@@ -36,6 +45,8 @@ use fuel_core_types::{
     signer::SignMode,
 };
 
+#[cfg(feature = "backup")]
+pub use crate::service::backup::BackupSchedulerConfig;
 use crate::{
     combined_database::CombinedDatabaseConfig,
     graphql_api::ServiceConfig as GraphQLConfig,
@@ -97,6 +108,14 @@ pub struct Config {
     pub time_until_synced: Duration,
     /// The timeout after which block production is considered failed.
     pub production_timeout: Duration,
+    /// The lease shared with the standby producers, if any.
+    pub leader_lease: Option<LeaderLeaseConfig>,
+    /// The periodic backups of the node, if any.
+    #[cfg(feature = "backup")]
+    pub backup: Option<BackupSchedulerConfig>,
+    /// The number of the latest blocks which keep their transactions, statuses
+    /// and receipts. The older blocks are pruned. Nothing is pruned if not set.
+    pub block_retention: Option<u32>,
     /// The size of the memory pool in number of `MemoryInstance`s.
     pub memory_pool_size: usize,
 }
@@ -177,6 +196,7 @@ impl Config {
                 costs: Default::default(),
                 required_fuel_block_height_tolerance: 10,
                 required_fuel_block_height_timeout: Duration::from_secs(30),
+                admin_api_token: None,
             },
             combined_db_config,
             continue_on_error: false,
@@ -224,6 +244,10 @@ impl Config {
             min_connected_reserved_peers: 0,
             time_until_synced: Duration::ZERO,
             production_timeout: Duration::from_secs(20),
+            leader_lease: None,
+            #[cfg(feature = "backup")]
+            backup: None,
+            block_retention: None,
             memory_pool_size: 4,
         }
     }
@@ -275,6 +299,11 @@ impl From<&Config> for fuel_core_poa::Config {
                 .chain_config()
                 .consensus_parameters
                 .chain_id(),
+            round_robin: match &config.snapshot_reader.chain_config().consensus {
+                ConsensusConfig::RoundRobin(round_robin) => Some(round_robin.clone()),
+                ConsensusConfig::PoA { .. } | ConsensusConfig::PoAV2(_) => None,
+            },
+            leader_lease: config.leader_lease.clone(),
         }
     }
 }
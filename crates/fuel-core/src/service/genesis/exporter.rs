@@ -16,11 +16,16 @@ use crate::{
         OldFuelBlocks,
         OldTransactions,
     },
+    state::{
+        ColumnType,
+        IterableKeyValueView,
+    },
 };
 use fuel_core_chain_config::{
     AddTable,
     ChainConfig,
     LastBlockConfig,
+    SnapshotDiffMetadata,
     SnapshotFragment,
     SnapshotMetadata,
     SnapshotWriter,
@@ -29,11 +34,16 @@ use fuel_core_chain_config::{
 };
 use fuel_core_poa::ports::Database as DatabaseTrait;
 use fuel_core_storage::{
+    blueprint::BlueprintInspect,
+    codec::Decode,
     iter::{
         IterDirection,
         IterableTable,
     },
-    kv_store::StorageColumn,
+    kv_store::{
+        KeyValueInspect,
+        StorageColumn,
+    },
     structured_storage::TableWithBlueprint,
     tables::{
         Coins,
@@ -51,13 +61,21 @@ use fuel_core_storage::{
             FuelBlockMerkleMetadata,
         },
     },
-    transactional::AtomicView,
+    transactional::{
+        AtomicView,
+        HistoricalView,
+        ReferenceBytesKey,
+    },
 };
 use fuel_core_types::{
-    fuel_types::ContractId,
+    fuel_types::{
+        BlockHeight,
+        ContractId,
+    },
     fuel_vm::BlobData,
 };
 use itertools::Itertools;
+use std::collections::BTreeSet;
 
 use super::{
     NotifyCancel,
@@ -70,10 +88,19 @@ pub struct Exporter<Fun, N> {
     prev_chain_config: ChainConfig,
     writer: Fun,
     group_size: usize,
-    task_manager: TaskManager<SnapshotFragment, N>,
+    task_manager: TaskManager<ExportedFragments, N>,
     multi_progress: MultipleProgressReporter,
 }
 
+/// The fragments written by one export task.
+struct ExportedFragments {
+    entries: SnapshotFragment,
+    /// The removed entries. Only the diff snapshot has them.
+    removed: Option<SnapshotFragment>,
+}
+
+type ViewAtHeight<DbDesc> = IterableKeyValueView<ColumnType<DbDesc>, BlockHeight>;
+
 impl<Fun, N> Exporter<Fun, N>
 where
     Fun: Fn() -> anyhow::Result<SnapshotWriter>,
@@ -158,6 +185,133 @@ where
         Ok(())
     }
 
+    /// Writes the entries changed by the blocks in the `(base_height, height]` range
+    /// with their values at the `height`. The entries removed in that range are
+    /// written by the `removed_writer` with their values at the `base_height`.
+    ///
+    /// The history of modifications must cover the whole range.
+    pub async fn write_diff_snapshot<RemovedFun>(
+        mut self,
+        base_height: BlockHeight,
+        height: BlockHeight,
+        removed_writer: RemovedFun,
+    ) -> Result<SnapshotDiffMetadata, anyhow::Error>
+    where
+        RemovedFun: Fn() -> anyhow::Result<SnapshotWriter>,
+    {
+        anyhow::ensure!(
+            base_height < height,
+            "The base height({base_height}) of the diff should be less than the height({height})"
+        );
+
+        let mut on_chain_keys =
+            self.db.on_chain().modified_keys(&base_height, &height)?;
+        let mut off_chain_keys =
+            self.db.off_chain().modified_keys(&base_height, &height)?;
+
+        // The JSON snapshot groups the state and balances by the contract, so each
+        // contract with changed state or balances is exported with its code and UTXO.
+        let contracts = [
+            ContractsState::column().id(),
+            ContractsAssets::column().id(),
+        ]
+        .iter()
+        .filter_map(|column| on_chain_keys.get(column))
+        .flatten()
+        .filter_map(|key| key.get(..ContractId::LEN))
+        .map(|contract_id| ReferenceBytesKey::from(contract_id.to_vec()))
+        .collect::<BTreeSet<_>>();
+        for column in [
+            ContractsRawCode::column().id(),
+            ContractsLatestUtxo::column().id(),
+        ] {
+            on_chain_keys
+                .entry(column)
+                .or_default()
+                .extend(contracts.iter().cloned());
+        }
+
+        macro_rules! export {
+            ($db: expr_2021, $keys: ident, $also_removed: expr_2021, $($table: ty),*) => {
+                $(self.spawn_diff_task::<$table, _, _>(
+                    base_height,
+                    height,
+                    $keys.remove(&<$table>::column().id()).unwrap_or_default(),
+                    $also_removed,
+                    &removed_writer,
+                    $db,
+                )?;)*
+            };
+        }
+
+        let no_contracts = BTreeSet::new();
+        export!(
+            |ctx: &Self| ctx.db.on_chain(),
+            on_chain_keys,
+            &no_contracts,
+            Coins,
+            Messages,
+            BlobData,
+            ContractsState,
+            ContractsAssets,
+            FuelBlocks,
+            FuelBlockMerkleData,
+            FuelBlockMerkleMetadata,
+            Transactions,
+            SealedBlockConsensus,
+            ProcessedTransactions
+        );
+        export!(
+            |ctx: &Self| ctx.db.on_chain(),
+            on_chain_keys,
+            &contracts,
+            ContractsRawCode,
+            ContractsLatestUtxo
+        );
+        export!(
+            |ctx: &Self| ctx.db.off_chain(),
+            off_chain_keys,
+            &no_contracts,
+            TransactionStatuses,
+            OwnedTransactions,
+            OldFuelBlocks,
+            OldFuelBlockConsensus,
+            OldTransactions,
+            SpentMessages
+        );
+
+        let last_block = self.last_block_config(&height)?;
+        let entries_fragment = self.create_writer()?.partial_close()?;
+        let removed_fragment = removed_writer()?.partial_close()?;
+
+        let (entries, removed): (Vec<_>, Vec<_>) = self
+            .task_manager
+            .wait()
+            .await?
+            .into_iter()
+            .map(|fragments| (fragments.entries, fragments.removed))
+            .unzip();
+
+        entries
+            .into_iter()
+            .try_fold(entries_fragment, |fragment, next_fragment| {
+                fragment.merge(next_fragment)
+            })?
+            .finalize(Some(last_block), &self.prev_chain_config)?;
+        removed
+            .into_iter()
+            .flatten()
+            .try_fold(removed_fragment, |fragment, next_fragment| {
+                fragment.merge(next_fragment)
+            })?
+            .finalize(None, &self.prev_chain_config)?;
+
+        Ok(SnapshotDiffMetadata {
+            base_block_height: base_height,
+            block_height: height,
+        })
+    }
+
     async fn finalize(self) -> anyhow::Result<SnapshotMetadata> {
         let writer = self.create_writer()?;
         let view = self.db.on_chain().latest_view()?;
@@ -172,12 +326,20 @@ where
             .wait()
             .await?
             .into_iter()
+            .map(|fragments| fragments.entries)
             .try_fold(writer_fragment, |fragment, next_fragment| {
                 fragment.merge(next_fragment)
             })?
             .finalize(Some(latest_block), &self.prev_chain_config)
     }
 
+    fn last_block_config(&self, height: &BlockHeight) -> anyhow::Result<LastBlockConfig> {
+        let view = self.db.on_chain().latest_view()?;
+        let header = view.block_header(height)?;
+        let blocks_root = view.block_header_merkle_root(height)?;
+        Ok(LastBlockConfig::from_header(&header, blocks_root))
+    }
+
     fn create_writer(&self) -> anyhow::Result<SnapshotWriter> {
         (self.writer)()
     }
@@ -215,7 +377,102 @@ where
 
                     writer.write(chunk.try_collect()?)
                 })?;
-            writer.partial_close()
+            Ok(ExportedFragments {
+                entries: writer.partial_close()?,
+                removed: None,
+            })
+        });
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn spawn_diff_task<T, DbDesc, RemovedFun>(
+        &mut self,
+        base_height: BlockHeight,
+        height: BlockHeight,
+        keys: BTreeSet<ReferenceBytesKey>,
+        also_removed: &BTreeSet<ReferenceBytesKey>,
+        removed_writer: &RemovedFun,
+        db_picker: impl FnOnce(&Self) -> &Database<DbDesc>,
+    ) -> anyhow::Result<()>
+    where
+        T: TableWithBlueprint<Column = ColumnType<DbDesc>> + 'static + Send + Sync,
+        T::Blueprint: BlueprintInspect<T, ViewAtHeight<DbDesc>>,
+        TableEntry<T>: serde::Serialize,
+        StateConfigBuilder: AddTable<T>,
+        DbDesc: DatabaseDescription<Height = BlockHeight>,
+        RemovedFun: Fn() -> anyhow::Result<SnapshotWriter>,
+    {
+        let mut writer = self.create_writer()?;
+        let mut removed = removed_writer()?;
+        let group_size = self.group_size;
+
+        let db = db_picker(self);
+        let view = db.view_at(&height)?;
+        let base_view = db.view_at(&base_height)?;
+        let also_removed = also_removed.clone();
+        let progress_tracker = self
+            .multi_progress
+            .table_reporter(Some(keys.len().div_ceil(group_size)), T::column().name());
+        self.task_manager.spawn_blocking(move |cancel| {
+            let decode =
+                |key: &[u8], value| -> anyhow::Result<TableEntry<T>> {
+                    Ok(TableEntry {
+                        key: <T::Blueprint as BlueprintInspect<
+                            T,
+                            ViewAtHeight<DbDesc>,
+                        >>::KeyCodec::decode(key)?,
+                        value: <T::Blueprint as BlueprintInspect<
+                            T,
+                            ViewAtHeight<DbDesc>,
+                        >>::ValueCodec::decode_from_value(
+                            value
+                        )?,
+                    })
+                };
+
+            for (index, chunk) in keys
+                .iter()
+                .chunks(group_size)
+                .into_iter()
+                .take_while(|_| !cancel.is_cancelled())
+                .enumerate()
+            {
+                progress_tracker.set_index(index);
+
+                let mut entries = vec![];
+                let mut removed_entries = vec![];
+                for key in chunk {
+                    match KeyValueInspect::get(&view, key, T::column())? {
+                        Some(value) => {
+                            if also_removed.contains(key) {
+                                removed_entries.push(decode(key, value.clone())?);
+                            }
+                            entries.push(decode(key, value)?);
+                        }
+                        None => {
+                            // Entries created and removed inside the range are skipped.
+                            if let Some(value) =
+                                KeyValueInspect::get(&base_view, key, T::column())?
+                            {
+                                removed_entries.push(decode(key, value)?);
+                            }
+                        }
+                    }
+                }
+                if !entries.is_empty() {
+                    writer.write(entries)?;
+                }
+                if !removed_entries.is_empty() {
+                    removed.write(removed_entries)?;
+                }
+            }
+
+            Ok(ExportedFragments {
+                entries: writer.partial_close()?,
+                removed: Some(removed.partial_close()?),
+            })
         });
 
         Ok(())
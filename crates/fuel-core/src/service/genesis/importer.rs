@@ -163,6 +163,7 @@ impl SnapshotImporter {
     pub fn spawn_worker_on_chain<TableBeingWritten>(&mut self) -> anyhow::Result<()>
     where
         TableBeingWritten: TableWithBlueprint + 'static + Send,
+        TableBeingWritten::OwnedKey: Ord + Send,
         TableEntry<TableBeingWritten>: serde::de::DeserializeOwned + Send,
         StateConfig: AsTable<TableBeingWritten>,
         Handler<TableBeingWritten, TableBeingWritten>:
@@ -216,6 +217,7 @@ impl SnapshotImporter {
     ) -> anyhow::Result<()>
     where
         TableInSnapshot: TableWithBlueprint + Send + 'static,
+        TableInSnapshot::OwnedKey: Ord + Send,
         TableEntry<TableInSnapshot>: serde::de::DeserializeOwned + Send,
         StateConfig: AsTable<TableInSnapshot>,
         Handler<TableBeingWritten, TableInSnapshot>:
@@ -210,7 +210,6 @@ mod tests {
         },
         state::{
             IterableKeyValueView,
-            KeyValueView,
             TransactableStorage,
             in_memory::memory_store::MemoryStore,
         },
@@ -584,7 +583,7 @@ mod tests {
         fn view_at_height(
             &self,
             _: &BlockHeight,
-        ) -> StorageResult<KeyValueView<Self::Column, BlockHeight>> {
+        ) -> StorageResult<IterableKeyValueView<Self::Column, BlockHeight>> {
             Err(anyhow::anyhow!("I refuse to work!").into())
         }
 
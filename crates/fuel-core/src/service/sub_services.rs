@@ -34,6 +34,8 @@ use crate::service::adapters::consensus_module::poa::pre_confirmation_signature:
     trigger::TimeBasedTrigger,
     tx_receiver::PreconfirmationsReceiver,
 };
+#[cfg(feature = "p2p")]
+use crate::service::adapters::p2p::P2PViewProvider;
 
 use super::{
     DbType,
@@ -97,7 +99,7 @@ pub type PoAService = fuel_core_poa::Service<
     ReadySignal,
 >;
 #[cfg(feature = "p2p")]
-pub type P2PService = fuel_core_p2p::service::Service<Database, TxPoolAdapter>;
+pub type P2PService = fuel_core_p2p::service::Service<P2PViewProvider, TxPoolAdapter>;
 pub type TxPoolSharedState = fuel_core_txpool::SharedState;
 pub type BlockProducerService = fuel_core_producer::block_producer::Producer<
     Database,
@@ -290,6 +292,15 @@ pub fn init_sub_services(
     );
     let tx_pool_adapter = TxPoolAdapter::new(txpool.shared.clone());
 
+    // The reputation of the peers is kept next to the database, if it is persistent.
+    #[cfg(feature = "p2p")]
+    let peer_reputation_file = {
+        let db_config = &config.combined_db_config;
+        let path = (db_config.database_type == DbType::RocksDb
+            && !db_config.database_path.as_os_str().is_empty())
+        .then(|| db_config.database_path.join("peer_reputations"));
+        fuel_core_p2p::peer_reputation_file::PeerReputationFile::new(path)
+    };
     #[cfg(feature = "p2p")]
     let mut network = config.p2p.clone().zip(p2p_externals).map(
         |(p2p_config, (shared_state, request_receiver))| {
@@ -299,9 +310,10 @@ pub fn init_sub_services(
                 p2p_config,
                 shared_state,
                 request_receiver,
-                database.on_chain().clone(),
+                P2PViewProvider::new(database.clone()),
                 importer_adapter.clone(),
                 tx_pool_adapter.clone(),
+                peer_reputation_file.clone(),
             )
         },
     );
@@ -517,6 +529,22 @@ pub fn init_sub_services(
         services.push(Box::new(compression_service));
     }
 
+    #[cfg(feature = "backup")]
+    if let Some(backup_config) = &config.backup {
+        let backup_service =
+            crate::service::backup::new_service(backup_config, shared.database.clone())?;
+        services.push(Box::new(backup_service));
+    }
+
+    if let Some(block_retention) = config.block_retention {
+        let block_pruner = crate::service::block_pruner::new_service(
+            block_retention,
+            shared.database.clone(),
+            shared.block_importer.events_shared_result(),
+        )?;
+        services.push(Box::new(block_pruner));
+    }
+
     // always make sure that the block producer is inserted last
     if let Some(poa) = poa {
         services.push(Box::new(poa));
@@ -13,9 +13,18 @@ use fuel_core_storage::{
         IterableStore,
     },
     kv_store::StorageColumn,
-    transactional::StorageChanges,
+    transactional::{
+        ReferenceBytesKey,
+        StorageChanges,
+    },
+};
+use std::{
+    collections::{
+        BTreeMap,
+        BTreeSet,
+    },
+    fmt::Debug,
 };
-use std::fmt::Debug;
 
 pub mod data_source;
 pub mod generic_database;
@@ -32,6 +41,9 @@ pub mod rocks_db_key_iterator;
 pub type ColumnType<Description> = <Description as DatabaseDescription>::Column;
 pub type HeightType<Description> = <Description as DatabaseDescription>::Height;
 
+/// The keys modified by a range of blocks, grouped by the column id.
+pub type ModifiedKeys = BTreeMap<u32, BTreeSet<ReferenceBytesKey>>;
+
 /// A type extends the `KeyValueView`, allowing iteration over the storage.
 pub type IterableKeyValueView<Column, BlockHeight> =
     GenericDatabase<IterableKeyValueViewWrapper<Column>, BlockHeight>;
@@ -63,17 +75,39 @@ pub trait TransactableStorage<Height>: IterableStore + Debug + Send + Sync {
     fn view_at_height(
         &self,
         height: &Height,
-    ) -> StorageResult<KeyValueView<Self::Column, Height>>;
+    ) -> StorageResult<IterableKeyValueView<Self::Column, Height>>;
 
     fn latest_view(&self) -> StorageResult<IterableKeyValueView<Self::Column, Height>>;
 
     fn rollback_block_to(&self, height: &Height) -> StorageResult<()>;
 
+    /// Returns the keys modified by the blocks in the `(from, to]` height range.
+    fn modified_keys(&self, _from: &Height, _to: &Height) -> StorageResult<ModifiedKeys> {
+        Err(
+            anyhow::anyhow!("The database doesn't keep the history of modifications")
+                .into(),
+        )
+    }
+
+    /// Creates a checkpoint of the storage, from which the backup can be created later.
+    #[cfg(feature = "backup")]
+    fn checkpoint(&self) -> StorageResult<Box<dyn BackupCheckpoint>> {
+        Err(anyhow::anyhow!("The database doesn't support backups").into())
+    }
+
     fn shutdown(&self) {
         // Do nothing by default
     }
 }
 
+/// The consistent point-in-time copy of the storage.
+#[cfg(feature = "backup")]
+pub trait BackupCheckpoint: Send {
+    /// Creates a new backup of the checkpoint in the `backup_dir`.
+    /// Returns the identifier of the backup.
+    fn backup(self: Box<Self>, backup_dir: &std::path::Path) -> StorageResult<u32>;
+}
+
 // It is used only to allow conversion of the `StorageTransaction` into the `DataSource`.
 #[cfg(feature = "test-helpers")]
 impl<Height, S> TransactableStorage<Height>
@@ -88,7 +122,7 @@ where
     fn view_at_height(
         &self,
         _: &Height,
-    ) -> StorageResult<KeyValueView<Self::Column, Height>> {
+    ) -> StorageResult<IterableKeyValueView<Self::Column, Height>> {
         unimplemented!()
     }
 
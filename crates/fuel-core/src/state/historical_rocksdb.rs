@@ -11,7 +11,7 @@ use crate::{
         ColumnType,
         HeightType,
         IterableKeyValueView,
-        KeyValueView,
+        ModifiedKeys,
         TransactableStorage,
         historical_rocksdb::{
             description::{
@@ -22,7 +22,6 @@ use crate::{
             view_at_height::ViewAtHeight,
         },
         iterable_key_value_view::IterableKeyValueViewWrapper,
-        key_value_view::KeyValueViewWrapper,
         rocks_db::RocksDb,
     },
 };
@@ -370,6 +369,29 @@ where
         Ok(())
     }
 
+    /// Returns the keys modified by the blocks in the `(from, to]` height range.
+    pub fn modified_keys(&self, from: u64, to: u64) -> StorageResult<ModifiedKeys> {
+        let has_v1_history = self.has_v1_history();
+        let storage_transaction = self.db.read_transaction();
+        let mut modified_keys = ModifiedKeys::new();
+
+        for height in from.saturating_add(1)..=to {
+            let changes = multiversion_get(&storage_transaction, height, has_v1_history)?
+                .ok_or(DatabaseError::NoHistoryForRequestedHeight {
+                    requested_height: height,
+                })?;
+
+            for (column, column_changes) in changes {
+                modified_keys
+                    .entry(column)
+                    .or_default()
+                    .extend(column_changes.into_keys());
+            }
+        }
+
+        Ok(modified_keys)
+    }
+
     fn has_v1_history(&self) -> bool {
         use core::sync::atomic::Ordering;
 
@@ -400,6 +422,31 @@ where
     }
 }
 
+// Try to get the value from `ModificationsHistoryV2`, or return value from
+// `ModificationsHistoryV1`, if database still has v1 entries.
+fn multiversion_get<Description, T>(
+    storage_transaction: &StorageTransaction<T>,
+    height: u64,
+    has_v1_history: bool,
+) -> StorageResult<Option<Changes>>
+where
+    Description: DatabaseDescription,
+    T: KeyValueInspect<Column = Column<Description>>,
+{
+    let v2_changes = storage_transaction
+        .storage_as_ref::<ModificationsHistoryV2<Description>>()
+        .get(&height)?;
+
+    if v2_changes.is_none() && has_v1_history {
+        let v1_changes = storage_transaction
+            .storage_as_ref::<ModificationsHistoryV1<Description>>()
+            .get(&height)?;
+        Ok(v1_changes.map(|changes| changes.into_owned()))
+    } else {
+        Ok(v2_changes.map(|changes| changes.into_owned()))
+    }
+}
+
 // Try to take the value from `ModificationsHistoryV2`, or return value from
 // `ModificationsHistoryV1`, if database still has v1 entries.
 fn multiversion_take<Description, T>(
@@ -597,11 +644,12 @@ where
     fn view_at_height(
         &self,
         height: &Description::Height,
-    ) -> StorageResult<KeyValueView<ColumnType<Description>, HeightType<Description>>>
-    {
+    ) -> StorageResult<
+        IterableKeyValueView<ColumnType<Description>, HeightType<Description>>,
+    > {
         let view = self.create_view_at(height)?;
-        Ok(KeyValueView::from_storage_and_metadata(
-            KeyValueViewWrapper::new(view),
+        Ok(IterableKeyValueView::from_storage_and_metadata(
+            IterableKeyValueViewWrapper::new(view),
             Some(*height),
         ))
     }
@@ -622,6 +670,19 @@ where
         self.rollback_block_to(height.as_u64())
     }
 
+    fn modified_keys(
+        &self,
+        from: &Description::Height,
+        to: &Description::Height,
+    ) -> StorageResult<ModifiedKeys> {
+        self.modified_keys(from.as_u64(), to.as_u64())
+    }
+
+    #[cfg(feature = "backup")]
+    fn checkpoint(&self) -> StorageResult<Box<dyn crate::state::BackupCheckpoint>> {
+        Ok(Box::new(self.db.create_checkpoint()?))
+    }
+
     fn shutdown(&self) {
         self.db.shutdown()
     }
@@ -661,12 +722,15 @@ mod tests {
         ContractsAssetKey,
         StorageAsMut,
         StorageAsRef,
+        kv_store::StorageColumn,
         tables::ContractsAssets,
         transactional::{
             IntoTransaction,
             ReadTransaction,
+            ReferenceBytesKey,
         },
     };
+    use std::collections::BTreeSet;
 
     #[test]
     fn test_height_key() {
@@ -760,6 +824,74 @@ mod tests {
         assert_eq!(latest_balance, 321);
     }
 
+    #[test]
+    fn modified_keys__returns_keys_changed_in_the_range() {
+        // Given
+        let rocks_db = RocksDb::<Historical<OnChain>>::default_open_temp().unwrap();
+        let historical_rocks_db =
+            HistoricalRocksDB::new(rocks_db, StateRewindPolicy::RewindFullRange).unwrap();
+        let other_key = ContractsAssetKey::new(&[1; 32].into(), &[2; 32].into());
+
+        // Set the value of the `key` at height 1.
+        let mut transaction = historical_rocks_db.read_transaction();
+        transaction
+            .storage_as_mut::<ContractsAssets>()
+            .insert(&key(), &123)
+            .unwrap();
+        historical_rocks_db
+            .commit_changes(Some(1u32.into()), transaction.into_changes().into())
+            .unwrap();
+
+        // Set the value of the `other_key` at height 2.
+        let mut transaction = historical_rocks_db.read_transaction();
+        transaction
+            .storage_as_mut::<ContractsAssets>()
+            .insert(&other_key, &321)
+            .unwrap();
+        historical_rocks_db
+            .commit_changes(Some(2u32.into()), transaction.into_changes().into())
+            .unwrap();
+
+        // When
+        let modified_keys = historical_rocks_db.modified_keys(1, 2).unwrap();
+
+        // Then
+        let expected = ModifiedKeys::from([(
+            ContractsAssets::column().id(),
+            BTreeSet::from([ReferenceBytesKey::from(other_key.as_ref().to_vec())]),
+        )]);
+        assert_eq!(modified_keys, expected);
+    }
+
+    #[test]
+    fn modified_keys__fails_without_history() {
+        // Given
+        let rocks_db = RocksDb::<Historical<OnChain>>::default_open_temp().unwrap();
+        let historical_rocks_db =
+            HistoricalRocksDB::new(rocks_db, StateRewindPolicy::NoRewind).unwrap();
+
+        let mut transaction = historical_rocks_db.read_transaction();
+        transaction
+            .storage_as_mut::<ContractsAssets>()
+            .insert(&key(), &123)
+            .unwrap();
+        historical_rocks_db
+            .commit_changes(Some(1u32.into()), transaction.into_changes().into())
+            .unwrap();
+
+        // When
+        let result = historical_rocks_db.modified_keys(0, 1);
+
+        // Then
+        assert_eq!(
+            result,
+            Err(DatabaseError::NoHistoryForRequestedHeight {
+                requested_height: 1,
+            }
+            .into())
+        );
+    }
+
     #[test]
     fn state_rewind_policy__no_rewind__create_view_at__fails() {
         // Given
@@ -11,23 +11,37 @@ use crate::{
         },
         rocks_db::{
             KeyAndValue,
+            KeyOnly,
             RocksDb,
         },
     },
 };
 use fuel_core_storage::{
     Result as StorageResult,
+    iter::{
+        BoxedIter,
+        IntoBoxedIter,
+        IterDirection,
+        IterableStore,
+    },
     kv_store::{
+        KVItem,
+        Key,
+        KeyItem,
         KeyValueInspect,
         Value,
         WriteOperation,
     },
 };
+use itertools::Itertools;
 use rocksdb::{
     IteratorMode,
     ReadOptions,
 };
 
+/// The size of the height suffix of the keys in the historical duplicate columns.
+const HEIGHT_SIZE: usize = core::mem::size_of::<u64>();
+
 pub struct ViewAtHeight<Description> {
     height: u64,
     read_db: RocksDb<Historical<Description>>,
@@ -40,6 +54,98 @@ where
     pub fn new(height: u64, read_db: RocksDb<Historical<Description>>) -> Self {
         Self { height, read_db }
     }
+
+    /// Returns the keys of the `column` modified at the height of the view or later,
+    /// in the forward order.
+    ///
+    /// These keys may not exist in the latest state, but may exist at the height of the view.
+    fn modified_keys(
+        &self,
+        column: Description::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+    ) -> impl Iterator<Item = KeyItem> + '_ + use<'_, Description> {
+        let prefix = prefix.unwrap_or_default().to_vec();
+        let from = match start {
+            Some(start) if start > prefix.as_slice() => start.to_vec(),
+            _ => prefix.clone(),
+        };
+        let mut options = ReadOptions::default();
+        // The prefix of the historical column includes the height,
+        // so we can't rely on the prefix seek.
+        options.set_total_order_seek(true);
+        let height = self.height;
+
+        self.read_db
+            .iterator::<KeyOnly>(
+                Column::HistoricalDuplicateColumn(column),
+                options,
+                IteratorMode::From(from.as_slice(), rocksdb::Direction::Forward),
+            )
+            .take_while(move |item| match item {
+                Ok(height_key) => height_key.starts_with(prefix.as_slice()),
+                Err(_) => true,
+            })
+            .filter_map(move |item| {
+                let height_key = match item {
+                    Ok(height_key) => height_key,
+                    Err(err) => return Some(Err(err)),
+                };
+                let key_len = height_key.len().checked_sub(HEIGHT_SIZE)?;
+                let (key, modified_at) = height_key.split_at(key_len);
+                let modified_at = u64::from_be_bytes(modified_at.try_into().ok()?);
+                (modified_at >= height).then(|| Ok(key.to_vec()))
+            })
+            .dedup_by(|a, b| matches!((a, b), (Ok(a), Ok(b)) if a == b))
+    }
+
+    /// Returns the keys of the `column` that may exist at the height of the view
+    /// in the `direction` order.
+    fn candidate_keys(
+        &self,
+        column: Description::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, KeyItem> {
+        let latest_keys = self.read_db.iter_store_keys(
+            Column::OriginalColumn(column),
+            prefix,
+            start,
+            direction,
+        );
+
+        match direction {
+            IterDirection::Forward => {
+                let modified_keys = self.modified_keys(column, prefix, start);
+                latest_keys
+                    .merge_by(modified_keys, |a, b| match (a, b) {
+                        (Ok(a), Ok(b)) => a <= b,
+                        _ => true,
+                    })
+                    .dedup_by(|a, b| matches!((a, b), (Ok(a), Ok(b)) if a == b))
+                    .into_boxed()
+            }
+            IterDirection::Reverse => {
+                // The history can only be read in the forward order,
+                // so the modified keys are collected to be reversed.
+                let modified_keys = self
+                    .modified_keys(column, prefix, None)
+                    .filter(|key| match (key, start) {
+                        (Ok(key), Some(start)) => key.as_slice() <= start,
+                        _ => true,
+                    })
+                    .collect::<Vec<_>>();
+                latest_keys
+                    .merge_by(modified_keys.into_iter().rev(), |a, b| match (a, b) {
+                        (Ok(a), Ok(b)) => a >= b,
+                        _ => true,
+                    })
+                    .dedup_by(|a, b| matches!((a, b), (Ok(a), Ok(b)) if a == b))
+                    .into_boxed()
+            }
+        }
+    }
 }
 
 impl<Description> KeyValueInspect for ViewAtHeight<Description>
@@ -84,7 +190,51 @@ where
     }
 }
 
+impl<Description> IterableStore for ViewAtHeight<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn iter_store(
+        &self,
+        column: Self::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KVItem> {
+        if let (Some(prefix), Some(start)) = (prefix, start) {
+            if !start.starts_with(prefix) {
+                return core::iter::empty().into_boxed();
+            }
+        }
+
+        self.candidate_keys(column, prefix, start, direction)
+            .filter_map(move |key| {
+                let key: Key = match key {
+                    Ok(key) => key,
+                    Err(err) => return Some(Err(err)),
+                };
+                self.get(&key, column)
+                    .map(|value| value.map(|value| (key, value)))
+                    .transpose()
+            })
+            .into_boxed()
+    }
+
+    fn iter_store_keys(
+        &self,
+        column: Self::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KeyItem> {
+        self.iter_store(column, prefix, start, direction)
+            .map(|item| item.map(|(key, _)| key))
+            .into_boxed()
+    }
+}
+
 #[cfg(test)]
+#[allow(non_snake_case)]
 mod tests {
     use super::*;
     use crate::{
@@ -101,6 +251,7 @@ mod tests {
         ContractsAssetKey,
         StorageAsMut,
         StorageAsRef,
+        structured_storage::TableWithBlueprint,
         tables::ContractsAssets,
         transactional::{
             IntoTransaction,
@@ -112,6 +263,153 @@ mod tests {
         ContractsAssetKey::new(&[123; 32].into(), &[213; 32].into())
     }
 
+    fn asset_key(contract: u8, asset: u8) -> ContractsAssetKey {
+        ContractsAssetKey::new(&[contract; 32].into(), &[asset; 32].into())
+    }
+
+    /// Creates the database with the history:
+    /// - Height 1: inserts the assets `1`, `2` of the contract `1` and the asset `1` of the contract `2`.
+    /// - Height 2: removes the asset `1`, updates the asset `2` and inserts the asset `3` of the contract `1`.
+    fn database_with_history() -> HistoricalRocksDB<OnChain> {
+        let rocks_db = RocksDb::<Historical<OnChain>>::default_open_temp().unwrap();
+        let historical_rocks_db =
+            HistoricalRocksDB::new(rocks_db, StateRewindPolicy::RewindFullRange).unwrap();
+
+        let mut transaction = historical_rocks_db.read_transaction();
+        let mut assets = transaction.storage_as_mut::<ContractsAssets>();
+        assets.insert(&asset_key(1, 1), &1).unwrap();
+        assets.insert(&asset_key(1, 2), &2).unwrap();
+        assets.insert(&asset_key(2, 1), &3).unwrap();
+        historical_rocks_db
+            .commit_changes(Some(1u32.into()), transaction.into_changes().into())
+            .unwrap();
+
+        let mut transaction = historical_rocks_db.read_transaction();
+        let mut assets = transaction.storage_as_mut::<ContractsAssets>();
+        assets.remove(&asset_key(1, 1)).unwrap();
+        assets.insert(&asset_key(1, 2), &20).unwrap();
+        assets.insert(&asset_key(1, 3), &30).unwrap();
+        historical_rocks_db
+            .commit_changes(Some(2u32.into()), transaction.into_changes().into())
+            .unwrap();
+
+        historical_rocks_db
+    }
+
+    fn keys_at(
+        historical_rocks_db: &HistoricalRocksDB<OnChain>,
+        height: u32,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> Vec<Key> {
+        historical_rocks_db
+            .create_view_at(&height.into())
+            .unwrap()
+            .iter_store_keys(ContractsAssets::column(), prefix, start, direction)
+            .try_collect()
+            .unwrap()
+    }
+
+    fn raw(key: ContractsAssetKey) -> Key {
+        key.as_ref().to_vec()
+    }
+
+    #[test]
+    fn iter_store__returns_the_state_at_the_height_of_the_view() {
+        // Given
+        let historical_rocks_db = database_with_history();
+
+        // When
+        let keys = keys_at(&historical_rocks_db, 1, None, None, IterDirection::Forward);
+
+        // Then
+        assert_eq!(
+            keys,
+            vec![
+                raw(asset_key(1, 1)),
+                raw(asset_key(1, 2)),
+                raw(asset_key(2, 1))
+            ]
+        );
+    }
+
+    #[test]
+    fn iter_store__returns_the_state_at_the_height_of_the_view_in_reverse_order() {
+        // Given
+        let historical_rocks_db = database_with_history();
+
+        // When
+        let keys = keys_at(&historical_rocks_db, 1, None, None, IterDirection::Reverse);
+
+        // Then
+        assert_eq!(
+            keys,
+            vec![
+                raw(asset_key(2, 1)),
+                raw(asset_key(1, 2)),
+                raw(asset_key(1, 1))
+            ]
+        );
+    }
+
+    #[test]
+    fn iter_store__respects_the_prefix_and_the_start() {
+        // Given
+        let historical_rocks_db = database_with_history();
+        let prefix = [1u8; 32];
+        let start = raw(asset_key(1, 2));
+
+        // When
+        let forward = keys_at(
+            &historical_rocks_db,
+            1,
+            Some(&prefix),
+            Some(&start),
+            IterDirection::Forward,
+        );
+        let reverse = keys_at(
+            &historical_rocks_db,
+            1,
+            Some(&prefix),
+            Some(&start),
+            IterDirection::Reverse,
+        );
+
+        // Then
+        assert_eq!(forward, vec![raw(asset_key(1, 2))]);
+        assert_eq!(reverse, vec![raw(asset_key(1, 2)), raw(asset_key(1, 1))]);
+    }
+
+    #[test]
+    fn iter_store__returns_the_values_at_the_height_of_the_view() {
+        // Given
+        let historical_rocks_db = database_with_history();
+        let prefix = [1u8; 32];
+
+        // When
+        let view = historical_rocks_db.create_view_at(&1u32.into()).unwrap();
+        let entries: Vec<_> = view
+            .iter_store(
+                ContractsAssets::column(),
+                Some(&prefix),
+                None,
+                IterDirection::Forward,
+            )
+            .try_collect()
+            .unwrap();
+
+        // Then
+        let value = |amount: u64| Value::from(amount.to_be_bytes().to_vec());
+        assert_eq!(
+            entries,
+            vec![
+                (raw(asset_key(1, 1)), value(1)),
+                (raw(asset_key(1, 2)), value(2))
+            ]
+        );
+    }
+
     #[test]
     fn historical_rocksdb_view_at_each_height_works() {
         // Given
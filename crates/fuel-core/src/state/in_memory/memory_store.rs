@@ -10,7 +10,6 @@ use crate::{
     state::{
         IterDirection,
         IterableKeyValueView,
-        KeyValueView,
         TransactableStorage,
         in_memory::memory_view::MemoryView,
         iterable_key_value_view::IterableKeyValueViewWrapper,
@@ -232,7 +231,7 @@ where
     fn view_at_height(
         &self,
         _: &Description::Height,
-    ) -> StorageResult<KeyValueView<Self::Column, Description::Height>> {
+    ) -> StorageResult<IterableKeyValueView<Self::Column, Description::Height>> {
         // TODO: https://github.com/FuelLabs/fuel-core/issues/1995
         Err(
             anyhow::anyhow!("The historical view is not implemented for `MemoryStore`")
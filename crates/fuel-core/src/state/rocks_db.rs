@@ -817,8 +817,6 @@ where
         db_dir: &P,
         backup_dir: &P,
     ) -> DatabaseResult<()> {
-        let mut backup_engine = Self::backup_engine(backup_dir)?;
-
         let db_config = DatabaseConfig {
             cache_capacity: None,
             max_fds: -1,
@@ -832,9 +830,115 @@ where
             db_config,
         )?;
 
-        backup_engine.create_new_backup(&db.db).map_err(|e| {
+        db.create_backup(backup_dir, false)?;
+
+        Ok(())
+    }
+
+    /// Creates a new backup of the opened database in the `backup_dir`.
+    ///
+    /// The backups in the same `backup_dir` share the files, so each new backup
+    /// only copies the files created since the previous one. If `flush` is set,
+    /// the memtables are flushed before the backup, and the write-ahead log
+    /// is not copied. Returns the identifier of the new backup.
+    #[cfg(feature = "backup")]
+    pub fn create_backup<P: AsRef<Path> + ?Sized>(
+        &self,
+        backup_dir: &P,
+        flush: bool,
+    ) -> DatabaseResult<u32> {
+        let mut backup_engine = Self::backup_engine(backup_dir)?;
+
+        backup_engine
+            .create_new_backup_flush(&self.db, flush)
+            .map_err(|e| {
+                DatabaseError::BackupError(anyhow::anyhow!(
+                    "Couldn't create new backup for path `{}`: {}",
+                    backup_dir.as_ref().display(),
+                    e
+                ))
+            })?;
+
+        backup_engine
+            .get_backup_info()
+            .iter()
+            .map(|info| info.backup_id)
+            .max()
+            .ok_or_else(|| {
+                DatabaseError::BackupError(anyhow::anyhow!(
+                    "The new backup is missing in the path `{}`",
+                    backup_dir.as_ref().display(),
+                ))
+            })
+    }
+
+    /// Creates a checkpoint of the opened database in the temporary directory
+    /// next to it.
+    ///
+    /// The checkpoint is a consistent copy of the database at the moment of the call.
+    /// Its files are hard links to the files of the database, so it is fast to create,
+    /// unlike the backup, which copies the files.
+    #[cfg(feature = "backup")]
+    pub fn create_checkpoint(&self) -> DatabaseResult<RocksDbCheckpoint<Description>> {
+        use rocksdb::checkpoint::Checkpoint;
+
+        let db_path = self.db.path();
+        let dir = tempfile::Builder::new()
+            .prefix(".checkpoint")
+            .tempdir_in(db_path.parent().unwrap_or(db_path))
+            .map_err(|e| DatabaseError::BackupError(e.into()))?;
+        let checkpoint_path = dir.path().join(Description::name());
+
+        Checkpoint::new(&self.db.0)
+            .and_then(|checkpoint| checkpoint.create_checkpoint(&checkpoint_path))
+            .map_err(|e| {
+                DatabaseError::BackupError(anyhow::anyhow!(
+                    "Couldn't create the checkpoint in the path `{}`: {}",
+                    checkpoint_path.display(),
+                    e
+                ))
+            })?;
+
+        Ok(RocksDbCheckpoint {
+            dir,
+            _marker: Default::default(),
+        })
+    }
+
+    /// Returns the backups of the database in the `backup_dir`, from the oldest to the newest.
+    #[cfg(feature = "backup")]
+    pub fn backups<P: AsRef<Path> + ?Sized>(
+        backup_dir: &P,
+    ) -> DatabaseResult<Vec<BackupInfo>> {
+        let backup_engine = Self::backup_engine(backup_dir)?;
+
+        let mut backups = backup_engine
+            .get_backup_info()
+            .into_iter()
+            .map(|info| BackupInfo {
+                backup_id: info.backup_id,
+                timestamp: info.timestamp,
+                size: info.size,
+                num_files: info.num_files,
+            })
+            .collect::<Vec<_>>();
+        backups.sort_by_key(|info| info.backup_id);
+
+        Ok(backups)
+    }
+
+    /// Verifies that the files of the backup exist and have the expected size and checksum.
+    #[cfg(feature = "backup")]
+    pub fn verify_backup<P: AsRef<Path> + ?Sized>(
+        backup_dir: &P,
+        backup_id: u32,
+    ) -> DatabaseResult<()> {
+        let backup_engine = Self::backup_engine(backup_dir)?;
+
+        backup_engine.verify_backup(backup_id).map_err(|e| {
             DatabaseError::BackupError(anyhow::anyhow!(
-                "Couldn't create new backup for path `{}`: {}",
+                "The backup {backup_id} of the `{}` database in the path `{}` is corrupted: {}",
+                Description::name(),
                 backup_dir.as_ref().display(),
                 e
             ))
@@ -843,11 +947,42 @@ where
         Ok(())
     }
 
+    /// Removes the oldest backups of the database, keeping `num_backups_to_keep` newest ones.
+    #[cfg(feature = "backup")]
+    pub fn purge_old_backups<P: AsRef<Path> + ?Sized>(
+        backup_dir: &P,
+        num_backups_to_keep: usize,
+    ) -> DatabaseResult<()> {
+        let mut backup_engine = Self::backup_engine(backup_dir)?;
+
+        backup_engine
+            .purge_old_backups(num_backups_to_keep)
+            .map_err(|e| {
+                DatabaseError::BackupError(anyhow::anyhow!(
+                    "Couldn't purge old backups for path `{}`: {}",
+                    backup_dir.as_ref().display(),
+                    e
+                ))
+            })?;
+
+        Ok(())
+    }
+
     /// We delegate opening of restored db to consumer, so they can apply their own options
     #[cfg(feature = "backup")]
     pub fn restore<P: AsRef<Path> + ?Sized>(
         db_dir: &P,
         backup_dir: &P,
+    ) -> DatabaseResult<()> {
+        Self::restore_backup(db_dir, backup_dir, None)
+    }
+
+    /// Restores the backup with the `backup_id`, or the latest backup if it is not set.
+    #[cfg(feature = "backup")]
+    pub fn restore_backup<P: AsRef<Path> + ?Sized>(
+        db_dir: &P,
+        backup_dir: &P,
+        backup_id: Option<u32>,
     ) -> DatabaseResult<()> {
         use rocksdb::backup::RestoreOptions;
 
@@ -857,15 +992,26 @@ where
         let db_dir = db_dir.as_ref().join(Description::name());
         let db_dir_path = db_dir.as_path();
         // we use the default wal directory, which is same as db path
-        backup_engine
-            .restore_from_latest_backup(db_dir_path, db_dir_path, &restore_option)
-            .map_err(|e| {
-                DatabaseError::RestoreError(anyhow::anyhow!(
-                    "Couldn't restore from latest backup for path `{}`: {}",
-                    db_dir_path.display(),
-                    e
-                ))
-            })?;
+        let result = match backup_id {
+            Some(backup_id) => backup_engine.restore_from_backup(
+                db_dir_path,
+                db_dir_path,
+                &restore_option,
+                backup_id,
+            ),
+            None => backup_engine.restore_from_latest_backup(
+                db_dir_path,
+                db_dir_path,
+                &restore_option,
+            ),
+        };
+        result.map_err(|e| {
+            DatabaseError::RestoreError(anyhow::anyhow!(
+                "Couldn't restore from backup {backup_id:?} for path `{}`: {}",
+                db_dir_path.display(),
+                e
+            ))
+        })?;
 
         Ok(())
     }
@@ -875,6 +1021,50 @@ where
     }
 }
 
+/// The checkpoint of the database. It is removed when dropped.
+#[cfg(feature = "backup")]
+pub struct RocksDbCheckpoint<Description> {
+    dir: TempDir,
+    _marker: core::marker::PhantomData<Description>,
+}
+
+#[cfg(feature = "backup")]
+impl<Description> crate::state::BackupCheckpoint for RocksDbCheckpoint<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn backup(self: Box<Self>, backup_dir: &Path) -> StorageResult<u32> {
+        // The checkpoint is closed before its directory is removed.
+        let db = RocksDb::<Description>::open_read_only(
+            self.dir.path(),
+            enum_iterator::all::<Description::Column>().collect::<Vec<_>>(),
+            false,
+            DatabaseConfig {
+                cache_capacity: None,
+                max_fds: -1,
+                columns_policy: ColumnsPolicy::Lazy,
+            },
+        )?;
+        let backup_id = db.create_backup(backup_dir, false)?;
+        drop(db);
+        Ok(backup_id)
+    }
+}
+
+/// The information about a backup of a single database.
+#[cfg(feature = "backup")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BackupInfo {
+    /// The identifier of the backup in the backup engine of the database.
+    pub backup_id: u32,
+    /// The unix timestamp of the backup creation in seconds.
+    pub timestamp: i64,
+    /// The size of the backup in bytes.
+    pub size: u64,
+    /// The number of files in the backup.
+    pub num_files: u32,
+}
+
 pub(crate) struct KeyOnly;
 
 impl ExtractItem for KeyOnly {
@@ -1641,4 +1831,34 @@ mod tests {
             .collect::<Vec<_>>();
         assert_eq!(db_iter, vec![]);
     }
+
+    #[cfg(feature = "backup")]
+    #[test]
+    fn create_checkpoint__backup_ignores_writes_after_the_checkpoint() {
+        use crate::state::BackupCheckpoint;
+
+        let (mut db, _tmp) = create_db();
+        let backup_dir = TempDir::new().unwrap();
+        let restore_dir = TempDir::new().unwrap();
+        let key_1 = [1];
+        let key_2 = [2];
+
+        // Given
+        db.put(&key_1, Column::Metadata, Value::from([1])).unwrap();
+        let checkpoint = Box::new(db.create_checkpoint().unwrap());
+        db.put(&key_2, Column::Metadata, Value::from([2])).unwrap();
+
+        // When
+        checkpoint.backup(backup_dir.path()).unwrap();
+
+        // Then
+        RocksDb::<OnChain>::restore(restore_dir.path(), backup_dir.path()).unwrap();
+        let restored = RocksDb::<OnChain>::default_open(
+            restore_dir.path(),
+            DatabaseConfig::config_for_tests(),
+        )
+        .unwrap();
+        assert!(restored.exists(&key_1, Column::Metadata).unwrap());
+        assert!(!restored.exists(&key_2, Column::Metadata).unwrap());
+    }
 }
@@ -1,15 +1,49 @@
-use crate::global_registry;
-use prometheus_client::metrics::{
-    counter::Counter,
-    gauge::Gauge,
+use crate::{
+    buckets::{
+        Buckets,
+        buckets,
+    },
+    global_registry,
 };
-use std::sync::OnceLock;
+use prometheus_client::{
+    encoding::EncodeLabelSet,
+    metrics::{
+        counter::Counter,
+        family::Family,
+        gauge::Gauge,
+        histogram::Histogram,
+    },
+};
+use std::{
+    sync::OnceLock,
+    time::Duration,
+};
+
+/// The label of the requests to the peers without their own series.
+const OTHER_PEERS: &str = "other";
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct PeerLabel {
+    // the base58 encoded peer id, or `other` for the rest of the peers
+    peer: String,
+}
+
+impl PeerLabel {
+    fn new(peer: Option<&str>) -> Self {
+        Self {
+            peer: peer.unwrap_or(OTHER_PEERS).to_string(),
+        }
+    }
+}
 
 pub struct P2PMetrics {
     pub unique_peers: Counter,
     pub blocks_requested: Gauge,
     pub p2p_req_res_cache_hits: Counter,
     pub p2p_req_res_cache_misses: Counter,
+    pub peer_request_duration: Family<PeerLabel, Histogram>,
+    pub peer_failed_requests: Family<PeerLabel, Counter>,
+    pub peer_bytes_served: Family<PeerLabel, Counter>,
 }
 
 impl P2PMetrics {
@@ -18,12 +52,21 @@ impl P2PMetrics {
         let blocks_requested = Gauge::default();
         let p2p_req_res_cache_hits = Counter::default();
         let p2p_req_res_cache_misses = Counter::default();
+        let peer_request_duration =
+            Family::<PeerLabel, Histogram>::new_with_constructor(|| {
+                Histogram::new(buckets(Buckets::Timing))
+            });
+        let peer_failed_requests = Family::<PeerLabel, Counter>::default();
+        let peer_bytes_served = Family::<PeerLabel, Counter>::default();
 
         let metrics = P2PMetrics {
             unique_peers,
             blocks_requested,
             p2p_req_res_cache_hits,
             p2p_req_res_cache_misses,
+            peer_request_duration,
+            peer_failed_requests,
+            peer_bytes_served,
         };
 
         let mut registry = global_registry().registry.lock();
@@ -51,6 +94,24 @@ impl P2PMetrics {
             metrics.p2p_req_res_cache_misses.clone()
         );
 
+        registry.register(
+            "P2p_Peer_Request_Duration_Seconds",
+            "A Histogram which keeps track of the latency of the successful p2p req/res requests per best ranked peer",
+            metrics.peer_request_duration.clone()
+        );
+
+        registry.register(
+            "P2p_Peer_Failed_Requests",
+            "A Counter which keeps track of the number of failed p2p req/res requests per best ranked peer",
+            metrics.peer_failed_requests.clone()
+        );
+
+        registry.register(
+            "P2p_Peer_Bytes_Served",
+            "A Counter which keeps track of the number of bytes received in p2p req/res responses per best ranked peer",
+            metrics.peer_bytes_served.clone()
+        );
+
         metrics
     }
 }
@@ -76,3 +137,37 @@ pub fn increment_p2p_req_res_cache_hits() {
 pub fn increment_p2p_req_res_cache_misses() {
     p2p_metrics().p2p_req_res_cache_misses.inc();
 }
+
+/// Records the successful request to the `peer`.
+/// The requests to the peers without their own series are recorded as `other`.
+pub fn observe_peer_request_success(peer: Option<&str>, latency: Duration, bytes: u64) {
+    let label = PeerLabel::new(peer);
+    let metrics = p2p_metrics();
+    metrics
+        .peer_request_duration
+        .get_or_create(&label)
+        .observe(latency.as_secs_f64());
+    metrics
+        .peer_bytes_served
+        .get_or_create(&label)
+        .inc_by(bytes);
+}
+
+/// Records the failed request to the `peer`.
+/// The requests to the peers without their own series are recorded as `other`.
+pub fn increment_peer_failed_requests(peer: Option<&str>) {
+    let label = PeerLabel::new(peer);
+    p2p_metrics()
+        .peer_failed_requests
+        .get_or_create(&label)
+        .inc();
+}
+
+/// Removes the series of the `peer`.
+pub fn remove_peer_metrics(peer: &str) {
+    let label = PeerLabel::new(Some(peer));
+    let metrics = p2p_metrics();
+    metrics.peer_request_duration.remove(&label);
+    metrics.peer_failed_requests.remove(&label);
+    metrics.peer_bytes_served.remove(&label);
+}
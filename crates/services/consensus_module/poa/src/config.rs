@@ -1,3 +1,8 @@
+use crate::{
+    leader_lease::LeaderLeaseConfig,
+    ports::PendingTransactions,
+};
+use fuel_core_chain_config::RoundRobinPoA;
 use fuel_core_types::{
     fuel_types::ChainId,
     signer::SignMode,
@@ -13,6 +18,12 @@ pub struct Config {
     pub time_until_synced: Duration,
     pub production_timeout: Duration,
     pub chain_id: ChainId,
+    /// The schedule of producers, if the chain uses the round-robin PoA.
+    /// The node produces blocks only during its turns in the schedule.
+    pub round_robin: Option<RoundRobinPoA>,
+    /// The lease shared with the standby producers, if any.
+    /// The node produces blocks only while it holds the lease.
+    pub leader_lease: Option<LeaderLeaseConfig>,
 }
 
 #[cfg(feature = "test-helpers")]
@@ -26,6 +37,8 @@ impl Default for Config {
             time_until_synced: Duration::ZERO,
             production_timeout: Duration::from_secs(20),
             chain_id: ChainId::default(),
+            round_robin: None,
+            leader_lease: None,
         }
     }
 }
@@ -44,4 +57,34 @@ pub enum Trigger {
     /// Opens the block production immediately and keeps it open for the specified period.
     /// After period is over, the block is produced.
     Open { period: Duration },
+    /// A new block is produced as soon as the pending transactions reach one of the
+    /// thresholds, or when the `max_latency` passes since the previous block.
+    /// Without thresholds, it behaves like the `Interval` trigger.
+    Hybrid {
+        /// The maximum time between blocks.
+        max_latency: Duration,
+        /// The total gas of the pending transactions that triggers the block production.
+        gas_threshold: Option<u64>,
+        /// The number of pending transactions that triggers the block production.
+        tx_count_threshold: Option<u64>,
+    },
+}
+
+impl Trigger {
+    /// Returns `true` if the pending transactions are enough to produce the block
+    /// without waiting for the latency of the `Hybrid` trigger.
+    pub fn thresholds_reached(&self, pending: &PendingTransactions) -> bool {
+        match self {
+            Trigger::Hybrid {
+                gas_threshold,
+                tx_count_threshold,
+                ..
+            } => {
+                gas_threshold.is_some_and(|threshold| pending.total_gas >= threshold)
+                    || tx_count_threshold
+                        .is_some_and(|threshold| pending.tx_count >= threshold)
+            }
+            _ => false,
+        }
+    }
 }
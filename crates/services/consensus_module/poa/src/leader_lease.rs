@@ -0,0 +1,158 @@
+//! The leader lease elects a single block producer among several producer nodes.
+//!
+//! Only the node holding the lease produces blocks. The holder renews the lease
+//! periodically, and the standby node acquires it once the lease lapses.
+
+use crate::ports::LeaderLease;
+use anyhow::Context;
+use fs2::FileExt;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use std::{
+    fs::{
+        File,
+        OpenOptions,
+    },
+    path::PathBuf,
+    sync::Arc,
+    time::{
+        Duration,
+        SystemTime,
+        UNIX_EPOCH,
+    },
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The configuration of the leader lease used by the PoA service.
+#[derive(Clone, Debug)]
+pub struct LeaderLeaseConfig {
+    /// The backend keeping the lease.
+    pub lease: Arc<dyn LeaderLease>,
+    /// How often the lease holder renews the lease, and the standby tries to acquire it.
+    /// Should be noticeably shorter than the duration of the lease.
+    pub renew_interval: Duration,
+}
+
+/// The record stored in the lease file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct LeaseRecord {
+    /// The identifier of the lease holder.
+    holder: String,
+    /// The UNIX time in milliseconds after which the lease lapses.
+    expires_at: u64,
+}
+
+/// The lease kept in a file on the filesystem shared by the producer nodes.
+///
+/// The record is read and updated while holding the exclusive OS lock of the
+/// sidecar `<path>.lock` file, so two nodes can't acquire the lapsed lease at the
+/// same time. The shared filesystem must support the file locks. The record is
+/// written to a temporary file and renamed over the lease file, so a crash never
+/// leaves a partially written record. The lease relies on the clocks of the
+/// nodes being synchronized much closer than the duration of the lease.
+#[derive(Debug, Clone)]
+pub struct FileLeaderLease {
+    path: PathBuf,
+    holder: String,
+    duration: Duration,
+}
+
+impl FileLeaderLease {
+    /// Creates the lease stored at the `path` for the node identified by the `holder`.
+    pub fn new(path: PathBuf, holder: String, duration: Duration) -> Self {
+        Self {
+            path,
+            holder,
+            duration,
+        }
+    }
+
+    /// Takes the exclusive lock of the lease, released when the returned file is dropped.
+    fn lock(&self) -> anyhow::Result<File> {
+        let mut lock_path = self.path.clone().into_os_string();
+        lock_path.push(".lock");
+        let lock_path = PathBuf::from(lock_path);
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&lock_path)
+            .with_context(|| format!("Failed to open the lease lock {lock_path:?}"))?;
+        file.lock_exclusive()
+            .with_context(|| format!("Failed to lock the lease lock {lock_path:?}"))?;
+        Ok(file)
+    }
+
+    fn read(&self) -> anyhow::Result<Option<LeaseRecord>> {
+        let bytes = match std::fs::read(&self.path) {
+            Ok(bytes) => bytes,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        // The malformed lease is treated as lapsed and will be overwritten.
+        Ok(serde_json::from_slice(&bytes).ok())
+    }
+
+    fn write(&self, record: &LeaseRecord) -> anyhow::Result<()> {
+        let mut tmp_path = self.path.clone().into_os_string();
+        tmp_path.push(format!(".{}.tmp", self.holder));
+        let tmp_path = PathBuf::from(tmp_path);
+        std::fs::write(&tmp_path, serde_json::to_vec(record)?)?;
+        std::fs::rename(&tmp_path, &self.path)?;
+        Ok(())
+    }
+
+    fn try_acquire_or_renew_at(&self, now: u64) -> anyhow::Result<bool> {
+        let _lock = self.lock()?;
+        if let Some(record) = self.read()? {
+            if record.holder != self.holder && record.expires_at > now {
+                return Ok(false)
+            }
+        }
+
+        let duration = u64::try_from(self.duration.as_millis()).unwrap_or(u64::MAX);
+        let record = LeaseRecord {
+            holder: self.holder.clone(),
+            expires_at: now.saturating_add(duration),
+        };
+        self.write(&record)
+            .with_context(|| format!("Failed to write the lease {:?}", self.path))?;
+        Ok(true)
+    }
+
+    fn release_now(&self) -> anyhow::Result<()> {
+        let _lock = self.lock()?;
+        if self
+            .read()?
+            .is_some_and(|record| record.holder == self.holder)
+        {
+            std::fs::remove_file(&self.path)?;
+        }
+        Ok(())
+    }
+}
+
+// The file system calls and waiting for the lock run on the blocking threads.
+#[async_trait::async_trait]
+impl LeaderLease for FileLeaderLease {
+    async fn try_acquire_or_renew(&self) -> anyhow::Result<bool> {
+        let lease = self.clone();
+        tokio::task::spawn_blocking(move || lease.try_acquire_or_renew_at(unix_millis()?))
+            .await?
+    }
+
+    async fn release(&self) -> anyhow::Result<()> {
+        let lease = self.clone();
+        tokio::task::spawn_blocking(move || lease.release_now()).await?
+    }
+}
+
+fn unix_millis() -> anyhow::Result<u64> {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?;
+    Ok(u64::try_from(now.as_millis())?)
+}
@@ -0,0 +1,160 @@
+#![allow(non_snake_case)]
+
+use super::*;
+
+const DURATION: Duration = Duration::from_secs(10);
+
+fn lease(dir: &tempfile::TempDir, holder: &str) -> FileLeaderLease {
+    FileLeaderLease::new(
+        dir.path().join("leader.lease"),
+        holder.to_string(),
+        DURATION,
+    )
+}
+
+#[test]
+fn try_acquire_or_renew__acquires_missing_lease() {
+    // Given
+    let dir = tempfile::tempdir().unwrap();
+    let primary = lease(&dir, "primary");
+
+    // When
+    let acquired = primary.try_acquire_or_renew_at(1_000).unwrap();
+
+    // Then
+    assert!(acquired);
+}
+
+#[test]
+fn try_acquire_or_renew__standby_cant_acquire_valid_lease() {
+    // Given
+    let dir = tempfile::tempdir().unwrap();
+    let primary = lease(&dir, "primary");
+    let standby = lease(&dir, "standby");
+    assert!(primary.try_acquire_or_renew_at(1_000).unwrap());
+
+    // When
+    let acquired = standby.try_acquire_or_renew_at(10_999).unwrap();
+
+    // Then
+    assert!(!acquired);
+}
+
+#[test]
+fn try_acquire_or_renew__standby_acquires_lapsed_lease() {
+    // Given
+    let dir = tempfile::tempdir().unwrap();
+    let primary = lease(&dir, "primary");
+    let standby = lease(&dir, "standby");
+    assert!(primary.try_acquire_or_renew_at(1_000).unwrap());
+
+    // When
+    let acquired = standby.try_acquire_or_renew_at(11_000).unwrap();
+
+    // Then
+    assert!(acquired);
+    assert!(!primary.try_acquire_or_renew_at(11_001).unwrap());
+}
+
+#[test]
+fn try_acquire_or_renew__renewal_extends_lease() {
+    // Given
+    let dir = tempfile::tempdir().unwrap();
+    let primary = lease(&dir, "primary");
+    let standby = lease(&dir, "standby");
+    assert!(primary.try_acquire_or_renew_at(1_000).unwrap());
+
+    // When
+    assert!(primary.try_acquire_or_renew_at(9_000).unwrap());
+
+    // Then
+    assert!(!standby.try_acquire_or_renew_at(11_000).unwrap());
+    assert!(standby.try_acquire_or_renew_at(19_000).unwrap());
+}
+
+#[test]
+fn release__lets_standby_acquire_lease() {
+    // Given
+    let dir = tempfile::tempdir().unwrap();
+    let primary = lease(&dir, "primary");
+    let standby = lease(&dir, "standby");
+    assert!(primary.try_acquire_or_renew_at(1_000).unwrap());
+
+    // When
+    primary.release_now().unwrap();
+
+    // Then
+    assert!(standby.try_acquire_or_renew_at(1_001).unwrap());
+}
+
+#[test]
+fn release__keeps_lease_of_another_holder() {
+    // Given
+    let dir = tempfile::tempdir().unwrap();
+    let primary = lease(&dir, "primary");
+    let standby = lease(&dir, "standby");
+    assert!(primary.try_acquire_or_renew_at(1_000).unwrap());
+
+    // When
+    standby.release_now().unwrap();
+
+    // Then
+    assert!(!standby.try_acquire_or_renew_at(1_001).unwrap());
+}
+
+#[test]
+fn try_acquire_or_renew__overwrites_malformed_lease() {
+    // Given
+    let dir = tempfile::tempdir().unwrap();
+    let primary = lease(&dir, "primary");
+    std::fs::write(dir.path().join("leader.lease"), b"garbage").unwrap();
+
+    // When
+    let acquired = primary.try_acquire_or_renew_at(1_000).unwrap();
+
+    // Then
+    assert!(acquired);
+}
+
+#[test]
+fn try_acquire_or_renew__only_one_node_acquires_lapsed_lease_concurrently() {
+    for _ in 0..50 {
+        // Given
+        let dir = tempfile::tempdir().unwrap();
+        let stale = lease(&dir, "stale");
+        assert!(stale.try_acquire_or_renew_at(1_000).unwrap());
+        let barrier = std::sync::Barrier::new(2);
+
+        // When
+        let acquired = std::thread::scope(|scope| {
+            let handles = ["primary", "standby"].map(|holder| {
+                let lease = lease(&dir, holder);
+                let barrier = &barrier;
+                scope.spawn(move || {
+                    barrier.wait();
+                    lease.try_acquire_or_renew_at(11_000).unwrap()
+                })
+            });
+            handles.map(|handle| handle.join().unwrap())
+        });
+
+        // Then
+        assert_eq!(acquired.iter().filter(|acquired| **acquired).count(), 1);
+    }
+}
+
+#[tokio::test]
+async fn release__lets_standby_acquire_lease_through_async_api() {
+    // Given
+    let dir = tempfile::tempdir().unwrap();
+    let primary = lease(&dir, "primary");
+    let standby = lease(&dir, "standby");
+    assert!(primary.try_acquire_or_renew().await.unwrap());
+    assert!(!standby.try_acquire_or_renew().await.unwrap());
+
+    // When
+    primary.release().await.unwrap();
+
+    // Then
+    assert!(standby.try_acquire_or_renew().await.unwrap());
+}
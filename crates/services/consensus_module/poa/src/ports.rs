@@ -30,6 +30,18 @@ use tokio::time::Instant;
 #[cfg_attr(test, mockall::automock)]
 pub trait TransactionPool: Send + Sync {
     fn new_txs_watcher(&self) -> tokio::sync::watch::Receiver<()>;
+
+    /// Returns the summary of the transactions waiting in the pool.
+    fn pending_transactions(&self) -> PendingTransactions;
+}
+
+/// The summary of the transactions waiting in the pool.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PendingTransactions {
+    /// The number of transactions.
+    pub tx_count: u64,
+    /// The total gas of the transactions.
+    pub total_gas: u64,
 }
 
 /// The source of transactions for the block.
@@ -68,6 +80,18 @@ pub trait BlockImporter: Send + Sync {
     fn block_stream(&self) -> BoxStream<BlockImportInfo>;
 }
 
+/// The lease electing the single block producer among several producer nodes.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait::async_trait]
+pub trait LeaderLease: core::fmt::Debug + Send + Sync {
+    /// Acquires the lease or extends it if this node already holds it.
+    /// Returns `true` if this node holds the lease after the call.
+    async fn try_acquire_or_renew(&self) -> anyhow::Result<bool>;
+
+    /// Releases the lease if this node holds it.
+    async fn release(&self) -> anyhow::Result<()>;
+}
+
 #[async_trait::async_trait]
 pub trait BlockSigner: Send + Sync {
     async fn seal_block(&self, block: &Block) -> anyhow::Result<Consensus>;
@@ -14,6 +14,7 @@ use tokio::{
 use crate::{
     Config,
     Trigger,
+    leader_lease::LeaderLeaseConfig,
     ports::{
         BlockImporter,
         BlockProducer,
@@ -31,6 +32,7 @@ use crate::{
         SyncTask,
     },
 };
+use fuel_core_chain_config::RoundRobinPoA;
 use fuel_core_services::{
     RunnableService,
     RunnableTask,
@@ -51,7 +53,10 @@ use fuel_core_types::{
         Transaction,
         TxId,
     },
-    fuel_types::BlockHeight,
+    fuel_types::{
+        Address,
+        BlockHeight,
+    },
     services::{
         Uncommitted,
         block_importer::ImportResult,
@@ -125,6 +130,7 @@ pub struct MainTask<B, I, S, PB, C, RS> {
     signer: Arc<S>,
     block_producer: B,
     block_importer: I,
+    txpool: Box<dyn TransactionPool>,
     new_txs_watcher: tokio::sync::watch::Receiver<()>,
     request_receiver: mpsc::Receiver<Request>,
     shared_state: SharedState,
@@ -139,6 +145,14 @@ pub struct MainTask<B, I, S, PB, C, RS> {
     production_timeout: Duration,
     /// externally controlled start of block production
     block_production_ready_signal: BlockProductionReadySignal<RS>,
+    /// The schedule of producers for the round-robin PoA
+    round_robin: Option<RoundRobinPoA>,
+    /// The address of the producer signing the blocks
+    producer_address: Option<Address>,
+    /// The lease electing the producer among the standby nodes
+    leader_lease: Option<LeaderLeaseConfig>,
+    /// Whether the node held the leader lease after the last renewal
+    is_leader: bool,
 }
 
 impl<B, I, S, PB, C, RS> MainTask<B, I, S, PB, C, RS>
@@ -149,7 +163,7 @@ where
     RS: WaitForReadySignal,
 {
     #[allow(clippy::too_many_arguments)]
-    pub fn new<P: P2pPort, T: TransactionPool>(
+    pub fn new<P: P2pPort, T: TransactionPool + 'static>(
         last_block: &BlockHeader,
         config: Config,
         txpool: T,
@@ -169,11 +183,14 @@ where
         let block_stream = block_importer.block_stream();
         let peer_connections_stream = p2p_port.reserved_peers_count();
 
+        let producer_address = config.signer.address().ok().flatten();
         let Config {
             min_connected_reserved_peers,
             time_until_synced,
             trigger,
             production_timeout,
+            round_robin,
+            leader_lease,
             ..
         } = config;
 
@@ -194,6 +211,7 @@ where
             signer,
             block_producer,
             block_importer,
+            txpool: Box::new(txpool),
             new_txs_watcher,
             request_receiver,
             shared_state: SharedState { request_sender },
@@ -206,6 +224,10 @@ where
             clock,
             production_timeout,
             block_production_ready_signal,
+            round_robin,
+            producer_address,
+            leader_lease,
+            is_leader: false,
         }
     }
 
@@ -229,10 +251,61 @@ where
             .expect("It should be impossible to produce more blocks than u32::MAX")
     }
 
+    /// Returns the offset of this node from the producer scheduled for the next height.
+    /// Without the round-robin PoA, the node is always the scheduled producer.
+    /// Returns `None` if the node is not part of the round-robin schedule.
+    fn producer_offset(&self) -> Option<u64> {
+        match &self.round_robin {
+            None => Some(0),
+            Some(round_robin) => self.producer_address.as_ref().and_then(|address| {
+                round_robin.producer_offset(self.next_height(), address)
+            }),
+        }
+    }
+
+    /// Returns how long after the previous block the producer
+    /// at the `offset` has to wait for its turn.
+    fn fallback_delay(&self, offset: u64) -> Duration {
+        let timeout = self
+            .round_robin
+            .as_ref()
+            .map(RoundRobinPoA::fallback_timeout_secs)
+            .unwrap_or_default();
+        Duration::from_secs(timeout.saturating_mul(offset))
+    }
+
+    /// Returns the moment when the turn of this node to produce the next block starts,
+    /// or `None` if the node doesn't produce the next block.
+    fn round_robin_turn(&self) -> anyhow::Result<Option<Instant>> {
+        let Some(offset) = self.producer_offset() else {
+            return Ok(None)
+        };
+        let turn = self
+            .last_block_created
+            .checked_add(self.fallback_delay(offset))
+            .ok_or(anyhow!("Time exceeds system limits"))?;
+        Ok(Some(turn))
+    }
+
+    /// Adjusts the `block_time` so that other nodes accept the block
+    /// as sealed during the turn of this node in the round-robin schedule.
+    fn round_robin_block_time(&self, block_time: Tai64) -> anyhow::Result<Tai64> {
+        if self.round_robin.is_none() {
+            return Ok(block_time)
+        }
+        let offset = self.producer_offset().ok_or(anyhow!(
+            "The node is not a producer of the block at height {}",
+            self.next_height()
+        ))?;
+        let earliest_time =
+            increase_time(self.last_timestamp, self.fallback_delay(offset))?;
+        Ok(block_time.max(earliest_time))
+    }
+
     fn next_time(&self, request_type: RequestType) -> anyhow::Result<Tai64> {
         match request_type {
             RequestType::Manual => match self.trigger {
-                Trigger::Never | Trigger::Instant => {
+                Trigger::Never | Trigger::Instant | Trigger::Hybrid { .. } => {
                     let duration = self.last_block_created.elapsed();
                     increase_time(self.last_timestamp, duration)
                 }
@@ -303,9 +376,11 @@ where
         &mut self,
         deadline: Instant,
     ) -> anyhow::Result<()> {
+        let block_time =
+            self.round_robin_block_time(self.next_time(RequestType::Trigger)?)?;
         self.produce_block(
             self.next_height(),
-            self.next_time(RequestType::Trigger)?,
+            block_time,
             TransactionsSource::TxPool,
             deadline,
         )
@@ -512,6 +587,63 @@ where
         None
     }
 
+    /// Acquires or renews the leader lease.
+    /// Returns `true` if the node may produce blocks.
+    async fn ensure_leadership(&mut self) -> bool {
+        let Some(leader_lease) = &self.leader_lease else {
+            return true
+        };
+        let is_leader = match leader_lease.lease.try_acquire_or_renew().await {
+            Ok(is_leader) => is_leader,
+            Err(err) => {
+                tracing::error!("Failed to renew the leader lease: {err:?}");
+                false
+            }
+        };
+        if is_leader != self.is_leader {
+            if is_leader {
+                tracing::info!("Acquired the leader lease, starting block production");
+            } else {
+                tracing::warn!("Lost the leader lease, stopping block production");
+            }
+            self.is_leader = is_leader;
+        }
+        is_leader
+    }
+
+    /// Waits for the next attempt to acquire the leader lease.
+    /// The standby node doesn't produce blocks, including manually requested ones.
+    async fn wait_as_standby(&mut self, watcher: &mut StateWatcher) -> TaskNextAction {
+        let renew_interval = self
+            .leader_lease
+            .as_ref()
+            .map(|leader_lease| leader_lease.renew_interval)
+            .unwrap_or_default();
+        tokio::select! {
+            biased;
+            _ = watcher.while_started() => {
+                TaskNextAction::Stop
+            }
+            request = self.request_receiver.recv() => {
+                match request {
+                    Some(Request::ManualBlocks((_, response))) => {
+                        let _ = response.send(Err(anyhow!(
+                            "The node doesn't hold the leader lease"
+                        )));
+                        TaskNextAction::Continue
+                    }
+                    None => {
+                        tracing::error!("The PoA task should be the holder of the `Sender`");
+                        TaskNextAction::Stop
+                    }
+                }
+            }
+            _ = tokio::time::sleep(renew_interval) => {
+                TaskNextAction::Continue
+            }
+        }
+    }
+
     async fn maybe_produce_predefined_block(&mut self) -> Option<TaskNextAction> {
         let next_height = self.next_height();
         let maybe_block = match self.predefined_blocks.get_block(&next_height) {
@@ -595,7 +727,7 @@ where
 
         match self.trigger {
             Trigger::Never | Trigger::Instant => {}
-            Trigger::Interval { .. } | Trigger::Open { .. } => {
+            Trigger::Interval { .. } | Trigger::Open { .. } | Trigger::Hybrid { .. } => {
                 return Ok(Self {
                     last_block_created: Instant::now(),
                     ..self
@@ -629,10 +761,49 @@ where
             return action;
         }
 
+        // The standby keeps following the chain through the sync task above,
+        // so it continues from the latest block once it acquires the lease.
+        if !self.ensure_leadership().await {
+            return self.wait_as_standby(watcher).await;
+        }
+
         if let Some(action) = self.maybe_produce_predefined_block().await {
             return action;
         }
 
+        let lease_renewal: BoxFuture<()> = match &self.leader_lease {
+            Some(leader_lease) => {
+                Box::pin(tokio::time::sleep(leader_lease.renew_interval))
+            }
+            None => Box::pin(core::future::pending::<()>()),
+        };
+
+        let round_robin_turn = match self.round_robin_turn() {
+            Ok(turn) => turn,
+            Err(err) => return TaskNextAction::ErrorContinue(err),
+        };
+        let round_robin = self.round_robin.is_some();
+        let block_from_other_producer: BoxFuture<()> = if round_robin {
+            let next_height = self.next_height();
+            let mut sync_state = self.sync_task_handle.shared.clone();
+            Box::pin(async move {
+                loop {
+                    let synced_height = match &*sync_state.borrow_and_update() {
+                        SyncState::Synced(header) => Some(*header.height()),
+                        SyncState::NotSynced => None,
+                    };
+                    if synced_height.is_some_and(|height| height >= next_height) {
+                        break
+                    }
+                    if sync_state.changed().await.is_err() {
+                        core::future::pending::<()>().await;
+                    }
+                }
+            })
+        } else {
+            Box::pin(core::future::pending::<()>())
+        };
+
         let next_block_production: BoxFuture<Instant> = match self.trigger {
             Trigger::Never => Box::pin(core::future::pending::<Instant>()),
             Trigger::Instant => Box::pin(async {
@@ -664,8 +835,56 @@ where
                 };
                 Box::pin(async move { deadline })
             }
+            Trigger::Hybrid { max_latency, .. } => {
+                let next_block_time = match self
+                    .last_block_created
+                    .checked_add(max_latency)
+                    .ok_or(anyhow!("Time exceeds system limits"))
+                {
+                    Ok(time) => time,
+                    Err(err) => return TaskNextAction::ErrorContinue(err),
+                };
+                let trigger = self.trigger;
+                let txpool = &self.txpool;
+                let new_txs_watcher = &mut self.new_txs_watcher;
+                Box::pin(async move {
+                    // The pool may already reach the thresholds, e.g. after the restart
+                    // or the block that didn't include all pending transactions.
+                    if trigger.thresholds_reached(&txpool.pending_transactions()) {
+                        return Instant::now()
+                    }
+                    loop {
+                        tokio::select! {
+                            _ = sleep_until(next_block_time) => break,
+                            result = new_txs_watcher.changed() => {
+                                if result.is_err() {
+                                    // The txpool is gone, only the latency is left.
+                                    sleep_until(next_block_time).await;
+                                    break
+                                }
+                                if trigger.thresholds_reached(&txpool.pending_transactions()) {
+                                    break
+                                }
+                            }
+                        }
+                    }
+                    Instant::now()
+                })
+            }
         };
 
+        // In the round-robin mode, the node waits for its turn after the trigger fires.
+        let next_block_production: BoxFuture<Instant> =
+            match (round_robin, round_robin_turn) {
+                (false, _) => next_block_production,
+                (true, None) => Box::pin(core::future::pending::<Instant>()),
+                (true, Some(turn)) => Box::pin(async move {
+                    let deadline = next_block_production.await;
+                    sleep_until(turn).await;
+                    deadline.max(turn)
+                }),
+            };
+
         tokio::select! {
             biased;
             _ = watcher.while_started() => {
@@ -674,14 +893,26 @@ where
             request = self.request_receiver.recv() => {
                 self.handle_requested_production(request).await
             }
+            // Another producer sealed the next block, so the node skips its turn.
+            _ = block_from_other_producer => {
+                TaskNextAction::Continue
+            }
             deadline = next_block_production => {
                 self.handle_normal_block_production(deadline).await
             }
+            _ = lease_renewal => {
+                TaskNextAction::Continue
+            }
         }
     }
 
     async fn shutdown(self) -> anyhow::Result<()> {
         tracing::info!("PoA MainTask shutting down");
+        if let Some(leader_lease) = &self.leader_lease {
+            if let Err(err) = leader_lease.lease.release().await {
+                tracing::error!("Failed to release the leader lease: {err:?}");
+            }
+        }
         self.sync_task_handle.stop_and_await().await?;
         Ok(())
     }
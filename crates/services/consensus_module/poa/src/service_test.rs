@@ -5,6 +5,7 @@ use crate::{
     Config,
     Service,
     Trigger,
+    leader_lease::LeaderLeaseConfig,
     new_service,
     ports::{
         BlockProducer,
@@ -13,12 +14,17 @@ use crate::{
         InMemoryPredefinedBlocks,
         MockBlockImporter,
         MockBlockProducer,
+        MockLeaderLease,
         MockP2pPort,
         MockTransactionPool,
+        PendingTransactions,
         TransactionsSource,
         WaitForReadySignal,
     },
-    service::MainTask,
+    service::{
+        MainTask,
+        Mode,
+    },
 };
 use fuel_core_chain_config::default_consensus_dev_key;
 use fuel_core_services::{
@@ -260,6 +266,9 @@ impl MockTransactionPool {
             move || sender.subscribe()
         });
         txpool
+            .expect_pending_transactions()
+            .returning(PendingTransactions::default);
+        txpool
     }
 
     pub fn new_with_txs(txs: Vec<Script>) -> TxPoolContext {
@@ -271,6 +280,16 @@ impl MockTransactionPool {
             let sender = new_txs_notifier.clone();
             move || sender.subscribe()
         });
+        txpool.expect_pending_transactions().returning({
+            let txs = txs.clone();
+            move || {
+                let txs = txs.lock().unwrap();
+                PendingTransactions {
+                    tx_count: txs.len() as u64,
+                    total_gas: txs.iter().map(|tx| *tx.script_gas_limit()).sum(),
+                }
+            }
+        });
 
         TxPoolContext {
             txpool,
@@ -619,3 +638,120 @@ async fn consensus_service__run__will_produce_blocks_with_ready_signal() {
     let produced_block = block_receiver.recv().await.unwrap();
     assert!(matches!(produced_block, FakeProducedBlock::New(_, _)));
 }
+
+fn leader_lease_task(
+    lease: MockLeaderLease,
+    block_producer: FakeBlockProducer,
+) -> MainTask<
+    FakeBlockProducer,
+    MockBlockImporter,
+    FakeBlockSigner,
+    InMemoryPredefinedBlocks,
+    test_time::Watch,
+    FakeBlockProductionReadySignal,
+> {
+    let last_block = BlockHeader::new_block(BlockHeight::from(1u32), Tai64::now());
+    let config = Config {
+        trigger: Trigger::Interval {
+            block_time: Duration::from_millis(10),
+        },
+        signer: SignMode::Key(test_signing_key()),
+        metrics: false,
+        leader_lease: Some(LeaderLeaseConfig {
+            lease: Arc::new(lease),
+            renew_interval: Duration::from_millis(10),
+        }),
+        ..Default::default()
+    };
+    let mut block_importer = MockBlockImporter::default();
+    block_importer.expect_commit_result().returning(|_| Ok(()));
+    block_importer
+        .expect_block_stream()
+        .returning(|| Box::pin(tokio_stream::pending()));
+    let mut rng = StdRng::seed_from_u64(0);
+    let tx = make_tx(&mut rng);
+    let TxPoolContext { txpool, .. } = MockTransactionPool::new_with_txs(vec![tx]);
+    let time = TestTime::at_unix_epoch();
+
+    MainTask::new(
+        &last_block,
+        config,
+        txpool,
+        block_producer,
+        block_importer,
+        generate_p2p_port(),
+        FakeBlockSigner { succeeds: true }.into(),
+        InMemoryPredefinedBlocks::new(HashMap::new()),
+        time.watch(),
+        FakeBlockProductionReadySignal,
+    )
+}
+
+#[tokio::test]
+async fn consensus_service__run__standby_doesnt_produce_blocks_without_leader_lease() {
+    // given
+    let (block_producer, mut block_receiver) = FakeBlockProducer::new();
+    let mut lease = MockLeaderLease::default();
+    lease.expect_try_acquire_or_renew().returning(|| Ok(false));
+    lease.expect_release().returning(|| Ok(()));
+    let task = leader_lease_task(lease, block_producer);
+
+    // when
+    let service = ServiceRunner::new(task);
+    service.start_and_await().await.unwrap();
+
+    // then
+    time::sleep(Duration::from_millis(200)).await;
+    assert!(block_receiver.try_recv().is_err());
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn consensus_service__run__standby_takes_over_when_leader_lease_lapses() {
+    // given
+    let (block_producer, mut block_receiver) = FakeBlockProducer::new();
+    let attempts = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+    let mut lease = MockLeaderLease::default();
+    lease.expect_try_acquire_or_renew().returning(move || {
+        // The lease of the primary lapses after a few renewal attempts.
+        let attempt = attempts.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        Ok(attempt >= 3)
+    });
+    lease.expect_release().returning(|| Ok(()));
+    let task = leader_lease_task(lease, block_producer);
+
+    // when
+    let service = ServiceRunner::new(task);
+    service.start_and_await().await.unwrap();
+
+    // then
+    let produced_block = block_receiver.recv().await.unwrap();
+    assert!(matches!(produced_block, FakeProducedBlock::New(_, _)));
+}
+
+#[tokio::test]
+async fn consensus_service__run__standby_rejects_manual_block_production() {
+    // given
+    let (block_producer, _block_receiver) = FakeBlockProducer::new();
+    let mut lease = MockLeaderLease::default();
+    lease.expect_try_acquire_or_renew().returning(|| Ok(false));
+    lease.expect_release().returning(|| Ok(()));
+    let task = leader_lease_task(lease, block_producer);
+    let service = ServiceRunner::new(task);
+    service.start_and_await().await.unwrap();
+
+    // when
+    let result = service
+        .shared
+        .manually_produce_block(
+            None,
+            Mode::Blocks {
+                number_of_blocks: 1,
+            },
+        )
+        .await;
+
+    // then
+    assert!(result.is_err());
+    service.stop_and_await().await.unwrap();
+}
@@ -1,3 +1,4 @@
+use fuel_core_chain_config::RoundRobinPoA;
 use mockall::Sequence;
 use tokio::{
     sync::Notify,
@@ -14,6 +15,11 @@ async fn clean_startup_shutdown_each_trigger() -> anyhow::Result<()> {
         Trigger::Interval {
             block_time: Duration::new(1, 0),
         },
+        Trigger::Hybrid {
+            max_latency: Duration::new(1, 0),
+            gas_threshold: Some(1),
+            tx_count_threshold: Some(1),
+        },
     ] {
         let mut ctx_builder = TestContextBuilder::new();
         ctx_builder.with_config(Config {
@@ -583,3 +589,246 @@ async fn open_trigger__produce_blocks_with_correct_time() {
     assert_eq!(first_block_time.0, expected_first_block_time);
     assert_eq!(second_block_time.0, expected_second_block_time);
 }
+
+#[tokio::test]
+async fn hybrid_trigger__produces_block_when_tx_count_threshold_is_reached() {
+    // Given
+    let mut ctx = DefaultContext::new(Config {
+        trigger: Trigger::Hybrid {
+            max_latency: Duration::from_secs(10),
+            gas_threshold: None,
+            tx_count_threshold: Some(5),
+        },
+        signer: SignMode::Key(test_signing_key()),
+        metrics: false,
+        ..Default::default()
+    })
+    .await;
+    {
+        let mut guard = ctx.txs.lock().unwrap();
+        for _ in 0..3 {
+            guard.push(make_tx(&mut ctx.rng));
+        }
+    }
+    ctx.new_txs_notifier.send_replace(());
+    time::sleep(Duration::from_secs(1)).await;
+    assert!(matches!(
+        ctx.block_import.try_recv(),
+        Err(broadcast::error::TryRecvError::Empty)
+    ));
+
+    // When
+    ctx.txs.lock().unwrap().push(make_tx(&mut ctx.rng));
+    ctx.new_txs_notifier.send_replace(());
+    time::sleep(Duration::from_millis(1)).await;
+
+    // Then
+    assert!(ctx.block_import.try_recv().is_ok());
+    assert!(matches!(
+        ctx.block_import.try_recv(),
+        Err(broadcast::error::TryRecvError::Empty)
+    ));
+}
+
+#[tokio::test]
+async fn hybrid_trigger__produces_block_when_gas_threshold_is_reached() {
+    // Given
+    let mut rng = StdRng::seed_from_u64(1234u64);
+    // `DefaultContext` starts with the same first transaction.
+    let first_tx_gas = *make_tx(&mut rng).script_gas_limit();
+    let mut ctx = DefaultContext::new(Config {
+        trigger: Trigger::Hybrid {
+            max_latency: Duration::from_secs(10),
+            gas_threshold: Some(first_tx_gas + 1),
+            tx_count_threshold: None,
+        },
+        signer: SignMode::Key(test_signing_key()),
+        metrics: false,
+        ..Default::default()
+    })
+    .await;
+    ctx.new_txs_notifier.send_replace(());
+    time::sleep(Duration::from_secs(1)).await;
+    assert!(matches!(
+        ctx.block_import.try_recv(),
+        Err(broadcast::error::TryRecvError::Empty)
+    ));
+
+    // When
+    ctx.txs.lock().unwrap().push(make_tx(&mut ctx.rng));
+    ctx.new_txs_notifier.send_replace(());
+    time::sleep(Duration::from_millis(1)).await;
+
+    // Then
+    assert!(ctx.block_import.try_recv().is_ok());
+}
+
+#[tokio::test]
+async fn hybrid_trigger__produces_block_at_startup_when_pool_reaches_threshold() {
+    // Given
+    let mut rng = StdRng::seed_from_u64(1234u64);
+    let mut ctx_builder = TestContextBuilder::new();
+    ctx_builder.with_config(Config {
+        trigger: Trigger::Hybrid {
+            max_latency: Duration::from_secs(10),
+            gas_threshold: None,
+            tx_count_threshold: Some(3),
+        },
+        signer: SignMode::Key(test_signing_key()),
+        metrics: false,
+        ..Default::default()
+    });
+    let pre_filled_txs = (0..3).map(|_| make_tx(&mut rng)).collect();
+    let TxPoolContext {
+        txpool,
+        new_txs_notifier: _new_txs_notifier,
+        txs,
+    } = MockTransactionPool::new_with_txs(pre_filled_txs);
+    ctx_builder.with_txpool(txpool);
+
+    let (block_import_sender, mut block_import) = broadcast::channel(100);
+    let mut importer = MockBlockImporter::default();
+    importer.expect_commit_result().returning(move |result| {
+        let (result, _) = result.into();
+        block_import_sender.send(result.sealed_block)?;
+        Ok(())
+    });
+    importer
+        .expect_block_stream()
+        .returning(|| Box::pin(tokio_stream::pending()));
+    ctx_builder.with_importer(importer);
+
+    let mut block_producer = MockBlockProducer::default();
+    block_producer
+        .expect_produce_and_execute_block()
+        .returning(move |_, time, _, _| {
+            // The produced block includes all pending transactions.
+            txs.lock().unwrap().clear();
+            let mut block = Block::default();
+            block.header_mut().set_time(time);
+            block.header_mut().recalculate_metadata();
+            Ok(UncommittedResult::new(
+                ExecutionResult {
+                    block,
+                    ..Default::default()
+                },
+                Default::default(),
+            ))
+        });
+    ctx_builder.with_producer(block_producer);
+
+    // When
+    let ctx = ctx_builder.build().await;
+    time::sleep(Duration::from_millis(100)).await;
+
+    // Then
+    assert!(block_import.try_recv().is_ok());
+    assert!(matches!(
+        block_import.try_recv(),
+        Err(broadcast::error::TryRecvError::Empty)
+    ));
+
+    // Stop
+    assert_eq!(ctx.stop().await, State::Stopped);
+}
+
+#[tokio::test]
+async fn hybrid_trigger__produces_block_after_max_latency_without_enough_txs() {
+    // Given
+    let max_latency = Duration::from_secs(2);
+    let offset = Duration::from_millis(500);
+    let mut ctx = DefaultContext::new(Config {
+        trigger: Trigger::Hybrid {
+            max_latency,
+            gas_threshold: Some(u64::MAX),
+            tx_count_threshold: Some(1_000),
+        },
+        signer: SignMode::Key(test_signing_key()),
+        metrics: false,
+        ..Default::default()
+    })
+    .await;
+    time::sleep(offset).await;
+
+    for _ in 0..5 {
+        // When
+        ctx.new_txs_notifier.send_replace(());
+        time::sleep(max_latency / 2).await;
+        let before_latency = ctx.block_import.try_recv();
+        time::sleep(max_latency / 2).await;
+        let after_latency = ctx.block_import.try_recv();
+
+        // Then
+        assert!(before_latency.is_err());
+        assert!(after_latency.is_ok());
+    }
+
+    // Stop
+    assert_eq!(ctx.test_ctx.stop().await, State::Stopped);
+}
+
+#[tokio::test]
+async fn round_robin__next_producer_takes_over_after_fallback_timeout()
+-> anyhow::Result<()> {
+    // Given
+    let signer = SignMode::Key(test_signing_key());
+    let own_address = signer.address()?.expect("The signer has a key");
+    let scheduled_producer = Address::from([1; 32]);
+    // The next height is `2`, so the first producer is scheduled and this node is next.
+    let round_robin = RoundRobinPoA::new(vec![scheduled_producer, own_address], 5);
+    let mut ctx = DefaultContext::new(Config {
+        trigger: Trigger::Interval {
+            block_time: Duration::new(2, 0),
+        },
+        signer,
+        metrics: false,
+        round_robin: Some(round_robin),
+        ..Default::default()
+    })
+    .await;
+    let start_time = ctx.now();
+
+    // When
+    time::sleep(Duration::new(3, 0)).await;
+
+    // Then
+    assert!(matches!(
+        ctx.block_import.try_recv(),
+        Err(broadcast::error::TryRecvError::Empty)
+    ));
+    time::sleep(Duration::new(3, 0)).await;
+    let block = ctx.block_import.try_recv()?;
+    assert_eq!(block.entity.header().time(), Tai64(start_time.0 + 5));
+
+    ctx.test_ctx.service.stop_and_await().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn round_robin__node_outside_of_producers_set_never_produces_blocks()
+-> anyhow::Result<()> {
+    // Given
+    let round_robin = RoundRobinPoA::new(vec![Address::from([1; 32])], 1);
+    let mut ctx = DefaultContext::new(Config {
+        trigger: Trigger::Interval {
+            block_time: Duration::new(2, 0),
+        },
+        signer: SignMode::Key(test_signing_key()),
+        metrics: false,
+        round_robin: Some(round_robin),
+        ..Default::default()
+    })
+    .await;
+
+    // When
+    time::sleep(Duration::new(10, 0)).await;
+
+    // Then
+    assert!(matches!(
+        ctx.block_import.try_recv(),
+        Err(broadcast::error::TryRecvError::Empty)
+    ));
+
+    ctx.test_ctx.service.stop_and_await().await?;
+    Ok(())
+}
@@ -1,6 +1,12 @@
 use crate::ports::Database;
-use anyhow::ensure;
-use fuel_core_chain_config::ConsensusConfig;
+use anyhow::{
+    anyhow,
+    ensure,
+};
+use fuel_core_chain_config::{
+    ConsensusConfig,
+    RoundRobinPoA,
+};
 use fuel_core_types::{
     blockchain::{
         block::Block,
@@ -8,11 +14,17 @@ use fuel_core_types::{
         header::BlockHeader,
     },
     fuel_tx::Input,
+    tai64::Tai64,
 };
 
 #[cfg(test)]
 mod tests;
 
+/// The number of seconds the time of the round-robin block may be ahead of the local clock.
+/// The schedule depends on the block time, so the producer could take the slot
+/// of another producer by dating the block in the future.
+pub const MAX_BLOCK_TIME_DRIFT_SECS: u64 = 2;
+
 // TODO: Make this function `async` and await the synchronization with the relayer.
 pub fn verify_consensus(
     consensus_config: &ConsensusConfig,
@@ -37,9 +49,60 @@ pub fn verify_consensus(
                 .recover(m)
                 .is_ok_and(|k| Input::owner(&k) == signing_key)
         }
+        ConsensusConfig::RoundRobin(poa) => {
+            // Only the membership in the producer set is checked here, because
+            // the previous block may be unknown yet. The schedule is enforced by
+            // the `verify_producer_schedule` during the import of the block.
+            let id = header.id();
+            let m = id.as_message();
+            consensus
+                .signature
+                .recover(m)
+                .is_ok_and(|k| poa.contains(&Input::owner(&k)))
+        }
     }
 }
 
+/// Verifies that the block was sealed by the producer allowed
+/// to do it according to the round-robin schedule.
+/// The block time can't be ahead of the `now` by more than [`MAX_BLOCK_TIME_DRIFT_SECS`].
+pub fn verify_producer_schedule<D: Database>(
+    database: &D,
+    round_robin: &RoundRobinPoA,
+    header: &BlockHeader,
+    consensus: &PoAConsensus,
+    now: Tai64,
+) -> anyhow::Result<()> {
+    ensure!(
+        header.time().0 <= now.0.saturating_add(MAX_BLOCK_TIME_DRIFT_SECS),
+        "The block time {} is ahead of the local time {}",
+        header.time().0,
+        now.0
+    );
+
+    let height = *header.height();
+    let prev_height = height
+        .pred()
+        .ok_or_else(|| anyhow!("The PoA block can't have the zero height"))?;
+    let prev_header = database.block_header(&prev_height)?;
+    let elapsed_secs = header.time().0.saturating_sub(prev_header.time().0);
+
+    let id = header.id();
+    let producer = consensus
+        .signature
+        .recover(id.as_message())
+        .map(|k| Input::owner(&k))
+        .map_err(|e| anyhow!("Failed to recover the block producer: {e:?}"))?;
+
+    ensure!(
+        round_robin.is_allowed_to_produce(height, &producer, elapsed_secs),
+        "The producer {producer} is not allowed to seal the block at height {height} \
+        {elapsed_secs} seconds after the previous block"
+    );
+
+    Ok(())
+}
+
 pub fn verify_block_fields<D: Database>(
     database: &D,
     block: &Block,
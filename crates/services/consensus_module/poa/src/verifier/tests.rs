@@ -7,9 +7,17 @@ use fuel_core_types::{
         ConsensusHeader,
         PartialBlockHeader,
     },
+    fuel_crypto::{
+        SecretKey,
+        Signature,
+    },
     fuel_tx::Transaction,
     tai64::Tai64,
 };
+use rand::{
+    SeedableRng,
+    rngs::StdRng,
+};
 use test_case::test_case;
 
 struct Input {
@@ -129,3 +137,98 @@ fn test_verify_genesis_block_fields(input: Input) -> anyhow::Result<()> {
     *b.transactions_mut() = txs;
     verify_block_fields(&d, &b)
 }
+
+fn round_robin_producers() -> Vec<SecretKey> {
+    let mut rng = StdRng::seed_from_u64(2322);
+    (0..3).map(|_| SecretKey::random(&mut rng)).collect()
+}
+
+fn round_robin_config(
+    producers: &[SecretKey],
+    fallback_timeout_secs: u64,
+) -> RoundRobinPoA {
+    let producers = producers
+        .iter()
+        .map(|key| fuel_core_types::fuel_tx::Input::owner(&key.public_key()))
+        .collect();
+    RoundRobinPoA::new(producers, fallback_timeout_secs)
+}
+
+fn sealed_header(height: u32, time: u64, key: &SecretKey) -> (BlockHeader, PoAConsensus) {
+    let mut header = BlockHeader::default();
+    header.set_block_height(height.into());
+    header.set_time(Tai64(time));
+    let signature = Signature::sign(key, header.id().as_message());
+    (header, PoAConsensus::new(signature))
+}
+
+fn round_robin_database(prev_header_time: u64) -> MockDatabase {
+    let mut d = MockDatabase::default();
+    d.expect_block_header().returning(move |_| {
+        let mut h = BlockHeader::default();
+        h.set_time(Tai64(prev_header_time));
+        Ok(h)
+    });
+    d
+}
+
+#[test_case(3, 0, 10 => matches Ok(_) ; "scheduled producer seals in time")]
+#[test_case(3, 0, 1_000 => matches Ok(_) ; "scheduled producer seals late")]
+#[test_case(3, 1, 14 => matches Err(_) ; "next producer seals before the timeout")]
+#[test_case(3, 1, 15 => matches Ok(_) ; "next producer seals after the timeout")]
+#[test_case(3, 2, 15 => matches Err(_) ; "second next producer seals after one timeout")]
+#[test_case(3, 2, 20 => matches Ok(_) ; "second next producer seals after two timeouts")]
+#[test_case(4, 0, 15 => matches Err(_) ; "previous producer can't take back the slot")]
+#[test_case(0, 0, 10 => matches Err(_) ; "zero height")]
+#[allow(non_snake_case)]
+fn verify_producer_schedule__enforces_round_robin(
+    height: u32,
+    producer_index: usize,
+    block_time: u64,
+) -> anyhow::Result<()> {
+    // Given
+    let keys = round_robin_producers();
+    let round_robin = round_robin_config(&keys, 5);
+    let database = round_robin_database(10);
+    let (header, consensus) = sealed_header(height, block_time, &keys[producer_index]);
+
+    // When
+    verify_producer_schedule(&database, &round_robin, &header, &consensus, Tai64(1_000))
+}
+
+#[test]
+#[allow(non_snake_case)]
+fn verify_producer_schedule__rejects_future_block_of_not_scheduled_producer() {
+    // Given
+    let keys = round_robin_producers();
+    let round_robin = round_robin_config(&keys, 5);
+    let database = round_robin_database(10);
+    // The next producer may seal the block only 5 seconds after the previous one.
+    let (header, consensus) = sealed_header(3, 15, &keys[1]);
+
+    // When
+    let before_block_time =
+        verify_producer_schedule(&database, &round_robin, &header, &consensus, Tai64(11));
+    let at_block_time =
+        verify_producer_schedule(&database, &round_robin, &header, &consensus, Tai64(15));
+
+    // Then
+    assert!(before_block_time.is_err());
+    assert!(at_block_time.is_ok());
+}
+
+#[test]
+#[allow(non_snake_case)]
+fn verify_consensus__round_robin_accepts_only_producers_from_the_set() {
+    // Given
+    let keys = round_robin_producers();
+    let round_robin = round_robin_config(&keys, 5);
+    let config = ConsensusConfig::RoundRobin(round_robin);
+    let outsider = SecretKey::random(&mut StdRng::seed_from_u64(1));
+    let (header, by_producer) = sealed_header(5, 10, &keys[1]);
+    let (_, by_outsider) = sealed_header(5, 10, &outsider);
+
+    // When/Then
+    assert!(verify_consensus(&config, &header, &by_producer));
+    assert!(!verify_consensus(&config, &header, &by_outsider));
+}
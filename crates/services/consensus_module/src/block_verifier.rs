@@ -3,6 +3,7 @@
 
 use crate::block_verifier::config::Config;
 use anyhow::ensure;
+use fuel_core_chain_config::ConsensusConfig;
 use fuel_core_poa::ports::Database as PoAVerifierDatabase;
 use fuel_core_storage::transactional::AtomicView;
 use fuel_core_types::{
@@ -64,9 +65,19 @@ where
                     block.header(),
                 )
             }
-            Consensus::PoA(_) => {
+            Consensus::PoA(consensus) => {
                 let view = self.view_provider.latest_view()?;
-                fuel_core_poa::verifier::verify_block_fields(&view, block)
+                fuel_core_poa::verifier::verify_block_fields(&view, block)?;
+                if let ConsensusConfig::RoundRobin(round_robin) = &self.config.consensus {
+                    fuel_core_poa::verifier::verify_producer_schedule(
+                        &view,
+                        round_robin,
+                        block.header(),
+                        consensus,
+                        Tai64::now(),
+                    )?;
+                }
+                Ok(())
             }
             _ => Err(anyhow::anyhow!("Unsupported consensus: {:?}", consensus)),
         }
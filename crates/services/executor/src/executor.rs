@@ -329,6 +329,59 @@ impl ExecutionData {
             event_inbox_root: Default::default(),
         }
     }
+
+    /// Returns the storage changes accumulated during the execution.
+    pub fn changes(&self) -> &Changes {
+        &self.changes
+    }
+
+    /// Returns the gas used by the executed transactions.
+    pub fn used_gas(&self) -> u64 {
+        self.used_gas
+    }
+
+    /// Returns the size of the executed transactions.
+    pub fn used_size(&self) -> u32 {
+        self.used_size
+    }
+
+    /// Returns the number of the executed transactions.
+    pub fn tx_count(&self) -> u16 {
+        self.tx_count
+    }
+}
+
+/// The transaction executed by the
+/// [`ExecutionInstance::execute_transactions_without_commit`].
+#[derive(Debug)]
+pub struct ExecutedTransaction {
+    /// The index of the transaction inside of the block.
+    pub index: u16,
+    /// The transaction with the inputs and outputs updated after the execution.
+    pub transaction: Transaction,
+    /// The status of the execution.
+    pub status: TransactionExecutionStatus,
+    /// The events emitted during the execution of the transaction.
+    pub events: Vec<ExecutorEvent>,
+    /// The identifiers of the messages sent by the transaction.
+    pub message_ids: Vec<MessageId>,
+    /// The fee paid by the transaction.
+    pub fee: u64,
+    /// The gas used by the transaction.
+    pub used_gas: u64,
+    /// The metered size of the transaction.
+    pub used_size: u32,
+}
+
+/// The result of the execution of the subset of the block's transactions.
+#[derive(Debug, Default)]
+pub struct ExecutedTransactions {
+    /// Executed transactions in the order of execution.
+    pub transactions: Vec<ExecutedTransaction>,
+    /// Transactions that were skipped during the execution.
+    pub skipped_transactions: Vec<(TxId, ExecutorError)>,
+    /// The storage changes done by the executed transactions.
+    pub changes: Changes,
 }
 
 /// Per-block execution options.
@@ -402,43 +455,12 @@ where
         let (partial_block, execution_data) =
             block_executor.execute(components, storage_tx).await?;
 
-        let ExecutionData {
-            message_ids,
-            event_inbox_root,
-            changes,
-            events,
-            tx_status,
-            skipped_transactions,
-            coinbase,
-            used_gas,
-            used_size,
-            ..
-        } = execution_data;
-
-        let block = partial_block
-            .generate(
-                &message_ids[..],
-                event_inbox_root,
-                #[cfg(feature = "fault-proving")]
-                &chain_id,
-            )
-            .map_err(ExecutorError::BlockHeaderError)?;
-
-        let finalized_block_id = block.id();
-
-        debug!(
-            "Block {:#x} fees: {} gas: {} tx_size: {}",
-            finalized_block_id, coinbase, used_gas, used_size
-        );
-
-        let result = ExecutionResult {
-            block,
-            skipped_transactions,
-            tx_status,
-            events,
-        };
-
-        Ok(UncommittedResult::new(result, changes))
+        into_execution_result(
+            partial_block,
+            execution_data,
+            #[cfg(feature = "fault-proving")]
+            &chain_id,
+        )
     }
 
     pub fn validate_without_commit(
@@ -453,26 +475,148 @@ where
             false,
         )?;
 
-        let ExecutionData {
-            coinbase,
-            used_gas,
-            used_size,
-            tx_status,
-            events,
-            changes,
-            ..
-        } = block_executor.validate_block(block, storage_tx)?;
+        let execution_data = block_executor.validate_block(block, storage_tx)?;
 
-        let finalized_block_id = block.id();
+        into_validation_result(block, execution_data)
+    }
 
-        debug!(
-            "Block {:#x} fees: {} gas: {} tx_size: {}",
-            finalized_block_id, coinbase, used_gas, used_size
-        );
+    /// Processes the relayed part of the block: imports messages
+    /// and executes forced transactions from the DA layer.
+    ///
+    /// It is the first step of the execution of the block in parts.
+    /// The remaining transactions can be executed by the
+    /// [`Self::execute_transactions_without_commit`], and the block is finished by
+    /// [`Self::produce_from_parts_without_commit`] or
+    /// [`Self::validate_from_parts_without_commit`].
+    pub fn process_l1_without_commit(
+        self,
+        header: PartialBlockHeader,
+        coinbase_contract_id: ContractId,
+    ) -> ExecutorResult<(PartialFuelBlock, ExecutionData)> {
+        let (mut block_executor, mut storage_tx) = self.into_executor(
+            header.consensus_parameters_version,
+            TimeoutOnlyTxWaiter,
+            TransparentPreconfirmationSender,
+            false,
+        )?;
+
+        let mut partial_block = PartialFuelBlock::new(header, vec![]);
+        let mut data = ExecutionData::new();
+        block_executor.process_l1_txs(
+            &mut partial_block,
+            coinbase_contract_id,
+            &mut storage_tx,
+            &mut data,
+            &mut MemoryInstance::new(),
+        )?;
+
+        data.changes = storage_tx.into_changes();
+        Ok((partial_block, data))
+    }
+
+    /// Executes `transactions` at the specified indexes inside of the block
+    /// on top of the database.
+    ///
+    /// If `skip_invalid` is `true`, invalid transactions are reported in the
+    /// `skipped_transactions`(like during block production), otherwise the first
+    /// invalid transaction fails the execution(like during block validation).
+    pub fn execute_transactions_without_commit(
+        self,
+        header: &PartialBlockHeader,
+        transactions: Vec<(u16, MaybeCheckedTransaction)>,
+        coinbase_contract_id: ContractId,
+        gas_price: Word,
+        skip_invalid: bool,
+    ) -> ExecutorResult<ExecutedTransactions> {
+        let (block_executor, storage_tx) = self.into_executor(
+            header.consensus_parameters_version,
+            TimeoutOnlyTxWaiter,
+            TransparentPreconfirmationSender,
+            false,
+        )?;
+
+        block_executor.execute_transactions(
+            *header,
+            transactions,
+            coinbase_contract_id,
+            gas_price,
+            skip_invalid,
+            storage_tx,
+        )
+    }
 
-        let result = ValidationResult { tx_status, events };
+    /// Finishes the production of the block executed in parts.
+    ///
+    /// The `partial_block` and `data` are the result of the
+    /// [`Self::process_l1_without_commit`]. The `executed` transactions
+    /// must be sorted by index and follow the relayed transactions without gaps.
+    /// The `Mint` transaction is produced on top of the changes of all parts,
+    /// and the returned changes contain modifications of the whole block.
+    pub fn produce_from_parts_without_commit(
+        self,
+        partial_block: PartialFuelBlock,
+        data: ExecutionData,
+        executed: ExecutedTransactions,
+        coinbase_contract_id: ContractId,
+        gas_price: Word,
+    ) -> ExecutorResult<UncommittedResult<Changes>> {
+        let (block_executor, storage_tx) = self.into_executor(
+            partial_block.header.consensus_parameters_version,
+            TimeoutOnlyTxWaiter,
+            TransparentPreconfirmationSender,
+            false,
+        )?;
 
-        Ok(UncommittedValidationResult::new(result, changes))
+        #[cfg(feature = "fault-proving")]
+        let chain_id = block_executor.consensus_params.chain_id();
+
+        let (partial_block, data) = block_executor.produce_from_parts(
+            partial_block,
+            data,
+            executed,
+            coinbase_contract_id,
+            gas_price,
+            storage_tx,
+        )?;
+
+        into_execution_result(
+            partial_block,
+            data,
+            #[cfg(feature = "fault-proving")]
+            &chain_id,
+        )
+    }
+
+    /// Finishes the validation of the `block` executed in parts.
+    ///
+    /// The `partial_block` and `data` are the result of the
+    /// [`Self::process_l1_without_commit`]. The `executed` transactions
+    /// must be sorted by index and follow the relayed transactions without gaps.
+    /// The `Mint` transaction of the `block` is executed on top of the changes of all parts,
+    /// and the returned changes contain modifications of the whole block.
+    pub fn validate_from_parts_without_commit(
+        self,
+        block: &Block,
+        partial_block: PartialFuelBlock,
+        data: ExecutionData,
+        executed: ExecutedTransactions,
+    ) -> ExecutorResult<UncommittedValidationResult<Changes>> {
+        let (block_executor, storage_tx) = self.into_executor(
+            block.header().consensus_parameters_version(),
+            TimeoutOnlyTxWaiter,
+            TransparentPreconfirmationSender,
+            false,
+        )?;
+
+        let data = block_executor.validate_from_parts(
+            block,
+            partial_block,
+            data,
+            executed,
+            storage_tx,
+        )?;
+
+        into_validation_result(block, data)
     }
 
     fn into_executor<N, P>(
@@ -505,6 +649,79 @@ where
     }
 }
 
+/// Generates the final block from the `partial_block` and the data
+/// accumulated during its execution.
+fn into_execution_result(
+    partial_block: PartialFuelBlock,
+    execution_data: ExecutionData,
+    #[cfg(feature = "fault-proving")] chain_id: &fuel_core_types::fuel_types::ChainId,
+) -> ExecutorResult<UncommittedResult<Changes>> {
+    let ExecutionData {
+        message_ids,
+        event_inbox_root,
+        changes,
+        events,
+        tx_status,
+        skipped_transactions,
+        coinbase,
+        used_gas,
+        used_size,
+        ..
+    } = execution_data;
+
+    let block = partial_block
+        .generate(
+            &message_ids[..],
+            event_inbox_root,
+            #[cfg(feature = "fault-proving")]
+            chain_id,
+        )
+        .map_err(ExecutorError::BlockHeaderError)?;
+
+    let finalized_block_id = block.id();
+
+    debug!(
+        "Block {:#x} fees: {} gas: {} tx_size: {}",
+        finalized_block_id, coinbase, used_gas, used_size
+    );
+
+    let result = ExecutionResult {
+        block,
+        skipped_transactions,
+        tx_status,
+        events,
+    };
+
+    Ok(UncommittedResult::new(result, changes))
+}
+
+/// Converts the data accumulated during the validation of the `block` into the result.
+fn into_validation_result(
+    block: &Block,
+    execution_data: ExecutionData,
+) -> ExecutorResult<UncommittedValidationResult<Changes>> {
+    let ExecutionData {
+        coinbase,
+        used_gas,
+        used_size,
+        tx_status,
+        events,
+        changes,
+        ..
+    } = execution_data;
+
+    let finalized_block_id = block.id();
+
+    debug!(
+        "Block {:#x} fees: {} gas: {} tx_size: {}",
+        finalized_block_id, coinbase, used_gas, used_size
+    );
+
+    let result = ValidationResult { tx_status, events };
+
+    Ok(UncommittedValidationResult::new(result, changes))
+}
+
 type BlockStorageTransaction<T> = StorageTransaction<T>;
 type TxStorageTransaction<'a, T> = StorageTransaction<&'a mut BlockStorageTransaction<T>>;
 
@@ -854,6 +1071,226 @@ where
         Ok(())
     }
 
+    #[tracing::instrument(skip_all)]
+    fn execute_transactions<D>(
+        self,
+        header: PartialBlockHeader,
+        transactions: Vec<(u16, MaybeCheckedTransaction)>,
+        coinbase_contract_id: ContractId,
+        gas_price: Word,
+        skip_invalid: bool,
+        mut block_storage_tx: BlockStorageTransaction<D>,
+    ) -> ExecutorResult<ExecutedTransactions>
+    where
+        D: KeyValueInspect<Column = Column>,
+    {
+        let mut partial_block = PartialFuelBlock::new(header, vec![]);
+        let mut data = ExecutionData::new();
+        let mut memory = MemoryInstance::new();
+        let mut executed = Vec::with_capacity(transactions.len());
+
+        for (index, transaction) in transactions {
+            let tx_id = transaction.id(&self.consensus_params.chain_id());
+            data.tx_count = index;
+            let coinbase_before = data.coinbase;
+            let used_gas_before = data.used_gas;
+            let used_size_before = data.used_size;
+            let events_before = data.events.len();
+            let message_ids_before = data.message_ids.len();
+
+            let result = self.execute_transaction_and_commit(
+                &mut partial_block,
+                &mut block_storage_tx,
+                &mut data,
+                transaction,
+                gas_price,
+                coinbase_contract_id,
+                &mut memory,
+            );
+
+            match result {
+                Ok(()) => {
+                    let transaction = partial_block
+                        .transactions
+                        .pop()
+                        .expect("Shouldn't happens as we just added a transaction; qed");
+                    let status = data
+                        .tx_status
+                        .pop()
+                        .expect("Shouldn't happens as we just added a transaction; qed");
+                    executed.push(ExecutedTransaction {
+                        index,
+                        transaction,
+                        status,
+                        events: data.events.split_off(events_before),
+                        message_ids: data.message_ids.split_off(message_ids_before),
+                        fee: data.coinbase.saturating_sub(coinbase_before),
+                        used_gas: data.used_gas.saturating_sub(used_gas_before),
+                        used_size: data.used_size.saturating_sub(used_size_before),
+                    });
+                }
+                Err(err) => {
+                    if skip_invalid {
+                        data.skipped_transactions.push((tx_id, err));
+                    } else {
+                        return Err(err)
+                    }
+                }
+            }
+        }
+
+        Ok(ExecutedTransactions {
+            transactions: executed,
+            skipped_transactions: data.skipped_transactions,
+            changes: block_storage_tx.into_changes(),
+        })
+    }
+
+    fn produce_from_parts<D>(
+        &self,
+        mut partial_block: PartialFuelBlock,
+        mut data: ExecutionData,
+        executed: ExecutedTransactions,
+        coinbase_contract_id: ContractId,
+        gas_price: Word,
+        block_storage_tx: BlockStorageTransaction<D>,
+    ) -> ExecutorResult<(PartialFuelBlock, ExecutionData)>
+    where
+        D: KeyValueInspect<Column = Column>,
+    {
+        let mut block_storage_tx =
+            block_storage_tx.with_changes(core::mem::take(&mut data.changes));
+        Self::append_executed_transactions(
+            &mut partial_block,
+            &mut data,
+            executed,
+            &mut block_storage_tx,
+        )?;
+
+        let components = Components {
+            header_to_produce: partial_block.header,
+            transactions_source: (),
+            coinbase_recipient: coinbase_contract_id,
+            gas_price,
+        };
+        self.produce_mint_tx(
+            &mut partial_block,
+            &components,
+            &mut block_storage_tx,
+            &mut data,
+            &mut MemoryInstance::new(),
+        )?;
+        debug_assert!(data.found_mint, "Mint transaction is not found");
+
+        data.changes = block_storage_tx.into_changes();
+        Ok((partial_block, data))
+    }
+
+    fn validate_from_parts<D>(
+        &self,
+        block: &Block,
+        mut partial_block: PartialFuelBlock,
+        mut data: ExecutionData,
+        executed: ExecutedTransactions,
+        block_storage_tx: BlockStorageTransaction<D>,
+    ) -> ExecutorResult<ExecutionData>
+    where
+        D: KeyValueInspect<Column = Column>,
+    {
+        let mut block_storage_tx =
+            block_storage_tx.with_changes(core::mem::take(&mut data.changes));
+        Self::append_executed_transactions(
+            &mut partial_block,
+            &mut data,
+            executed,
+            &mut block_storage_tx,
+        )?;
+
+        let transactions = block.transactions();
+        let (gas_price, coinbase_contract_id) =
+            Self::get_coinbase_info_from_mint_tx(transactions)?;
+        if transactions.len() != partial_block.transactions.len().saturating_add(1) {
+            return Err(ExecutorError::BlockMismatch)
+        }
+        let mint = transactions
+            .last()
+            .expect("The `Mint` transaction is present; qed")
+            .clone();
+
+        self.execute_transaction_and_commit(
+            &mut partial_block,
+            &mut block_storage_tx,
+            &mut data,
+            MaybeCheckedTransaction::Transaction(mint),
+            gas_price,
+            coinbase_contract_id,
+            &mut MemoryInstance::new(),
+        )?;
+        self.check_block_matches(partial_block, block, &data)?;
+
+        data.changes = block_storage_tx.into_changes();
+        Ok(data)
+    }
+
+    /// Appends transactions executed by the [`Self::execute_transactions`]
+    /// to the block along with their storage changes.
+    fn append_executed_transactions<D>(
+        block: &mut PartialFuelBlock,
+        data: &mut ExecutionData,
+        executed: ExecutedTransactions,
+        block_storage_tx: &mut BlockStorageTransaction<D>,
+    ) -> ExecutorResult<()>
+    where
+        D: KeyValueInspect<Column = Column>,
+    {
+        let ExecutedTransactions {
+            transactions,
+            skipped_transactions,
+            changes,
+        } = executed;
+
+        block_storage_tx.commit_changes(changes)?;
+
+        for executed in transactions {
+            if executed.index != data.tx_count {
+                return Err(ExecutorError::Other(format!(
+                    "The transaction {} has index {} while {} is expected",
+                    executed.status.id, executed.index, data.tx_count
+                )))
+            }
+
+            data.coinbase = data
+                .coinbase
+                .checked_add(executed.fee)
+                .ok_or(ExecutorError::FeeOverflow)?;
+            data.used_gas =
+                data.used_gas
+                    .checked_add(executed.used_gas)
+                    .ok_or_else(|| {
+                        ExecutorError::GasOverflow(
+                            "Execution used gas overflowed.".into(),
+                            data.used_gas,
+                            executed.used_gas,
+                        )
+                    })?;
+            data.used_size = data
+                .used_size
+                .checked_add(executed.used_size)
+                .ok_or(ExecutorError::TxSizeOverflow)?;
+            data.message_ids.extend(executed.message_ids);
+            data.events.extend(executed.events);
+            data.tx_status.push(executed.status);
+            block.transactions.push(executed.transaction);
+            data.tx_count = data
+                .tx_count
+                .checked_add(1)
+                .ok_or(ExecutorError::TooManyTransactions)?;
+        }
+        data.skipped_transactions.extend(skipped_transactions);
+
+        Ok(())
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn execute_transaction_and_commit<'a, W>(
         &'a self,
@@ -1117,6 +1554,9 @@ where
                 .get_events(&da_height)
                 .map_err(|err| ExecutorError::RelayerError(err.to_string()))?;
             for event in events {
+                if !event.is_consensus_event() {
+                    continue
+                }
                 root_calculator.push(event.hash().as_ref());
                 match event {
                     Event::Message(message) => {
@@ -1154,6 +1594,7 @@ where
                             }
                         }
                     }
+                    Event::Custom(_) => {}
                 }
             }
         }
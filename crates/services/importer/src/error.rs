@@ -4,6 +4,7 @@ use fuel_core_storage::{
 };
 use fuel_core_types::{
     blockchain::primitives::BlockId,
+    fuel_tx::Bytes32,
     fuel_types::BlockHeight,
     services::executor,
 };
@@ -38,6 +39,9 @@ pub enum Error {
     FailedExecution(executor::Error),
     #[display(fmt = "It is not possible to execute the genesis block.")]
     ExecuteGenesis,
+    #[display(fmt = "The previous root of the header is {_1}, \
+        when the latest block root is {_0:?}.")]
+    InvalidPrevRoot(Option<MerkleRoot>, Bytes32),
     #[display(fmt = "The database already contains the data at the height {_0}.")]
     NotUnique(BlockHeight),
     #[display(fmt = "The previous block processing is not finished yet.")]
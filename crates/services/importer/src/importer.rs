@@ -22,6 +22,7 @@ use fuel_core_storage::{
 use fuel_core_types::{
     blockchain::{
         SealedBlock,
+        SealedBlockHeader,
         consensus::Consensus,
     },
     fuel_tx::{
@@ -87,6 +88,10 @@ enum Commands {
         sealed_block: SealedBlock,
         callback: oneshot::Sender<Result<PrepareImportResult, Error>>,
     },
+    CommitHeader {
+        header: SealedBlockHeader,
+        callback: oneshot::Sender<Result<(), Error>>,
+    },
 }
 
 struct ImporterInner<D, E, V> {
@@ -283,6 +288,16 @@ impl Importer {
         receiver.await?
     }
 
+    async fn run_commit_header(&self, header: SealedBlockHeader) -> Result<(), Error> {
+        let (sender, receiver) = oneshot::channel();
+        let command = Commands::CommitHeader {
+            header,
+            callback: sender,
+        };
+        self.commands.send(command)?;
+        receiver.await?
+    }
+
     async fn run_prepare_import_result(
         &self,
         sealed_block: SealedBlock,
@@ -399,6 +414,54 @@ where
         Ok(())
     }
 
+    /// The method commits the sealed block header without the transactions of the block.
+    ///
+    /// It doesn't verify the consensus of the header. It only checks that the header
+    /// continues the chain: the height follows the latest height, and the previous root
+    /// matches the root of the latest block.
+    ///
+    /// Doesn't notify about a new imported block, because there is no block to process.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            block_id = % header.entity.id(),
+            height = * * header.entity.height(),
+        ),
+        err
+    )]
+    fn commit_header(
+        &mut self,
+        runner: &LocalRunner,
+        header: SealedBlockHeader,
+    ) -> Result<(), Error> {
+        runner.run(move || {
+            let actual_next_height = *header.entity.height();
+            verify_next_height(&header.consensus, actual_next_height, &self.database)?;
+
+            let latest_block_root = self.database.latest_block_root()?;
+            let prev_root = *header.entity.prev_root();
+            if latest_block_root != Some(prev_root.into()) {
+                return Err(Error::InvalidPrevRoot(latest_block_root, prev_root))
+            }
+
+            let mut transaction = self.database.storage_transaction(Changes::new());
+            if !transaction.store_new_header(&header)? {
+                return Err(Error::NotUnique(actual_next_height))
+            }
+            let changes = transaction.into_changes();
+            self.database
+                .commit_changes(StorageChanges::Changes(changes))?;
+
+            if self.metrics {
+                importer_metrics()
+                    .block_height
+                    .set(*actual_next_height.deref() as i64);
+            }
+            tracing::info!("Committed header {:#x}", header.entity.id());
+            Ok(())
+        })
+    }
+
     /// Should only be called once after startup to set importer metrics to their initial values
     pub fn init_metrics(&self) {
         // load starting values from database
@@ -517,6 +580,10 @@ where
                     let result = self.prepare_import_result(&local_runner, sealed_block);
                     let _ = callback.send(result);
                 }
+                Commands::CommitHeader { header, callback } => {
+                    let result = self.commit_header(&local_runner, header);
+                    let _ = callback.send(result);
+                }
             }
         }
     }
@@ -685,6 +752,15 @@ impl Importer {
 
         commit_result
     }
+
+    /// The method commits the `SealedBlockHeader` without the transactions of the block.
+    /// It is used by light clients that sync only the header chain.
+    ///
+    /// The consensus of the header should be verified before the call.
+    pub async fn commit_header(&self, header: SealedBlockHeader) -> Result<(), Error> {
+        let _guard = self.lock()?;
+        self.run_commit_header(header).await
+    }
 }
 
 /// The wrapper around `ImportResult` to notify about the end of the processing of a new block.
@@ -717,7 +793,22 @@ fn create_block_changes<D: ImporterDatabase + Transactional>(
 ) -> Result<Changes, Error> {
     let consensus = &sealed_block.consensus;
     let actual_next_height = *sealed_block.entity.header().height();
+    verify_next_height(consensus, actual_next_height, database)?;
 
+    let mut transaction = database.storage_transaction(Changes::new());
+
+    if !transaction.store_new_block(chain_id, sealed_block)? {
+        return Err(Error::NotUnique(actual_next_height))
+    }
+
+    Ok(transaction.into_changes())
+}
+
+fn verify_next_height<D: ImporterDatabase>(
+    consensus: &Consensus,
+    actual_next_height: BlockHeight,
+    database: &D,
+) -> Result<(), Error> {
     // During importing of the genesis block, the database should not be initialized
     // and the genesis block defines the next height.
     // During the production of the non-genesis block, the next height should be underlying
@@ -761,11 +852,5 @@ fn create_block_changes<D: ImporterDatabase + Transactional>(
         ))
     }
 
-    let mut transaction = database.storage_transaction(Changes::new());
-
-    if !transaction.store_new_block(chain_id, sealed_block)? {
-        return Err(Error::NotUnique(actual_next_height))
-    }
-
-    Ok(transaction.into_changes())
+    Ok(())
 }
@@ -22,6 +22,7 @@ use fuel_core_storage::{
 use fuel_core_types::{
     blockchain::{
         SealedBlock,
+        SealedBlockHeader,
         block::Block,
         consensus::Consensus,
     },
@@ -409,6 +410,85 @@ async fn execute_and_commit_assert(
     result
 }
 
+//////////////////////////// PoA Header ////////////////////////////
+fn poa_header(height: u32, prev_root: u32) -> SealedBlockHeader {
+    let mut block = Block::default();
+    block.header_mut().set_block_height(height.into());
+    block
+        .header_mut()
+        .set_previous_root(u32_to_merkle_root(prev_root).into());
+    block.header_mut().recalculate_metadata();
+
+    SealedBlockHeader {
+        entity: block.header().clone(),
+        consensus: Consensus::PoA(Default::default()),
+    }
+}
+
+fn header_db_transaction<B>(
+    store_header: B,
+) -> impl Fn() -> MockDatabaseTransaction + Sync + Send + 'static
+where
+    B: Fn() -> StorageResult<bool> + Sync + Send + 'static + Clone,
+{
+    move || {
+        let store_header = store_header.clone();
+        let mut db = MockDatabaseTransaction::default();
+        db.expect_store_new_header()
+            .returning(move |_| store_header());
+        db.expect_into_changes().returning(Changes::default);
+        db
+    }
+}
+
+#[test_case(
+    poa_header(113, 112),
+    underlying_db(ok(Some(112)), 1),
+    header_db_transaction(ok(true))
+    => Ok(());
+    "successfully commits header when it continues the chain"
+)]
+#[test_case(
+    poa_header(113, 111),
+    underlying_db(ok(Some(112)), 0),
+    header_db_transaction(ok(true))
+    => Err(Error::InvalidPrevRoot(Some(u32_to_merkle_root(112)), u32_to_merkle_root(111).into()));
+    "fails to commit header when previous root doesn't match latest block root"
+)]
+#[test_case(
+    poa_header(114, 112),
+    underlying_db(ok(Some(112)), 0),
+    header_db_transaction(ok(true))
+    => Err(Error::IncorrectBlockHeight(113u32.into(), 114u32.into()));
+    "fails to commit header at height 114 when latest block height is 112"
+)]
+#[test_case(
+    poa_header(113, 112),
+    underlying_db(ok(Some(112)), 0),
+    header_db_transaction(ok(false))
+    => Err(Error::NotUnique(113u32.into()));
+    "fails to commit header when block exists"
+)]
+#[tokio::test]
+async fn commit_header_poa(
+    header: SealedBlockHeader,
+    mut underlying_db: MockDatabase,
+    db_transaction: impl Fn() -> MockDatabaseTransaction + Sync + Send + 'static,
+) -> Result<(), Error> {
+    underlying_db
+        .expect_storage_transaction()
+        .returning(move |_| db_transaction());
+    let importer =
+        Importer::default_config(underlying_db, executor(ex_result), verifier(ok(())));
+
+    let mut imported_blocks = importer.subscribe();
+    let result = importer.commit_header(header).await;
+
+    // Headers are committed without blocks, so there is nothing to broadcast.
+    assert_eq!(imported_blocks.try_recv().unwrap_err(), TryRecvError::Empty);
+    result
+}
+
 #[tokio::test]
 async fn commit_result_fail_when_locked() {
     let importer = Importer::default_config(
@@ -26,7 +26,11 @@ use fuel_core_storage::{
 use fuel_core_types::{
     blockchain::{
         SealedBlock,
-        block::Block,
+        SealedBlockHeader,
+        block::{
+            Block,
+            CompressedBlock,
+        },
         consensus::Consensus,
     },
     fuel_tx::UniqueIdentifier,
@@ -91,6 +95,11 @@ pub trait DatabaseTransaction {
         block: &SealedBlock,
     ) -> StorageResult<bool>;
 
+    /// Inserts the `SealedBlockHeader` without the transactions of the block.
+    ///
+    /// The method returns `true` if the header is a new, otherwise `false`.
+    fn store_new_header(&mut self, header: &SealedBlockHeader) -> StorageResult<bool>;
+
     /// Returns the changes of the transaction.
     fn into_changes(self) -> Changes;
 }
@@ -164,6 +173,21 @@ where
         Ok(!found)
     }
 
+    fn store_new_header(&mut self, header: &SealedBlockHeader) -> StorageResult<bool> {
+        let mut storage = self.write_transaction();
+        let height = header.entity.height();
+        let mut found = storage
+            .storage_as_mut::<FuelBlocks>()
+            .replace(height, &CompressedBlock::from_header(header.entity.clone()))?
+            .is_some();
+        found |= storage
+            .storage_as_mut::<SealedBlockConsensus>()
+            .replace(height, &header.consensus)?
+            .is_some();
+        storage.commit()?;
+        Ok(!found)
+    }
+
     fn into_changes(self) -> Changes {
         self.into_changes()
     }
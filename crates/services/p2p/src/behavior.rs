@@ -14,7 +14,7 @@ use crate::{
     peer_report,
     request_response::messages::{
         RequestMessage,
-        V2ResponseMessage,
+        V3ResponseMessage,
     },
 };
 use fuel_core_types::fuel_types::BlockHeight;
@@ -209,9 +209,9 @@ impl FuelBehaviour {
 
     pub fn send_response_msg(
         &mut self,
-        channel: ResponseChannel<V2ResponseMessage>,
-        message: V2ResponseMessage,
-    ) -> Result<(), V2ResponseMessage> {
+        channel: ResponseChannel<V3ResponseMessage>,
+        message: V3ResponseMessage,
+    ) -> Result<(), V3ResponseMessage> {
         self.request_response.send_response(channel, message)
     }
 
@@ -257,4 +257,8 @@ impl FuelBehaviour {
     pub fn block_peer(&mut self, peer_id: PeerId) {
         self.blocked_peer.block_peer(peer_id)
     }
+
+    pub fn unblock_peer(&mut self, peer_id: PeerId) {
+        self.blocked_peer.unblock_peer(peer_id)
+    }
 }
@@ -12,7 +12,10 @@ use crate::{
     },
     ports::P2PPreConfirmationMessage,
 };
-use fuel_core_types::fuel_tx::Transaction;
+use fuel_core_types::{
+    blockchain::SealedBlockHeader,
+    fuel_tx::Transaction,
+};
 use std::{
     io,
     ops::Deref,
@@ -28,7 +31,9 @@ where
     Codec: Encode<Transaction, Error = io::Error>
         + Decode<Transaction, Error = io::Error>
         + Encode<P2PPreConfirmationMessage, Error = io::Error>
-        + Decode<P2PPreConfirmationMessage, Error = io::Error>,
+        + Decode<P2PPreConfirmationMessage, Error = io::Error>
+        + Encode<SealedBlockHeader, Error = io::Error>
+        + Decode<SealedBlockHeader, Error = io::Error>,
 {
     type RequestMessage = GossipsubBroadcastRequest;
     type ResponseMessage = GossipsubMessage;
@@ -41,6 +46,9 @@ where
             GossipsubBroadcastRequest::TxPreConfirmations(msg) => {
                 Ok(self.codec.encode(msg.deref())?.into_bytes())
             }
+            GossipsubBroadcastRequest::NewBlock(header) => {
+                Ok(self.codec.encode(header.deref())?.into_bytes())
+            }
         }
     }
 
@@ -56,6 +64,9 @@ where
             GossipTopicTag::TxPreconfirmations => {
                 GossipsubMessage::TxPreConfirmations(self.codec.decode(encoded_data)?)
             }
+            GossipTopicTag::NewBlock => {
+                GossipsubMessage::NewBlock(self.codec.decode(encoded_data)?)
+            }
         };
 
         Ok(decoded_response)
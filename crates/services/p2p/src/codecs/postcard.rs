@@ -64,7 +64,10 @@ where
 #[allow(non_snake_case)]
 mod tests {
     use fuel_core_types::{
-        blockchain::SealedBlockHeader,
+        blockchain::{
+            SealedBlock,
+            SealedBlockHeader,
+        },
         fuel_tx::Transaction,
         services::p2p::NetworkableTransactionPool,
     };
@@ -78,7 +81,7 @@ mod tests {
                 RequestMessage,
                 ResponseMessageErrorCode,
                 V1ResponseMessage,
-                V2ResponseMessage,
+                V3ResponseMessage,
             },
             protocols::RequestResponseProtocol,
         },
@@ -100,7 +103,7 @@ mod tests {
      {
         // Given
         let sealed_block_headers = vec![SealedBlockHeader::default()];
-        let response = V2ResponseMessage::SealedHeaders(Ok(sealed_block_headers.clone()));
+        let response = V3ResponseMessage::SealedHeaders(Ok(sealed_block_headers.clone()));
         let mut codec: RequestResponseMessageHandler<PostcardCodec> =
             RequestResponseMessageHandler::new(MAX_REQUEST_SIZE);
         let mut buf = Vec::with_capacity(1024);
@@ -119,7 +122,7 @@ mod tests {
         // Then
         assert!(matches!(
             deserialized,
-            V2ResponseMessage::SealedHeaders(Ok(sealed_headers)) if sealed_headers == sealed_block_headers
+            V3ResponseMessage::SealedHeaders(Ok(sealed_headers)) if sealed_headers == sealed_block_headers
         ));
     }
 
@@ -131,7 +134,7 @@ mod tests {
             Transaction::default_test_tx(),
         ))];
         let response =
-            V2ResponseMessage::TxPoolFullTransactions(Ok(full_transactions.clone()));
+            V3ResponseMessage::TxPoolFullTransactions(Ok(full_transactions.clone()));
         let mut codec: RequestResponseMessageHandler<PostcardCodec> =
             RequestResponseMessageHandler::new(MAX_REQUEST_SIZE);
         let mut buf = Vec::with_capacity(1024);
@@ -150,16 +153,80 @@ mod tests {
         // Then
         assert!(matches!(
             deserialized,
-            V2ResponseMessage::TxPoolFullTransactions(Ok(actual)) if actual == full_transactions
+            V3ResponseMessage::TxPoolFullTransactions(Ok(actual)) if actual == full_transactions
+        ));
+    }
+
+    #[tokio::test]
+    async fn codec__serialization_roundtrip_using_v3_on_successful_response_returns_original_value__sealed_blocks()
+     {
+        // Given
+        let sealed_blocks = vec![SealedBlock::default()];
+        let response = V3ResponseMessage::SealedBlocks(Ok(sealed_blocks.clone()));
+        let mut codec: RequestResponseMessageHandler<PostcardCodec> =
+            RequestResponseMessageHandler::new(MAX_REQUEST_SIZE);
+        let mut buf = Vec::with_capacity(1024);
+
+        // When
+        codec
+            .write_response(&RequestResponseProtocol::V3, &mut buf, response)
+            .await
+            .expect("Valid sealed blocks should be serialized using v3");
+
+        let deserialized = codec
+            .read_response(&RequestResponseProtocol::V3, &mut buf.as_slice())
+            .await
+            .expect("Valid sealed blocks should be deserialized using v3");
+
+        // Then
+        assert!(matches!(
+            deserialized,
+            V3ResponseMessage::SealedBlocks(Ok(actual)) if actual == sealed_blocks
         ));
     }
 
+    #[tokio::test]
+    async fn codec__write_response_fails_for_sealed_blocks_using_v2() {
+        // Given
+        let response = V3ResponseMessage::SealedBlocks(Ok(vec![SealedBlock::default()]));
+        let mut codec: RequestResponseMessageHandler<PostcardCodec> =
+            RequestResponseMessageHandler::new(MAX_REQUEST_SIZE);
+        let mut buf = Vec::with_capacity(1024);
+
+        // When
+        let result = codec
+            .write_response(&RequestResponseProtocol::V2, &mut buf, response)
+            .await;
+
+        // Then
+        assert!(result.is_err());
+        assert!(buf.is_empty());
+    }
+
+    #[tokio::test]
+    async fn codec__write_request_fails_for_sealed_blocks_using_v2() {
+        // Given
+        let request = RequestMessage::SealedBlocks(1..2);
+        let mut codec: RequestResponseMessageHandler<PostcardCodec> =
+            RequestResponseMessageHandler::new(MAX_REQUEST_SIZE);
+        let mut buf = Vec::with_capacity(1024);
+
+        // When
+        let result = codec
+            .write_request(&RequestResponseProtocol::V2, &mut buf, request)
+            .await;
+
+        // Then
+        assert!(result.is_err());
+        assert!(buf.is_empty());
+    }
+
     #[tokio::test]
     async fn codec__serialization_roundtrip_using_v1_on_successful_response_returns_original_value()
      {
         // Given
         let sealed_block_headers = vec![SealedBlockHeader::default()];
-        let response = V2ResponseMessage::SealedHeaders(Ok(sealed_block_headers.clone()));
+        let response = V3ResponseMessage::SealedHeaders(Ok(sealed_block_headers.clone()));
         let mut codec: RequestResponseMessageHandler<PostcardCodec> =
             RequestResponseMessageHandler::new(MAX_REQUEST_SIZE);
         let mut buf = Vec::with_capacity(1024);
@@ -177,7 +244,7 @@ mod tests {
 
         // Then
         assert!(
-            matches!(deserialized, V2ResponseMessage::SealedHeaders(Ok(sealed_headers)) if sealed_headers == sealed_block_headers)
+            matches!(deserialized, V3ResponseMessage::SealedHeaders(Ok(sealed_headers)) if sealed_headers == sealed_block_headers)
         );
     }
 
@@ -185,7 +252,7 @@ mod tests {
     async fn codec__serialization_roundtrip_using_v2_on_error_response_returns_original_value()
      {
         // Given
-        let response = V2ResponseMessage::SealedHeaders(Err(
+        let response = V3ResponseMessage::SealedHeaders(Err(
             ResponseMessageErrorCode::ProtocolV1EmptyResponse,
         ));
         let mut codec: RequestResponseMessageHandler<PostcardCodec> =
@@ -206,7 +273,7 @@ mod tests {
         // Then
         assert!(matches!(
             deserialized,
-            V2ResponseMessage::SealedHeaders(Err(
+            V3ResponseMessage::SealedHeaders(Err(
                 ResponseMessageErrorCode::ProtocolV1EmptyResponse
             ))
         ));
@@ -216,7 +283,7 @@ mod tests {
     async fn codec__serialization_roundtrip_using_v1_on_error_response_returns_predefined_error_code()
      {
         // Given
-        let response = V2ResponseMessage::SealedHeaders(Err(
+        let response = V3ResponseMessage::SealedHeaders(Err(
             ResponseMessageErrorCode::RequestedRangeTooLarge,
         ));
         let mut codec: RequestResponseMessageHandler<PostcardCodec> =
@@ -237,7 +304,7 @@ mod tests {
         // Then
         assert!(matches!(
             deserialized,
-            V2ResponseMessage::SealedHeaders(Err(
+            V3ResponseMessage::SealedHeaders(Err(
                 ResponseMessageErrorCode::ProtocolV1EmptyResponse
             ))
         ));
@@ -246,7 +313,7 @@ mod tests {
     #[tokio::test]
     async fn codec__write_response_is_backwards_compatible_with_v1() {
         // Given
-        let response = V2ResponseMessage::SealedHeaders(Err(
+        let response = V3ResponseMessage::SealedHeaders(Err(
             ResponseMessageErrorCode::ProtocolV1EmptyResponse,
         ));
         let mut codec: RequestResponseMessageHandler<PostcardCodec> =
@@ -291,7 +358,7 @@ mod tests {
         // Then
         assert!(matches!(
             deserialized,
-            V2ResponseMessage::SealedHeaders(Err(
+            V3ResponseMessage::SealedHeaders(Err(
                 ResponseMessageErrorCode::ProtocolV1EmptyResponse
             ))
         ));
@@ -3,8 +3,10 @@ use std::io;
 use crate::request_response::{
     messages::{
         RequestMessage,
+        UnsupportedByProtocolError,
         V1ResponseMessage,
         V2ResponseMessage,
+        V3ResponseMessage,
     },
     protocols::RequestResponseProtocol,
 };
@@ -50,11 +52,13 @@ where
         + Decode<V1ResponseMessage, Error = io::Error>
         + Encode<V2ResponseMessage, Error = io::Error>
         + Decode<V2ResponseMessage, Error = io::Error>
+        + Encode<V3ResponseMessage, Error = io::Error>
+        + Decode<V3ResponseMessage, Error = io::Error>
         + Send,
 {
     type Protocol = RequestResponseProtocol;
     type Request = RequestMessage;
-    type Response = V2ResponseMessage;
+    type Response = V3ResponseMessage;
 
     async fn read_request<T>(
         &mut self,
@@ -89,21 +93,34 @@ where
         match protocol {
             RequestResponseProtocol::V1 => {
                 let v1_response: V1ResponseMessage = self.codec.decode(&response)?;
-                Ok(v1_response.into())
+                let v2_response: V2ResponseMessage = v1_response.into();
+                Ok(v2_response.into())
             }
-            RequestResponseProtocol::V2 => self.codec.decode(&response),
+            RequestResponseProtocol::V2 => {
+                let v2_response: V2ResponseMessage = self.codec.decode(&response)?;
+                Ok(v2_response.into())
+            }
+            RequestResponseProtocol::V3 => self.codec.decode(&response),
         }
     }
 
     async fn write_request<T>(
         &mut self,
-        _protocol: &Self::Protocol,
+        protocol: &Self::Protocol,
         socket: &mut T,
         req: Self::Request,
     ) -> io::Result<()>
     where
         T: AsyncWrite + Unpin + Send,
     {
+        if let RequestMessage::SealedBlocks(_) = req {
+            match protocol {
+                RequestResponseProtocol::V1 | RequestResponseProtocol::V2 => {
+                    return Err(unsupported_by_protocol(UnsupportedByProtocolError))
+                }
+                RequestResponseProtocol::V3 => {}
+            }
+        }
         let encoded_data = self.codec.encode(&req)?;
         socket.write_all(&encoded_data.into_bytes()).await?;
         Ok(())
@@ -120,12 +137,21 @@ where
     {
         match protocol {
             RequestResponseProtocol::V1 => {
-                let v1_response: V1ResponseMessage = res.into();
+                let v2_response =
+                    V2ResponseMessage::try_from(res).map_err(unsupported_by_protocol)?;
+                let v1_response: V1ResponseMessage = v2_response.into();
                 let res = self.codec.encode(&v1_response)?;
                 let res = res.into_bytes();
                 socket.write_all(&res).await?;
             }
             RequestResponseProtocol::V2 => {
+                let v2_response =
+                    V2ResponseMessage::try_from(res).map_err(unsupported_by_protocol)?;
+                let res = self.codec.encode(&v2_response)?;
+                let res = res.into_bytes();
+                socket.write_all(&res).await?;
+            }
+            RequestResponseProtocol::V3 => {
                 let res = self.codec.encode(&res)?;
                 let res = res.into_bytes();
                 socket.write_all(&res).await?;
@@ -136,6 +162,10 @@ where
     }
 }
 
+fn unsupported_by_protocol(error: UnsupportedByProtocolError) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, error)
+}
+
 impl<Codec> RequestResponseProtocols for Codec
 where
     Codec: request_response::Codec<Protocol = RequestResponseProtocol>,
@@ -145,7 +175,7 @@ where
     ) -> impl Iterator<Item = <Self as request_response::Codec>::Protocol> {
         // TODO: https://github.com/FuelLabs/fuel-core/issues/2458.
         // Iterating over versions in reverse order should prefer
-        // peers to use V2 over V1 for exchanging messages. However, this is
+        // peers to use the latest version for exchanging messages. However, this is
         // not guaranteed by the specs for the `request_response` protocol.
         RequestResponseProtocol::iter().rev()
     }
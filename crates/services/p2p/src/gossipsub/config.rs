@@ -1,4 +1,5 @@
 use super::topics::{
+    NEW_BLOCK_GOSSIP_TOPIC,
     NEW_TX_GOSSIP_TOPIC,
     TX_PRECONFIRMATIONS_GOSSIP_TOPIC,
 };
@@ -54,6 +55,9 @@ const NEW_TX_GOSSIP_WEIGHT: f64 = 0.05;
 
 const TX_PRECONFIRMATIONS_GOSSIP_WEIGHT: f64 = 0.05;
 
+// The weight applied to the score for delivering announcements of the new blocks.
+const NEW_BLOCK_GOSSIP_WEIGHT: f64 = 0.05;
+
 // The threshold for a peer's score to be considered for greylisting.
 // If a peer's score falls below this value, they will be greylisted.
 // Greylisting is a lighter form of banning, where the peer's messages might be ignored or given lower priority,
@@ -227,7 +231,10 @@ fn initialize_gossipsub(gossipsub: &mut gossipsub::Behaviour, p2p_config: &Confi
         .with_peer_score(peer_score_params, peer_score_thresholds)
         .expect("gossipsub initialized with peer score");
 
-    let mut topics = vec![(NEW_TX_GOSSIP_TOPIC, NEW_TX_GOSSIP_WEIGHT)];
+    let mut topics = vec![
+        (NEW_TX_GOSSIP_TOPIC, NEW_TX_GOSSIP_WEIGHT),
+        (NEW_BLOCK_GOSSIP_TOPIC, NEW_BLOCK_GOSSIP_WEIGHT),
+    ];
     if p2p_config.subscribe_to_pre_confirmations {
         topics.push((
             TX_PRECONFIRMATIONS_GOSSIP_TOPIC,
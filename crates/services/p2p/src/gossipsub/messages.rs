@@ -1,5 +1,8 @@
 use crate::ports::P2PPreConfirmationMessage;
-use fuel_core_types::fuel_tx::Transaction;
+use fuel_core_types::{
+    blockchain::SealedBlockHeader,
+    fuel_tx::Transaction,
+};
 use serde::{
     Deserialize,
     Serialize,
@@ -12,6 +15,7 @@ use std::sync::Arc;
 pub enum GossipTopicTag {
     NewTx,
     TxPreconfirmations,
+    NewBlock,
 }
 
 /// Takes `Arc<T>` and wraps it in a matching GossipsubBroadcastRequest
@@ -21,6 +25,7 @@ pub enum GossipTopicTag {
 pub enum GossipsubBroadcastRequest {
     NewTx(Arc<Transaction>),
     TxPreConfirmations(Arc<P2PPreConfirmationMessage>),
+    NewBlock(Arc<SealedBlockHeader>),
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -28,4 +33,5 @@ pub enum GossipsubBroadcastRequest {
 pub enum GossipsubMessage {
     NewTx(Transaction),
     TxPreConfirmations(P2PPreConfirmationMessage),
+    NewBlock(SealedBlockHeader),
 }
@@ -11,6 +11,7 @@ use super::messages::{
 
 pub const NEW_TX_GOSSIP_TOPIC: &str = "new_tx";
 pub const TX_PRECONFIRMATIONS_GOSSIP_TOPIC: &str = "tx_preconfirmations";
+pub const NEW_BLOCK_GOSSIP_TOPIC: &str = "new_block";
 
 /// Holds used Gossipsub Topics
 /// Each field contains TopicHash of existing topics
@@ -19,6 +20,7 @@ pub const TX_PRECONFIRMATIONS_GOSSIP_TOPIC: &str = "tx_preconfirmations";
 pub struct GossipsubTopics {
     new_tx_topic: TopicHash,
     tx_preconfirmations_topic: TopicHash,
+    new_block_topic: TopicHash,
 }
 
 impl GossipsubTopics {
@@ -27,9 +29,12 @@ impl GossipsubTopics {
             Topic::new(format!("{NEW_TX_GOSSIP_TOPIC}/{network_name}"));
         let tx_preconfirmations_topic: Sha256Topic =
             Topic::new(format!("{TX_PRECONFIRMATIONS_GOSSIP_TOPIC}/{network_name}"));
+        let new_block_topic: Sha256Topic =
+            Topic::new(format!("{NEW_BLOCK_GOSSIP_TOPIC}/{network_name}"));
         Self {
             new_tx_topic: new_tx_topic.hash(),
             tx_preconfirmations_topic: tx_preconfirmations_topic.hash(),
+            new_block_topic: new_block_topic.hash(),
         }
     }
 
@@ -43,6 +48,7 @@ impl GossipsubTopics {
             hash if hash == &self.tx_preconfirmations_topic => {
                 Some(GossipTopicTag::TxPreconfirmations)
             }
+            hash if hash == &self.new_block_topic => Some(GossipTopicTag::NewBlock),
             _ => None,
         }
     }
@@ -58,6 +64,7 @@ impl GossipsubTopics {
             GossipsubBroadcastRequest::TxPreConfirmations(_) => {
                 self.tx_preconfirmations_topic.clone()
             }
+            GossipsubBroadcastRequest::NewBlock(_) => self.new_block_topic.clone(),
         }
     }
 }
@@ -13,6 +13,7 @@ pub mod heartbeat;
 pub mod p2p_service;
 pub mod peer_manager;
 pub mod peer_report;
+pub mod peer_reputation_file;
 pub mod ports;
 pub mod request_response;
 pub mod service;
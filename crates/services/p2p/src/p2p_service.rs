@@ -28,20 +28,33 @@ use crate::{
         ConnectionState,
         PeerManager,
         Punisher,
+        ban_list::{
+            Ban,
+            BanTarget,
+        },
     },
     peer_report::PeerReportEvent,
-    request_response::messages::{
-        RequestError,
-        RequestMessage,
-        ResponseError,
-        ResponseSendError,
-        ResponseSender,
-        V2ResponseMessage,
+    request_response::{
+        messages::{
+            RequestError,
+            RequestMessage,
+            ResponseError,
+            ResponseSendError,
+            ResponseSender,
+            V3ResponseMessage,
+            is_unsupported_protocol,
+        },
+        protocols::V3_REQUEST_RESPONSE_PROTOCOL_ID,
     },
 };
 use fuel_core_metrics::{
     global_registry,
-    p2p_metrics::increment_unique_peers,
+    p2p_metrics::{
+        increment_peer_failed_requests,
+        increment_unique_peers,
+        observe_peer_request_success,
+        remove_peer_metrics,
+    },
 };
 use fuel_core_types::{
     fuel_types::BlockHeight,
@@ -81,7 +94,10 @@ use std::{
     collections::HashMap,
     time::Duration,
 };
-use tokio::sync::broadcast;
+use tokio::{
+    sync::broadcast,
+    time::Instant,
+};
 use tracing::{
     debug,
     warn,
@@ -93,10 +109,48 @@ mod tests;
 /// Maximum amount of peer's addresses that we are ready to store per peer
 const MAX_IDENTIFY_ADDRESSES: usize = 10;
 
+/// The number of the best peers that have their own series in the request metrics.
+/// The requests to the rest of the peers are aggregated to limit the number of series.
+const PEERS_WITH_METRICS: usize = 10;
+
 impl Punisher for Swarm<FuelBehaviour> {
     fn ban_peer(&mut self, peer_id: PeerId) {
         self.behaviour_mut().block_peer(peer_id)
     }
+
+    fn unban_peer(&mut self, peer_id: PeerId) {
+        self.behaviour_mut().unblock_peer(peer_id)
+    }
+
+    fn disconnect_peer(&mut self, peer_id: PeerId) {
+        let _ = self.disconnect_peer_id(peer_id);
+    }
+}
+
+/// Returns `true` if the peer answered the request with the expected data.
+fn is_successful_response(
+    channel: &ResponseSender,
+    response: &V3ResponseMessage,
+) -> bool {
+    matches!(
+        (channel, response),
+        (
+            ResponseSender::SealedHeaders(_),
+            V3ResponseMessage::SealedHeaders(Ok(_))
+        ) | (
+            ResponseSender::Transactions(_) | ResponseSender::TransactionsFromPeer(_),
+            V3ResponseMessage::Transactions(Ok(_))
+        ) | (
+            ResponseSender::TxPoolAllTransactionsIds(_),
+            V3ResponseMessage::TxPoolAllTransactionsIds(Ok(_))
+        ) | (
+            ResponseSender::TxPoolFullTransactions(_),
+            V3ResponseMessage::TxPoolFullTransactions(Ok(_))
+        ) | (
+            ResponseSender::SealedBlocks(_),
+            V3ResponseMessage::SealedBlocks(Ok(_))
+        )
+    )
 }
 
 /// Listens to the events on the p2p network
@@ -119,14 +173,15 @@ pub struct FuelP2PService {
     /// must provide a channel to receive the response.
     /// Whenever a response (or an error) is received from the p2p network,
     /// the request is removed from this table, and the channel is used to
-    /// send the result to the caller.
-    outbound_requests_table: HashMap<OutboundRequestId, ResponseSender>,
+    /// send the result to the caller. The time when the request was sent is used
+    /// to measure the latency of the peer.
+    outbound_requests_table: HashMap<OutboundRequestId, (ResponseSender, Instant)>,
 
     /// Holds active inbound requests and associated oneshot channels.
     /// Whenever we're done processing the request, it's removed from this table,
     /// and the channel is used to send the result to libp2p, which will forward it
     /// to the peer that requested it.
-    inbound_requests_table: HashMap<InboundRequestId, ResponseChannel<V2ResponseMessage>>,
+    inbound_requests_table: HashMap<InboundRequestId, ResponseChannel<V3ResponseMessage>>,
 
     /// `PostcardCodec` as GossipsubCodec for encoding and decoding of Gossipsub messages
     gossipsub_codec: GossipsubMessageHandler<PostcardCodec>,
@@ -350,6 +405,14 @@ impl FuelP2PService {
         }
     }
 
+    /// Returns the label of the peer in the request metrics,
+    /// if the peer is among the best ones.
+    fn peer_metrics_label(&self, peer_id: &PeerId) -> Option<String> {
+        self.peer_manager
+            .is_among_best_peers(peer_id, PEERS_WITH_METRICS)
+            .then(|| peer_id.to_base58())
+    }
+
     pub fn update_libp2p_metrics<E>(&self, event: &E)
     where
         Metrics: Recorder<E>,
@@ -424,7 +487,9 @@ impl FuelP2PService {
             .behaviour_mut()
             .send_request_msg(message_request, &peer_id);
 
-        self.outbound_requests_table.insert(request_id, on_response);
+        self.outbound_requests_table
+            .insert(request_id, (on_response, Instant::now()));
+        self.peer_manager.handle_request_sent(&peer_id);
 
         Ok(request_id)
     }
@@ -433,7 +498,7 @@ impl FuelP2PService {
     pub fn send_response_msg(
         &mut self,
         request_id: InboundRequestId,
-        message: V2ResponseMessage,
+        message: V3ResponseMessage,
     ) -> Result<(), ResponseSendError> {
         let Some(channel) = self.inbound_requests_table.remove(&request_id) else {
             debug!("ResponseChannel for {:?} does not exist!", request_id);
@@ -508,6 +573,27 @@ impl FuelP2PService {
         );
     }
 
+    /// Bans the peer or the IP address for the `duration`,
+    /// or until it is unbanned if the `duration` is not set.
+    pub fn ban(&mut self, target: BanTarget, duration: Option<Duration>) {
+        self.peer_manager.ban(target, duration, &mut self.swarm);
+    }
+
+    /// Returns `true` if the `target` was banned.
+    pub fn unban(&mut self, target: &BanTarget) -> bool {
+        self.peer_manager.unban(target, &mut self.swarm)
+    }
+
+    pub fn bans(&self) -> Vec<Ban> {
+        self.peer_manager.bans()
+    }
+
+    /// Restores the reputation scores of the peers saved by the previous run of the node.
+    pub fn restore_peer_reputations(&mut self, reputations: Vec<(PeerId, AppScore)>) {
+        self.peer_manager
+            .restore_reputations(reputations, &mut self.swarm);
+    }
+
     #[tracing::instrument(skip_all,
         level = "debug",
         fields(
@@ -539,6 +625,33 @@ impl FuelP2PService {
                 );
                 None
             }
+            SwarmEvent::ConnectionEstablished {
+                peer_id,
+                ref endpoint,
+                ..
+            } => {
+                let remote_address = endpoint.get_remote_address().clone();
+                self.update_libp2p_metrics(&event);
+                if self
+                    .peer_manager
+                    .handle_connection_established(peer_id, &remote_address)
+                {
+                    debug!(target: "fuel-p2p", "Closing the connection with the banned peer {peer_id} from {remote_address}");
+                    let _ = self.swarm.disconnect_peer_id(peer_id);
+                }
+                None
+            }
+            SwarmEvent::ConnectionClosed {
+                peer_id,
+                num_established,
+                ..
+            } => {
+                self.update_libp2p_metrics(&event);
+                if num_established == 0 {
+                    self.peer_manager.handle_connection_closed(&peer_id);
+                }
+                None
+            }
             _ => {
                 self.update_libp2p_metrics(&event);
                 None
@@ -632,7 +745,8 @@ impl FuelP2PService {
     ) -> Option<FuelP2PEvent> {
         match event {
             PeerReportEvent::PerformDecay => {
-                self.peer_manager.batch_update_score_with_decay()
+                self.peer_manager.batch_update_score_with_decay();
+                self.peer_manager.remove_expired_bans(&mut self.swarm);
             }
             PeerReportEvent::PeerConnected { peer_id } => {
                 if self.peer_manager.handle_peer_connected(&peer_id) {
@@ -643,6 +757,7 @@ impl FuelP2PService {
             }
             PeerReportEvent::PeerDisconnected { peer_id } => {
                 self.peer_manager.handle_peer_disconnect(peer_id);
+                self.update_metrics(|| remove_peer_metrics(&peer_id.to_base58()));
                 return Some(FuelP2PEvent::PeerDisconnected(peer_id));
             }
         }
@@ -651,7 +766,7 @@ impl FuelP2PService {
 
     fn handle_request_response_event(
         &mut self,
-        event: request_response::Event<RequestMessage, V2ResponseMessage>,
+        event: request_response::Event<RequestMessage, V3ResponseMessage>,
     ) -> Option<FuelP2PEvent> {
         match event {
             request_response::Event::Message { peer, message } => match message {
@@ -671,15 +786,39 @@ impl FuelP2PService {
                     request_id,
                     response,
                 } => {
-                    let Some(channel) = self.outbound_requests_table.remove(&request_id)
+                    let Some((channel, sent_at)) =
+                        self.outbound_requests_table.remove(&request_id)
                     else {
                         debug!("Send channel not found for {:?}", request_id);
                         return None;
                     };
 
+                    if is_successful_response(&channel, &response) {
+                        let latency = sent_at.elapsed();
+                        let bytes = postcard::experimental::serialized_size(&response)
+                            .unwrap_or_default()
+                            as u64;
+                        self.peer_manager
+                            .handle_request_succeeded(&peer, latency, bytes);
+                        self.update_metrics(|| {
+                            observe_peer_request_success(
+                                self.peer_metrics_label(&peer).as_deref(),
+                                latency,
+                                bytes,
+                            )
+                        });
+                    } else {
+                        self.peer_manager.handle_request_failed(&peer);
+                        self.update_metrics(|| {
+                            increment_peer_failed_requests(
+                                self.peer_metrics_label(&peer).as_deref(),
+                            )
+                        });
+                    }
+
                     let send_ok = match channel {
                         ResponseSender::SealedHeaders(c) => match response {
-                            V2ResponseMessage::SealedHeaders(v) => {
+                            V3ResponseMessage::SealedHeaders(v) => {
                                 c.send(Ok((peer, Ok(v)))).is_ok()
                             }
                             _ => {
@@ -692,7 +831,7 @@ impl FuelP2PService {
                             }
                         },
                         ResponseSender::Transactions(c) => match response {
-                            V2ResponseMessage::Transactions(v) => {
+                            V3ResponseMessage::Transactions(v) => {
                                 c.send(Ok((peer, Ok(v)))).is_ok()
                             }
                             _ => {
@@ -705,7 +844,7 @@ impl FuelP2PService {
                             }
                         },
                         ResponseSender::TransactionsFromPeer(c) => match response {
-                            V2ResponseMessage::Transactions(v) => {
+                            V3ResponseMessage::Transactions(v) => {
                                 c.send((peer, Ok(v))).is_ok()
                             }
                             _ => {
@@ -717,7 +856,7 @@ impl FuelP2PService {
                             }
                         },
                         ResponseSender::TxPoolAllTransactionsIds(c) => match response {
-                            V2ResponseMessage::TxPoolAllTransactionsIds(v) => {
+                            V3ResponseMessage::TxPoolAllTransactionsIds(v) => {
                                 c.send((peer, Ok(v))).is_ok()
                             }
                             _ => {
@@ -729,7 +868,7 @@ impl FuelP2PService {
                             }
                         },
                         ResponseSender::TxPoolFullTransactions(c) => match response {
-                            V2ResponseMessage::TxPoolFullTransactions(v) => {
+                            V3ResponseMessage::TxPoolFullTransactions(v) => {
                                 c.send((peer, Ok(v))).is_ok()
                             }
                             _ => {
@@ -740,6 +879,19 @@ impl FuelP2PService {
                                 c.send((peer, Err(ResponseError::TypeMismatch))).is_ok()
                             }
                         },
+                        ResponseSender::SealedBlocks(c) => match response {
+                            V3ResponseMessage::SealedBlocks(v) => {
+                                c.send(Ok((peer, Ok(v)))).is_ok()
+                            }
+                            _ => {
+                                warn!(
+                                    "Invalid response type received for request {:?}",
+                                    request_id
+                                );
+                                c.send(Ok((peer, Err(ResponseError::TypeMismatch))))
+                                    .is_ok()
+                            }
+                        },
                     };
 
                     if !send_ok {
@@ -780,7 +932,21 @@ impl FuelP2PService {
                     error
                 );
 
-                if let Some(channel) = self.outbound_requests_table.remove(&request_id) {
+                if let Some((channel, _)) =
+                    self.outbound_requests_table.remove(&request_id)
+                {
+                    // The peer that doesn't support the protocol didn't misbehave,
+                    // it just shouldn't be asked for the full blocks again.
+                    if is_unsupported_protocol(&error) {
+                        self.peer_manager.handle_sealed_blocks_unsupported(&peer);
+                    } else {
+                        self.peer_manager.handle_request_failed(&peer);
+                        self.update_metrics(|| {
+                            increment_peer_failed_requests(
+                                self.peer_metrics_label(&peer).as_deref(),
+                            )
+                        });
+                    }
                     match channel {
                         ResponseSender::SealedHeaders(c) => {
                             let _ = c.send(Ok((peer, Err(ResponseError::P2P(error)))));
@@ -797,6 +963,9 @@ impl FuelP2PService {
                         ResponseSender::TxPoolFullTransactions(c) => {
                             let _ = c.send((peer, Err(ResponseError::P2P(error))));
                         }
+                        ResponseSender::SealedBlocks(c) => {
+                            let _ = c.send(Ok((peer, Err(ResponseError::P2P(error)))));
+                        }
                     };
                 }
             }
@@ -812,6 +981,10 @@ impl FuelP2PService {
 
                 let mut addresses = info.listen_addrs;
                 let agent_version = info.agent_version;
+                let supports_sealed_blocks = info
+                    .protocols
+                    .iter()
+                    .any(|protocol| protocol.as_ref() == V3_REQUEST_RESPONSE_PROTOCOL_ID);
 
                 if addresses.len() > MAX_IDENTIFY_ADDRESSES {
                     let protocol_version = info.protocol_version;
@@ -827,6 +1000,7 @@ impl FuelP2PService {
                     &peer_id,
                     addresses.clone(),
                     agent_version,
+                    supports_sealed_blocks,
                 );
 
                 self.swarm
@@ -18,6 +18,7 @@ use fuel_core_p2p::{
             GossipsubMessage,
         },
         topics::{
+            NEW_BLOCK_GOSSIP_TOPIC,
             NEW_TX_GOSSIP_TOPIC,
             TX_PRECONFIRMATIONS_GOSSIP_TOPIC,
         },
@@ -32,12 +33,13 @@ use fuel_core_p2p::{
         RequestMessage,
         ResponseError,
         ResponseSender,
-        V2ResponseMessage,
+        V3ResponseMessage,
     },
     service::to_message_acceptance,
 };
 use fuel_core_types::{
     blockchain::{
+        SealedBlock,
         SealedBlockHeader,
         consensus::{
             Consensus,
@@ -635,6 +637,25 @@ async fn gossipsub_broadcast_tx_with_accept__tx_preconfirmations() {
     }
 }
 
+#[tokio::test]
+#[instrument]
+async fn gossipsub_broadcast_with_accept__new_block() {
+    for _ in 0..100 {
+        tokio::time::timeout(
+            Duration::from_secs(5),
+            gossipsub_broadcast(
+                GossipsubBroadcastRequest::NewBlock(Arc::new(
+                    SealedBlockHeader::default(),
+                )),
+                GossipsubMessageAcceptance::Accept,
+                None,
+            ),
+        )
+        .await
+        .unwrap();
+    }
+}
+
 #[tokio::test]
 #[instrument]
 async fn gossipsub_broadcast_tx_with_reject__new_tx() {
@@ -816,6 +837,9 @@ async fn gossipsub_broadcast(
                 TX_PRECONFIRMATIONS_GOSSIP_TOPIC,
                 GossipTopicTag::TxPreconfirmations,
             ),
+            GossipsubBroadcastRequest::NewBlock(_) => {
+                (NEW_BLOCK_GOSSIP_TOPIC, GossipTopicTag::NewBlock)
+            }
         };
 
         (
@@ -949,6 +973,14 @@ fn check_message_matches_request(
             received,
             "Both messages were `Preconfirmations`, but the received message did not match the requested message"
         ),
+        (
+            GossipsubMessage::NewBlock(received),
+            GossipsubBroadcastRequest::NewBlock(requested),
+        ) => assert_eq!(
+            requested.deref(),
+            received,
+            "Both messages were `NewBlock`s, but the received message did not match the requested message"
+        ),
         _ => panic!(
             "Message does not match the expected request, expected: {:?}, actual: {:?}",
             expected, message
@@ -1127,6 +1159,22 @@ async fn request_response_works_with(
                                         }
                                     });
                                 }
+                                RequestMessage::SealedBlocks(range) => {
+                                    let (tx_orchestrator, rx_orchestrator) = oneshot::channel();
+                                    assert!(node_a.send_request_msg(None, request_msg.clone(), ResponseSender::SealedBlocks(tx_orchestrator)).is_ok());
+                                    let tx_test_end = tx_test_end.clone();
+                                    tokio::spawn(async move {
+                                        let response_message = rx_orchestrator.await;
+
+                                        if let Ok(Ok((_, Ok(Ok(sealed_blocks))))) = response_message {
+                                            let check = sealed_blocks.len() == range.len();
+                                            let _ = tx_test_end.send(check).await;
+                                        } else {
+                                            tracing::error!("Orchestrator failed to receive a message: {:?}", response_message);
+                                            let _ = tx_test_end.send(false).await;
+                                        }
+                                    });
+                                }
                             }
                         }
                     }
@@ -1141,16 +1189,16 @@ async fn request_response_works_with(
                         RequestMessage::SealedHeaders(range) => {
                             let sealed_headers: Vec<_> = arbitrary_headers_for_range(range.clone());
 
-                            let _ = node_b.send_response_msg(*request_id, V2ResponseMessage::SealedHeaders(Ok(sealed_headers)));
+                            let _ = node_b.send_response_msg(*request_id, V3ResponseMessage::SealedHeaders(Ok(sealed_headers)));
                         }
                         RequestMessage::Transactions(_) => {
                             let txs = (0..5).map(|_| Transaction::default_test_tx()).collect();
                             let transactions = vec![Transactions(txs)];
-                            let _ = node_b.send_response_msg(*request_id, V2ResponseMessage::Transactions(Ok(transactions)));
+                            let _ = node_b.send_response_msg(*request_id, V3ResponseMessage::Transactions(Ok(transactions)));
                         }
                         RequestMessage::TxPoolAllTransactionsIds => {
                             let tx_ids = (0..5).map(|_| Transaction::default_test_tx().id(&ChainId::new(1))).collect();
-                            let _ = node_b.send_response_msg(*request_id, V2ResponseMessage::TxPoolAllTransactionsIds(Ok(tx_ids)));
+                            let _ = node_b.send_response_msg(*request_id, V3ResponseMessage::TxPoolAllTransactionsIds(Ok(tx_ids)));
                         }
                         RequestMessage::TxPoolFullTransactions(tx_ids) => {
                             let txs = tx_ids.iter().enumerate().map(|(i, _)| {
@@ -1160,7 +1208,11 @@ async fn request_response_works_with(
                                     Some(NetworkableTransactionPool::Transaction(Transaction::default_test_tx()))
                                 }
                             }).collect();
-                            let _ = node_b.send_response_msg(*request_id, V2ResponseMessage::TxPoolFullTransactions(Ok(txs)));
+                            let _ = node_b.send_response_msg(*request_id, V3ResponseMessage::TxPoolFullTransactions(Ok(txs)));
+                        }
+                        RequestMessage::SealedBlocks(range) => {
+                            let sealed_blocks = range.clone().map(|_| SealedBlock::default()).collect();
+                            let _ = node_b.send_response_msg(*request_id, V3ResponseMessage::SealedBlocks(Ok(sealed_blocks)));
                         }
                     }
                 }
@@ -1186,6 +1238,13 @@ async fn request_response_works_with_sealed_headers_range_inclusive() {
         .await
 }
 
+#[tokio::test]
+#[instrument]
+async fn request_response_works_with_sealed_blocks() {
+    let arbitrary_range = 2..6;
+    request_response_works_with(RequestMessage::SealedBlocks(arbitrary_range), None).await
+}
+
 #[tokio::test]
 #[instrument]
 async fn request_response_works_with_transactions_ids() {
@@ -1274,7 +1333,7 @@ async fn invalid_response_type_is_detected() {
                 // 2. Node B receives the RequestMessage from Node A initiated by the NetworkOrchestrator
                 if let Some(FuelP2PEvent::InboundRequestMessage{ request_id, request_message: _ }) = &node_b_event {
                     let sealed_headers: Vec<_> = arbitrary_headers_for_range(1..3);
-                    let _ = node_b.send_response_msg(*request_id, V2ResponseMessage::SealedHeaders(Ok(sealed_headers)));
+                    let _ = node_b.send_response_msg(*request_id, V3ResponseMessage::SealedHeaders(Ok(sealed_headers)));
                 }
 
                 tracing::info!("Node B Event: {:?}", node_b_event);
@@ -1,6 +1,14 @@
 use crate::{
     gossipsub_config::GRAYLIST_THRESHOLD,
-    peer_manager::heartbeat_data::HeartbeatData,
+    peer_manager::{
+        ban_list::{
+            Ban,
+            BanList,
+            BanTarget,
+        },
+        heartbeat_data::HeartbeatData,
+        request_stats::RequestStats,
+    },
 };
 use fuel_core_services::seqlock::{
     SeqLock,
@@ -20,28 +28,49 @@ use fuel_core_types::{
 use libp2p::{
     Multiaddr,
     PeerId,
+    multiaddr::Protocol,
 };
-use rand::seq::IteratorRandom;
-use std::collections::{
-    HashMap,
-    HashSet,
+use rand::seq::SliceRandom;
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    net::IpAddr,
+    time::{
+        Duration,
+        SystemTime,
+    },
 };
 use tracing::{
     debug,
     info,
 };
 
+pub mod ban_list;
 pub mod heartbeat_data;
+pub mod request_stats;
 
 /// At this point we better just ban the peer
 const MIN_GOSSIPSUB_SCORE_BEFORE_BAN: AppScore = GRAYLIST_THRESHOLD;
 
+/// The number of the best ranked peers among which the peer for the request is chosen.
+/// The random choice among several good peers avoids hammering the single best one.
+const PEER_SELECTION_CANDIDATES: usize = 3;
+
+/// The maximum number of the remembered scores of the disconnected peers.
+/// When the limit is reached, the score closest to the default one is forgotten first.
+const MAX_DISCONNECTED_PEERS_SCORES: usize = 10_000;
+
 // Info about a single Peer that we're connected to
 #[derive(Debug, Clone)]
 pub struct PeerInfo {
     pub peer_addresses: HashSet<Multiaddr>,
     pub client_version: Option<String>,
     pub heartbeat_data: HeartbeatData,
+    pub request_stats: RequestStats,
+    /// Whether the peer advertised the request-response protocol that serves the full blocks.
+    pub supports_sealed_blocks: bool,
     pub score: AppScore,
 }
 
@@ -51,6 +80,8 @@ impl PeerInfo {
             peer_addresses: HashSet::new(),
             client_version: None,
             heartbeat_data: HeartbeatData::new(heartbeat_avg_window),
+            request_stats: RequestStats::default(),
+            supports_sealed_blocks: false,
             score: DEFAULT_APP_SCORE,
         }
     }
@@ -66,6 +97,12 @@ pub struct PeerManager {
     connection_state_writer: SeqLockWriter<ConnectionState>,
     max_non_reserved_peers: usize,
     reserved_peers_updates: tokio::sync::broadcast::Sender<usize>,
+    /// Scores of the non-reserved peers that are not connected at the moment.
+    /// They are restored when the peer connects again.
+    disconnected_peers_scores: HashMap<PeerId, AppScore>,
+    /// The IP addresses of the established connections.
+    peers_ips: HashMap<PeerId, IpAddr>,
+    ban_list: BanList,
 }
 
 impl PeerManager {
@@ -83,6 +120,9 @@ impl PeerManager {
             connection_state_writer,
             max_non_reserved_peers,
             reserved_peers_updates,
+            disconnected_peers_scores: HashMap::new(),
+            peers_ips: HashMap::new(),
+            ban_list: BanList::default(),
         }
     }
 
@@ -95,7 +135,7 @@ impl PeerManager {
     }
 
     pub fn handle_gossip_score_update<T: Punisher>(
-        &self,
+        &mut self,
         peer_id: PeerId,
         gossip_score: f64,
         punisher: &mut T,
@@ -103,7 +143,7 @@ impl PeerManager {
         if gossip_score < self.score_config.min_gossip_score_allowed
             && !self.reserved_peers.contains(&peer_id)
         {
-            punisher.ban_peer(peer_id);
+            self.ban(BanTarget::Peer(peer_id), None, punisher);
         }
     }
 
@@ -133,10 +173,14 @@ impl PeerManager {
         peer_id: &PeerId,
         addresses: Vec<Multiaddr>,
         agent_version: String,
+        supports_sealed_blocks: bool,
     ) {
         let peers = self.get_assigned_peer_table_mut(peer_id);
         insert_client_version(peers, peer_id, agent_version);
         insert_peer_addresses(peers, peer_id, addresses);
+        if let Some(peer) = peers.get_mut(peer_id) {
+            peer.supports_sealed_blocks = supports_sealed_blocks;
+        }
     }
 
     pub fn batch_update_score_with_decay(&mut self) {
@@ -161,7 +205,7 @@ impl PeerManager {
                 info!(target: "fuel-p2p", "{reporting_service} updated {peer_id} with new score {score}");
 
                 if new_score < self.score_config.min_app_score_allowed {
-                    punisher.ban_peer(peer_id);
+                    self.ban(BanTarget::Peer(peer_id), None, punisher);
                 }
             }
             _ => {
@@ -206,14 +250,16 @@ impl PeerManager {
             let all_slots_taken = self.max_non_reserved_peers
                 == self.non_reserved_connected_peers.len().saturating_add(1);
 
-            if self.non_reserved_connected_peers.remove(&peer_id).is_some()
-                && all_slots_taken
-            {
-                // since all the slots were full prior to this disconnect
-                // let's allow new peer non-reserved peers connections
-                self.connection_state_writer.write(|data| {
-                    data.allow_new_peers();
-                });
+            if let Some(peer_info) = self.non_reserved_connected_peers.remove(&peer_id) {
+                self.remember_disconnected_peer_score(peer_id, peer_info.score);
+
+                if all_slots_taken {
+                    // since all the slots were full prior to this disconnect
+                    // let's allow new peer non-reserved peers connections
+                    self.connection_state_writer.write(|data| {
+                        data.allow_new_peers();
+                    });
+                }
             }
 
             false
@@ -225,20 +271,240 @@ impl PeerManager {
         }
     }
 
-    /// Find a peer that is holding the given block height.
-    pub fn get_peer_id_with_height(&self, height: &BlockHeight) -> Option<PeerId> {
+    /// Find a peer that is holding the given block height, skipping the `excluded_peers`.
+    /// The peer is chosen among the best ranked ones according to their request statistics.
+    pub fn get_peer_id_with_height(
+        &self,
+        height: &BlockHeight,
+        excluded_peers: &HashSet<PeerId>,
+    ) -> Option<PeerId> {
+        self.select_peer_with_height(height, excluded_peers, |_| true)
+    }
+
+    /// Same as [`Self::get_peer_id_with_height`], but only the peers
+    /// that support the request of the full sealed blocks are considered.
+    pub fn get_peer_id_with_sealed_blocks(
+        &self,
+        height: &BlockHeight,
+        excluded_peers: &HashSet<PeerId>,
+    ) -> Option<PeerId> {
+        self.select_peer_with_height(height, excluded_peers, |peer_info| {
+            peer_info.supports_sealed_blocks
+        })
+    }
+
+    fn select_peer_with_height(
+        &self,
+        height: &BlockHeight,
+        excluded_peers: &HashSet<PeerId>,
+        is_suitable: impl Fn(&PeerInfo) -> bool,
+    ) -> Option<PeerId> {
         let mut range = rand::thread_rng();
-        // TODO: Optimize the selection of the peer.
-        //  We can store pair `(peer id, height)` for all nodes(reserved and not) in the
-        //  https://docs.rs/sorted-vec/latest/sorted_vec/struct.SortedVec.html
-        self.non_reserved_connected_peers
-            .iter()
-            .chain(self.reserved_connected_peers.iter())
-            .filter(|(_, peer_info)| {
+        let mut candidates = self
+            .get_all_peers()
+            .filter(|(peer_id, peer_info)| {
                 peer_info.heartbeat_data.block_height >= Some(*height)
+                    && !excluded_peers.contains(peer_id)
+                    && is_suitable(peer_info)
             })
-            .map(|(peer_id, _)| *peer_id)
-            .choose(&mut range)
+            .map(|(peer_id, peer_info)| (*peer_id, peer_info.request_stats.rank()))
+            .collect::<Vec<_>>();
+        candidates.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+        candidates.truncate(PEER_SELECTION_CANDIDATES);
+        candidates.choose(&mut range).map(|(peer_id, _)| *peer_id)
+    }
+
+    /// Returns `true` if the peer is among the `count` peers with the best quality
+    /// of the responses. The load of the peers is ignored to keep the set stable.
+    pub fn is_among_best_peers(&self, peer_id: &PeerId, count: usize) -> bool {
+        let Some(peer) = self.get_peer_info(peer_id) else {
+            return false
+        };
+        let quality = peer.request_stats.quality();
+        let better_peers = self
+            .get_all_peers()
+            .filter(|(id, info)| *id != peer_id && info.request_stats.quality() > quality)
+            .count();
+        better_peers < count
+    }
+
+    /// Records that the request was sent to the peer.
+    pub fn handle_request_sent(&mut self, peer_id: &PeerId) {
+        let peers = self.get_assigned_peer_table_mut(peer_id);
+        if let Some(peer) = peers.get_mut(peer_id) {
+            peer.request_stats.on_request_sent();
+        }
+    }
+
+    /// Records that the peer responded to the request after `latency`
+    /// with a valid response of `bytes` size.
+    pub fn handle_request_succeeded(
+        &mut self,
+        peer_id: &PeerId,
+        latency: Duration,
+        bytes: u64,
+    ) {
+        let peers = self.get_assigned_peer_table_mut(peer_id);
+        if let Some(peer) = peers.get_mut(peer_id) {
+            peer.request_stats.on_success(latency, bytes);
+        }
+    }
+
+    /// Records that the request to the peer failed or the response was invalid.
+    pub fn handle_request_failed(&mut self, peer_id: &PeerId) {
+        let peers = self.get_assigned_peer_table_mut(peer_id);
+        if let Some(peer) = peers.get_mut(peer_id) {
+            peer.request_stats.on_failure();
+        }
+    }
+
+    /// Records that the peer rejected the request of the full sealed blocks because
+    /// it doesn't support the protocol. It is not counted as a failure of the peer,
+    /// but the peer isn't asked for the full blocks anymore.
+    pub fn handle_sealed_blocks_unsupported(&mut self, peer_id: &PeerId) {
+        let peers = self.get_assigned_peer_table_mut(peer_id);
+        if let Some(peer) = peers.get_mut(peer_id) {
+            peer.request_stats.on_request_canceled();
+            peer.supports_sealed_blocks = false;
+        }
+    }
+
+    /// Records the IP address of the new connection.
+    /// Returns `true` signaling that the connection should be closed
+    /// because the peer or its IP address is banned.
+    pub fn handle_connection_established(
+        &mut self,
+        peer_id: PeerId,
+        remote_address: &Multiaddr,
+    ) -> bool {
+        let now = SystemTime::now();
+        if let Some(ip) = ip_address(remote_address) {
+            self.peers_ips.insert(peer_id, ip);
+            if self.ban_list.is_banned(&BanTarget::Ip(ip), now) {
+                return true
+            }
+        }
+        self.ban_list.is_banned(&BanTarget::Peer(peer_id), now)
+    }
+
+    /// Handles the last connection to the peer getting closed.
+    pub fn handle_connection_closed(&mut self, peer_id: &PeerId) {
+        self.peers_ips.remove(peer_id);
+    }
+
+    /// Bans the `target` for the `duration`, or until it is unbanned if the `duration` is not set.
+    /// Connected peers affected by the ban are disconnected.
+    pub fn ban<T: Punisher>(
+        &mut self,
+        target: BanTarget,
+        duration: Option<Duration>,
+        punisher: &mut T,
+    ) {
+        info!(target: "fuel-p2p", "Banning {target:?} for {duration:?}");
+        self.ban_list.ban(target, duration, SystemTime::now());
+        match target {
+            BanTarget::Peer(peer_id) => punisher.ban_peer(peer_id),
+            BanTarget::Ip(ip) => {
+                let peers = self
+                    .peers_ips
+                    .iter()
+                    .filter(|(_, peer_ip)| **peer_ip == ip)
+                    .map(|(peer_id, _)| *peer_id);
+                for peer_id in peers {
+                    punisher.disconnect_peer(peer_id);
+                }
+            }
+        }
+    }
+
+    /// Removes the ban of the `target`. The unbanned peer starts with the default score.
+    /// Returns `true` if the `target` was banned.
+    pub fn unban<T: Punisher>(&mut self, target: &BanTarget, punisher: &mut T) -> bool {
+        let unbanned = self.ban_list.unban(target);
+        if let BanTarget::Peer(peer_id) = target {
+            self.lift_peer_ban(*peer_id, punisher);
+        }
+        unbanned
+    }
+
+    /// Removes the bans that have expired.
+    pub fn remove_expired_bans<T: Punisher>(&mut self, punisher: &mut T) {
+        for target in self.ban_list.remove_expired(SystemTime::now()) {
+            info!(target: "fuel-p2p", "The ban of {target:?} has expired");
+            if let BanTarget::Peer(peer_id) = target {
+                self.lift_peer_ban(peer_id, punisher);
+            }
+        }
+    }
+
+    pub fn bans(&self) -> Vec<Ban> {
+        self.ban_list.bans()
+    }
+
+    /// Restores the reputation scores saved by the previous run of the node.
+    /// Peers with the score below the allowed minimum stay banned until they are unbanned.
+    pub fn restore_reputations<T: Punisher>(
+        &mut self,
+        reputations: Vec<(PeerId, AppScore)>,
+        punisher: &mut T,
+    ) {
+        for (peer_id, score) in reputations {
+            if self.reserved_peers.contains(&peer_id) {
+                continue
+            }
+
+            if score < self.score_config.min_app_score_allowed {
+                self.ban(BanTarget::Peer(peer_id), None, punisher);
+            }
+            self.remember_disconnected_peer_score(peer_id, score);
+        }
+    }
+
+    /// Remembers the score of the disconnected peer until it connects again.
+    /// The default score is not remembered, and the number of the remembered
+    /// scores is limited by [`MAX_DISCONNECTED_PEERS_SCORES`].
+    fn remember_disconnected_peer_score(&mut self, peer_id: PeerId, score: AppScore) {
+        if is_default_score(score) {
+            self.disconnected_peers_scores.remove(&peer_id);
+            return
+        }
+
+        if self.disconnected_peers_scores.len() >= MAX_DISCONNECTED_PEERS_SCORES
+            && !self.disconnected_peers_scores.contains_key(&peer_id)
+        {
+            let closest_to_default = self
+                .disconnected_peers_scores
+                .iter()
+                .map(|(peer_id, score)| (*peer_id, distance_to_default(*score)))
+                .min_by(|(_, a), (_, b)| a.total_cmp(b));
+            if let Some((closest_peer_id, distance)) = closest_to_default {
+                if distance > distance_to_default(score) {
+                    return
+                }
+                self.disconnected_peers_scores.remove(&closest_peer_id);
+            }
+        }
+        self.disconnected_peers_scores.insert(peer_id, score);
+    }
+
+    /// Returns the reputation scores of the non-reserved peers, connected or not,
+    /// that differ from the default score.
+    pub fn reputations(&self) -> Vec<(PeerId, AppScore)> {
+        self.non_reserved_connected_peers
+            .iter()
+            .map(|(peer_id, peer_info)| (*peer_id, peer_info.score))
+            .chain(
+                self.disconnected_peers_scores
+                    .iter()
+                    .map(|(peer_id, score)| (*peer_id, *score)),
+            )
+            .filter(|(_, score)| !is_default_score(*score))
+            .collect()
+    }
+
+    fn lift_peer_ban<T: Punisher>(&mut self, peer_id: PeerId, punisher: &mut T) {
+        punisher.unban_peer(peer_id);
+        self.disconnected_peers_scores.remove(&peer_id);
     }
 
     /// Handles the first connection established with a Peer
@@ -264,8 +530,12 @@ impl PeerManager {
                 });
             }
 
+            let mut peer_info = PeerInfo::new(HEARTBEAT_AVG_WINDOW);
+            if let Some(score) = self.disconnected_peers_scores.remove(peer_id) {
+                peer_info.score = score;
+            }
             self.non_reserved_connected_peers
-                .insert(*peer_id, PeerInfo::new(HEARTBEAT_AVG_WINDOW));
+                .insert(*peer_id, peer_info);
         } else if is_reserved && !self.reserved_connected_peers.contains_key(peer_id) {
             self.reserved_connected_peers
                 .insert(*peer_id, PeerInfo::new(HEARTBEAT_AVG_WINDOW));
@@ -339,6 +609,14 @@ impl ConnectionState {
     }
 }
 
+fn ip_address(address: &Multiaddr) -> Option<IpAddr> {
+    address.iter().find_map(|protocol| match protocol {
+        Protocol::Ip4(ip) => Some(ip.into()),
+        Protocol::Ip6(ip) => Some(ip.into()),
+        _ => None,
+    })
+}
+
 fn update_heartbeat(
     peers: &mut HashMap<PeerId, PeerInfo>,
     peer_id: &PeerId,
@@ -392,8 +670,21 @@ impl ScoreConfig {
 
 pub trait Punisher {
     fn ban_peer(&mut self, peer_id: PeerId);
+
+    fn unban_peer(&mut self, peer_id: PeerId);
+
+    fn disconnect_peer(&mut self, peer_id: PeerId);
+}
+
+fn distance_to_default(score: AppScore) -> f64 {
+    (score - DEFAULT_APP_SCORE).abs()
+}
+
+fn is_default_score(score: AppScore) -> bool {
+    distance_to_default(score) <= f64::EPSILON
 }
 
+#[allow(non_snake_case)]
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -493,4 +784,370 @@ mod tests {
             reserved_peers.len() + max_non_reserved_peers
         );
     }
+
+    fn connect_peers_with_height(
+        peer_manager: &mut PeerManager,
+        count: usize,
+        height: BlockHeight,
+    ) -> Vec<PeerId> {
+        let peers = get_random_peers(count);
+        for peer_id in &peers {
+            peer_manager.handle_initial_connection(peer_id);
+            peer_manager.handle_peer_info_updated(peer_id, height);
+        }
+        peers
+    }
+
+    #[test]
+    fn get_peer_id_with_height__skips_peers_below_height() {
+        // Given
+        let mut peer_manager = initialize_peer_manager(vec![], 10);
+        let low_peers =
+            connect_peers_with_height(&mut peer_manager, 5, BlockHeight::new(10));
+        let high_peers =
+            connect_peers_with_height(&mut peer_manager, 1, BlockHeight::new(20));
+
+        // When
+        let selected =
+            peer_manager.get_peer_id_with_height(&BlockHeight::new(15), &HashSet::new());
+
+        // Then
+        assert_eq!(selected, high_peers.first().copied());
+        assert!(!low_peers.contains(&selected.unwrap()));
+    }
+
+    #[test]
+    fn get_peer_id_with_height__avoids_failing_peers() {
+        // Given
+        let mut peer_manager = initialize_peer_manager(vec![], 10);
+        let peers = connect_peers_with_height(&mut peer_manager, 6, BlockHeight::new(10));
+        let (good_peers, failing_peers) = peers.split_at(PEER_SELECTION_CANDIDATES);
+        for peer_id in good_peers {
+            peer_manager.handle_request_sent(peer_id);
+            peer_manager.handle_request_succeeded(
+                peer_id,
+                Duration::from_millis(100),
+                1024,
+            );
+        }
+        for peer_id in failing_peers {
+            for _ in 0..10 {
+                peer_manager.handle_request_sent(peer_id);
+                peer_manager.handle_request_failed(peer_id);
+            }
+        }
+
+        // When
+        let selected = (0..100)
+            .filter_map(|_| {
+                peer_manager
+                    .get_peer_id_with_height(&BlockHeight::new(10), &HashSet::new())
+            })
+            .collect::<HashSet<_>>();
+
+        // Then
+        assert!(selected.iter().all(|peer_id| good_peers.contains(peer_id)));
+    }
+
+    #[test]
+    fn is_among_best_peers__ignores_failing_peers_and_load() {
+        // Given
+        let mut peer_manager = initialize_peer_manager(vec![], 10);
+        let peers = connect_peers_with_height(&mut peer_manager, 4, BlockHeight::new(10));
+        let (good_peers, failing_peers) = peers.split_at(2);
+        for peer_id in good_peers {
+            peer_manager.handle_request_sent(peer_id);
+            peer_manager.handle_request_succeeded(
+                peer_id,
+                Duration::from_millis(100),
+                1024,
+            );
+            peer_manager.handle_request_sent(peer_id);
+        }
+        for peer_id in failing_peers {
+            peer_manager.handle_request_sent(peer_id);
+            peer_manager.handle_request_failed(peer_id);
+        }
+
+        // When
+        let best = peers
+            .iter()
+            .filter(|peer_id| peer_manager.is_among_best_peers(peer_id, 2))
+            .copied()
+            .collect::<Vec<_>>();
+
+        // Then
+        assert_eq!(best, good_peers);
+    }
+
+    #[test]
+    fn get_peer_id_with_height__spreads_concurrent_requests() {
+        // Given
+        let mut peer_manager = initialize_peer_manager(vec![], 10);
+        connect_peers_with_height(&mut peer_manager, 5, BlockHeight::new(10));
+
+        // When
+        let mut selected = HashSet::new();
+        for _ in 0..5 {
+            let peer_id = peer_manager
+                .get_peer_id_with_height(&BlockHeight::new(10), &HashSet::new())
+                .unwrap();
+            peer_manager.handle_request_sent(&peer_id);
+            selected.insert(peer_id);
+        }
+
+        // Then
+        assert!(selected.len() > 1);
+    }
+
+    #[test]
+    fn get_peer_id_with_height__skips_excluded_peers() {
+        // Given
+        let mut peer_manager = initialize_peer_manager(vec![], 10);
+        let peers = connect_peers_with_height(&mut peer_manager, 2, BlockHeight::new(10));
+        let excluded_peers = peers.iter().take(1).copied().collect::<HashSet<_>>();
+
+        // When
+        let selected = (0..100)
+            .filter_map(|_| {
+                peer_manager
+                    .get_peer_id_with_height(&BlockHeight::new(10), &excluded_peers)
+            })
+            .collect::<HashSet<_>>();
+
+        // Then
+        assert_eq!(selected, peers.iter().skip(1).copied().collect());
+    }
+
+    #[derive(Default)]
+    struct FakePunisher {
+        banned: HashSet<PeerId>,
+        disconnected: HashSet<PeerId>,
+    }
+
+    impl Punisher for FakePunisher {
+        fn ban_peer(&mut self, peer_id: PeerId) {
+            self.banned.insert(peer_id);
+        }
+
+        fn unban_peer(&mut self, peer_id: PeerId) {
+            self.banned.remove(&peer_id);
+        }
+
+        fn disconnect_peer(&mut self, peer_id: PeerId) {
+            self.disconnected.insert(peer_id);
+        }
+    }
+
+    #[test]
+    fn restore_reputations__bans_peers_with_too_low_score() {
+        // Given
+        let mut peer_manager = initialize_peer_manager(vec![], 10);
+        let mut punisher = FakePunisher::default();
+        let bad_peer = PeerId::random();
+        let good_peer = PeerId::random();
+
+        // When
+        peer_manager.restore_reputations(
+            vec![(bad_peer, MIN_APP_SCORE - 1.0), (good_peer, 10.0)],
+            &mut punisher,
+        );
+
+        // Then
+        assert_eq!(punisher.banned, HashSet::from([bad_peer]));
+        assert!(peer_manager.handle_connection_established(
+            bad_peer,
+            &"/ip4/127.0.0.1/tcp/4000".parse().unwrap()
+        ));
+    }
+
+    #[test]
+    fn handle_peer_connected__restores_the_score_of_the_known_peer() {
+        // Given
+        let mut peer_manager = initialize_peer_manager(vec![], 10);
+        let mut punisher = FakePunisher::default();
+        let peer_id = PeerId::random();
+        peer_manager.restore_reputations(vec![(peer_id, -20.0)], &mut punisher);
+
+        // When
+        peer_manager.handle_peer_connected(&peer_id);
+
+        // Then
+        let score = peer_manager.get_peer_info(&peer_id).unwrap().score;
+        assert_eq!(score, -20.0);
+    }
+
+    #[test]
+    fn reputations__keeps_the_score_of_disconnected_peer() {
+        // Given
+        let mut peer_manager = initialize_peer_manager(vec![], 10);
+        let mut punisher = FakePunisher::default();
+        let peer_id = PeerId::random();
+        peer_manager.handle_peer_connected(&peer_id);
+        peer_manager.update_app_score(peer_id, -20.0, "test", &mut punisher);
+
+        // When
+        peer_manager.handle_peer_disconnect(peer_id);
+
+        // Then
+        assert_eq!(peer_manager.reputations(), vec![(peer_id, -20.0)]);
+    }
+
+    #[test]
+    fn handle_peer_disconnect__forgets_the_default_score() {
+        // Given
+        let mut peer_manager = initialize_peer_manager(vec![], 10);
+        let peer_id = PeerId::random();
+        peer_manager.handle_peer_connected(&peer_id);
+
+        // When
+        peer_manager.handle_peer_disconnect(peer_id);
+
+        // Then
+        assert!(peer_manager.disconnected_peers_scores.is_empty());
+    }
+
+    #[test]
+    fn restore_reputations__keeps_limited_number_of_the_most_notable_scores() {
+        // Given
+        let mut peer_manager = initialize_peer_manager(vec![], 10);
+        let mut punisher = FakePunisher::default();
+        let known_peers = (0..MAX_DISCONNECTED_PEERS_SCORES)
+            .map(|_| (PeerId::random(), -1.0))
+            .collect();
+        peer_manager.restore_reputations(known_peers, &mut punisher);
+        let bad_peer = PeerId::random();
+        let almost_default_peer = PeerId::random();
+
+        // When
+        peer_manager.restore_reputations(
+            vec![(bad_peer, -30.0), (almost_default_peer, -0.5)],
+            &mut punisher,
+        );
+
+        // Then
+        let scores = &peer_manager.disconnected_peers_scores;
+        assert_eq!(scores.len(), MAX_DISCONNECTED_PEERS_SCORES);
+        assert_eq!(scores.get(&bad_peer), Some(&-30.0));
+        assert!(!scores.contains_key(&almost_default_peer));
+    }
+
+    #[test]
+    fn ban__disconnects_peers_connected_from_the_banned_ip() {
+        // Given
+        let mut peer_manager = initialize_peer_manager(vec![], 10);
+        let mut punisher = FakePunisher::default();
+        let banned_ip_peer = PeerId::random();
+        let other_peer = PeerId::random();
+        peer_manager.handle_connection_established(
+            banned_ip_peer,
+            &"/ip4/10.0.0.1/tcp/4000".parse().unwrap(),
+        );
+        peer_manager.handle_connection_established(
+            other_peer,
+            &"/ip4/10.0.0.2/tcp/4000".parse().unwrap(),
+        );
+
+        // When
+        peer_manager.ban(
+            BanTarget::Ip([10, 0, 0, 1].into()),
+            Some(Duration::from_secs(60)),
+            &mut punisher,
+        );
+
+        // Then
+        assert_eq!(punisher.disconnected, HashSet::from([banned_ip_peer]));
+        assert!(punisher.banned.is_empty());
+        assert!(peer_manager.handle_connection_established(
+            PeerId::random(),
+            &"/ip4/10.0.0.1/tcp/5000".parse().unwrap()
+        ));
+    }
+
+    #[test]
+    fn unban__gives_the_peer_the_default_score() {
+        // Given
+        let mut peer_manager = initialize_peer_manager(vec![], 10);
+        let mut punisher = FakePunisher::default();
+        let peer_id = PeerId::random();
+        peer_manager
+            .restore_reputations(vec![(peer_id, MIN_APP_SCORE - 1.0)], &mut punisher);
+
+        // When
+        let unbanned = peer_manager.unban(&BanTarget::Peer(peer_id), &mut punisher);
+
+        // Then
+        assert!(unbanned);
+        assert!(punisher.banned.is_empty());
+        assert!(peer_manager.bans().is_empty());
+        assert!(peer_manager.reputations().is_empty());
+    }
+
+    #[test]
+    fn get_peer_id_with_height__returns_none_when_all_peers_are_excluded() {
+        // Given
+        let mut peer_manager = initialize_peer_manager(vec![], 10);
+        let peers = connect_peers_with_height(&mut peer_manager, 3, BlockHeight::new(10));
+        let excluded_peers = peers.into_iter().collect::<HashSet<_>>();
+
+        // When
+        let selected =
+            peer_manager.get_peer_id_with_height(&BlockHeight::new(10), &excluded_peers);
+
+        // Then
+        assert_eq!(selected, None);
+    }
+
+    #[test]
+    fn get_peer_id_with_sealed_blocks__skips_peers_without_support() {
+        // Given
+        let mut peer_manager = initialize_peer_manager(vec![], 10);
+        let peers = connect_peers_with_height(&mut peer_manager, 3, BlockHeight::new(10));
+        let supporting_peer = peers[0];
+        peer_manager.handle_peer_identified(
+            &supporting_peer,
+            vec![],
+            "fuel-core".to_string(),
+            true,
+        );
+
+        // When
+        let selected = (0..100)
+            .filter_map(|_| {
+                peer_manager.get_peer_id_with_sealed_blocks(
+                    &BlockHeight::new(10),
+                    &HashSet::new(),
+                )
+            })
+            .collect::<HashSet<_>>();
+
+        // Then
+        assert_eq!(selected, HashSet::from([supporting_peer]));
+    }
+
+    #[test]
+    fn handle_sealed_blocks_unsupported__stops_selecting_peer_without_failure() {
+        // Given
+        let mut peer_manager = initialize_peer_manager(vec![], 10);
+        let peers = connect_peers_with_height(&mut peer_manager, 1, BlockHeight::new(10));
+        let peer_id = peers[0];
+        peer_manager.handle_peer_identified(
+            &peer_id,
+            vec![],
+            "fuel-core".to_string(),
+            true,
+        );
+        peer_manager.handle_request_sent(&peer_id);
+
+        // When
+        peer_manager.handle_sealed_blocks_unsupported(&peer_id);
+
+        // Then
+        let selected = peer_manager
+            .get_peer_id_with_sealed_blocks(&BlockHeight::new(10), &HashSet::new());
+        assert_eq!(selected, None);
+        let request_stats = &peer_manager.get_peer_info(&peer_id).unwrap().request_stats;
+        assert_eq!(request_stats.failed_requests, 0);
+        assert_eq!(request_stats.in_flight_requests, 0);
+    }
 }
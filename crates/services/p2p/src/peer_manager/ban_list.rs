@@ -0,0 +1,153 @@
+use libp2p::PeerId;
+use std::{
+    collections::HashMap,
+    net::IpAddr,
+    time::{
+        Duration,
+        SystemTime,
+    },
+};
+
+/// The subject of the ban.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BanTarget {
+    Peer(PeerId),
+    /// All peers connecting from the IP address.
+    Ip(IpAddr),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ban {
+    pub target: BanTarget,
+    /// The time when the ban is lifted. `None` means the ban lasts until it is removed.
+    pub expires_at: Option<SystemTime>,
+}
+
+/// Banned peers and IP addresses along with the expiration time of their bans.
+///
+/// The bans are kept only in memory. The peers banned for the low reputation are
+/// banned again on start from the stored reputation, while the bans set by the operator
+/// are expected to be temporary measures and are lifted by the restart.
+#[derive(Debug, Default)]
+pub struct BanList {
+    bans: HashMap<BanTarget, Option<SystemTime>>,
+}
+
+impl BanList {
+    /// Bans the `target` for the `duration` starting from `now`, or until it is unbanned
+    /// if the `duration` is not set. The new ban replaces the existing one.
+    pub fn ban(
+        &mut self,
+        target: BanTarget,
+        duration: Option<Duration>,
+        now: SystemTime,
+    ) {
+        let expires_at = duration.and_then(|duration| now.checked_add(duration));
+        self.bans.insert(target, expires_at);
+    }
+
+    /// Returns `true` if the `target` was banned.
+    pub fn unban(&mut self, target: &BanTarget) -> bool {
+        self.bans.remove(target).is_some()
+    }
+
+    pub fn is_banned(&self, target: &BanTarget, now: SystemTime) -> bool {
+        match self.bans.get(target) {
+            Some(Some(expires_at)) => *expires_at > now,
+            Some(None) => true,
+            None => false,
+        }
+    }
+
+    /// Removes the bans that have expired by `now` and returns their targets.
+    pub fn remove_expired(&mut self, now: SystemTime) -> Vec<BanTarget> {
+        let expired = self
+            .bans
+            .iter()
+            .filter(|(_, expires_at)| {
+                expires_at.is_some_and(|expires_at| expires_at <= now)
+            })
+            .map(|(target, _)| *target)
+            .collect::<Vec<_>>();
+        for target in &expired {
+            self.bans.remove(target);
+        }
+        expired
+    }
+
+    pub fn bans(&self) -> Vec<Ban> {
+        self.bans
+            .iter()
+            .map(|(target, expires_at)| Ban {
+                target: *target,
+                expires_at: *expires_at,
+            })
+            .collect()
+    }
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_banned__true_until_the_ban_expires() {
+        // Given
+        let mut ban_list = BanList::default();
+        let target = BanTarget::Peer(PeerId::random());
+        let now = SystemTime::now();
+        ban_list.ban(target, Some(Duration::from_secs(10)), now);
+
+        // When
+        let banned_before = ban_list.is_banned(&target, now + Duration::from_secs(9));
+        let banned_after = ban_list.is_banned(&target, now + Duration::from_secs(10));
+
+        // Then
+        assert!(banned_before);
+        assert!(!banned_after);
+    }
+
+    #[test]
+    fn remove_expired__keeps_active_and_permanent_bans() {
+        // Given
+        let mut ban_list = BanList::default();
+        let expired = BanTarget::Ip([10, 0, 0, 1].into());
+        let active = BanTarget::Ip([10, 0, 0, 2].into());
+        let permanent = BanTarget::Peer(PeerId::random());
+        let now = SystemTime::now();
+        ban_list.ban(expired, Some(Duration::from_secs(1)), now);
+        ban_list.ban(active, Some(Duration::from_secs(100)), now);
+        ban_list.ban(permanent, None, now);
+
+        // When
+        let removed = ban_list.remove_expired(now + Duration::from_secs(10));
+
+        // Then
+        assert_eq!(removed, vec![expired]);
+        let mut remaining = ban_list
+            .bans()
+            .into_iter()
+            .map(|ban| ban.target)
+            .collect::<Vec<_>>();
+        remaining.sort_by_key(|target| matches!(target, BanTarget::Peer(_)));
+        assert_eq!(remaining, vec![active, permanent]);
+    }
+
+    #[test]
+    fn unban__removes_the_ban() {
+        // Given
+        let mut ban_list = BanList::default();
+        let target = BanTarget::Peer(PeerId::random());
+        let now = SystemTime::now();
+        ban_list.ban(target, None, now);
+
+        // When
+        let unbanned = ban_list.unban(&target);
+
+        // Then
+        assert!(unbanned);
+        assert!(!ban_list.is_banned(&target, now));
+        assert!(!ban_list.unban(&target));
+    }
+}
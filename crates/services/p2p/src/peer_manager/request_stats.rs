@@ -0,0 +1,143 @@
+use std::time::Duration;
+
+/// The weight of the newest sample in the moving average of the latency.
+const LATENCY_SMOOTHING_FACTOR: f64 = 0.2;
+
+/// The latency assumed for the peer that hasn't served any request yet.
+/// It is optimistic enough to give new peers a chance to be selected.
+const DEFAULT_LATENCY: Duration = Duration::from_millis(500);
+
+/// Statistics of the request-response interactions with a single peer.
+#[derive(Debug, Clone, Default)]
+pub struct RequestStats {
+    pub successful_requests: u64,
+    pub failed_requests: u64,
+    pub bytes_served: u64,
+    /// The exponential moving average of the response latency.
+    pub avg_latency: Option<Duration>,
+    /// The number of requests sent to the peer that are still waiting for the response.
+    pub in_flight_requests: u64,
+}
+
+impl RequestStats {
+    pub fn on_request_sent(&mut self) {
+        self.in_flight_requests = self.in_flight_requests.saturating_add(1);
+    }
+
+    pub fn on_success(&mut self, latency: Duration, bytes: u64) {
+        self.in_flight_requests = self.in_flight_requests.saturating_sub(1);
+        self.successful_requests = self.successful_requests.saturating_add(1);
+        self.bytes_served = self.bytes_served.saturating_add(bytes);
+        self.avg_latency = Some(match self.avg_latency {
+            Some(avg_latency) => avg_latency
+                .mul_f64(1.0 - LATENCY_SMOOTHING_FACTOR)
+                .saturating_add(latency.mul_f64(LATENCY_SMOOTHING_FACTOR)),
+            None => latency,
+        });
+    }
+
+    pub fn on_failure(&mut self) {
+        self.in_flight_requests = self.in_flight_requests.saturating_sub(1);
+        self.failed_requests = self.failed_requests.saturating_add(1);
+    }
+
+    /// The request ended without telling anything about the quality of the peer.
+    pub fn on_request_canceled(&mut self) {
+        self.in_flight_requests = self.in_flight_requests.saturating_sub(1);
+    }
+
+    /// The ratio of successful requests, smoothed towards `0.5`
+    /// while the peer has only a few samples.
+    pub fn success_rate(&self) -> f64 {
+        let successful = self.successful_requests as f64 + 1.0;
+        let failed = self.failed_requests as f64 + 1.0;
+        successful / (successful + failed)
+    }
+
+    /// The quality of the peer regardless of its current load. The higher is better.
+    pub fn quality(&self) -> f64 {
+        let latency = self.avg_latency.unwrap_or(DEFAULT_LATENCY).as_secs_f64();
+        self.success_rate() / latency.max(f64::EPSILON)
+    }
+
+    /// The rank of the peer for the next request. The higher is better.
+    ///
+    /// Reliable and fast peers are preferred, while the requests that are already
+    /// in flight lower the rank to spread the concurrent requests between peers.
+    pub fn rank(&self) -> f64 {
+        let load = self.in_flight_requests as f64 + 1.0;
+        self.quality() / load
+    }
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rank__prefers_reliable_peer() {
+        // Given
+        let mut reliable = RequestStats::default();
+        let mut unreliable = RequestStats::default();
+
+        // When
+        for _ in 0..10 {
+            reliable.on_request_sent();
+            reliable.on_success(DEFAULT_LATENCY, 100);
+            unreliable.on_request_sent();
+            unreliable.on_failure();
+        }
+
+        // Then
+        assert!(reliable.rank() > unreliable.rank());
+        assert_eq!(reliable.bytes_served, 1000);
+        assert_eq!(reliable.in_flight_requests, 0);
+    }
+
+    #[test]
+    fn rank__prefers_fast_peer() {
+        // Given
+        let mut fast = RequestStats::default();
+        let mut slow = RequestStats::default();
+
+        // When
+        for _ in 0..10 {
+            fast.on_request_sent();
+            fast.on_success(Duration::from_millis(50), 0);
+            slow.on_request_sent();
+            slow.on_success(Duration::from_secs(5), 0);
+        }
+
+        // Then
+        assert!(fast.rank() > slow.rank());
+    }
+
+    #[test]
+    fn rank__is_lowered_by_requests_in_flight() {
+        // Given
+        let idle = RequestStats::default();
+        let mut busy = RequestStats::default();
+
+        // When
+        busy.on_request_sent();
+
+        // Then
+        assert!(idle.rank() > busy.rank());
+    }
+
+    #[test]
+    fn on_success__moves_average_latency_towards_new_sample() {
+        // Given
+        let mut stats = RequestStats::default();
+        stats.on_success(Duration::from_secs(1), 0);
+
+        // When
+        stats.on_success(Duration::from_secs(2), 0);
+
+        // Then
+        let avg_latency = stats.avg_latency.unwrap();
+        assert!(avg_latency > Duration::from_secs(1));
+        assert!(avg_latency < Duration::from_secs(2));
+    }
+}
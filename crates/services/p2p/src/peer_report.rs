@@ -64,6 +64,7 @@ pub enum PeerReportEvent {
         peer_id: PeerId,
     },
     /// Informs p2p service / PeerManager to perform reputation decay of connected nodes
+    /// and to lift the expired bans
     PerformDecay,
 }
 
@@ -0,0 +1,127 @@
+//! The file that keeps the reputation of the peers between restarts of the node.
+//!
+//! The reputation belongs to the p2p service rather than to the chain, so it is kept
+//! in its own file next to the database instead of the database itself.
+
+use crate::ports::PeerReputationStore;
+use fuel_core_types::services::p2p::{
+    PeerId,
+    peer_reputation::AppScore,
+};
+use parking_lot::Mutex;
+use std::{
+    fs::File,
+    io::{
+        self,
+        Write,
+    },
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::Arc,
+};
+
+/// The storage of the peers' reputation in the file.
+/// Without the path, the reputation is not kept between restarts.
+#[derive(Debug, Clone, Default)]
+pub struct PeerReputationFile {
+    path: Option<PathBuf>,
+    /// Serializes the writes of the file, they share the temporary file.
+    write_lock: Arc<Mutex<()>>,
+}
+
+impl PeerReputationFile {
+    pub fn new(path: Option<PathBuf>) -> Self {
+        Self {
+            path,
+            write_lock: Default::default(),
+        }
+    }
+}
+
+impl PeerReputationStore for PeerReputationFile {
+    fn load_peer_reputations(&self) -> anyhow::Result<Vec<(PeerId, AppScore)>> {
+        let Some(path) = &self.path else {
+            return Ok(vec![])
+        };
+        let content = match std::fs::read(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(err) => return Err(err.into()),
+        };
+
+        postcard::from_bytes(&content).map_err(|e| {
+            anyhow::anyhow!("Failed to decode the reputation of the peers: {e}")
+        })
+    }
+
+    fn store_peer_reputations(
+        &self,
+        reputations: Vec<(PeerId, AppScore)>,
+    ) -> anyhow::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(())
+        };
+        let content = postcard::to_stdvec(&reputations)?;
+        let _guard = self.write_lock.lock();
+        write(path, &content)?;
+        Ok(())
+    }
+}
+
+/// Replaces the content of the file at `path` with the `content`.
+fn write(path: &Path, content: &[u8]) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let tmp_path = path.with_extension("tmp");
+    {
+        let mut file = File::create(&tmp_path)?;
+        file.write_all(content)?;
+        file.sync_all()?;
+    }
+    std::fs::rename(&tmp_path, path)
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn load_peer_reputations__returns_stored_reputations() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = PeerReputationFile::new(Some(dir.path().join("peer_reputations")));
+
+        // Given
+        let reputations = vec![
+            (PeerId::from(vec![1; 32]), -20.0),
+            (PeerId::from(vec![2; 32]), 10.0),
+        ];
+        file.store_peer_reputations(reputations.clone()).unwrap();
+        file.store_peer_reputations(reputations[1..].to_vec())
+            .unwrap();
+
+        // When
+        let result = file.load_peer_reputations().unwrap();
+
+        // Then
+        assert_eq!(result, reputations[1..]);
+    }
+
+    #[test]
+    fn load_peer_reputations__returns_nothing_when_file_does_not_exist() {
+        let dir = tempfile::tempdir().unwrap();
+
+        // Given
+        let file = PeerReputationFile::new(Some(dir.path().join("peer_reputations")));
+
+        // When
+        let result = file.load_peer_reputations().unwrap();
+
+        // Then
+        assert!(result.is_empty());
+    }
+}
@@ -14,9 +14,11 @@ use fuel_core_types::{
         DelegatePublicKey,
         GossipData,
         NetworkableTransactionPool,
+        PeerId,
         PreConfirmationMessage,
         ProtocolSignature,
         Transactions,
+        peer_reputation::AppScore,
     },
 };
 use std::ops::Range;
@@ -35,9 +37,24 @@ pub trait P2pDb: Send + Sync {
     fn get_genesis(&self) -> StorageResult<Genesis>;
 }
 
+/// The storage of the peers' reputation that survives restarts of the node.
+pub trait PeerReputationStore: Send + Sync {
+    /// Returns the reputation scores saved by the previous runs of the node.
+    fn load_peer_reputations(&self) -> anyhow::Result<Vec<(PeerId, AppScore)>>;
+
+    /// Replaces the saved reputation scores with the `reputations`.
+    fn store_peer_reputations(
+        &self,
+        reputations: Vec<(PeerId, AppScore)>,
+    ) -> anyhow::Result<()>;
+}
+
 pub trait BlockHeightImporter: Send + Sync {
     /// Creates a stream of next block heights
     fn next_block_height(&self) -> BoxStream<BlockHeight>;
+
+    /// Creates a stream of headers of the blocks produced by this node
+    fn locally_produced_headers(&self) -> BoxStream<SealedBlockHeader>;
 }
 
 pub trait TxPool: Send + Sync + Clone {
@@ -1,6 +1,9 @@
 use crate::service::TaskError;
 use fuel_core_types::{
-    blockchain::SealedBlockHeader,
+    blockchain::{
+        SealedBlock,
+        SealedBlockHeader,
+    },
     fuel_tx::TxId,
     services::p2p::{
         NetworkableTransactionPool,
@@ -15,7 +18,10 @@ use serde::{
     Deserialize,
     Serialize,
 };
-use std::ops::Range;
+use std::{
+    io,
+    ops::Range,
+};
 use thiserror::Error;
 use tokio::sync::oneshot;
 
@@ -25,6 +31,8 @@ pub enum RequestMessage {
     Transactions(Range<u32>),
     TxPoolAllTransactionsIds,
     TxPoolFullTransactions(Vec<TxId>),
+    /// Full sealed blocks, supported only by the protocol `/fuel/req_res/0.0.3`.
+    SealedBlocks(Range<u32>),
 }
 
 #[derive(Error, Debug, Clone, Serialize, Deserialize)]
@@ -61,6 +69,17 @@ pub enum V2ResponseMessage {
     ),
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum V3ResponseMessage {
+    SealedHeaders(Result<Vec<SealedBlockHeader>, ResponseMessageErrorCode>),
+    Transactions(Result<Vec<Transactions>, ResponseMessageErrorCode>),
+    TxPoolAllTransactionsIds(Result<Vec<TxId>, ResponseMessageErrorCode>),
+    TxPoolFullTransactions(
+        Result<Vec<Option<NetworkableTransactionPool>>, ResponseMessageErrorCode>,
+    ),
+    SealedBlocks(Result<Vec<SealedBlock>, ResponseMessageErrorCode>),
+}
+
 impl From<V1ResponseMessage> for V2ResponseMessage {
     fn from(v1_response: V1ResponseMessage) -> Self {
         match v1_response {
@@ -106,6 +125,52 @@ impl From<V2ResponseMessage> for V1ResponseMessage {
     }
 }
 
+impl From<V2ResponseMessage> for V3ResponseMessage {
+    fn from(v2_response: V2ResponseMessage) -> Self {
+        match v2_response {
+            V2ResponseMessage::SealedHeaders(sealed_headers) => {
+                V3ResponseMessage::SealedHeaders(sealed_headers)
+            }
+            V2ResponseMessage::Transactions(transactions) => {
+                V3ResponseMessage::Transactions(transactions)
+            }
+            V2ResponseMessage::TxPoolAllTransactionsIds(tx_ids) => {
+                V3ResponseMessage::TxPoolAllTransactionsIds(tx_ids)
+            }
+            V2ResponseMessage::TxPoolFullTransactions(tx_pool) => {
+                V3ResponseMessage::TxPoolFullTransactions(tx_pool)
+            }
+        }
+    }
+}
+
+impl TryFrom<V3ResponseMessage> for V2ResponseMessage {
+    type Error = UnsupportedByProtocolError;
+
+    fn try_from(response: V3ResponseMessage) -> Result<Self, Self::Error> {
+        match response {
+            V3ResponseMessage::SealedHeaders(sealed_headers) => {
+                Ok(V2ResponseMessage::SealedHeaders(sealed_headers))
+            }
+            V3ResponseMessage::Transactions(transactions) => {
+                Ok(V2ResponseMessage::Transactions(transactions))
+            }
+            V3ResponseMessage::TxPoolAllTransactionsIds(tx_ids) => {
+                Ok(V2ResponseMessage::TxPoolAllTransactionsIds(tx_ids))
+            }
+            V3ResponseMessage::TxPoolFullTransactions(tx_pool) => {
+                Ok(V2ResponseMessage::TxPoolFullTransactions(tx_pool))
+            }
+            V3ResponseMessage::SealedBlocks(_) => Err(UnsupportedByProtocolError),
+        }
+    }
+}
+
+/// The message can't be represented by the negotiated request-response protocol.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("The message is not supported by the negotiated protocol")]
+pub struct UnsupportedByProtocolError;
+
 pub type OnResponse<T> = oneshot::Sender<(PeerId, Result<T, ResponseError>)>;
 // This type is more complex because it's used in tasks that need to select a peer to send the request and this
 // can cause errors where the peer is not defined.
@@ -130,6 +195,9 @@ pub enum ResponseSender {
             Result<Vec<Option<NetworkableTransactionPool>>, ResponseMessageErrorCode>,
         >,
     ),
+    SealedBlocks(
+        OnResponseWithPeerSelection<Result<Vec<SealedBlock>, ResponseMessageErrorCode>>,
+    ),
 }
 
 #[derive(Debug, Error)]
@@ -148,6 +216,32 @@ pub enum ResponseError {
     TypeMismatch,
 }
 
+impl ResponseError {
+    /// Returns `true` if the peer couldn't serve the request
+    /// because it doesn't support the required protocol.
+    pub fn is_unsupported_protocol(&self) -> bool {
+        match self {
+            ResponseError::P2P(failure) => is_unsupported_protocol(failure),
+            ResponseError::TypeMismatch => false,
+        }
+    }
+}
+
+/// Returns `true` if the request failed because the peer doesn't support any protocol
+/// or the negotiated protocol can't represent the request.
+pub fn is_unsupported_protocol(failure: &OutboundFailure) -> bool {
+    match failure {
+        OutboundFailure::UnsupportedProtocols => true,
+        OutboundFailure::Io(error) => {
+            error.kind() == io::ErrorKind::InvalidInput
+                && error
+                    .get_ref()
+                    .is_some_and(|error| error.is::<UnsupportedByProtocolError>())
+        }
+        _ => false,
+    }
+}
+
 /// Errors than can occur when attempting to send a response
 #[derive(Debug, Eq, PartialEq, Error)]
 pub enum ResponseSendError {
@@ -2,11 +2,13 @@ use strum_macros::EnumIter;
 
 pub(crate) const V1_REQUEST_RESPONSE_PROTOCOL_ID: &str = "/fuel/req_res/0.0.1";
 pub(crate) const V2_REQUEST_RESPONSE_PROTOCOL_ID: &str = "/fuel/req_res/0.0.2";
+pub(crate) const V3_REQUEST_RESPONSE_PROTOCOL_ID: &str = "/fuel/req_res/0.0.3";
 
 #[derive(Debug, Clone, EnumIter)]
 pub enum RequestResponseProtocol {
     V1,
     V2,
+    V3,
 }
 
 impl AsRef<str> for RequestResponseProtocol {
@@ -14,6 +16,7 @@ impl AsRef<str> for RequestResponseProtocol {
         match self {
             RequestResponseProtocol::V1 => V1_REQUEST_RESPONSE_PROTOCOL_ID,
             RequestResponseProtocol::V2 => V2_REQUEST_RESPONSE_PROTOCOL_ID,
+            RequestResponseProtocol::V3 => V3_REQUEST_RESPONSE_PROTOCOL_ID,
         }
     }
 }
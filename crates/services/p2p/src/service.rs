@@ -17,12 +17,19 @@ use crate::{
         FuelP2PEvent,
         FuelP2PService,
     },
-    peer_manager::PeerInfo,
+    peer_manager::{
+        PeerInfo,
+        ban_list::{
+            Ban,
+            BanTarget,
+        },
+    },
     ports::{
         BlockHeightImporter,
         P2PPreConfirmationGossipData,
         P2PPreConfirmationMessage,
         P2pDb,
+        PeerReputationStore,
         TxPool,
     },
     request_response::messages::{
@@ -31,7 +38,7 @@ use crate::{
         RequestMessage,
         ResponseMessageErrorCode,
         ResponseSender,
-        V2ResponseMessage,
+        V3ResponseMessage,
     },
 };
 use anyhow::anyhow;
@@ -49,7 +56,11 @@ use fuel_core_services::{
 };
 use fuel_core_storage::transactional::AtomicView;
 use fuel_core_types::{
-    blockchain::SealedBlockHeader,
+    blockchain::{
+        SealedBlock,
+        SealedBlockHeader,
+        block::Block,
+    },
     fuel_tx::{
         Transaction,
         TxId,
@@ -60,11 +71,14 @@ use fuel_core_types::{
         ChainId,
     },
     services::p2p::{
+        BanTarget as FuelBanTarget,
         BlockHeightHeartbeatData,
         GossipData,
         GossipsubMessageAcceptance,
         GossipsubMessageInfo,
         NetworkableTransactionPool,
+        NewBlockGossipData,
+        PeerBan,
         PeerId as FuelPeerId,
         TransactionGossipData,
         Transactions,
@@ -88,6 +102,7 @@ use libp2p::{
     request_response::InboundRequestId,
 };
 use std::{
+    collections::HashSet,
     fmt::Debug,
     future::Future,
     ops::Range,
@@ -136,16 +151,25 @@ pub enum TaskRequest {
     },
     GetSealedHeaders {
         block_height_range: Range<u32>,
+        excluded_peers: HashSet<PeerId>,
         channel: OnResponseWithPeerSelection<
             Result<Vec<SealedBlockHeader>, ResponseMessageErrorCode>,
         >,
     },
     GetTransactions {
         block_height_range: Range<u32>,
+        excluded_peers: HashSet<PeerId>,
         channel: OnResponseWithPeerSelection<
             Result<Vec<Transactions>, ResponseMessageErrorCode>,
         >,
     },
+    GetSealedBlocks {
+        block_height_range: Range<u32>,
+        excluded_peers: HashSet<PeerId>,
+        channel: OnResponseWithPeerSelection<
+            Result<Vec<SealedBlock>, ResponseMessageErrorCode>,
+        >,
+    },
     GetTransactionsFromPeer {
         block_height_range: Range<u32>,
         from_peer: PeerId,
@@ -169,6 +193,17 @@ pub enum TaskRequest {
         score: AppScore,
         reporting_service: &'static str,
     },
+    BanPeer {
+        target: BanTarget,
+        duration: Option<Duration>,
+    },
+    UnbanPeer {
+        target: BanTarget,
+        channel: oneshot::Sender<bool>,
+    },
+    GetPeerBans {
+        channel: oneshot::Sender<Vec<Ban>>,
+    },
     DatabaseTransactionsLookUp {
         response: Result<Vec<Transactions>, ResponseMessageErrorCode>,
         request_id: InboundRequestId,
@@ -177,6 +212,10 @@ pub enum TaskRequest {
         response: Result<Vec<SealedBlockHeader>, ResponseMessageErrorCode>,
         request_id: InboundRequestId,
     },
+    DatabaseBlocksLookUp {
+        response: Result<Vec<SealedBlock>, ResponseMessageErrorCode>,
+        request_id: InboundRequestId,
+    },
     TxPoolAllTransactionsIds {
         response: Result<Vec<TxId>, ResponseMessageErrorCode>,
         request_id: InboundRequestId,
@@ -203,6 +242,9 @@ impl Debug for TaskRequest {
             TaskRequest::GetTransactions { .. } => {
                 write!(f, "TaskRequest::GetTransactions")
             }
+            TaskRequest::GetSealedBlocks { .. } => {
+                write!(f, "TaskRequest::GetSealedBlocks")
+            }
             TaskRequest::GetTransactionsFromPeer { .. } => {
                 write!(f, "TaskRequest::GetTransactionsFromPeer")
             }
@@ -221,12 +263,24 @@ impl Debug for TaskRequest {
             TaskRequest::GetAllPeerInfo { .. } => {
                 write!(f, "TaskRequest::GetPeerInfo")
             }
+            TaskRequest::BanPeer { .. } => {
+                write!(f, "TaskRequest::BanPeer")
+            }
+            TaskRequest::UnbanPeer { .. } => {
+                write!(f, "TaskRequest::UnbanPeer")
+            }
+            TaskRequest::GetPeerBans { .. } => {
+                write!(f, "TaskRequest::GetPeerBans")
+            }
             TaskRequest::DatabaseTransactionsLookUp { .. } => {
                 write!(f, "TaskRequest::DatabaseTransactionsLookUp")
             }
             TaskRequest::DatabaseHeaderLookUp { .. } => {
                 write!(f, "TaskRequest::DatabaseHeaderLookUp")
             }
+            TaskRequest::DatabaseBlocksLookUp { .. } => {
+                write!(f, "TaskRequest::DatabaseBlocksLookUp")
+            }
             TaskRequest::TxPoolAllTransactionsIds { .. } => {
                 write!(f, "TaskRequest::TxPoolAllTransactionsIds")
             }
@@ -245,7 +299,16 @@ pub enum HeartBeatPeerReportReason {
 
 pub trait TaskP2PService: Send {
     fn get_all_peer_info(&self) -> Vec<(&PeerId, &PeerInfo)>;
-    fn get_peer_id_with_height(&self, height: &BlockHeight) -> Option<PeerId>;
+    fn get_peer_id_with_height(
+        &self,
+        height: &BlockHeight,
+        excluded_peers: &HashSet<PeerId>,
+    ) -> Option<PeerId>;
+    fn get_peer_id_with_sealed_blocks(
+        &self,
+        height: &BlockHeight,
+        excluded_peers: &HashSet<PeerId>,
+    ) -> Option<PeerId>;
 
     fn next_event(&mut self) -> BoxFuture<'_, Option<FuelP2PEvent>>;
 
@@ -264,7 +327,7 @@ pub trait TaskP2PService: Send {
     fn send_response_msg(
         &mut self,
         request_id: InboundRequestId,
-        message: V2ResponseMessage,
+        message: V3ResponseMessage,
     ) -> anyhow::Result<()>;
 
     fn report_message(
@@ -282,6 +345,14 @@ pub trait TaskP2PService: Send {
 
     fn update_block_height(&mut self, height: BlockHeight) -> anyhow::Result<()>;
 
+    fn ban(&mut self, target: BanTarget, duration: Option<Duration>);
+
+    fn unban(&mut self, target: &BanTarget) -> bool;
+
+    fn get_bans(&self) -> Vec<Ban>;
+
+    fn get_peer_reputations(&self) -> Vec<(PeerId, AppScore)>;
+
     fn update_metrics<T>(&self, update_fn: T)
     where
         T: FnOnce();
@@ -299,8 +370,22 @@ impl TaskP2PService for FuelP2PService {
         self.peer_manager().get_all_peers().collect()
     }
 
-    fn get_peer_id_with_height(&self, height: &BlockHeight) -> Option<PeerId> {
-        self.peer_manager().get_peer_id_with_height(height)
+    fn get_peer_id_with_height(
+        &self,
+        height: &BlockHeight,
+        excluded_peers: &HashSet<PeerId>,
+    ) -> Option<PeerId> {
+        self.peer_manager()
+            .get_peer_id_with_height(height, excluded_peers)
+    }
+
+    fn get_peer_id_with_sealed_blocks(
+        &self,
+        height: &BlockHeight,
+        excluded_peers: &HashSet<PeerId>,
+    ) -> Option<PeerId> {
+        self.peer_manager()
+            .get_peer_id_with_sealed_blocks(height, excluded_peers)
     }
 
     fn next_event(&mut self) -> BoxFuture<'_, Option<FuelP2PEvent>> {
@@ -338,7 +423,7 @@ impl TaskP2PService for FuelP2PService {
     fn send_response_msg(
         &mut self,
         request_id: InboundRequestId,
-        message: V2ResponseMessage,
+        message: V3ResponseMessage,
     ) -> anyhow::Result<()> {
         self.send_response_msg(request_id, message)?;
         Ok(())
@@ -367,6 +452,22 @@ impl TaskP2PService for FuelP2PService {
         self.update_block_height(height);
         Ok(())
     }
+
+    fn ban(&mut self, target: BanTarget, duration: Option<Duration>) {
+        self.ban(target, duration)
+    }
+
+    fn unban(&mut self, target: &BanTarget) -> bool {
+        self.unban(target)
+    }
+
+    fn get_bans(&self) -> Vec<Ban> {
+        self.bans()
+    }
+
+    fn get_peer_reputations(&self) -> Vec<(PeerId, AppScore)> {
+        self.peer_manager().reputations()
+    }
 }
 
 pub trait Broadcast: Send {
@@ -389,6 +490,8 @@ pub trait Broadcast: Send {
         confirmations: P2PPreConfirmationGossipData,
     ) -> anyhow::Result<()>;
 
+    fn new_block_broadcast(&self, new_block: NewBlockGossipData) -> anyhow::Result<()>;
+
     fn new_tx_subscription_broadcast(&self, peer_id: FuelPeerId) -> anyhow::Result<()>;
 }
 
@@ -423,6 +526,11 @@ impl Broadcast for SharedState {
         Ok(())
     }
 
+    fn new_block_broadcast(&self, new_block: NewBlockGossipData) -> anyhow::Result<()> {
+        self.new_block_broadcast.send(new_block)?;
+        Ok(())
+    }
+
     fn new_tx_subscription_broadcast(&self, peer_id: FuelPeerId) -> anyhow::Result<()> {
         self.new_tx_subscription_broadcast.send(peer_id)?;
         Ok(())
@@ -435,10 +543,13 @@ pub struct UninitializedTask<V, B, T> {
     last_height: BlockHeight,
     view_provider: V,
     next_block_height: BoxStream<BlockHeight>,
+    /// Headers of the blocks produced by this node, announced to the network
+    locally_produced_headers: BoxStream<SealedBlockHeader>,
     /// Receive internal Task Requests
     request_receiver: mpsc::Receiver<TaskRequest>,
     broadcast: B,
     tx_pool: T,
+    reputation_store: Arc<dyn PeerReputationStore>,
     config: Config<NotInitialized>,
 }
 
@@ -450,6 +561,8 @@ pub struct Task<P, V, B, T> {
     p2p_service: P,
     view_provider: V,
     next_block_height: BoxStream<BlockHeight>,
+    /// Headers of the blocks produced by this node, announced to the network
+    locally_produced_headers: BoxStream<SealedBlockHeader>,
     /// Receive internal Task Requests
     request_receiver: mpsc::Receiver<TaskRequest>,
     request_sender: mpsc::Sender<TaskRequest>,
@@ -457,6 +570,8 @@ pub struct Task<P, V, B, T> {
     tx_pool_heavy_task_processor: AsyncProcessor,
     broadcast: B,
     tx_pool: T,
+    /// Keeps the reputation of the peers between restarts
+    reputation_store: Arc<dyn PeerReputationStore>,
     max_headers_per_request: usize,
     max_txs_per_request: usize,
     // milliseconds wait time between peer heartbeat reputation checks
@@ -491,6 +606,10 @@ where
                 let data = GossipData::new(confirmations, peer_id, message_id);
                 let _ = self.broadcast.pre_confirmation_broadcast(data);
             }
+            GossipsubMessage::NewBlock(header) => {
+                let data = GossipData::new(header, peer_id, message_id);
+                let _ = self.broadcast.new_block_broadcast(data);
+            }
         }
     }
 }
@@ -512,15 +631,19 @@ impl<V, T> UninitializedTask<V, SharedState, T> {
         view_provider: V,
         block_importer: B,
         tx_pool: T,
+        reputation_store: Arc<dyn PeerReputationStore>,
     ) -> Self {
         let next_block_height = block_importer.next_block_height();
+        let locally_produced_headers = block_importer.locally_produced_headers();
 
         Self {
             chain_id,
             last_height,
             view_provider,
             tx_pool,
+            reputation_store,
             next_block_height,
+            locally_produced_headers,
             request_receiver,
             broadcast: shared_state,
             config,
@@ -574,6 +697,38 @@ where
             .report_peer(peer_id, app_score, reporting_service)?;
         Ok(())
     }
+
+    fn peer_reputations(&self) -> Vec<(FuelPeerId, AppScore)> {
+        self.p2p_service
+            .get_peer_reputations()
+            .into_iter()
+            .map(|(peer_id, score)| (FuelPeerId::from(peer_id.to_bytes()), score))
+            .collect()
+    }
+
+    /// Stores the reputation of the peers on the database processor
+    /// to not block the task with the file system.
+    fn spawn_store_peer_reputations(&self) {
+        let reputations = self.peer_reputations();
+        let reputation_store = self.reputation_store.clone();
+        let result = self.db_heavy_task_processor.try_spawn(move || {
+            store_peer_reputations(reputation_store.as_ref(), reputations)
+        });
+        if result.is_err() {
+            tracing::warn!(
+                "Skipped storing the reputation of the peers, the database processor is busy"
+            );
+        }
+    }
+}
+
+fn store_peer_reputations(
+    reputation_store: &dyn PeerReputationStore,
+    reputations: Vec<(FuelPeerId, AppScore)>,
+) {
+    if let Err(e) = reputation_store.store_peer_reputations(reputations) {
+        tracing::error!("Failed to store the reputation of the peers: {:?}", e);
+    }
 }
 
 impl<P, V, B, T> Task<P, V, B, T>
@@ -609,6 +764,9 @@ where
             RequestMessage::TxPoolFullTransactions(tx_ids) => {
                 self.handle_full_transactions_request(tx_ids, request_id)
             }
+            RequestMessage::SealedBlocks(range) => {
+                self.handle_sealed_blocks_request(range, request_id)
+            }
         }
     }
 
@@ -626,7 +784,7 @@ where
             + Send
             + 'static,
         ResponseSenderFn:
-            Fn(Result<R, ResponseMessageErrorCode>) -> V2ResponseMessage + Send + 'static,
+            Fn(Result<R, ResponseMessageErrorCode>) -> V3ResponseMessage + Send + 'static,
         TaskRequestFn: Fn(Result<R, ResponseMessageErrorCode>, InboundRequestId) -> TaskRequest
             + Send
             + 'static,
@@ -690,7 +848,7 @@ where
         self.handle_db_request(
             range,
             request_id,
-            V2ResponseMessage::Transactions,
+            V3ResponseMessage::Transactions,
             |view, cached_view, range| {
                 cached_view
                     .get_transactions(view, range)
@@ -712,7 +870,7 @@ where
         self.handle_db_request(
             range,
             request_id,
-            V2ResponseMessage::SealedHeaders,
+            V3ResponseMessage::SealedHeaders,
             |view, cached_view, range| {
                 cached_view
                     .get_sealed_headers(view, range)
@@ -726,6 +884,31 @@ where
         )
     }
 
+    fn handle_sealed_blocks_request(
+        &mut self,
+        range: Range<u32>,
+        request_id: InboundRequestId,
+    ) -> anyhow::Result<()> {
+        self.handle_db_request(
+            range,
+            request_id,
+            V3ResponseMessage::SealedBlocks,
+            |view, cached_view, range| {
+                let headers = cached_view.get_sealed_headers(view, range.clone())?;
+                let transactions = cached_view.get_transactions(view, range)?;
+                let (Some(headers), Some(transactions)) = (headers, transactions) else {
+                    return Ok(None);
+                };
+                Ok(into_sealed_blocks(headers, transactions))
+            },
+            |response, request_id| TaskRequest::DatabaseBlocksLookUp {
+                response,
+                request_id,
+            },
+            self.max_headers_per_request,
+        )
+    }
+
     fn handle_txpool_request<F, ResponseSenderFn, TaskRequestFn, R>(
         &mut self,
         request_id: InboundRequestId,
@@ -735,7 +918,7 @@ where
     ) -> anyhow::Result<()>
     where
         ResponseSenderFn:
-            Fn(Result<R, ResponseMessageErrorCode>) -> V2ResponseMessage + Send + 'static,
+            Fn(Result<R, ResponseMessageErrorCode>) -> V3ResponseMessage + Send + 'static,
         TaskRequestFn: Fn(Result<R, ResponseMessageErrorCode>, InboundRequestId) -> TaskRequest
             + Send
             + 'static,
@@ -779,7 +962,7 @@ where
         self.handle_txpool_request(
             request_id,
             async move { tx_pool.get_tx_ids(max_txs).await },
-            V2ResponseMessage::TxPoolAllTransactionsIds,
+            V3ResponseMessage::TxPoolAllTransactionsIds,
             |response, request_id| TaskRequest::TxPoolAllTransactionsIds {
                 response,
                 request_id,
@@ -795,7 +978,7 @@ where
         if tx_ids.len() > self.max_txs_per_request {
             self.p2p_service.send_response_msg(
                 request_id,
-                V2ResponseMessage::TxPoolFullTransactions(Err(
+                V3ResponseMessage::TxPoolFullTransactions(Err(
                     ResponseMessageErrorCode::RequestedRangeTooLarge,
                 )),
             )?;
@@ -805,7 +988,7 @@ where
         self.handle_txpool_request(
             request_id,
             async move { tx_pool.get_full_txs(tx_ids).await },
-            V2ResponseMessage::TxPoolFullTransactions,
+            V3ResponseMessage::TxPoolFullTransactions,
             |response, request_id| TaskRequest::TxPoolFullTransactions {
                 response,
                 request_id,
@@ -819,6 +1002,45 @@ fn convert_peer_id(peer_id: &PeerId) -> anyhow::Result<FuelPeerId> {
     Ok(FuelPeerId::from(inner))
 }
 
+/// Combines the headers and the transactions of the same blocks into sealed blocks.
+/// Returns `None` if any transactions don't match their header.
+fn into_sealed_blocks(
+    headers: Vec<SealedBlockHeader>,
+    transactions: Vec<Transactions>,
+) -> Option<Vec<SealedBlock>> {
+    if headers.len() != transactions.len() {
+        return None;
+    }
+
+    headers
+        .into_iter()
+        .zip(transactions)
+        .map(|(header, transactions)| {
+            let block = Block::try_from_executed(header.entity, transactions.0)?;
+            Some(SealedBlock {
+                entity: block,
+                consensus: header.consensus,
+            })
+        })
+        .collect()
+}
+
+fn convert_ban_target(target: FuelBanTarget) -> anyhow::Result<BanTarget> {
+    match target {
+        FuelBanTarget::Peer(peer_id) => PeerId::from_bytes(peer_id.as_ref())
+            .map(BanTarget::Peer)
+            .map_err(|e| anyhow!("Invalid peer id {peer_id}: {e}")),
+        FuelBanTarget::Ip(ip) => Ok(BanTarget::Ip(ip)),
+    }
+}
+
+fn convert_excluded_peers(excluded_peers: Vec<FuelPeerId>) -> HashSet<PeerId> {
+    excluded_peers
+        .into_iter()
+        .filter_map(|peer_id| PeerId::from_bytes(peer_id.as_ref()).ok())
+        .collect()
+}
+
 #[async_trait::async_trait]
 impl<V, T> RunnableService for UninitializedTask<V, SharedState, T>
 where
@@ -846,9 +1068,11 @@ where
             last_height,
             view_provider,
             next_block_height,
+            locally_produced_headers,
             request_receiver,
             broadcast,
             tx_pool,
+            reputation_store,
             config,
         } = self;
 
@@ -884,6 +1108,23 @@ where
         )
         .await?;
         p2p_service.update_block_height(last_height);
+        // The reputation only speeds up recognizing the bad peers,
+        // so the node can start without it.
+        let reputations = reputation_store
+            .load_peer_reputations()
+            .unwrap_or_else(|e| {
+                tracing::warn!(
+                    "Failed to load the reputation of the peers, starting without it: {:?}",
+                    e
+                );
+                vec![]
+            })
+            .into_iter()
+            .filter_map(|(peer_id, score)| {
+                Some((PeerId::from_bytes(peer_id.as_ref()).ok()?, score))
+            })
+            .collect();
+        p2p_service.restore_peer_reputations(reputations);
         p2p_service.start().await?;
 
         let next_check_time =
@@ -907,8 +1148,10 @@ where
             request_receiver,
             request_sender,
             next_block_height,
+            locally_produced_headers,
             broadcast,
             tx_pool,
+            reputation_store,
             db_heavy_task_processor,
             tx_pool_heavy_task_processor,
             max_headers_per_request,
@@ -948,6 +1191,19 @@ where
                     TaskNextAction::Stop
                 }
             },
+            produced_header = self.locally_produced_headers.next() => {
+                if let Some(header) = produced_header {
+                    let height = *header.entity.height();
+                    let broadcast = GossipsubBroadcastRequest::NewBlock(Arc::new(header));
+                    let result = self.p2p_service.publish_message(broadcast);
+                    if let Err(e) = result {
+                        tracing::error!("Got an error during new block {} announcement {}", height, e);
+                    }
+                    TaskNextAction::Continue
+                } else {
+                    TaskNextAction::Stop
+                }
+            },
             next_service_request = self.request_receiver.recv() => {
                 match next_service_request {
                     Some(TaskRequest::BroadcastTransaction(transaction)) => {
@@ -965,11 +1221,11 @@ where
                             tracing::error!("Got an error during pre-confirmation message broadcasting {:?}: {}", broadcast, e);
                         }
                     }
-                    Some(TaskRequest::GetSealedHeaders { block_height_range, channel}) => {
+                    Some(TaskRequest::GetSealedHeaders { block_height_range, excluded_peers, channel}) => {
                         // Note: this range has already been checked for
                         // validity in `SharedState::get_sealed_block_headers`.
                         let height = BlockHeight::from(block_height_range.end.saturating_sub(1));
-                        let Some(peer) = self.p2p_service.get_peer_id_with_height(&height) else {
+                        let Some(peer) = self.p2p_service.get_peer_id_with_height(&height, &excluded_peers) else {
                             let _ = channel.send(Err(TaskError::NoPeerFound));
                             return TaskNextAction::Continue
                         };
@@ -977,9 +1233,9 @@ where
                         let request_msg = RequestMessage::SealedHeaders(block_height_range.clone());
                         self.p2p_service.send_request_msg(Some(peer), request_msg, channel).expect("We always have a peer here, so send has a target");
                     }
-                    Some(TaskRequest::GetTransactions {block_height_range, channel }) => {
+                    Some(TaskRequest::GetTransactions {block_height_range, excluded_peers, channel }) => {
                         let height = BlockHeight::from(block_height_range.end.saturating_sub(1));
-                        let Some(peer) = self.p2p_service.get_peer_id_with_height(&height) else {
+                        let Some(peer) = self.p2p_service.get_peer_id_with_height(&height, &excluded_peers) else {
                             let _ = channel.send(Err(TaskError::NoPeerFound));
                             return TaskNextAction::Continue
                         };
@@ -987,6 +1243,16 @@ where
                         let request_msg = RequestMessage::Transactions(block_height_range.clone());
                         self.p2p_service.send_request_msg(Some(peer), request_msg, channel).expect("We always have a peer here, so send has a target");
                     }
+                    Some(TaskRequest::GetSealedBlocks { block_height_range, excluded_peers, channel }) => {
+                        let height = BlockHeight::from(block_height_range.end.saturating_sub(1));
+                        let Some(peer) = self.p2p_service.get_peer_id_with_sealed_blocks(&height, &excluded_peers) else {
+                            let _ = channel.send(Err(TaskError::NoPeerFound));
+                            return TaskNextAction::Continue
+                        };
+                        let channel = ResponseSender::SealedBlocks(channel);
+                        let request_msg = RequestMessage::SealedBlocks(block_height_range.clone());
+                        self.p2p_service.send_request_msg(Some(peer), request_msg, channel).expect("We always have a peer here, so send has a target");
+                    }
                     Some(TaskRequest::GetTransactionsFromPeer { block_height_range, from_peer, channel }) => {
                         let channel = ResponseSender::TransactionsFromPeer(channel);
                         let request_msg = RequestMessage::Transactions(block_height_range);
@@ -1018,17 +1284,29 @@ where
                             .collect::<Vec<_>>();
                         let _ = channel.send(peers);
                     }
+                    Some(TaskRequest::BanPeer { target, duration }) => {
+                        self.p2p_service.ban(target, duration);
+                    }
+                    Some(TaskRequest::UnbanPeer { target, channel }) => {
+                        let _ = channel.send(self.p2p_service.unban(&target));
+                    }
+                    Some(TaskRequest::GetPeerBans { channel }) => {
+                        let _ = channel.send(self.p2p_service.get_bans());
+                    }
                     Some(TaskRequest::DatabaseTransactionsLookUp { response, request_id }) => {
-                        let _ = self.p2p_service.send_response_msg(request_id, V2ResponseMessage::Transactions(response));
+                        let _ = self.p2p_service.send_response_msg(request_id, V3ResponseMessage::Transactions(response));
                     }
                     Some(TaskRequest::DatabaseHeaderLookUp { response, request_id }) => {
-                        let _ = self.p2p_service.send_response_msg(request_id, V2ResponseMessage::SealedHeaders(response));
+                        let _ = self.p2p_service.send_response_msg(request_id, V3ResponseMessage::SealedHeaders(response));
+                    }
+                    Some(TaskRequest::DatabaseBlocksLookUp { response, request_id }) => {
+                        let _ = self.p2p_service.send_response_msg(request_id, V3ResponseMessage::SealedBlocks(response));
                     }
                     Some(TaskRequest::TxPoolAllTransactionsIds { response, request_id }) => {
-                        let _ = self.p2p_service.send_response_msg(request_id, V2ResponseMessage::TxPoolAllTransactionsIds(response));
+                        let _ = self.p2p_service.send_response_msg(request_id, V3ResponseMessage::TxPoolAllTransactionsIds(response));
                     }
                     Some(TaskRequest::TxPoolFullTransactions { response, request_id }) => {
-                        let _ = self.p2p_service.send_response_msg(request_id, V2ResponseMessage::TxPoolFullTransactions(response));
+                        let _ = self.p2p_service.send_response_msg(request_id, V3ResponseMessage::TxPoolFullTransactions(response));
                     }
                     None => {
                         tracing::error!("The P2P `Task` should be holder of the `Sender`");
@@ -1075,6 +1353,7 @@ where
                         tracing::error!("Failed to perform peer heartbeat reputation checks: {:?}", e);
                     }
                 }
+                self.spawn_store_peer_reputations();
 
                 if let Some(next_check_time) = self.next_check_time.checked_add(self.heartbeat_check_interval) {
                     self.next_check_time = next_check_time;
@@ -1088,8 +1367,9 @@ where
     }
 
     async fn shutdown(self) -> anyhow::Result<()> {
-        // Nothing to shut down because we don't have any temporary state that should be dumped,
-        // and we don't spawn any sub-tasks that we need to finish or await.
+        // The only state that should be dumped is the reputation of the peers.
+        // We don't spawn any sub-tasks that we need to finish or await.
+        store_peer_reputations(self.reputation_store.as_ref(), self.peer_reputations());
 
         // `FuelP2PService` doesn't support graceful shutdown(with informing of connected peers).
         // https://github.com/libp2p/specs/blob/master/ROADMAP.md#%EF%B8%8F-polite-peering
@@ -1107,6 +1387,8 @@ pub struct SharedState {
     tx_broadcast: broadcast::Sender<TransactionGossipData>,
     /// Sender of p2p transaction preconfirmations used for subscribing.
     pre_confirmations_broadcast: broadcast::Sender<P2PPreConfirmationGossipData>,
+    /// Sender of p2p new block announcements used for subscribing.
+    new_block_broadcast: broadcast::Sender<NewBlockGossipData>,
     /// Sender of reserved peers connection updates.
     reserved_peers_broadcast: broadcast::Sender<usize>,
     /// Used for communicating with the `Task`.
@@ -1131,9 +1413,14 @@ impl SharedState {
         Ok(())
     }
 
+    /// Requests the sealed block headers from a peer that is not in `excluded_peers`.
+    ///
+    /// If the peer failed to respond, e.g. the request timed out,
+    /// the peer is returned without data so the caller can retry with another peer.
     pub async fn get_sealed_block_headers(
         &self,
         block_height_range: Range<u32>,
+        excluded_peers: Vec<FuelPeerId>,
     ) -> anyhow::Result<(Vec<u8>, Option<Vec<SealedBlockHeader>>)> {
         let (sender, receiver) = oneshot::channel();
 
@@ -1146,6 +1433,7 @@ impl SharedState {
         self.request_sender
             .send(TaskRequest::GetSealedHeaders {
                 block_height_range,
+                excluded_peers: convert_excluded_peers(excluded_peers),
                 channel: sender,
             })
             .await?;
@@ -1155,19 +1443,33 @@ impl SharedState {
             .map_err(|e| anyhow!("{e}"))?
             .map_err(|e| anyhow!("{e}"))?;
 
-        let data = response.map_err(|e| anyhow!("Invalid response from peer {e:?}"))?;
-        if let Err(ref response_error_code) = data {
-            warn!(
-                "Peer {peer_id:?} failed to respond with sealed headers: {response_error_code:?}"
-            );
+        let data = match response {
+            Err(request_response_protocol_error) => {
+                warn!(
+                    "Invalid response from peer {peer_id:?} for sealed headers: {request_response_protocol_error:?}"
+                );
+                None
+            }
+            Ok(Err(response_error_code)) => {
+                warn!(
+                    "Peer {peer_id:?} failed to respond with sealed headers: {response_error_code:?}"
+                );
+                None
+            }
+            Ok(Ok(headers)) => Some(headers),
         };
 
-        Ok((peer_id.to_bytes(), data.ok()))
+        Ok((peer_id.to_bytes(), data))
     }
 
+    /// Requests the transactions from a peer that is not in `excluded_peers`.
+    ///
+    /// If the peer failed to respond, e.g. the request timed out,
+    /// the peer is returned without data so the caller can retry with another peer.
     pub async fn get_transactions(
         &self,
         range: Range<u32>,
+        excluded_peers: Vec<FuelPeerId>,
     ) -> anyhow::Result<(Vec<u8>, Option<Vec<Transactions>>)> {
         let (sender, receiver) = oneshot::channel();
 
@@ -1180,6 +1482,7 @@ impl SharedState {
         self.request_sender
             .send(TaskRequest::GetTransactions {
                 block_height_range: range,
+                excluded_peers: convert_excluded_peers(excluded_peers),
                 channel: sender,
             })
             .await?;
@@ -1190,18 +1493,80 @@ impl SharedState {
             .map_err(|e| anyhow!("{e}"))?;
 
         let data = match response {
-            Err(request_response_protocol_error) => Err(anyhow!(
-                "Invalid response from peer {request_response_protocol_error:?}"
-            )),
+            Err(request_response_protocol_error) => {
+                warn!(
+                    "Invalid response from peer {peer_id:?} for transactions: {request_response_protocol_error:?}"
+                );
+                None
+            }
             Ok(Err(response_error_code)) => {
                 warn!(
-                    "Peer {peer_id:?} failed to respond with sealed headers: {response_error_code:?}"
+                    "Peer {peer_id:?} failed to respond with transactions: {response_error_code:?}"
                 );
-                Ok(None)
+                None
+            }
+            Ok(Ok(transactions)) => Some(transactions),
+        };
+
+        Ok((peer_id.to_bytes(), data))
+    }
+
+    /// Requests the full sealed blocks from a peer that is not in `excluded_peers`.
+    ///
+    /// Only peers that support the `/fuel/req_res/0.0.3` protocol can serve the request.
+    /// If the peer failed to respond, the peer is returned without data.
+    /// If the peer doesn't support the protocol, an error is returned instead,
+    /// because the peer didn't misbehave.
+    pub async fn get_sealed_blocks(
+        &self,
+        block_height_range: Range<u32>,
+        excluded_peers: Vec<FuelPeerId>,
+    ) -> anyhow::Result<(Vec<u8>, Option<Vec<SealedBlock>>)> {
+        let (sender, receiver) = oneshot::channel();
+
+        if block_height_range.is_empty() {
+            return Err(anyhow!(
+                "Cannot retrieve blocks for an empty range of block heights"
+            ));
+        }
+
+        self.request_sender
+            .send(TaskRequest::GetSealedBlocks {
+                block_height_range,
+                excluded_peers: convert_excluded_peers(excluded_peers),
+                channel: sender,
+            })
+            .await?;
+
+        let (peer_id, response) = receiver
+            .await
+            .map_err(|e| anyhow!("{e}"))?
+            .map_err(|e| anyhow!("{e}"))?;
+
+        let data = match response {
+            Err(request_response_protocol_error)
+                if request_response_protocol_error.is_unsupported_protocol() =>
+            {
+                return Err(anyhow!(
+                    "Peer {peer_id:?} doesn't support the sealed blocks: {request_response_protocol_error}"
+                ))
+            }
+            Err(request_response_protocol_error) => {
+                warn!(
+                    "Invalid response from peer {peer_id:?} for sealed blocks: {request_response_protocol_error:?}"
+                );
+                None
             }
-            Ok(Ok(headers)) => Ok(Some(headers)),
+            Ok(Err(response_error_code)) => {
+                warn!(
+                    "Peer {peer_id:?} failed to respond with sealed blocks: {response_error_code:?}"
+                );
+                None
+            }
+            Ok(Ok(blocks)) => Some(blocks),
         };
-        data.map(|data| (peer_id.to_bytes(), data))
+
+        Ok((peer_id.to_bytes(), data))
     }
 
     pub async fn get_transactions_from_peer(
@@ -1339,6 +1704,59 @@ impl SharedState {
         receiver.await.map_err(|e| anyhow!("{}", e))
     }
 
+    /// Bans the peer or the IP address for the `duration`,
+    /// or until it is unbanned if the `duration` is not set.
+    /// Connected peers affected by the ban are disconnected.
+    pub async fn ban_peer(
+        &self,
+        target: FuelBanTarget,
+        duration: Option<Duration>,
+    ) -> anyhow::Result<()> {
+        let target = convert_ban_target(target)?;
+        self.request_sender
+            .send(TaskRequest::BanPeer { target, duration })
+            .await?;
+        Ok(())
+    }
+
+    /// Removes the ban of the peer or the IP address.
+    /// Returns `true` if the `target` was banned.
+    pub async fn unban_peer(&self, target: FuelBanTarget) -> anyhow::Result<bool> {
+        let (sender, receiver) = oneshot::channel();
+        let target = convert_ban_target(target)?;
+
+        self.request_sender
+            .send(TaskRequest::UnbanPeer {
+                target,
+                channel: sender,
+            })
+            .await?;
+
+        receiver.await.map_err(|e| anyhow!("{}", e))
+    }
+
+    pub async fn get_peer_bans(&self) -> anyhow::Result<Vec<PeerBan>> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.request_sender
+            .send(TaskRequest::GetPeerBans { channel: sender })
+            .await?;
+
+        let bans = receiver.await.map_err(|e| anyhow!("{}", e))?;
+        Ok(bans
+            .into_iter()
+            .map(|ban| PeerBan {
+                target: match ban.target {
+                    BanTarget::Peer(peer_id) => {
+                        FuelBanTarget::Peer(FuelPeerId::from(peer_id.to_bytes()))
+                    }
+                    BanTarget::Ip(ip) => FuelBanTarget::Ip(ip),
+                },
+                expires_at: ban.expires_at,
+            })
+            .collect())
+    }
+
     pub fn subscribe_new_peers(&self) -> broadcast::Receiver<FuelPeerId> {
         self.new_tx_subscription_broadcast.subscribe()
     }
@@ -1353,6 +1771,10 @@ impl SharedState {
         self.pre_confirmations_broadcast.subscribe()
     }
 
+    pub fn subscribe_new_blocks(&self) -> broadcast::Receiver<NewBlockGossipData> {
+        self.new_block_broadcast.subscribe()
+    }
+
     pub fn subscribe_block_height(
         &self,
     ) -> broadcast::Receiver<BlockHeightHeartbeatData> {
@@ -1396,6 +1818,7 @@ pub fn build_shared_state(
     let (request_sender, request_receiver) = mpsc::channel(CHANNEL_SIZE);
     let (tx_broadcast, _) = broadcast::channel(CHANNEL_SIZE);
     let (preconfirmations_broadcast, _) = broadcast::channel(CHANNEL_SIZE);
+    let (new_block_broadcast, _) = broadcast::channel(CHANNEL_SIZE);
     let (new_tx_subscription_broadcast, _) = broadcast::channel(CHANNEL_SIZE);
     let (block_height_broadcast, _) = broadcast::channel(CHANNEL_SIZE);
 
@@ -1413,6 +1836,7 @@ pub fn build_shared_state(
             new_tx_subscription_broadcast,
             tx_broadcast,
             pre_confirmations_broadcast: preconfirmations_broadcast,
+            new_block_broadcast,
             reserved_peers_broadcast,
             block_height_broadcast,
             max_txs_per_request: config.max_txs_per_request,
@@ -1422,7 +1846,7 @@ pub fn build_shared_state(
 }
 
 #[allow(clippy::too_many_arguments)]
-pub fn new_service<V, B, T>(
+pub fn new_service<V, B, T, R>(
     chain_id: ChainId,
     last_height: BlockHeight,
     p2p_config: Config<NotInitialized>,
@@ -1431,12 +1855,14 @@ pub fn new_service<V, B, T>(
     view_provider: V,
     block_importer: B,
     tx_pool: T,
+    reputation_store: R,
 ) -> Service<V, T>
 where
     V: AtomicView + 'static,
     V::LatestView: P2pDb,
     B: BlockHeightImporter,
     T: TxPool,
+    R: PeerReputationStore + 'static,
 {
     let task = UninitializedTask::new(
         chain_id,
@@ -1447,6 +1873,7 @@ where
         view_provider,
         block_importer,
         tx_pool,
+        Arc::new(reputation_store),
     );
     Service::new(task)
 }
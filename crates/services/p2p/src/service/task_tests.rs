@@ -1,5 +1,8 @@
 #![allow(non_snake_case)]
-use crate::ports::P2pDb;
+use crate::ports::{
+    P2pDb,
+    PeerReputationStore,
+};
 
 use super::*;
 
@@ -20,6 +23,7 @@ use futures::FutureExt;
 use libp2p::gossipsub::TopicHash;
 use std::{
     collections::VecDeque,
+    sync::Mutex,
     time::SystemTime,
 };
 
@@ -61,6 +65,10 @@ impl BlockHeightImporter for FakeBlockImporter {
     fn next_block_height(&self) -> BoxStream<BlockHeight> {
         Box::pin(fuel_core_services::stream::pending())
     }
+
+    fn locally_produced_headers(&self) -> BoxStream<SealedBlockHeader> {
+        Box::pin(fuel_core_services::stream::pending())
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -82,6 +90,25 @@ impl TxPool for FakeTxPool {
     }
 }
 
+#[derive(Default)]
+struct FakeReputationStore {
+    reputations: Mutex<Vec<(FuelPeerId, AppScore)>>,
+}
+
+impl PeerReputationStore for FakeReputationStore {
+    fn load_peer_reputations(&self) -> anyhow::Result<Vec<(FuelPeerId, AppScore)>> {
+        Ok(self.reputations.lock().unwrap().clone())
+    }
+
+    fn store_peer_reputations(
+        &self,
+        reputations: Vec<(FuelPeerId, AppScore)>,
+    ) -> anyhow::Result<()> {
+        *self.reputations.lock().unwrap() = reputations;
+        Ok(())
+    }
+}
+
 #[tokio::test]
 async fn start_and_stop_awaits_works() {
     let p2p_config = Config::<NotInitialized>::default("start_stop_works");
@@ -95,6 +122,7 @@ async fn start_and_stop_awaits_works() {
         FakeDb,
         FakeBlockImporter,
         FakeTxPool,
+        FakeReputationStore::default(),
     );
 
     // Node with p2p service started
@@ -103,6 +131,46 @@ async fn start_and_stop_awaits_works() {
     assert!(service.stop_and_await().await.unwrap().stopped());
 }
 
+struct FailingReputationStore;
+
+impl PeerReputationStore for FailingReputationStore {
+    fn load_peer_reputations(&self) -> anyhow::Result<Vec<(FuelPeerId, AppScore)>> {
+        Err(anyhow::anyhow!("The reputation of the peers is corrupted"))
+    }
+
+    fn store_peer_reputations(
+        &self,
+        _reputations: Vec<(FuelPeerId, AppScore)>,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn start__succeeds_when_the_peer_reputations_fail_to_load() {
+    // Given
+    let p2p_config = Config::<NotInitialized>::default("start_without_reputations");
+    let (shared_state, request_receiver) = build_shared_state(p2p_config.clone());
+    let service = new_service(
+        ChainId::default(),
+        0.into(),
+        p2p_config,
+        shared_state,
+        request_receiver,
+        FakeDb,
+        FakeBlockImporter,
+        FakeTxPool,
+        FailingReputationStore,
+    );
+
+    // When
+    let state = service.start_and_await().await.unwrap();
+
+    // Then
+    assert!(state.started());
+    assert!(service.stop_and_await().await.unwrap().stopped());
+}
+
 struct FakeP2PService {
     peer_info: Vec<(PeerId, PeerInfo)>,
     next_event_stream: BoxStream<FuelP2PEvent>,
@@ -120,7 +188,19 @@ impl TaskP2PService for FakeP2PService {
         self.peer_info.iter().map(|tup| (&tup.0, &tup.1)).collect()
     }
 
-    fn get_peer_id_with_height(&self, _height: &BlockHeight) -> Option<PeerId> {
+    fn get_peer_id_with_height(
+        &self,
+        _height: &BlockHeight,
+        _excluded_peers: &HashSet<PeerId>,
+    ) -> Option<PeerId> {
+        todo!()
+    }
+
+    fn get_peer_id_with_sealed_blocks(
+        &self,
+        _height: &BlockHeight,
+        _excluded_peers: &HashSet<PeerId>,
+    ) -> Option<PeerId> {
         todo!()
     }
 
@@ -147,7 +227,7 @@ impl TaskP2PService for FakeP2PService {
     fn send_response_msg(
         &mut self,
         _request_id: InboundRequestId,
-        _message: V2ResponseMessage,
+        _message: V3ResponseMessage,
     ) -> anyhow::Result<()> {
         todo!()
     }
@@ -172,6 +252,25 @@ impl TaskP2PService for FakeP2PService {
     fn update_block_height(&mut self, _height: BlockHeight) -> anyhow::Result<()> {
         Ok(())
     }
+
+    fn ban(&mut self, _target: BanTarget, _duration: Option<Duration>) {
+        todo!()
+    }
+
+    fn unban(&mut self, _target: &BanTarget) -> bool {
+        todo!()
+    }
+
+    fn get_bans(&self) -> Vec<Ban> {
+        todo!()
+    }
+
+    fn get_peer_reputations(&self) -> Vec<(PeerId, AppScore)> {
+        self.peer_info
+            .iter()
+            .map(|(peer_id, peer_info)| (*peer_id, peer_info.score))
+            .collect()
+    }
 }
 
 #[derive(Clone)]
@@ -241,6 +340,10 @@ impl Broadcast for FakeBroadcast {
         Ok(())
     }
 
+    fn new_block_broadcast(&self, _new_block: NewBlockGossipData) -> anyhow::Result<()> {
+        todo!()
+    }
+
     fn new_tx_subscription_broadcast(&self, _peer_id: FuelPeerId) -> anyhow::Result<()> {
         todo!()
     }
@@ -266,6 +369,8 @@ async fn peer_heartbeat_reputation_checks__slow_heartbeat_sends_reports() {
         peer_addresses: Default::default(),
         client_version: None,
         heartbeat_data,
+        request_stats: Default::default(),
+        supports_sealed_blocks: false,
         score: 100.0,
     };
     let peer_info = vec![(peer_id, peer_info)];
@@ -298,12 +403,14 @@ async fn peer_heartbeat_reputation_checks__slow_heartbeat_sends_reports() {
         p2p_service,
         view_provider: FakeDB,
         next_block_height: FakeBlockImporter.next_block_height(),
+        locally_produced_headers: FakeBlockImporter.locally_produced_headers(),
         tx_pool: FakeTxPool,
         request_receiver,
         request_sender,
         db_heavy_task_processor: SyncProcessor::new("Test", 1, 1).unwrap(),
         tx_pool_heavy_task_processor: AsyncProcessor::new("Test", 1, 1).unwrap(),
         broadcast,
+        reputation_store: Arc::new(FakeReputationStore::default()),
         max_headers_per_request: 0,
         max_txs_per_request: 100,
         heartbeat_check_interval: Duration::from_secs(0),
@@ -360,6 +467,8 @@ async fn peer_heartbeat_reputation_checks__old_heartbeat_sends_reports() {
         peer_addresses: Default::default(),
         client_version: None,
         heartbeat_data,
+        request_stats: Default::default(),
+        supports_sealed_blocks: false,
         score: 100.0,
     };
     let peer_info = vec![(peer_id, peer_info)];
@@ -393,11 +502,13 @@ async fn peer_heartbeat_reputation_checks__old_heartbeat_sends_reports() {
         view_provider: FakeDB,
         tx_pool: FakeTxPool,
         next_block_height: FakeBlockImporter.next_block_height(),
+        locally_produced_headers: FakeBlockImporter.locally_produced_headers(),
         request_receiver,
         request_sender,
         db_heavy_task_processor: SyncProcessor::new("Test", 1, 1).unwrap(),
         tx_pool_heavy_task_processor: AsyncProcessor::new("Test", 1, 1).unwrap(),
         broadcast,
+        reputation_store: Arc::new(FakeReputationStore::default()),
         max_headers_per_request: 0,
         max_txs_per_request: 100,
         heartbeat_check_interval: Duration::from_secs(0),
@@ -446,6 +557,72 @@ async fn wait_until_report_received(
     }
 }
 
+#[tokio::test]
+async fn run__heartbeat_check_stores_peer_reputations() {
+    // given
+    let peer_id = PeerId::random();
+    let heartbeat_data = HeartbeatData {
+        block_height: None,
+        last_heartbeat: Instant::now(),
+        last_heartbeat_sys: SystemTime::now(),
+        window: 0,
+        durations: VecDeque::new(),
+    };
+    let peer_info = PeerInfo {
+        peer_addresses: Default::default(),
+        client_version: None,
+        heartbeat_data,
+        request_stats: Default::default(),
+        supports_sealed_blocks: false,
+        score: -20.0,
+    };
+    let p2p_service = FakeP2PService {
+        peer_info: vec![(peer_id, peer_info)],
+        next_event_stream: Box::pin(futures::stream::pending()),
+    };
+    let (request_sender, request_receiver) = mpsc::channel(100);
+    let broadcast = FakeBroadcast {
+        peer_reports: mpsc::channel(100).0,
+        confirmation_gossip_broadcast: mpsc::channel(100).0,
+    };
+    let reputation_store = Arc::new(FakeReputationStore::default());
+
+    let mut task = Task {
+        chain_id: Default::default(),
+        response_timeout: Default::default(),
+        p2p_service,
+        view_provider: FakeDB,
+        tx_pool: FakeTxPool,
+        next_block_height: FakeBlockImporter.next_block_height(),
+        locally_produced_headers: FakeBlockImporter.locally_produced_headers(),
+        request_receiver,
+        request_sender,
+        db_heavy_task_processor: SyncProcessor::new("Test", 1, 1).unwrap(),
+        tx_pool_heavy_task_processor: AsyncProcessor::new("Test", 1, 1).unwrap(),
+        broadcast,
+        reputation_store: reputation_store.clone(),
+        max_headers_per_request: 0,
+        max_txs_per_request: 100,
+        heartbeat_check_interval: Duration::from_secs(10),
+        heartbeat_max_avg_interval: Duration::from_secs(20),
+        heartbeat_max_time_since_last: Duration::from_secs(40),
+        next_check_time: Instant::now(),
+        heartbeat_peer_reputation_config: Default::default(),
+        cached_view: Arc::new(CachedView::new(100, false)),
+    };
+    let (_watch_sender, watch_receiver) = tokio::sync::watch::channel(State::Started);
+    let mut watcher = StateWatcher::from(watch_receiver);
+
+    // when
+    let _ = task.run(&mut watcher).await;
+    // The reputation is stored by the database processor.
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    // then
+    let stored = reputation_store.load_peer_reputations().unwrap();
+    assert_eq!(stored, vec![(FuelPeerId::from(peer_id.to_bytes()), -20.0)]);
+}
+
 #[tokio::test]
 async fn should_process_all_imported_block_under_infinite_events_from_p2p() {
     // Given
@@ -473,11 +650,13 @@ async fn should_process_all_imported_block_under_infinite_events_from_p2p() {
         tx_pool: FakeTxPool,
         view_provider: FakeDB,
         next_block_height,
+        locally_produced_headers: FakeBlockImporter.locally_produced_headers(),
         request_receiver,
         request_sender,
         db_heavy_task_processor: SyncProcessor::new("Test", 1, 1).unwrap(),
         tx_pool_heavy_task_processor: AsyncProcessor::new("Test", 1, 1).unwrap(),
         broadcast,
+        reputation_store: Arc::new(FakeReputationStore::default()),
         max_headers_per_request: 0,
         max_txs_per_request: 100,
         heartbeat_check_interval: Duration::from_secs(0),
@@ -537,12 +716,14 @@ async fn run__gossip_message_from_p2p_service_is_broadcasted__tx_preconfirmation
         p2p_service,
         view_provider: FakeDB,
         next_block_height: FakeBlockImporter.next_block_height(),
+        locally_produced_headers: FakeBlockImporter.locally_produced_headers(),
         tx_pool: FakeTxPool,
         request_receiver,
         request_sender,
         db_heavy_task_processor: SyncProcessor::new("Test", 1, 1).unwrap(),
         tx_pool_heavy_task_processor: AsyncProcessor::new("Test", 1, 1).unwrap(),
         broadcast,
+        reputation_store: Arc::new(FakeReputationStore::default()),
         max_headers_per_request: 0,
         max_txs_per_request: 100,
         heartbeat_check_interval: Duration::from_secs(0),
@@ -1,28 +1,80 @@
-use crate::config::Config;
-use fuel_core_storage::transactional::Changes;
+use crate::{
+    config::Config,
+    scheduler,
+};
+use fuel_core_storage::{
+    StorageAsRef,
+    column::Column,
+    kv_store::KeyValueInspect,
+    tables::ConsensusParametersVersions,
+    transactional::{
+        AtomicView,
+        Changes,
+        ConflictPolicy,
+        HistoricalView,
+        ReadTransaction,
+        StorageTransaction,
+    },
+};
 use fuel_core_types::{
-    blockchain::block::Block,
-    fuel_tx::Transaction,
+    blockchain::{
+        block::Block,
+        header::PartialBlockHeader,
+        primitives::DaBlockHeight,
+        transaction::TransactionExt,
+    },
+    fuel_tx::{
+        ConsensusParameters,
+        ContractId,
+        Transaction,
+        field::{
+            InputContract,
+            MintGasPrice,
+        },
+    },
+    fuel_types::BlockHeight,
     services::{
         Uncommitted,
         block_producer::Components,
         executor::{
+            Error as ExecutorError,
             ExecutionResult,
             Result as ExecutorResult,
             TransactionExecutionStatus,
             ValidationResult,
         },
+        relayer::Event,
     },
 };
 use fuel_core_upgradable_executor::{
     executor::Executor as UpgradableExecutor,
-    native_executor::ports::TransactionsSource,
+    native_executor::{
+        executor::{
+            ExecutedTransactions,
+            ExecutionInstance,
+            ExecutionOptions,
+            OnceTransactionsSource,
+            max_tx_count,
+        },
+        ports::{
+            MaybeCheckedTransaction,
+            RelayerPort,
+            TransactionsSource,
+        },
+    },
 };
 use std::{
+    borrow::Cow,
+    collections::{
+        BTreeMap,
+        HashMap,
+        HashSet,
+        btree_map,
+    },
     num::NonZeroUsize,
     sync::{
         Arc,
-        RwLock,
+        mpsc,
     },
 };
 use tokio::runtime::Runtime;
@@ -33,10 +85,21 @@ use fuel_core_upgradable_executor::error::UpgradableError;
 #[cfg(feature = "wasm-executor")]
 use fuel_core_types::fuel_merkle::common::Bytes32;
 
+#[cfg(test)]
+mod tests;
+
+/// The executor splits transactions of the block into independent groups
+/// (see [`scheduler::partition`]) and executes them in parallel on top of
+/// the state after processing of the relayed transactions. The changes of
+/// all groups are merged, and the `Mint` transaction is executed at the end.
+///
+/// Transactions keep their order from the source, so the block is identical to the
+/// block produced by the sequential execution. If the block can't be executed
+/// in parallel, the executor falls back to the [`UpgradableExecutor`].
 pub struct Executor<S, R> {
-    _executor: Arc<RwLock<UpgradableExecutor<S, R>>>,
+    executor: Arc<UpgradableExecutor<S, R>>,
     runtime: Option<Runtime>,
-    _number_of_cores: NonZeroUsize,
+    number_of_cores: NonZeroUsize,
 }
 
 // Shutdown the tokio runtime to avoid panic if executor is already
@@ -68,47 +131,564 @@ impl<S, R> Executor<S, R> {
         let number_of_cores = config.number_of_cores;
 
         Self {
-            _executor: Arc::new(RwLock::new(executor)),
+            executor: Arc::new(executor),
             runtime: Some(runtime),
-            _number_of_cores: number_of_cores,
+            number_of_cores,
         }
     }
 }
 
-impl<S, R> Executor<S, R> {
+/// The outcome of the parallel execution.
+enum Parallel<T> {
+    /// The block was executed in parallel.
+    Executed(T),
+    /// The block can't be executed in parallel and requires sequential execution.
+    Sequential,
+}
+
+/// The group of transactions executed by one task.
+struct Group {
+    /// Positions of transactions in the source order.
+    positions: Vec<usize>,
+    /// Transactions with their indexes inside of the block.
+    transactions: Vec<(u16, MaybeCheckedTransaction)>,
+}
+
+impl<S, R> Executor<S, R>
+where
+    S: HistoricalView<Height = BlockHeight>,
+    S::LatestView: KeyValueInspect<Column = Column> + Send + Sync + 'static,
+    S::ViewAtHeight: KeyValueInspect<Column = Column> + Send + Sync + 'static,
+    R: AtomicView,
+    R::LatestView: RelayerPort + Send + Sync + 'static,
+{
     /// Produces the block and returns the result of the execution without committing the changes.
     pub fn produce_without_commit_with_source<TxSource>(
         &self,
-        _components: Components<TxSource>,
+        components: Components<TxSource>,
     ) -> ExecutorResult<Uncommitted<ExecutionResult, Changes>>
     where
         TxSource: TransactionsSource + Send + Sync + 'static,
     {
-        unimplemented!("Not implemented yet");
+        let header = components.header_to_produce;
+        if header.state_transition_bytecode_version
+            != self.executor.native_executor_version()
+        {
+            return self
+                .executor
+                .produce_without_commit_with_source_direct_resolve(components);
+        }
+
+        let Components {
+            transactions_source,
+            coinbase_recipient,
+            gas_price,
+            ..
+        } = components;
+        let view = Arc::new(self.executor.storage_view_provider.latest_view()?);
+        let relayer = self.executor.relayer_view_provider.latest_view()?;
+        let consensus_params =
+            consensus_parameters(view.as_ref(), &header.consensus_parameters_version)?;
+        let options = ExecutionOptions::from(self.executor.config.as_ref());
+
+        let (l1_block, l1_data) =
+            ExecutionInstance::new(relayer, view.clone(), options.clone())
+                .process_l1_without_commit(header, coinbase_recipient)?;
+
+        let block_gas_limit = consensus_params.block_gas_limit();
+        let block_transaction_size_limit = consensus_params
+            .block_transaction_size_limit()
+            .try_into()
+            .unwrap_or(u32::MAX);
+        let remaining_gas_limit = block_gas_limit.saturating_sub(l1_data.used_gas());
+        let remaining_tx_count = max_tx_count().saturating_sub(l1_data.tx_count());
+        let transactions = transactions_source.next(
+            remaining_gas_limit,
+            remaining_tx_count,
+            block_transaction_size_limit.saturating_sub(l1_data.used_size()),
+        );
+
+        let produced = self.produce_in_parallel(
+            &header,
+            &transactions,
+            l1_data.tx_count(),
+            remaining_gas_limit,
+            &consensus_params,
+            coinbase_recipient,
+            gas_price,
+            &view,
+            l1_data.changes(),
+            &options,
+        )?;
+
+        match produced {
+            Parallel::Executed(executed) => {
+                ExecutionInstance::new(DisabledRelayer, view, options)
+                    .produce_from_parts_without_commit(
+                        l1_block,
+                        l1_data,
+                        executed,
+                        coinbase_recipient,
+                        gas_price,
+                    )
+            }
+            Parallel::Sequential => {
+                let components = Components {
+                    header_to_produce: header,
+                    transactions_source: OnceTransactionsSource::new_maybe_checked(
+                        transactions,
+                    ),
+                    coinbase_recipient,
+                    gas_price,
+                };
+                self.executor
+                    .produce_without_commit_with_source_direct_resolve(components)
+            }
+        }
     }
 
     pub fn validate(
         &self,
-        _block: &Block,
+        block: &Block,
     ) -> ExecutorResult<Uncommitted<ValidationResult, Changes>> {
-        unimplemented!("Not implemented yet");
+        if block.header().state_transition_bytecode_version()
+            != self.executor.native_executor_version()
+        {
+            return self.executor.validate(block);
+        }
+
+        match self.validate_in_parallel(block)? {
+            Parallel::Executed(result) => Ok(result),
+            Parallel::Sequential => self.executor.validate(block),
+        }
     }
 
     #[cfg(feature = "wasm-executor")]
     pub fn validate_uploaded_wasm(
         &self,
-        _wasm_root: &Bytes32,
+        wasm_root: &Bytes32,
     ) -> Result<(), UpgradableError> {
-        unimplemented!("Not implemented yet");
+        self.executor.validate_uploaded_wasm(wasm_root)
     }
 
     /// Executes the block and returns the result of the execution without committing
     /// the changes in the dry run mode.
     pub fn dry_run(
         &self,
-        _component: Components<Vec<Transaction>>,
-        _utxo_validation: Option<bool>,
+        component: Components<Vec<Transaction>>,
+        utxo_validation: Option<bool>,
     ) -> ExecutorResult<Vec<TransactionExecutionStatus>> {
-        unimplemented!("Not implemented yet");
+        let result = self
+            .executor
+            .dry_run(component, utxo_validation, None, false)?;
+
+        Ok(result
+            .transactions
+            .into_iter()
+            .map(|(_, status)| status)
+            .collect())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn produce_in_parallel(
+        &self,
+        header: &PartialBlockHeader,
+        transactions: &[MaybeCheckedTransaction],
+        first_index: u16,
+        remaining_gas_limit: u64,
+        consensus_params: &ConsensusParameters,
+        coinbase_recipient: ContractId,
+        gas_price: u64,
+        view: &Arc<S::LatestView>,
+        l1_changes: &Changes,
+        options: &ExecutionOptions,
+    ) -> ExecutorResult<Parallel<ExecutedTransactions>> {
+        // The sequential executor skips transactions that don't fit into the remaining
+        // gas of the block. It depends on the gas used by previous transactions,
+        // so we only execute in parallel when all transactions fit.
+        let mut max_gas = 0u64;
+        for transaction in transactions {
+            max_gas = max_gas.saturating_add(transaction.max_gas(consensus_params)?);
+        }
+        if max_gas > remaining_gas_limit {
+            return Ok(Parallel::Sequential);
+        }
+
+        let chain_id = consensus_params.chain_id();
+        let Some(partition) = self.partition(transactions, &chain_id) else {
+            return Ok(Parallel::Sequential);
+        };
+
+        // Optimistically assume that all transactions are included into the block.
+        let groups = build_groups(partition, transactions, |position| {
+            index_at(first_index, position)
+        })?;
+        let mut results = self.execute_groups(
+            header,
+            groups,
+            coinbase_recipient,
+            gas_price,
+            true,
+            view,
+            l1_changes,
+            options,
+        )?;
+
+        let mut skipped_transactions = vec![];
+        for (_, executed) in results.iter_mut() {
+            skipped_transactions.append(&mut executed.skipped_transactions);
+        }
+
+        if !skipped_transactions.is_empty() {
+            // Skipped transactions shift indexes of the following transactions.
+            // Groups with shifted transactions are re-executed with the indexes
+            // of the actual block. Skipping doesn't depend on the index,
+            // so the re-execution includes all remaining transactions.
+            let skipped = skipped_transactions
+                .iter()
+                .map(|(tx_id, _)| *tx_id)
+                .collect::<HashSet<_>>();
+            let included = transactions
+                .iter()
+                .enumerate()
+                .filter(|(_, tx)| !skipped.contains(&tx.id(&chain_id)))
+                .map(|(position, _)| position)
+                .collect::<Vec<_>>();
+            let first_skipped = transactions
+                .iter()
+                .position(|tx| skipped.contains(&tx.id(&chain_id)))
+                .expect("At least one transaction is skipped; qed");
+
+            let (shifted, unchanged): (Vec<_>, Vec<_>) =
+                results.into_iter().partition(|(group, _)| {
+                    group
+                        .positions
+                        .iter()
+                        .any(|position| *position > first_skipped)
+                });
+
+            let partition = shifted
+                .into_iter()
+                .map(|(group, _)| {
+                    group
+                        .positions
+                        .into_iter()
+                        .filter(|position| included.binary_search(position).is_ok())
+                        .collect::<Vec<_>>()
+                })
+                .collect();
+            let groups = build_groups(partition, transactions, |position| {
+                let rank = included
+                    .binary_search(&position)
+                    .expect("Only included transactions are re-executed; qed");
+                index_at(first_index, rank)
+            })?;
+            let rerun = self.execute_groups(
+                header,
+                groups,
+                coinbase_recipient,
+                gas_price,
+                true,
+                view,
+                l1_changes,
+                options,
+            )?;
+
+            if rerun
+                .iter()
+                .any(|(_, executed)| !executed.skipped_transactions.is_empty())
+            {
+                tracing::warn!(
+                    "Re-execution of transactions skipped new transactions, \
+                    falling back to the sequential execution"
+                );
+                return Ok(Parallel::Sequential);
+            }
+
+            results = unchanged.into_iter().chain(rerun).collect();
+        }
+
+        let Some(mut merged) = merge(results) else {
+            return Ok(Parallel::Sequential);
+        };
+
+        let positions = transactions
+            .iter()
+            .enumerate()
+            .map(|(position, tx)| (tx.id(&chain_id), position))
+            .collect::<HashMap<_, _>>();
+        skipped_transactions.sort_by_key(|(tx_id, _)| positions.get(tx_id).copied());
+        merged.skipped_transactions = skipped_transactions;
+
+        Ok(Parallel::Executed(merged))
+    }
+
+    fn validate_in_parallel(
+        &self,
+        block: &Block,
+    ) -> ExecutorResult<Parallel<Uncommitted<ValidationResult, Changes>>> {
+        let Some(Transaction::Mint(mint)) = block.transactions().last() else {
+            return Ok(Parallel::Sequential);
+        };
+        let gas_price = *mint.gas_price();
+        let coinbase_recipient = mint.input_contract().contract_id;
+
+        let header = PartialBlockHeader::from(block.header());
+        let view = Arc::new(self.executor.storage_view_provider.latest_view()?);
+        let relayer = self.executor.relayer_view_provider.latest_view()?;
+        let consensus_params =
+            consensus_parameters(view.as_ref(), &header.consensus_parameters_version)?;
+        let options = ExecutionOptions::from(self.executor.config.as_ref());
+
+        let (l1_block, l1_data) =
+            ExecutionInstance::new(relayer, view.clone(), options.clone())
+                .process_l1_without_commit(header, coinbase_recipient)?;
+
+        let first_index = l1_data.tx_count();
+        let Some(transactions) =
+            block
+                .transactions()
+                .len()
+                .checked_sub(1)
+                .and_then(|mint_index| {
+                    block.transactions().get(first_index as usize..mint_index)
+                })
+        else {
+            return Ok(Parallel::Sequential);
+        };
+        let transactions = transactions
+            .iter()
+            .cloned()
+            .map(MaybeCheckedTransaction::Transaction)
+            .collect::<Vec<_>>();
+
+        let chain_id = consensus_params.chain_id();
+        let Some(partition) = self.partition(&transactions, &chain_id) else {
+            return Ok(Parallel::Sequential);
+        };
+        let groups = build_groups(partition, &transactions, |position| {
+            index_at(first_index, position)
+        })?;
+
+        let results = match self.execute_groups(
+            &header,
+            groups,
+            coinbase_recipient,
+            gas_price,
+            false,
+            &view,
+            l1_data.changes(),
+            &options,
+        ) {
+            Ok(results) => results,
+            Err(err) => {
+                // The sequential execution is the source of truth for invalid blocks.
+                tracing::debug!("Parallel validation of the block failed: {err}");
+                return Ok(Parallel::Sequential);
+            }
+        };
+        let Some(merged) = merge(results) else {
+            return Ok(Parallel::Sequential);
+        };
+
+        let result = ExecutionInstance::new(DisabledRelayer, view, options)
+            .validate_from_parts_without_commit(block, l1_block, l1_data, merged)?;
+
+        Ok(Parallel::Executed(result))
+    }
+
+    /// Splits transactions into groups if it makes sense to execute them in parallel.
+    fn partition(
+        &self,
+        transactions: &[MaybeCheckedTransaction],
+        chain_id: &fuel_core_types::fuel_types::ChainId,
+    ) -> Option<Vec<Vec<usize>>> {
+        if self.number_of_cores.get() == 1 {
+            return None;
+        }
+
+        let partition = scheduler::partition(transactions, chain_id)?;
+        if partition.len() <= 1 {
+            return None;
+        }
+
+        Some(partition)
+    }
+
+    /// Executes each group in a separate task and waits for all of them.
+    #[allow(clippy::too_many_arguments)]
+    fn execute_groups(
+        &self,
+        header: &PartialBlockHeader,
+        groups: Vec<Group>,
+        coinbase_recipient: ContractId,
+        gas_price: u64,
+        skip_invalid: bool,
+        view: &Arc<S::LatestView>,
+        l1_changes: &Changes,
+        options: &ExecutionOptions,
+    ) -> ExecutorResult<Vec<(Group, ExecutedTransactions)>> {
+        let runtime = self
+            .runtime
+            .as_ref()
+            .expect("The runtime is only removed on drop; qed");
+        let (sender, receiver) = mpsc::channel();
+        let len = groups.len();
+
+        for (number, group) in groups.into_iter().enumerate() {
+            let sender = sender.clone();
+            let header = *header;
+            let storage = StorageTransaction::transaction(
+                view.clone(),
+                ConflictPolicy::Overwrite,
+                l1_changes.clone(),
+            );
+            let options = options.clone();
+
+            runtime.spawn(async move {
+                let Group {
+                    positions,
+                    transactions,
+                } = group;
+                let result = ExecutionInstance::new(DisabledRelayer, storage, options)
+                    .execute_transactions_without_commit(
+                        &header,
+                        transactions,
+                        coinbase_recipient,
+                        gas_price,
+                        skip_invalid,
+                    );
+                let group = Group {
+                    positions,
+                    transactions: vec![],
+                };
+                let _ = sender.send((number, group, result));
+            });
+        }
+        drop(sender);
+
+        let mut results = Vec::with_capacity(len);
+        for _ in 0..len {
+            let (number, group, result) = receiver.recv().map_err(|_| {
+                ExecutorError::Other(
+                    "The task executing the group of transactions panicked".to_string(),
+                )
+            })?;
+            results.push((number, group, result?));
+        }
+        results.sort_by_key(|(number, _, _)| *number);
+
+        Ok(results
+            .into_iter()
+            .map(|(_, group, executed)| (group, executed))
+            .collect())
+    }
+}
+
+/// Builds groups of transactions from the `partition` assigning
+/// indexes inside of the block with `index_of`.
+fn build_groups<F>(
+    partition: Vec<Vec<usize>>,
+    transactions: &[MaybeCheckedTransaction],
+    index_of: F,
+) -> ExecutorResult<Vec<Group>>
+where
+    F: Fn(usize) -> ExecutorResult<u16>,
+{
+    partition
+        .into_iter()
+        .map(|positions| {
+            let transactions = positions
+                .iter()
+                .map(|position| {
+                    Ok((index_of(*position)?, transactions[*position].clone()))
+                })
+                .collect::<ExecutorResult<Vec<_>>>()?;
+            Ok(Group {
+                positions,
+                transactions,
+            })
+        })
+        .collect()
+}
+
+fn index_at(first_index: u16, position: usize) -> ExecutorResult<u16> {
+    u16::try_from(position)
+        .ok()
+        .and_then(|position| first_index.checked_add(position))
+        .ok_or(ExecutorError::TooManyTransactions)
+}
+
+/// Merges results of the groups into one result.
+///
+/// Returns `None` if groups modified the same entry differently,
+/// meaning that groups were not independent.
+fn merge(results: Vec<(Group, ExecutedTransactions)>) -> Option<ExecutedTransactions> {
+    let mut merged = ExecutedTransactions::default();
+    let mut changes = HashMap::<u32, BTreeMap<_, _>>::new();
+
+    for (_, executed) in results {
+        let ExecutedTransactions {
+            transactions,
+            changes: group_changes,
+            ..
+        } = executed;
+
+        for (column, operations) in group_changes {
+            let merged_operations = changes.entry(column).or_default();
+            for (key, operation) in operations {
+                match merged_operations.entry(key) {
+                    btree_map::Entry::Vacant(entry) => {
+                        entry.insert(operation);
+                    }
+                    btree_map::Entry::Occupied(entry) => {
+                        if entry.get() != &operation {
+                            tracing::warn!(
+                                "Groups of transactions modified the same key {:?} \
+                                in the column {column}, falling back to \
+                                the sequential execution",
+                                entry.key()
+                            );
+                            return None;
+                        }
+                    }
+                }
+            }
+        }
+
+        merged.transactions.extend(transactions);
+    }
+
+    merged.transactions.sort_by_key(|executed| executed.index);
+    merged.changes = changes.into_iter().collect();
+
+    Some(merged)
+}
+
+fn consensus_parameters<V>(view: &V, version: &u32) -> ExecutorResult<ConsensusParameters>
+where
+    V: KeyValueInspect<Column = Column>,
+{
+    let consensus_params = view
+        .read_transaction()
+        .storage::<ConsensusParametersVersions>()
+        .get(version)?
+        .map(Cow::into_owned)
+        .ok_or(ExecutorError::ConsensusParametersNotFound(*version))?;
+
+    Ok(consensus_params)
+}
+
+/// The relayer used by the parts of the block that don't process DA events.
+#[derive(Clone, Copy)]
+struct DisabledRelayer;
+
+impl RelayerPort for DisabledRelayer {
+    fn enabled(&self) -> bool {
+        false
+    }
+
+    fn get_events(&self, _: &DaBlockHeight) -> anyhow::Result<Vec<Event>> {
+        Ok(vec![])
     }
 }
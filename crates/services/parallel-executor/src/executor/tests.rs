@@ -0,0 +1,433 @@
+#![allow(non_snake_case)]
+
+use super::*;
+use fuel_core_storage::{
+    Result as StorageResult,
+    StorageAsMut,
+    kv_store::Value,
+    structured_storage::test::InMemoryStorage,
+    transactional::{
+        Modifiable,
+        WriteTransaction,
+    },
+};
+use fuel_core_types::{
+    blockchain::{
+        header::{
+            ApplicationHeader,
+            ConsensusHeader,
+        },
+        primitives::Empty,
+    },
+    fuel_crypto::SecretKey,
+    fuel_tx::{
+        AssetId,
+        Output,
+        Receipt,
+        TransactionBuilder,
+        TxId,
+        UniqueIdentifier,
+        UtxoId,
+    },
+    tai64::Tai64,
+};
+use fuel_core_upgradable_executor::config::Config as ExecutorConfig;
+use rand::{
+    Rng,
+    SeedableRng,
+    rngs::StdRng,
+};
+
+#[derive(Clone, Debug)]
+struct Storage(InMemoryStorage<Column>);
+
+impl AtomicView for Storage {
+    type LatestView = InMemoryStorage<Column>;
+
+    fn latest_view(&self) -> StorageResult<Self::LatestView> {
+        Ok(self.0.clone())
+    }
+}
+
+impl HistoricalView for Storage {
+    type Height = BlockHeight;
+    type ViewAtHeight = Self::LatestView;
+
+    fn latest_height(&self) -> Option<Self::Height> {
+        None
+    }
+
+    fn view_at(&self, _: &Self::Height) -> StorageResult<Self::ViewAtHeight> {
+        self.latest_view()
+    }
+}
+
+impl KeyValueInspect for Storage {
+    type Column = Column;
+
+    fn get(&self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
+        self.0.get(key, column)
+    }
+}
+
+impl Modifiable for Storage {
+    fn commit_changes(&mut self, changes: Changes) -> StorageResult<()> {
+        self.0.commit_changes(changes)
+    }
+}
+
+impl AtomicView for DisabledRelayer {
+    type LatestView = Self;
+
+    fn latest_view(&self) -> StorageResult<Self::LatestView> {
+        Ok(*self)
+    }
+}
+
+const CONSENSUS_PARAMETERS_VERSION: u32 = 0;
+
+fn storage() -> Storage {
+    let mut storage = Storage(InMemoryStorage::default());
+    let mut tx = storage.write_transaction();
+    tx.storage_as_mut::<ConsensusParametersVersions>()
+        .insert(&CONSENSUS_PARAMETERS_VERSION, &Default::default())
+        .unwrap();
+    tx.commit().unwrap();
+
+    storage
+}
+
+fn parallel_executor(storage: Storage) -> Executor<Storage, DisabledRelayer> {
+    let config = Config {
+        number_of_cores: NonZeroUsize::new(4).unwrap(),
+        executor_config: Default::default(),
+    };
+    Executor::new(storage, DisabledRelayer, config)
+}
+
+fn sequential_executor(storage: Storage) -> UpgradableExecutor<Storage, DisabledRelayer> {
+    UpgradableExecutor::new(storage, DisabledRelayer, ExecutorConfig::default())
+}
+
+fn header() -> PartialBlockHeader {
+    PartialBlockHeader {
+        application: ApplicationHeader {
+            da_height: Default::default(),
+            consensus_parameters_version: CONSENSUS_PARAMETERS_VERSION,
+            state_transition_bytecode_version: UpgradableExecutor::<
+                Storage,
+                DisabledRelayer,
+            >::VERSION,
+            generated: Empty,
+        },
+        consensus: ConsensusHeader {
+            prev_root: Default::default(),
+            height: 1.into(),
+            time: Tai64::now(),
+            generated: Empty,
+        },
+    }
+}
+
+fn components(
+    transactions: Vec<Transaction>,
+    gas_price: u64,
+) -> Components<OnceTransactionsSource> {
+    Components {
+        header_to_produce: header(),
+        transactions_source: OnceTransactionsSource::new(transactions),
+        coinbase_recipient: Default::default(),
+        gas_price,
+    }
+}
+
+fn receipts(statuses: &[TransactionExecutionStatus]) -> Vec<(TxId, Vec<Receipt>)> {
+    statuses
+        .iter()
+        .map(|status| (status.id, status.result.receipts().to_vec()))
+        .collect()
+}
+
+fn transfer(rng: &mut StdRng, utxo_id: UtxoId) -> Transaction {
+    TransactionBuilder::script(vec![], vec![])
+        .add_unsigned_coin_input(
+            SecretKey::random(rng),
+            utxo_id,
+            1_000,
+            AssetId::BASE,
+            Default::default(),
+        )
+        .add_output(Output::coin(rng.r#gen(), 100, AssetId::BASE))
+        .add_output(Output::change(rng.r#gen(), 0, AssetId::BASE))
+        .max_fee_limit(100)
+        .finalize_as_transaction()
+}
+
+fn random_transfer(rng: &mut StdRng) -> Transaction {
+    let utxo_id = rng.r#gen();
+    transfer(rng, utxo_id)
+}
+
+fn independent_and_dependent_transactions(rng: &mut StdRng) -> Vec<Transaction> {
+    let first = random_transfer(rng);
+    let spends_first = transfer(rng, UtxoId::new(first.id(&Default::default()), 0));
+    vec![
+        first,
+        random_transfer(rng),
+        random_transfer(rng),
+        spends_first,
+        random_transfer(rng),
+    ]
+}
+
+#[test]
+fn produce_without_commit_with_source__produces_the_same_block_as_sequential_execution() {
+    let mut rng = StdRng::seed_from_u64(2322);
+
+    // Given
+    let transactions = independent_and_dependent_transactions(&mut rng);
+    let parallel = parallel_executor(storage());
+    let sequential = sequential_executor(storage());
+    let components = components(transactions.clone(), 0);
+    let header = components.header_to_produce;
+
+    // When
+    let (parallel_result, parallel_changes) = parallel
+        .produce_without_commit_with_source(components)
+        .unwrap()
+        .into();
+
+    // Then
+    let sequential_components = Components {
+        header_to_produce: header,
+        transactions_source: OnceTransactionsSource::new(transactions),
+        coinbase_recipient: Default::default(),
+        gas_price: 0,
+    };
+    let (sequential_result, sequential_changes) = sequential
+        .produce_without_commit_with_source_direct_resolve(sequential_components)
+        .unwrap()
+        .into();
+    assert_eq!(parallel_result.block.id(), sequential_result.block.id());
+    assert_eq!(
+        receipts(&parallel_result.tx_status),
+        receipts(&sequential_result.tx_status)
+    );
+    assert_eq!(
+        format!("{:?}", parallel_result.events),
+        format!("{:?}", sequential_result.events)
+    );
+    assert!(parallel_result.skipped_transactions.is_empty());
+    assert_eq!(parallel_changes, sequential_changes);
+}
+
+#[test]
+fn produce_without_commit_with_source__skips_invalid_transactions_like_sequential_execution()
+ {
+    let mut rng = StdRng::seed_from_u64(2322);
+
+    // Given
+    let gas_price = 1;
+    let mut transactions = independent_and_dependent_transactions(&mut rng);
+    let cannot_pay_fee = TransactionBuilder::script(vec![], vec![])
+        .add_unsigned_coin_input(
+            SecretKey::random(&mut rng),
+            rng.r#gen(),
+            1_000,
+            AssetId::BASE,
+            Default::default(),
+        )
+        .max_fee_limit(0)
+        .finalize_as_transaction();
+    let cannot_pay_fee_id = cannot_pay_fee.id(&Default::default());
+    transactions.insert(1, cannot_pay_fee);
+    let parallel = parallel_executor(storage());
+    let sequential = sequential_executor(storage());
+
+    // When
+    let (parallel_result, parallel_changes) = parallel
+        .produce_without_commit_with_source(components(transactions.clone(), gas_price))
+        .unwrap()
+        .into();
+
+    // Then
+    let (sequential_result, sequential_changes) = sequential
+        .produce_without_commit_with_source_direct_resolve(components(
+            transactions,
+            gas_price,
+        ))
+        .unwrap()
+        .into();
+    let skipped = parallel_result
+        .skipped_transactions
+        .iter()
+        .map(|(tx_id, _)| *tx_id)
+        .collect::<Vec<_>>();
+    assert_eq!(skipped, vec![cannot_pay_fee_id]);
+    assert_eq!(
+        parallel_result.block.transactions(),
+        sequential_result.block.transactions()
+    );
+    assert_eq!(parallel_changes, sequential_changes);
+}
+
+#[test]
+fn validate__accepts_block_produced_in_parallel() {
+    let mut rng = StdRng::seed_from_u64(2322);
+
+    // Given
+    let transactions = independent_and_dependent_transactions(&mut rng);
+    let producer = parallel_executor(storage());
+    let (result, produced_changes) = producer
+        .produce_without_commit_with_source(components(transactions, 0))
+        .unwrap()
+        .into();
+    let block = result.block;
+    let validator = parallel_executor(storage());
+
+    // When
+    let (parallel_result, parallel_changes) = validator.validate(&block).unwrap().into();
+
+    // Then
+    let (sequential_result, sequential_changes) = sequential_executor(storage())
+        .validate(&block)
+        .unwrap()
+        .into();
+    assert_eq!(
+        receipts(&parallel_result.tx_status),
+        receipts(&sequential_result.tx_status)
+    );
+    assert_eq!(
+        format!("{:?}", parallel_result.events),
+        format!("{:?}", sequential_result.events)
+    );
+    assert_eq!(parallel_changes, sequential_changes);
+    assert_eq!(parallel_changes, produced_changes);
+}
+
+#[test]
+fn validate__rejects_block_with_modified_transaction() {
+    let mut rng = StdRng::seed_from_u64(2322);
+
+    // Given
+    let transactions = independent_and_dependent_transactions(&mut rng);
+    let producer = parallel_executor(storage());
+    let (result, _) = producer
+        .produce_without_commit_with_source(components(transactions, 0))
+        .unwrap()
+        .into();
+    let mut block = result.block;
+    let replaced = random_transfer(&mut rng);
+    block.transactions_mut()[2] = replaced;
+    let validator = parallel_executor(storage());
+
+    // When
+    let result = validator.validate(&block);
+
+    // Then
+    assert!(result.is_err());
+}
+
+/// Generates a random block mixing independent transfers, transfers spending the
+/// outputs of other transactions of the block and transfers competing for the
+/// coin spent by another transaction. The dependent transactions sometimes come
+/// before the transactions they depend on.
+fn random_transactions(rng: &mut StdRng) -> Vec<Transaction> {
+    let count = rng.gen_range(1..=30);
+    let mut transactions: Vec<Transaction> = Vec::with_capacity(count);
+    let mut spent: Vec<UtxoId> = Vec::with_capacity(count);
+
+    for _ in 0..count {
+        let utxo_id = match rng.gen_range(0..4) {
+            0 if !transactions.is_empty() => {
+                let parent = &transactions[rng.gen_range(0..transactions.len())];
+                UtxoId::new(parent.id(&Default::default()), rng.gen_range(0..2))
+            }
+            1 if !spent.is_empty() => spent[rng.gen_range(0..spent.len())],
+            _ => rng.r#gen(),
+        };
+        spent.push(utxo_id);
+        let transaction = transfer(rng, utxo_id);
+        if rng.gen_bool(0.1) {
+            let index = rng.gen_range(0..=transactions.len());
+            transactions.insert(index, transaction);
+        } else {
+            transactions.push(transaction);
+        }
+    }
+
+    transactions
+}
+
+fn skipped(result: &ExecutionResult) -> Vec<TxId> {
+    result
+        .skipped_transactions
+        .iter()
+        .map(|(tx_id, _)| *tx_id)
+        .collect()
+}
+
+#[test]
+fn parallel_execution__matches_sequential_execution_for_random_blocks() {
+    const ITERATIONS: usize = 50;
+    let seed: u64 = rand::random();
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    for iteration in 0..ITERATIONS {
+        // Given
+        let transactions = random_transactions(&mut rng);
+        let gas_price = rng.gen_range(0..=1);
+        let header = header();
+        let components = |transactions| Components {
+            header_to_produce: header,
+            transactions_source: OnceTransactionsSource::new(transactions),
+            coinbase_recipient: Default::default(),
+            gas_price,
+        };
+
+        // When
+        let (parallel_result, parallel_changes) = parallel_executor(storage())
+            .produce_without_commit_with_source(components(transactions.clone()))
+            .unwrap()
+            .into();
+
+        // Then
+        let (sequential_result, sequential_changes) = sequential_executor(storage())
+            .produce_without_commit_with_source_direct_resolve(components(transactions))
+            .unwrap()
+            .into();
+        let context = format!("seed: {seed}, iteration: {iteration}");
+        assert_eq!(
+            parallel_result.block.id(),
+            sequential_result.block.id(),
+            "{context}"
+        );
+        assert_eq!(
+            skipped(&parallel_result),
+            skipped(&sequential_result),
+            "{context}"
+        );
+        assert_eq!(
+            receipts(&parallel_result.tx_status),
+            receipts(&sequential_result.tx_status),
+            "{context}"
+        );
+        assert_eq!(
+            format!("{:?}", parallel_result.events),
+            format!("{:?}", sequential_result.events),
+            "{context}"
+        );
+        assert_eq!(parallel_changes, sequential_changes, "{context}");
+
+        let (validated, validated_changes) = parallel_executor(storage())
+            .validate(&parallel_result.block)
+            .unwrap()
+            .into();
+        assert_eq!(
+            receipts(&validated.tx_status),
+            receipts(&sequential_result.tx_status),
+            "{context}"
+        );
+        assert_eq!(validated_changes, sequential_changes, "{context}");
+    }
+}
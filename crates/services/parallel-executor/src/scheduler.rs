@@ -0,0 +1,348 @@
+//! The scheduler splits transactions of the block into groups that don't conflict
+//! with each other, so the groups can be executed in parallel.
+//!
+//! Two transactions conflict if they touch the same coin, message or contract,
+//! or if one of them spends the output of another. Transactions that can
+//! affect the whole state of the chain, like `Upgrade`, `Upload` and `Blob`,
+//! can't be scheduled and require sequential execution of the block.
+
+use fuel_core_types::{
+    fuel_tx::{
+        ContractId,
+        Input,
+        Output,
+        Transaction,
+        TxId,
+        UtxoId,
+        field::{
+            Inputs,
+            Outputs,
+        },
+    },
+    fuel_types::{
+        ChainId,
+        Nonce,
+    },
+    fuel_vm::checked_transaction::CheckedTransaction,
+};
+use fuel_core_upgradable_executor::native_executor::ports::MaybeCheckedTransaction;
+use std::collections::{
+    HashMap,
+    HashSet,
+};
+
+/// The resource that can be accessed by several transactions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum ConflictKey {
+    /// The transaction itself. Used to link transactions
+    /// spending the outputs of the transaction from the same block.
+    Transaction(TxId),
+    Coin(UtxoId),
+    Message(Nonce),
+    Contract(ContractId),
+}
+
+/// Splits `transactions` into groups that can be executed independently.
+///
+/// Each group contains indexes of the transactions in the original order.
+/// Groups are sorted by the index of their first transaction.
+///
+/// Returns `None` if some transactions can't be scheduled, and the whole
+/// block should be executed sequentially.
+pub fn partition(
+    transactions: &[MaybeCheckedTransaction],
+    chain_id: &ChainId,
+) -> Option<Vec<Vec<usize>>> {
+    let ids = transactions
+        .iter()
+        .map(|tx| tx.id(chain_id))
+        .collect::<HashSet<_>>();
+
+    let mut sets = DisjointSets::new(transactions.len());
+    let mut owners = HashMap::<ConflictKey, usize>::new();
+
+    for (index, transaction) in transactions.iter().enumerate() {
+        let (inputs, outputs) = inputs_and_outputs(transaction)?;
+        let keys = conflict_keys(transaction.id(chain_id), inputs, outputs, &ids);
+
+        for key in keys {
+            match owners.get(&key) {
+                Some(owner) => sets.union(*owner, index),
+                None => {
+                    owners.insert(key, index);
+                }
+            }
+        }
+    }
+
+    let mut groups = Vec::<Vec<usize>>::new();
+    let mut group_of_root = HashMap::<usize, usize>::new();
+    for index in 0..transactions.len() {
+        let root = sets.find(index);
+        let group = *group_of_root.entry(root).or_insert_with(|| {
+            groups.push(vec![]);
+            groups.len().saturating_sub(1)
+        });
+        groups[group].push(index);
+    }
+
+    Some(groups)
+}
+
+fn inputs_and_outputs(
+    transaction: &MaybeCheckedTransaction,
+) -> Option<(&[Input], &[Output])> {
+    match transaction {
+        MaybeCheckedTransaction::CheckedTransaction(
+            CheckedTransaction::Script(tx),
+            _,
+        ) => Some((tx.transaction().inputs(), tx.transaction().outputs())),
+        MaybeCheckedTransaction::CheckedTransaction(
+            CheckedTransaction::Create(tx),
+            _,
+        ) => Some((tx.transaction().inputs(), tx.transaction().outputs())),
+        MaybeCheckedTransaction::Transaction(Transaction::Script(tx)) => {
+            Some((tx.inputs(), tx.outputs()))
+        }
+        MaybeCheckedTransaction::Transaction(Transaction::Create(tx)) => {
+            Some((tx.inputs(), tx.outputs()))
+        }
+        MaybeCheckedTransaction::CheckedTransaction(CheckedTransaction::Mint(_), _)
+        | MaybeCheckedTransaction::CheckedTransaction(
+            CheckedTransaction::Upgrade(_),
+            _,
+        )
+        | MaybeCheckedTransaction::CheckedTransaction(CheckedTransaction::Upload(_), _)
+        | MaybeCheckedTransaction::CheckedTransaction(CheckedTransaction::Blob(_), _)
+        | MaybeCheckedTransaction::Transaction(Transaction::Mint(_))
+        | MaybeCheckedTransaction::Transaction(Transaction::Upgrade(_))
+        | MaybeCheckedTransaction::Transaction(Transaction::Upload(_))
+        | MaybeCheckedTransaction::Transaction(Transaction::Blob(_)) => None,
+    }
+}
+
+fn conflict_keys(
+    tx_id: TxId,
+    inputs: &[Input],
+    outputs: &[Output],
+    block_transactions: &HashSet<TxId>,
+) -> Vec<ConflictKey> {
+    let mut keys = vec![ConflictKey::Transaction(tx_id)];
+
+    for input in inputs {
+        match input {
+            Input::CoinSigned(coin) => {
+                keys.push(ConflictKey::Coin(coin.utxo_id));
+                if block_transactions.contains(coin.utxo_id.tx_id()) {
+                    keys.push(ConflictKey::Transaction(*coin.utxo_id.tx_id()));
+                }
+            }
+            Input::CoinPredicate(coin) => {
+                keys.push(ConflictKey::Coin(coin.utxo_id));
+                if block_transactions.contains(coin.utxo_id.tx_id()) {
+                    keys.push(ConflictKey::Transaction(*coin.utxo_id.tx_id()));
+                }
+            }
+            Input::Contract(contract) => {
+                keys.push(ConflictKey::Contract(contract.contract_id));
+            }
+            Input::MessageCoinSigned(message) => {
+                keys.push(ConflictKey::Message(message.nonce));
+            }
+            Input::MessageCoinPredicate(message) => {
+                keys.push(ConflictKey::Message(message.nonce));
+            }
+            Input::MessageDataSigned(message) => {
+                keys.push(ConflictKey::Message(message.nonce));
+            }
+            Input::MessageDataPredicate(message) => {
+                keys.push(ConflictKey::Message(message.nonce));
+            }
+        }
+    }
+
+    for output in outputs {
+        if let Output::ContractCreated { contract_id, .. } = output {
+            keys.push(ConflictKey::Contract(*contract_id));
+        }
+    }
+
+    keys
+}
+
+/// Union-find over the indexes of transactions.
+struct DisjointSets {
+    parents: Vec<usize>,
+}
+
+impl DisjointSets {
+    fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+        }
+    }
+
+    fn find(&mut self, index: usize) -> usize {
+        let mut root = index;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        let mut current = index;
+        while self.parents[current] != root {
+            let next = self.parents[current];
+            self.parents[current] = root;
+            current = next;
+        }
+
+        root
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let a = self.find(a);
+        let b = self.find(b);
+        // The smallest index is the root to keep the order of groups stable.
+        if a < b {
+            self.parents[b] = a;
+        } else {
+            self.parents[a] = b;
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use fuel_core_types::{
+        fuel_tx::{
+            AssetId,
+            TransactionBuilder,
+            TxPointer,
+        },
+        fuel_types::Address,
+    };
+
+    fn coin_input(utxo_id: UtxoId) -> Input {
+        Input::coin_signed(
+            utxo_id,
+            Address::default(),
+            1,
+            AssetId::BASE,
+            TxPointer::default(),
+            0,
+        )
+    }
+
+    fn contract_input(contract_id: ContractId) -> Input {
+        Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract_id,
+        )
+    }
+
+    fn script(inputs: Vec<Input>) -> MaybeCheckedTransaction {
+        let mut builder = TransactionBuilder::script(vec![], vec![]);
+        for input in inputs {
+            builder.add_input(input);
+        }
+        MaybeCheckedTransaction::Transaction(builder.finalize_as_transaction())
+    }
+
+    fn utxo(byte: u8) -> UtxoId {
+        UtxoId::new([byte; 32].into(), 0)
+    }
+
+    #[test]
+    fn partition__independent_transactions_are_in_separate_groups() {
+        // Given
+        let transactions = vec![
+            script(vec![coin_input(utxo(1))]),
+            script(vec![coin_input(utxo(2))]),
+            script(vec![coin_input(utxo(3))]),
+        ];
+
+        // When
+        let groups = partition(&transactions, &ChainId::default());
+
+        // Then
+        assert_eq!(groups, Some(vec![vec![0], vec![1], vec![2]]));
+    }
+
+    #[test]
+    fn partition__transactions_using_the_same_contract_are_in_one_group() {
+        // Given
+        let contract_id = ContractId::from([7; 32]);
+        let transactions = vec![
+            script(vec![coin_input(utxo(1)), contract_input(contract_id)]),
+            script(vec![coin_input(utxo(2))]),
+            script(vec![coin_input(utxo(3)), contract_input(contract_id)]),
+        ];
+
+        // When
+        let groups = partition(&transactions, &ChainId::default());
+
+        // Then
+        assert_eq!(groups, Some(vec![vec![0, 2], vec![1]]));
+    }
+
+    #[test]
+    fn partition__transaction_spending_output_of_another_is_in_its_group() {
+        // Given
+        let chain_id = ChainId::default();
+        let parent = script(vec![coin_input(utxo(1))]);
+        let parent_id = parent.id(&chain_id);
+        let transactions = vec![
+            parent,
+            script(vec![coin_input(utxo(2))]),
+            script(vec![coin_input(UtxoId::new(parent_id, 0))]),
+        ];
+
+        // When
+        let groups = partition(&transactions, &chain_id);
+
+        // Then
+        assert_eq!(groups, Some(vec![vec![0, 2], vec![1]]));
+    }
+
+    #[test]
+    fn partition__transitive_conflicts_merge_groups() {
+        // Given
+        let transactions = vec![
+            script(vec![coin_input(utxo(1)), contract_input([1; 32].into())]),
+            script(vec![coin_input(utxo(2)), contract_input([2; 32].into())]),
+            script(vec![
+                coin_input(utxo(3)),
+                contract_input([1; 32].into()),
+                contract_input([2; 32].into()),
+            ]),
+            script(vec![coin_input(utxo(4))]),
+        ];
+
+        // When
+        let groups = partition(&transactions, &ChainId::default());
+
+        // Then
+        assert_eq!(groups, Some(vec![vec![0, 1, 2], vec![3]]));
+    }
+
+    #[test]
+    fn partition__upgradable_transactions_are_not_scheduled() {
+        // Given
+        let transactions = vec![
+            script(vec![coin_input(utxo(1))]),
+            MaybeCheckedTransaction::Transaction(
+                TransactionBuilder::blob(Default::default()).finalize_as_transaction(),
+            ),
+        ];
+
+        // When
+        let groups = partition(&transactions, &ChainId::default());
+
+        // Then
+        assert_eq!(groups, None);
+    }
+}
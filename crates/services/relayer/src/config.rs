@@ -1,3 +1,4 @@
+use crate::decoder::EventDecoderRegistry;
 use ethers_contract::EthEvent;
 use ethers_core::types::H256;
 use fuel_core_types::{
@@ -6,6 +7,7 @@ use fuel_core_types::{
 };
 use once_cell::sync::Lazy;
 use std::{
+    collections::HashMap,
     str::FromStr,
     time::Duration,
 };
@@ -27,6 +29,15 @@ pub struct Config {
     // TODO: Create `EthAddress` into `fuel_core_types`.
     /// Ethereum contract address.
     pub eth_v2_listening_contracts: Vec<Bytes20>,
+    /// The names of the events watched for the listening contracts
+    /// in addition to messages and forced transactions.
+    pub contract_events: HashMap<Bytes20, Vec<String>>,
+    /// The decoders of the events available to the listening contracts.
+    pub event_decoders: EventDecoderRegistry,
+    /// The number of DA blocks that must be built on top of a DA block
+    /// before the relayer processes it. If not set, the relayer follows
+    /// the `finalized` block reported by the DA node.
+    pub confirmation_depth: Option<u64>,
     /// Number of pages or blocks containing logs that
     /// should be downloaded in a single call to the da layer
     pub log_page_size: u64,
@@ -61,6 +72,9 @@ impl Default for Config {
             eth_v2_listening_contracts: vec![
                 Bytes20::from_str("0x03E4538018285e1c03CCce2F92C9538c87606911").unwrap(),
             ],
+            contract_events: HashMap::new(),
+            event_decoders: EventDecoderRegistry::default(),
+            confirmation_depth: None,
             log_page_size: Self::DEFAULT_LOG_PAGE_SIZE,
             sync_minimum_duration: Self::DEFAULT_SYNC_MINIMUM_DURATION,
             syncing_call_frequency: Self::DEFAULT_SYNCING_CALL_FREQ,
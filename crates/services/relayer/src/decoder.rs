@@ -0,0 +1,358 @@
+//! Decoders turning the logs of the DA contracts into the relayer events.
+//!
+//! The relayer looks up the decoder by the signature of the event (the first topic of
+//! the log). The built-in decoders handle messages and forced transactions. Additional
+//! events can be registered in the [`EventDecoderRegistry`] and selected per listening
+//! contract via [`Config::contract_events`](crate::Config::contract_events).
+
+use crate::log::EthEventLog;
+use anyhow::anyhow;
+use ethers_core::{
+    abi::{
+        ParamType,
+        RawLog,
+        Token,
+    },
+    types::{
+        H160,
+        H256,
+        Log,
+    },
+};
+use fuel_core_types::{
+    blockchain::primitives::DaBlockHeight,
+    entities::{
+        Message,
+        RelayedTransaction,
+    },
+    fuel_types::Bytes20,
+    services::relayer::{
+        Event,
+        RelayedEvent,
+    },
+};
+use std::{
+    collections::{
+        BTreeSet,
+        HashMap,
+    },
+    fmt,
+    sync::Arc,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The name of the built-in decoder of the `MessageSent` events.
+pub const MESSAGE_EVENT: &str = "message";
+/// The name of the built-in decoder of the forced `Transaction` events.
+pub const TRANSACTION_EVENT: &str = "transaction";
+/// The events watched by all listening contracts, in addition to the configured events.
+pub const DEFAULT_EVENTS: [&str; 2] = [MESSAGE_EVENT, TRANSACTION_EVENT];
+
+/// Decodes the logs of a single DA event into the relayer events.
+pub trait EventDecoder: Send + Sync {
+    /// The name used to select the event for the listening contracts.
+    fn name(&self) -> &str;
+
+    /// The signature of the event, i.e., the first topic of its logs.
+    fn signature(&self) -> H256;
+
+    /// Decodes the log of the event.
+    fn decode(&self, log: &Log) -> anyhow::Result<Event>;
+}
+
+/// The set of decoders available to the relayer, keyed by the event signature.
+#[derive(Clone)]
+pub struct EventDecoderRegistry {
+    decoders: HashMap<H256, Arc<dyn EventDecoder>>,
+}
+
+impl EventDecoderRegistry {
+    /// Creates the registry without any decoders.
+    pub fn empty() -> Self {
+        Self {
+            decoders: HashMap::new(),
+        }
+    }
+
+    /// Registers the `decoder`. The name and the signature of the decoder must be unique.
+    pub fn register(&mut self, decoder: Arc<dyn EventDecoder>) -> anyhow::Result<()> {
+        if self.get(decoder.name()).is_some() {
+            return Err(anyhow!(
+                "The decoder of the `{}` events is already registered",
+                decoder.name()
+            ))
+        }
+        if let Some(other) = self.decoders.get(&decoder.signature()) {
+            return Err(anyhow!(
+                "The `{}` and `{}` events have the same signature {:?}",
+                other.name(),
+                decoder.name(),
+                decoder.signature()
+            ))
+        }
+        self.decoders.insert(decoder.signature(), decoder);
+        Ok(())
+    }
+
+    /// Returns the decoder with the `name`.
+    pub fn get(&self, name: &str) -> Option<&Arc<dyn EventDecoder>> {
+        self.decoders
+            .values()
+            .find(|decoder| decoder.name() == name)
+    }
+
+    /// Returns the decoder of the event with the `signature`.
+    pub fn get_by_signature(&self, signature: &H256) -> Option<&Arc<dyn EventDecoder>> {
+        self.decoders.get(signature)
+    }
+}
+
+impl Default for EventDecoderRegistry {
+    /// Creates the registry with the built-in decoders of messages and forced transactions.
+    fn default() -> Self {
+        let mut registry = Self::empty();
+        registry
+            .register(Arc::new(MessageSentDecoder))
+            .expect("The registry is empty");
+        registry
+            .register(Arc::new(ForcedTransactionDecoder))
+            .expect("The signatures of built-in events are unique");
+        registry
+    }
+}
+
+impl fmt::Debug for EventDecoderRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names: BTreeSet<_> = self.decoders.values().map(|d| d.name()).collect();
+        f.debug_struct("EventDecoderRegistry")
+            .field("decoders", &names)
+            .finish()
+    }
+}
+
+/// The built-in decoder of the `MessageSent` events.
+pub struct MessageSentDecoder;
+
+impl EventDecoder for MessageSentDecoder {
+    fn name(&self) -> &str {
+        MESSAGE_EVENT
+    }
+
+    fn signature(&self) -> H256 {
+        *crate::config::ETH_LOG_MESSAGE
+    }
+
+    fn decode(&self, log: &Log) -> anyhow::Result<Event> {
+        match EthEventLog::try_from(log)? {
+            EthEventLog::Message(message) => Ok(Event::Message(Message::from(&message))),
+            _ => Err(anyhow!("The log is not a `MessageSent` event")),
+        }
+    }
+}
+
+/// The built-in decoder of the forced `Transaction` events.
+pub struct ForcedTransactionDecoder;
+
+impl EventDecoder for ForcedTransactionDecoder {
+    fn name(&self) -> &str {
+        TRANSACTION_EVENT
+    }
+
+    fn signature(&self) -> H256 {
+        *crate::config::ETH_FORCED_TX
+    }
+
+    fn decode(&self, log: &Log) -> anyhow::Result<Event> {
+        match EthEventLog::try_from(log)? {
+            EthEventLog::Transaction(transaction) => {
+                Ok(Event::Transaction(RelayedTransaction::from(transaction)))
+            }
+            _ => Err(anyhow!("The log is not a forced `Transaction` event")),
+        }
+    }
+}
+
+/// Decodes any event described by its Solidity definition into [`RelayedEvent`].
+///
+/// The payload of the relayed event contains the ABI encoded parameters of the event
+/// in the order of the definition. The indexed parameters of dynamic types are
+/// only available as the hash, so they are encoded as `bytes32`.
+pub struct AbiEventDecoder {
+    name: String,
+    event: ethers_core::abi::Event,
+}
+
+impl AbiEventDecoder {
+    /// Creates the decoder from the human-readable definition of the event, e.g.
+    /// `event Deposit(bytes32 indexed recipient, address token, uint256 amount)`.
+    pub fn parse(name: impl Into<String>, definition: &str) -> anyhow::Result<Self> {
+        let abi = ethers_core::abi::parse_abi(&[definition])
+            .map_err(|err| anyhow!("Invalid event definition `{definition}`: {err}"))?;
+        let mut events = abi.events();
+        let (Some(event), None) = (events.next(), events.next()) else {
+            return Err(anyhow!(
+                "Expected exactly one event in the definition `{definition}`"
+            ))
+        };
+        if event.anonymous {
+            return Err(anyhow!(
+                "Anonymous events can't be decoded by the signature"
+            ))
+        }
+        Ok(Self {
+            name: name.into(),
+            event: event.clone(),
+        })
+    }
+
+    /// Decodes the parameters of the event from the payload of the [`RelayedEvent`].
+    pub fn decode_payload(&self, payload: &[u8]) -> anyhow::Result<Vec<Token>> {
+        let kinds: Vec<_> = self
+            .event
+            .inputs
+            .iter()
+            .map(|input| match input.kind {
+                ParamType::Bytes
+                | ParamType::String
+                | ParamType::Array(_)
+                | ParamType::FixedArray(_, _)
+                | ParamType::Tuple(_)
+                    if input.indexed =>
+                {
+                    ParamType::FixedBytes(32)
+                }
+                ref kind => kind.clone(),
+            })
+            .collect();
+        Ok(ethers_core::abi::decode(&kinds, payload)?)
+    }
+}
+
+impl EventDecoder for AbiEventDecoder {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn signature(&self) -> H256 {
+        self.event.signature()
+    }
+
+    fn decode(&self, log: &Log) -> anyhow::Result<Event> {
+        let raw_log = RawLog {
+            topics: log.topics.clone(),
+            data: log.data.to_vec(),
+        };
+        let tokens: Vec<_> = self
+            .event
+            .parse_log(raw_log)?
+            .params
+            .into_iter()
+            .map(|param| param.value)
+            .collect();
+        let da_height = log
+            .block_number
+            .ok_or(anyhow!("Log missing block height"))?
+            .as_u64();
+        let log_index = log
+            .log_index
+            .ok_or(anyhow!("Log missing `log_index`"))?
+            .as_u64();
+
+        Ok(Event::Custom(RelayedEvent {
+            kind: self.name.clone(),
+            contract: Bytes20::from(log.address.0),
+            da_height: DaBlockHeight::from(da_height),
+            log_index,
+            payload: ethers_core::abi::encode(&tokens),
+        }))
+    }
+}
+
+type DecodersBySignature = HashMap<H256, Arc<dyn EventDecoder>>;
+
+/// Decodes the logs of the listening contracts, taking into account
+/// the events selected for each contract.
+pub(crate) struct LogDecoder {
+    default_events: DecodersBySignature,
+    contract_events: HashMap<H160, DecodersBySignature>,
+}
+
+impl LogDecoder {
+    /// Selects the decoders of the `contract_events` from the `registry`.
+    /// Each contract also watches the [`DEFAULT_EVENTS`], so the configured events
+    /// never stop the relaying of messages and forced transactions.
+    pub fn new(
+        registry: &EventDecoderRegistry,
+        contract_events: &HashMap<Bytes20, Vec<String>>,
+    ) -> anyhow::Result<Self> {
+        let default_events = select(registry, DEFAULT_EVENTS)?;
+        let contract_events = contract_events
+            .iter()
+            .map(|(contract, names)| {
+                let contract = H160::from_slice(contract.as_slice());
+                let mut decoders = default_events.clone();
+                decoders.extend(select(registry, names.iter().map(String::as_str))?);
+                Ok((contract, decoders))
+            })
+            .collect::<anyhow::Result<_>>()?;
+
+        Ok(Self {
+            default_events,
+            contract_events,
+        })
+    }
+
+    /// Returns the signatures of all events watched by the relayer.
+    pub fn signatures(&self) -> Vec<H256> {
+        self.default_events
+            .keys()
+            .chain(
+                self.contract_events
+                    .values()
+                    .flat_map(|events| events.keys()),
+            )
+            .copied()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    /// Decodes the `log` if the event is watched for the contract that emitted it.
+    pub fn decode(&self, log: &Log) -> anyhow::Result<Option<Event>> {
+        let Some(signature) = log.topics.first() else {
+            return Err(anyhow!("Topic list is empty"))
+        };
+        let events = self
+            .contract_events
+            .get(&log.address)
+            .unwrap_or(&self.default_events);
+        events
+            .get(signature)
+            .map(|decoder| decoder.decode(log))
+            .transpose()
+    }
+}
+
+fn select<'a>(
+    registry: &EventDecoderRegistry,
+    names: impl IntoIterator<Item = &'a str>,
+) -> anyhow::Result<DecodersBySignature> {
+    names
+        .into_iter()
+        .map(|name| {
+            let decoder = registry
+                .get(name)
+                .ok_or_else(|| anyhow!("Unknown relayer event `{name}`"))?;
+            Ok((decoder.signature(), decoder.clone()))
+        })
+        .collect()
+}
+
+impl Default for LogDecoder {
+    fn default() -> Self {
+        Self::new(&EventDecoderRegistry::default(), &HashMap::new())
+            .expect("The built-in events are registered")
+    }
+}
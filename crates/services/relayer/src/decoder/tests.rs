@@ -0,0 +1,213 @@
+#![allow(non_snake_case)]
+
+use super::*;
+use crate::{
+    abi::bridge::MessageSentFilter,
+    test_helpers::EvtToLog,
+};
+use ethers_core::{
+    types::U256,
+    utils::keccak256,
+};
+
+const DEPOSIT: &str = "deposit";
+const DEPOSIT_DEFINITION: &str =
+    "event Deposit(bytes32 indexed recipient, address token, uint256 amount)";
+
+fn contract(n: u8) -> Bytes20 {
+    Bytes20::from([n; 20])
+}
+
+fn deposit_decoder() -> Arc<dyn EventDecoder> {
+    Arc::new(AbiEventDecoder::parse(DEPOSIT, DEPOSIT_DEFINITION).unwrap())
+}
+
+fn registry_with_deposit() -> EventDecoderRegistry {
+    let mut registry = EventDecoderRegistry::default();
+    registry.register(deposit_decoder()).unwrap();
+    registry
+}
+
+fn deposit_log(contract: Bytes20, recipient: H256, token: H160, amount: u64) -> Log {
+    Log {
+        address: H160::from_slice(contract.as_slice()),
+        topics: vec![deposit_decoder().signature(), recipient],
+        data: ethers_core::abi::encode(&[
+            Token::Address(token),
+            Token::Uint(amount.into()),
+        ])
+        .into(),
+        block_number: Some(5.into()),
+        log_index: Some(2.into()),
+        ..Default::default()
+    }
+}
+
+fn message_log(contract: Bytes20) -> Log {
+    let mut log = MessageSentFilter::default().into_log();
+    log.address = H160::from_slice(contract.as_slice());
+    log.block_number = Some(5.into());
+    log.log_index = Some(1.into());
+    log
+}
+
+#[test]
+fn abi_event_decoder__decodes_log_into_custom_event() {
+    // Given
+    let decoder = AbiEventDecoder::parse(DEPOSIT, DEPOSIT_DEFINITION).unwrap();
+    let recipient = H256::from([7; 32]);
+    let token = H160::from([8; 20]);
+    let log = deposit_log(contract(1), recipient, token, 100);
+
+    // When
+    let event = decoder.decode(&log).unwrap();
+
+    // Then
+    let Event::Custom(event) = event else {
+        panic!("Expected the custom event, got {event:?}")
+    };
+    assert_eq!(event.kind, DEPOSIT);
+    assert_eq!(event.contract, contract(1));
+    assert_eq!(event.da_height, 5u64.into());
+    assert_eq!(event.log_index, 2);
+    assert_eq!(
+        decoder.decode_payload(&event.payload).unwrap(),
+        vec![
+            Token::FixedBytes(recipient.0.to_vec()),
+            Token::Address(token),
+            Token::Uint(U256::from(100)),
+        ]
+    );
+}
+
+#[test]
+fn abi_event_decoder__encodes_indexed_dynamic_parameters_as_hash() {
+    // Given
+    let decoder = AbiEventDecoder::parse(
+        "named",
+        "event Named(string indexed name, uint256 value)",
+    )
+    .unwrap();
+    let name_hash = H256::from(keccak256("fuel"));
+    let log = Log {
+        topics: vec![decoder.signature(), name_hash],
+        data: ethers_core::abi::encode(&[Token::Uint(1.into())]).into(),
+        block_number: Some(1.into()),
+        log_index: Some(0.into()),
+        ..Default::default()
+    };
+
+    // When
+    let event = decoder.decode(&log).unwrap();
+
+    // Then
+    let Event::Custom(event) = event else {
+        panic!("Expected the custom event, got {event:?}")
+    };
+    assert_eq!(
+        decoder.decode_payload(&event.payload).unwrap(),
+        vec![
+            Token::FixedBytes(name_hash.0.to_vec()),
+            Token::Uint(U256::from(1))
+        ]
+    );
+}
+
+#[test]
+fn abi_event_decoder__rejects_invalid_definition() {
+    // When
+    let result = AbiEventDecoder::parse(DEPOSIT, "function deposit(uint256 amount)");
+
+    // Then
+    assert!(result.is_err());
+}
+
+#[test]
+fn event_decoder_registry__rejects_duplicated_name() {
+    // Given
+    let mut registry = EventDecoderRegistry::default();
+    let decoder = AbiEventDecoder::parse(MESSAGE_EVENT, DEPOSIT_DEFINITION).unwrap();
+
+    // When
+    let result = registry.register(Arc::new(decoder));
+
+    // Then
+    assert!(result.is_err());
+}
+
+#[test]
+fn event_decoder_registry__rejects_duplicated_signature() {
+    // Given
+    let mut registry = registry_with_deposit();
+    let decoder = AbiEventDecoder::parse("other", DEPOSIT_DEFINITION).unwrap();
+
+    // When
+    let result = registry.register(Arc::new(decoder));
+
+    // Then
+    assert!(result.is_err());
+    assert!(registry.get("other").is_none());
+}
+
+#[test]
+fn log_decoder__uses_default_events_for_contracts_without_configured_events() {
+    // Given
+    let contract_events = HashMap::from([(contract(2), vec![DEPOSIT.to_string()])]);
+    let decoder = LogDecoder::new(&registry_with_deposit(), &contract_events).unwrap();
+
+    // When
+    let message = decoder.decode(&message_log(contract(1))).unwrap();
+    let deposit = decoder
+        .decode(&deposit_log(contract(1), H256::zero(), H160::zero(), 1))
+        .unwrap();
+
+    // Then
+    assert!(matches!(message, Some(Event::Message(_))));
+    assert_eq!(deposit, None);
+}
+
+#[test]
+fn log_decoder__decodes_default_and_configured_events_of_the_contract() {
+    // Given
+    let contract_events = HashMap::from([(contract(2), vec![DEPOSIT.to_string()])]);
+    let decoder = LogDecoder::new(&registry_with_deposit(), &contract_events).unwrap();
+
+    // When
+    let message = decoder.decode(&message_log(contract(2))).unwrap();
+    let deposit = decoder
+        .decode(&deposit_log(contract(2), H256::zero(), H160::zero(), 1))
+        .unwrap();
+
+    // Then
+    assert!(matches!(message, Some(Event::Message(_))));
+    assert!(matches!(deposit, Some(Event::Custom(_))));
+}
+
+#[test]
+fn log_decoder__signatures_contain_events_of_all_contracts() {
+    // Given
+    let contract_events = HashMap::from([(contract(2), vec![DEPOSIT.to_string()])]);
+    let decoder = LogDecoder::new(&registry_with_deposit(), &contract_events).unwrap();
+
+    // When
+    let signatures = decoder.signatures();
+
+    // Then
+    assert_eq!(signatures.len(), 3);
+    assert!(signatures.contains(&*crate::config::ETH_LOG_MESSAGE));
+    assert!(signatures.contains(&*crate::config::ETH_FORCED_TX));
+    assert!(signatures.contains(&deposit_decoder().signature()));
+}
+
+#[test]
+fn log_decoder__fails_for_unknown_event() {
+    // Given
+    let contract_events = HashMap::from([(contract(2), vec!["unknown".to_string()])]);
+
+    // When
+    let result = LogDecoder::new(&registry_with_deposit(), &contract_events);
+
+    // Then
+    let err = result.err().expect("Should reject the unknown event");
+    assert!(err.to_string().contains("Unknown relayer event"));
+}
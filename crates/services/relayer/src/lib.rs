@@ -13,6 +13,7 @@ pub(crate) mod log;
 
 mod service;
 
+pub mod decoder;
 #[cfg(any(test, feature = "test-helpers"))]
 pub mod mock_db;
 pub mod ports;
@@ -9,8 +9,14 @@ use fuel_core_types::{
         RelayedTransaction,
         relayer::transaction::RelayedTransactionId,
     },
-    fuel_types::Nonce,
-    services::relayer::Event,
+    fuel_types::{
+        Bytes32,
+        Nonce,
+    },
+    services::relayer::{
+        Event,
+        RelayedEvent,
+    },
 };
 use std::{
     collections::BTreeMap,
@@ -26,6 +32,8 @@ pub struct Data {
     pub transactions:
         BTreeMap<DaBlockHeight, Vec<(RelayedTransactionId, RelayedTransaction)>>,
     pub finalized_da_height: Option<DaBlockHeight>,
+    pub block_hashes: BTreeMap<DaBlockHeight, Bytes32>,
+    pub custom_events: BTreeMap<DaBlockHeight, Vec<RelayedEvent>>,
 }
 
 // TODO: Maybe remove `Arc<Mutex<>>`
@@ -90,6 +98,19 @@ impl MockDb {
             .unwrap_or_default()
     }
 
+    pub fn get_custom_events_for_block(
+        &self,
+        da_block_height: DaBlockHeight,
+    ) -> Vec<RelayedEvent> {
+        self.data
+            .lock()
+            .unwrap()
+            .custom_events
+            .get(&da_block_height)
+            .cloned()
+            .unwrap_or_default()
+    }
+
     #[cfg(any(test, feature = "test-helpers"))]
     pub fn set_finalized_da_height_to_at_least(
         &mut self,
@@ -123,6 +144,12 @@ impl RelayerDb for MockDb {
                         .or_default()
                         .push((transaction.id(), transaction.clone()));
                 }
+                Event::Custom(event) => {
+                    m.custom_events
+                        .entry(event.da_height)
+                        .or_default()
+                        .push(event.clone());
+                }
             }
         }
         let max = m.finalized_da_height.get_or_insert(0u64.into());
@@ -130,7 +157,35 @@ impl RelayerDb for MockDb {
         Ok(())
     }
 
+    fn insert_events_with_block_hash(
+        &mut self,
+        da_height: &DaBlockHeight,
+        block_hash: &Bytes32,
+        events: &[Event],
+    ) -> StorageResult<()> {
+        self.insert_events(da_height, events)?;
+        self.data
+            .lock()
+            .unwrap()
+            .block_hashes
+            .insert(*da_height, *block_hash);
+        Ok(())
+    }
+
     fn get_finalized_da_height(&self) -> Option<DaBlockHeight> {
         self.data.lock().unwrap().finalized_da_height
     }
+
+    fn get_da_block_hash(
+        &self,
+        da_height: &DaBlockHeight,
+    ) -> StorageResult<Option<Bytes32>> {
+        Ok(self
+            .data
+            .lock()
+            .unwrap()
+            .block_hashes
+            .get(da_height)
+            .copied())
+    }
 }
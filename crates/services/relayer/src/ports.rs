@@ -3,6 +3,7 @@
 use fuel_core_storage::Result as StorageResult;
 use fuel_core_types::{
     blockchain::primitives::DaBlockHeight,
+    fuel_types::Bytes32,
     services::relayer::Event,
 };
 
@@ -19,9 +20,24 @@ pub trait RelayerDb: Send + Sync {
         events: &[Event],
     ) -> StorageResult<()>;
 
+    /// Same as [`RelayerDb::insert_events`], but also records the hash of the DA block
+    /// at `da_height`. The relayer compares it with the DA layer later to detect reorgs.
+    fn insert_events_with_block_hash(
+        &mut self,
+        da_height: &DaBlockHeight,
+        block_hash: &Bytes32,
+        events: &[Event],
+    ) -> StorageResult<()>;
+
     /// Get finalized da height that represent last block from da layer that got finalized.
     /// Panics if height is not set as of initialization of database.
     fn get_finalized_da_height(&self) -> Option<DaBlockHeight>;
+
+    /// Get the recorded hash of the DA block at `da_height`, if any.
+    fn get_da_block_hash(
+        &self,
+        da_height: &DaBlockHeight,
+    ) -> StorageResult<Option<Bytes32>>;
 }
 
 /// The trait that should be implemented by the database transaction returned by the database.
@@ -2,7 +2,7 @@
 
 use crate::{
     Config,
-    log::EthEventLog,
+    decoder::LogDecoder,
     ports::RelayerDb,
     service::state::EthLocal,
 };
@@ -30,10 +30,7 @@ use fuel_core_services::{
     StateWatcher,
     TaskNextAction,
 };
-use fuel_core_types::{
-    blockchain::primitives::DaBlockHeight,
-    entities::Message,
-};
+use fuel_core_types::blockchain::primitives::DaBlockHeight;
 use futures::StreamExt;
 use std::convert::TryInto;
 use tokio::sync::watch;
@@ -44,6 +41,7 @@ use self::{
 };
 
 mod get_logs;
+mod reorg;
 mod run;
 mod state;
 mod syncing;
@@ -112,6 +110,8 @@ pub struct Task<P, D> {
     database: D,
     /// Configuration settings.
     config: Config,
+    /// Decodes the logs of the listening contracts into the relayer events.
+    decoder: LogDecoder,
     /// The watcher used to track the state of the service. If the service stops,
     /// the task will stop synchronization.
     shutdown: StateWatcher,
@@ -164,6 +164,19 @@ where
         }
     }
 
+    async fn check_da_reorg(&self) -> anyhow::Result<()> {
+        let mut shutdown = self.shutdown.clone();
+        tokio::select! {
+            biased;
+            _ = shutdown.while_started() => {
+                Err(anyhow::anyhow!("The relayer got a stop signal"))
+            },
+            result = reorg::check_da_reorg(&self.eth_node, &self.database) => {
+                result
+            }
+        }
+    }
+
     async fn download_logs(
         &mut self,
         eth_sync_gap: &state::EthSyncGap,
@@ -171,12 +184,13 @@ where
         let logs = download_logs(
             eth_sync_gap,
             self.config.eth_v2_listening_contracts.clone(),
+            self.decoder.signatures(),
             &self.eth_node,
             self.config.log_page_size,
         );
         let logs = logs.take_until(self.shutdown.while_started());
 
-        write_logs(&mut self.database, logs).await
+        write_logs(&mut self.database, &self.decoder, logs).await
     }
 
     fn update_synced(&self, state: &state::EthState) {
@@ -229,11 +243,13 @@ where
             config,
             retry_on_error,
         } = self;
+        let decoder = LogDecoder::new(&config.event_decoders, &config.contract_events)?;
         let task = Task {
             synced,
             eth_node,
             database,
             config,
+            decoder,
             shutdown,
             retry_on_error,
         };
@@ -344,8 +360,35 @@ where
             _ = shutdown.while_started() => {
                 Err(anyhow::anyhow!("The relayer got a stop signal"))
             },
-            block = self.eth_node.get_block(ethers_core::types::BlockNumber::Finalized) => {
-                let block_number = block.map_err(|err| anyhow::anyhow!("failed to get block from Eth node: {err:?}"))?
+            block_number = self.finalized_block_number() => {
+                block_number
+            }
+        }
+    }
+}
+
+impl<P, D> Task<P, D>
+where
+    P: Middleware<Error = ProviderError>,
+{
+    /// The DA block is final once it has `confirmation_depth` blocks on top of it,
+    /// or once the DA node reports it as finalized if the depth is not configured.
+    async fn finalized_block_number(&self) -> anyhow::Result<u64> {
+        match self.config.confirmation_depth {
+            Some(confirmation_depth) => {
+                let latest = self.eth_node.get_block_number().await.map_err(|err| {
+                    anyhow::anyhow!("failed to get block number from Eth node: {err:?}")
+                })?;
+                Ok(latest.as_u64().saturating_sub(confirmation_depth))
+            }
+            None => {
+                let block_number = self
+                    .eth_node
+                    .get_block(ethers_core::types::BlockNumber::Finalized)
+                    .await
+                    .map_err(|err| {
+                        anyhow::anyhow!("failed to get block from Eth node: {err:?}")
+                    })?
                     .and_then(|block| block.number)
                     .ok_or(anyhow::anyhow!("Block pending"))?
                     .as_u64();
@@ -1,7 +1,11 @@
 use super::*;
+use crate::decoder::LogDecoder;
+use ethers_core::types::H256;
 use fuel_core_types::{
-    entities::RelayedTransaction,
-    fuel_types::Bytes20,
+    fuel_types::{
+        Bytes20,
+        Bytes32,
+    },
     services::relayer::Event,
 };
 use futures::TryStreamExt;
@@ -13,6 +17,8 @@ mod test;
 pub struct DownloadedLogs {
     pub start_height: u64,
     pub last_height: u64,
+    /// The hash of the DA block at the `last_height`.
+    pub last_block_hash: Bytes32,
     pub logs: Vec<Log>,
 }
 
@@ -20,6 +26,7 @@ pub struct DownloadedLogs {
 pub(crate) fn download_logs<'a, P>(
     eth_sync_gap: &state::EthSyncGap,
     contracts: Vec<Bytes20>,
+    events: Vec<H256>,
     eth_node: &'a P,
     page_size: u64,
 ) -> impl futures::Stream<Item = Result<DownloadedLogs, ProviderError>> + 'a + use<'a, P>
@@ -34,6 +41,7 @@ where
                 .iter()
                 .map(|c| ethereum_types::Address::from_slice(c.as_slice()))
                 .collect();
+            let events = events.clone();
             async move {
                 match page {
                     None => Ok(None),
@@ -43,10 +51,7 @@ where
                             .from_block(page.oldest())
                             .to_block(page.latest())
                             .address(ValueOrArray::Array(contracts))
-                            .topic0(ValueOrArray::Array(vec![
-                                *crate::config::ETH_LOG_MESSAGE,
-                                *crate::config::ETH_FORCED_TX,
-                            ]));
+                            .topic0(ValueOrArray::Array(events));
 
                         tracing::info!(
                             "Downloading logs for block range: {}..={}",
@@ -60,6 +65,11 @@ where
                         // Reduce the page.
                         let page = page.reduce();
 
+                        // The hash is requested before the logs, so a reorg between
+                        // the requests is detected by the next iteration of the relayer.
+                        let last_block_hash =
+                            reorg::da_block_hash(eth_node, latest_block).await?;
+
                         // Get the logs and return the reduced page.
                         eth_node
                             .get_logs(&filter)
@@ -79,6 +89,7 @@ where
                                     DownloadedLogs {
                                         start_height: oldest_block,
                                         last_height: latest_block,
+                                        last_block_hash,
                                         logs,
                                     },
                                     page,
@@ -92,7 +103,11 @@ where
 }
 
 /// Write the logs to the database.
-pub(crate) async fn write_logs<D, S>(database: &mut D, logs: S) -> anyhow::Result<()>
+pub(crate) async fn write_logs<D, S>(
+    database: &mut D,
+    decoder: &LogDecoder,
+    logs: S,
+) -> anyhow::Result<()>
 where
     D: RelayerDb,
     S: futures::Stream<Item = Result<DownloadedLogs, ProviderError>>,
@@ -101,28 +116,25 @@ where
     while let Some(DownloadedLogs {
         start_height,
         last_height,
+        last_block_hash,
         logs: events,
     }) = logs.try_next().await?
     {
         let mut unordered_events = HashMap::<DaBlockHeight, Vec<Event>>::new();
         let sorted_events = sort_events_by_log_index(events)?;
-        let fuel_events = sorted_events.into_iter().filter_map(|event| {
-            match EthEventLog::try_from(&event) {
-                Ok(event) => {
-                    match event {
-                        EthEventLog::Message(m) => {
-                            Some(Ok(Event::Message(Message::from(&m))))
-                        }
-                        EthEventLog::Transaction(tx) => {
-                            Some(Ok(Event::Transaction(RelayedTransaction::from(tx))))
-                        }
-                        // TODO: Log out ignored messages.
-                        EthEventLog::Ignored => None,
-                    }
-                }
-                Err(e) => Some(Err(e)),
+        let mut block_hashes = block_hashes_of_logs(&sorted_events)?;
+        match block_hashes.insert(last_height.into(), last_block_hash) {
+            Some(hash) if hash != last_block_hash => {
+                return Err(anyhow::anyhow!(
+                    "DA layer reorg detected at the DA height {last_height}: \
+                    the logs belong to the block {hash}, but the DA node has the block {last_block_hash}"
+                ))
             }
-        });
+            _ => {}
+        }
+        let fuel_events = sorted_events
+            .iter()
+            .filter_map(|event| decoder.decode(event).transpose());
 
         for event in fuel_events {
             let event = event?;
@@ -134,12 +146,39 @@ where
         for height in start_height..=last_height {
             let height: DaBlockHeight = height.into();
             let events = unordered_events.get(&height).unwrap_or(&empty_events);
-            database.insert_events(&height, events)?;
+            match block_hashes.get(&height) {
+                Some(block_hash) => {
+                    database.insert_events_with_block_hash(&height, block_hash, events)?
+                }
+                None => database.insert_events(&height, events)?,
+            }
         }
     }
     Ok(())
 }
 
+/// Collects the hashes of the DA blocks containing the `logs`.
+fn block_hashes_of_logs(logs: &[Log]) -> anyhow::Result<HashMap<DaBlockHeight, Bytes32>> {
+    let mut block_hashes = HashMap::<DaBlockHeight, Bytes32>::new();
+    for log in logs {
+        let (Some(number), Some(hash)) = (log.block_number, log.block_hash) else {
+            continue
+        };
+        let height = DaBlockHeight::from(number.as_u64());
+        let hash = Bytes32::from(hash.0);
+        match block_hashes.insert(height, hash) {
+            Some(other) if other != hash => {
+                return Err(anyhow::anyhow!(
+                    "The logs at the DA height {} belong to different blocks: {other} and {hash}",
+                    *height
+                ))
+            }
+            _ => {}
+        }
+    }
+    Ok(block_hashes)
+}
+
 fn sort_events_by_log_index(events: Vec<Log>) -> anyhow::Result<Vec<Log>> {
     let mut with_indexes = events
         .into_iter()
@@ -1,5 +1,8 @@
 #![allow(clippy::arithmetic_side_effects)]
-use ethers_core::types::U256;
+use ethers_core::types::{
+    H256,
+    U256,
+};
 use std::{
     ops::RangeInclusive,
     sync::atomic::{
@@ -189,6 +192,7 @@ async fn can_paginate_logs(input: Input) -> Expected {
     let result = download_logs(
         &EthSyncGap::new(*eth_gap.start(), *eth_gap.end()),
         contracts,
+        LogDecoder::default().signatures(),
         &eth_node,
         DEFAULT_LOG_PAGE_SIZE,
     )
@@ -233,11 +237,58 @@ async fn test_da_height_updates(
         result.map(|(start_height, last_height, logs)| DownloadedLogs {
             start_height,
             last_height,
+            last_block_hash: Default::default(),
             logs,
         })
     });
 
-    let _ = write_logs(&mut mock_db, logs).await;
+    let _ = write_logs(&mut mock_db, &LogDecoder::default(), logs).await;
 
     *mock_db.get_finalized_da_height().unwrap()
 }
+
+#[tokio::test]
+async fn write_logs_records_hashes_of_da_blocks() {
+    // Given
+    let mut mock_db = crate::mock_db::MockDb::default();
+    let mut log = message(0, 2, 0, 0);
+    log.block_hash = Some(H256::from([2; 32]));
+    let logs = futures::stream::iter(vec![Ok(DownloadedLogs {
+        start_height: 1,
+        last_height: 3,
+        last_block_hash: Bytes32::from([3; 32]),
+        logs: vec![log],
+    })]);
+
+    // When
+    write_logs(&mut mock_db, &LogDecoder::default(), logs)
+        .await
+        .unwrap();
+
+    // Then
+    let hash = |height: u64| mock_db.get_da_block_hash(&height.into()).unwrap();
+    assert_eq!(hash(1), None);
+    assert_eq!(hash(2), Some(Bytes32::from([2; 32])));
+    assert_eq!(hash(3), Some(Bytes32::from([3; 32])));
+}
+
+#[tokio::test]
+async fn write_logs_fails_if_logs_belong_to_another_da_block() {
+    // Given
+    let mut mock_db = crate::mock_db::MockDb::default();
+    let mut log = message(0, 3, 0, 0);
+    log.block_hash = Some(H256::from([4; 32]));
+    let logs = futures::stream::iter(vec![Ok(DownloadedLogs {
+        start_height: 1,
+        last_height: 3,
+        last_block_hash: Bytes32::from([3; 32]),
+        logs: vec![log],
+    })]);
+
+    // When
+    let result = write_logs(&mut mock_db, &LogDecoder::default(), logs).await;
+
+    // Then
+    assert!(result.is_err());
+    assert_eq!(mock_db.get_finalized_da_height(), None);
+}
@@ -0,0 +1,66 @@
+//! # reorg
+//! Detection of the DA layer reorgs that removed blocks already processed by the relayer.
+
+use super::*;
+use fuel_core_types::fuel_types::Bytes32;
+
+#[cfg(test)]
+mod tests;
+
+/// Get the hash of the DA block at the `height` from the DA node.
+pub(crate) async fn da_block_hash<P>(
+    eth_node: &P,
+    height: u64,
+) -> Result<Bytes32, ProviderError>
+where
+    P: Middleware<Error = ProviderError>,
+{
+    eth_node
+        .get_block(height)
+        .await?
+        .and_then(|block| block.hash)
+        .map(|hash| Bytes32::from(hash.0))
+        .ok_or_else(|| {
+            ProviderError::CustomError(format!(
+                "DA block {height} is not available on the DA node"
+            ))
+        })
+}
+
+/// Verifies that the DA block at the latest height processed by the relayer
+/// is still part of the DA chain.
+///
+/// If the DA layer reorged the block, the events stored by the relayer may no
+/// longer exist on the DA layer. The relayer can't revert already processed events,
+/// so it returns an error and doesn't make progress until an operator intervenes.
+pub(crate) async fn check_da_reorg<P, D>(eth_node: &P, database: &D) -> anyhow::Result<()>
+where
+    P: Middleware<Error = ProviderError>,
+    D: RelayerDb,
+{
+    let Some(height) = database.get_finalized_da_height() else {
+        return Ok(())
+    };
+    // The relayer doesn't know the hash of the blocks processed before
+    // it started to track them, so there is nothing to compare.
+    let Some(stored_hash) = database.get_da_block_hash(&height)? else {
+        return Ok(())
+    };
+
+    let da_hash = da_block_hash(eth_node, height.into()).await?;
+    if stored_hash != da_hash {
+        tracing::error!(
+            "DA layer reorg detected at the DA height {}: the relayer processed the block {}, \
+            but the DA node has the block {}. The relayer is halted.",
+            *height,
+            stored_hash,
+            da_hash
+        );
+        return Err(anyhow::anyhow!(
+            "DA layer reorg detected at the DA height {}: expected the block {stored_hash}, \
+            got {da_hash}",
+            *height
+        ))
+    }
+    Ok(())
+}
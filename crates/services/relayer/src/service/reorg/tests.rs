@@ -0,0 +1,67 @@
+#![allow(non_snake_case)]
+
+use super::*;
+use crate::{
+    mock_db::MockDb,
+    test_helpers::middleware::MockMiddleware,
+};
+use ethers_core::types::H256;
+
+const DA_HEIGHT: u64 = 10;
+
+fn database_with_block_hash(block_hash: Option<Bytes32>) -> MockDb {
+    let mut database = MockDb::default();
+    match block_hash {
+        Some(block_hash) => database
+            .insert_events_with_block_hash(&DA_HEIGHT.into(), &block_hash, &[])
+            .unwrap(),
+        None => database.insert_events(&DA_HEIGHT.into(), &[]).unwrap(),
+    }
+    database
+}
+
+#[tokio::test]
+async fn check_da_reorg__succeeds_when_block_hash_matches() {
+    // Given
+    let eth_node = MockMiddleware::default();
+    let block_hash = Bytes32::from(H256::from_low_u64_be(DA_HEIGHT).0);
+    let database = database_with_block_hash(Some(block_hash));
+
+    // When
+    let result = check_da_reorg(&eth_node, &database).await;
+
+    // Then
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn check_da_reorg__succeeds_when_block_hash_is_unknown() {
+    // Given
+    let eth_node = MockMiddleware::default();
+    let database = database_with_block_hash(None);
+
+    // When
+    let result = check_da_reorg(&eth_node, &database).await;
+
+    // Then
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn check_da_reorg__fails_when_da_layer_reorged_the_block() {
+    // Given
+    let eth_node = MockMiddleware::default();
+    let block_hash = Bytes32::from(H256::from_low_u64_be(DA_HEIGHT).0);
+    let database = database_with_block_hash(Some(block_hash));
+    eth_node.update_data(|data| {
+        data.block_hashes
+            .insert(DA_HEIGHT, H256::from_low_u64_be(u64::MAX));
+    });
+
+    // When
+    let result = check_da_reorg(&eth_node, &database).await;
+
+    // Then
+    let err = result.expect_err("Should detect the reorg");
+    assert!(err.to_string().contains("DA layer reorg detected"));
+}
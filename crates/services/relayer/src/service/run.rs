@@ -19,6 +19,11 @@ pub trait RelayerData: EthRemote + EthLocal {
         &self,
     ) -> impl core::future::Future<Output = anyhow::Result<()>> + Send;
 
+    /// Check that the DA layer didn't reorg the blocks already processed by the relayer.
+    fn check_da_reorg(
+        &self,
+    ) -> impl core::future::Future<Output = anyhow::Result<()>> + Send;
+
     /// Download the logs from the DA layer and write them
     /// to the database.
     fn download_logs(
@@ -42,6 +47,9 @@ where
     // Await the eth node to sync.
     relayer.wait_if_eth_syncing().await?;
 
+    // Halt if the DA layer reorged the blocks we already processed.
+    relayer.check_da_reorg().await?;
+
     // Build the DA layer state.
     let mut state = state::build_eth(relayer).await?;
 
@@ -10,6 +10,7 @@ use super::*;
 async fn can_set_da_height() {
     let mut relayer = MockRelayerData::default();
     relayer.expect_wait_if_eth_syncing().returning(|| Ok(()));
+    relayer.expect_check_da_reorg().returning(|| Ok(()));
     relayer.expect_update_synced().return_const(());
     relayer.expect_download_logs().returning(|_| Ok(()));
     relayer.expect_storage_da_block_height().returning(|| None);
@@ -27,6 +28,7 @@ async fn can_set_da_height() {
 async fn logs_are_downloaded_and_written() {
     let mut relayer = MockRelayerData::default();
     relayer.expect_wait_if_eth_syncing().returning(|| Ok(()));
+    relayer.expect_check_da_reorg().returning(|| Ok(()));
     relayer.expect_update_synced().return_const(());
     relayer
         .expect_download_logs()
@@ -48,6 +50,7 @@ async fn logs_are_downloaded_and_written_partially() {
     let mut relayer = MockRelayerData::default();
     let eth_state = Arc::new(Mutex::new(EthState::new(200, 0)));
     relayer.expect_wait_if_eth_syncing().returning(|| Ok(()));
+    relayer.expect_check_da_reorg().returning(|| Ok(()));
     relayer.expect_update_synced().returning({
         let eth_state = eth_state.clone();
         move |state| {
@@ -88,6 +91,7 @@ async fn logs_are_downloaded_and_written_failed_state_is_not_updated() {
     let mut relayer = MockRelayerData::default();
     let eth_state = Arc::new(Mutex::new(EthState::new(200, 0)));
     relayer.expect_wait_if_eth_syncing().returning(|| Ok(()));
+    relayer.expect_check_da_reorg().returning(|| Ok(()));
     relayer.expect_update_synced().returning({
         let eth_state = eth_state.clone();
         move |state| {
@@ -127,6 +131,7 @@ async fn logs_are_downloaded_and_written_failed_but_state_is_updated_because_of_
     let mut relayer = MockRelayerData::default();
     let eth_state = Arc::new(Mutex::new(EthState::new(200, 0)));
     relayer.expect_wait_if_eth_syncing().returning(|| Ok(()));
+    relayer.expect_check_da_reorg().returning(|| Ok(()));
     relayer.expect_update_synced().returning({
         let eth_state = eth_state.clone();
         move |state| {
@@ -162,6 +167,32 @@ async fn logs_are_downloaded_and_written_failed_but_state_is_updated_because_of_
     assert_eq!(actual, expected);
 }
 
+#[tokio::test]
+async fn logs_are_not_downloaded_after_da_reorg() {
+    let mut relayer = MockRelayerData::default();
+    relayer.expect_wait_if_eth_syncing().returning(|| Ok(()));
+    test_data_source(
+        &mut relayer,
+        TestDataSource {
+            eth_remote_finalized: 200,
+            eth_local_finalized: 100,
+        },
+    );
+
+    // Given
+    relayer
+        .expect_check_da_reorg()
+        .returning(|| Err(anyhow::anyhow!("DA layer reorg detected")));
+    relayer.expect_download_logs().never();
+    relayer.expect_update_synced().never();
+
+    // When
+    let result = run(&mut relayer).await;
+
+    // Then
+    assert!(result.is_err());
+}
+
 mockall::mock! {
     RelayerData {}
 
@@ -176,6 +207,8 @@ mockall::mock! {
     impl RelayerData for RelayerData{
         async fn wait_if_eth_syncing(&self) -> anyhow::Result<()>;
 
+        async fn check_da_reorg(&self) -> anyhow::Result<()>;
+
         async fn download_logs(
             &mut self,
             eth_sync_gap: &state::EthSyncGap,
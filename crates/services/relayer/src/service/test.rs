@@ -35,6 +35,7 @@ async fn can_download_logs() {
     let result = download_logs(
         &eth_state.needs_to_sync_eth().unwrap(),
         contracts,
+        LogDecoder::default().signatures(),
         &eth_node,
         DEFAULT_LOG_PAGE_SIZE,
     )
@@ -82,6 +83,7 @@ async fn quorum_agrees_on_logs() {
     let result = download_logs(
         &eth_state.needs_to_sync_eth().unwrap(),
         contracts,
+        LogDecoder::default().signatures(),
         &provider,
         DEFAULT_LOG_PAGE_SIZE,
     )
@@ -138,6 +140,7 @@ async fn quorum__disagree_on_logs() {
     let provider_error = download_logs(
         &eth_state.needs_to_sync_eth().unwrap(),
         contracts,
+        LogDecoder::default().signatures(),
         &provider,
         DEFAULT_LOG_PAGE_SIZE,
     )
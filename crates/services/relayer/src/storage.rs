@@ -10,11 +10,14 @@ use fuel_core_storage::{
     Mappable,
     Result as StorageResult,
     StorageAsMut,
+    StorageAsRef,
+    StorageInspect,
     StorageMutate,
     blueprint::plain::Plain,
     codec::{
         postcard::Postcard,
         primitive::Primitive,
+        raw::Raw,
     },
     kv_store::StorageColumn,
     structured_storage::TableWithBlueprint,
@@ -25,6 +28,7 @@ use fuel_core_storage::{
 };
 use fuel_core_types::{
     blockchain::primitives::DaBlockHeight,
+    fuel_types::Bytes32,
     services::relayer::Event,
 };
 
@@ -46,6 +50,8 @@ pub enum Column {
     Metadata = 0,
     /// The column of the table that stores history of the relayer.
     History = 1,
+    /// The column of the table that stores hashes of the DA blocks seen by the relayer.
+    DaBlockHashes = 2,
 }
 
 impl Column {
@@ -90,59 +96,122 @@ impl TableWithBlueprint for EventsHistory {
     }
 }
 
+/// The table contains hashes of the DA blocks processed by the relayer.
+/// The relayer uses them to detect reorgs of the DA layer.
+pub struct DaBlockHashes;
+
+impl Mappable for DaBlockHashes {
+    /// The key is the height of the DA.
+    type Key = Self::OwnedKey;
+    type OwnedKey = DaBlockHeight;
+    /// The value is the hash of the DA block at the height.
+    type Value = Self::OwnedValue;
+    type OwnedValue = Bytes32;
+}
+
+impl TableWithBlueprint for DaBlockHashes {
+    type Blueprint = Plain<Primitive<8>, Raw>;
+    type Column = Column;
+
+    fn column() -> Column {
+        Column::DaBlockHashes
+    }
+}
+
 impl<T> RelayerDb for T
 where
     T: Send + Sync,
     T: Transactional,
-    for<'a> T::Transaction<'a>: StorageMutate<EventsHistory, Error = StorageError>,
+    T: StorageInspect<DaBlockHashes, Error = StorageError>,
+    for<'a> T::Transaction<'a>: StorageMutate<EventsHistory, Error = StorageError>
+        + StorageMutate<DaBlockHashes, Error = StorageError>,
 {
     fn insert_events(
         &mut self,
         da_height: &DaBlockHeight,
         events: &[Event],
     ) -> StorageResult<()> {
-        // A transaction is required to ensure that the height is
-        // set atomically with the insertion based on the current
-        // height. Also so that the messages are inserted atomically
-        // with the height.
+        insert_events(self, da_height, None, events)
+    }
+
+    fn insert_events_with_block_hash(
+        &mut self,
+        da_height: &DaBlockHeight,
+        block_hash: &Bytes32,
+        events: &[Event],
+    ) -> StorageResult<()> {
+        insert_events(self, da_height, Some(block_hash), events)
+    }
+
+    fn get_finalized_da_height(&self) -> Option<DaBlockHeight> {
+        self.latest_da_height()
+    }
 
-        // Get the current DA block height from the database.
-        let before = self.latest_da_height().unwrap_or_default();
+    fn get_da_block_hash(
+        &self,
+        da_height: &DaBlockHeight,
+    ) -> StorageResult<Option<Bytes32>> {
+        let hash = self
+            .storage_as_ref::<DaBlockHashes>()
+            .get(da_height)?
+            .map(|hash| *hash);
+        Ok(hash)
+    }
+}
 
-        let mut db_tx = self.transaction();
+fn insert_events<T>(
+    database: &mut T,
+    da_height: &DaBlockHeight,
+    block_hash: Option<&Bytes32>,
+    events: &[Event],
+) -> StorageResult<()>
+where
+    T: Transactional,
+    for<'a> T::Transaction<'a>: StorageMutate<EventsHistory, Error = StorageError>
+        + StorageMutate<DaBlockHashes, Error = StorageError>,
+{
+    // A transaction is required to ensure that the height is
+    // set atomically with the insertion based on the current
+    // height. Also so that the messages are inserted atomically
+    // with the height.
 
-        for event in events {
-            if da_height != &event.da_height() {
-                return Err(anyhow::anyhow!("Invalid da height").into());
-            }
-        }
+    // Get the current DA block height from the database.
+    let before = database.latest_da_height().unwrap_or_default();
 
-        db_tx.storage::<EventsHistory>().insert(da_height, events)?;
-        db_tx.commit()?;
-
-        // Compare the new DA block height with previous the block height. Block
-        // height must always be monotonically increasing. If the new block
-        // height is less than the previous block height, the service is in
-        // an error state and must be shut down.
-        let after = self
-            .latest_da_height()
-            .expect("DA height must be set at this point");
-        if after < before {
-            StorageResult::Err(
-                anyhow::anyhow!("Block height must be monotonically increasing").into(),
-            )?
-        }
+    let mut db_tx = database.transaction();
 
-        // TODO: Think later about how to clean up the history of the relayer.
-        //  Since we don't have too much information on the relayer and it can be useful
-        //  at any time, maybe we want to consider keeping it all the time instead of creating snapshots.
-        //  https://github.com/FuelLabs/fuel-core/issues/1627
-        Ok(())
+    for event in events {
+        if da_height != &event.da_height() {
+            return Err(anyhow::anyhow!("Invalid da height").into());
+        }
     }
 
-    fn get_finalized_da_height(&self) -> Option<DaBlockHeight> {
-        self.latest_da_height()
+    db_tx.storage::<EventsHistory>().insert(da_height, events)?;
+    if let Some(block_hash) = block_hash {
+        db_tx
+            .storage::<DaBlockHashes>()
+            .insert(da_height, block_hash)?;
     }
+    db_tx.commit()?;
+
+    // Compare the new DA block height with previous the block height. Block
+    // height must always be monotonically increasing. If the new block
+    // height is less than the previous block height, the service is in
+    // an error state and must be shut down.
+    let after = database
+        .latest_da_height()
+        .expect("DA height must be set at this point");
+    if after < before {
+        StorageResult::Err(
+            anyhow::anyhow!("Block height must be monotonically increasing").into(),
+        )?
+    }
+
+    // TODO: Think later about how to clean up the history of the relayer.
+    //  Since we don't have too much information on the relayer and it can be useful
+    //  at any time, maybe we want to consider keeping it all the time instead of creating snapshots.
+    //  https://github.com/FuelLabs/fuel-core/issues/1627
+    Ok(())
 }
 
 impl<S> DatabaseTransaction for StorageTransaction<S>
@@ -167,4 +236,10 @@ mod tests {
             Event::Transaction(Default::default())
         ]
     );
+
+    fuel_core_storage::basic_storage_tests!(
+        DaBlockHashes,
+        <DaBlockHashes as Mappable>::Key::default(),
+        Bytes32::from([1u8; 32])
+    );
 }
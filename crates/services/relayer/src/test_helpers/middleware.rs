@@ -2,6 +2,7 @@ use async_trait::async_trait;
 use ethers_core::types::{
     Block,
     BlockId,
+    BlockNumber,
     Filter,
     H256,
     Log,
@@ -23,6 +24,7 @@ use serde::{
     de::DeserializeOwned,
 };
 use std::{
+    collections::HashMap,
     fmt,
     fmt::Debug,
     str::FromStr,
@@ -53,6 +55,31 @@ pub struct MockData {
     pub best_block: Block<TxHash>,
     pub logs_batch: Vec<Vec<Log>>,
     pub logs_batch_index: usize,
+    /// Overrides the hashes of the blocks requested by the number.
+    /// Other blocks have a hash derived from their number.
+    pub block_hashes: HashMap<u64, H256>,
+}
+
+impl MockData {
+    /// Returns the block by the number, or the best block for any block tag.
+    pub fn block(&self, block_id: BlockId) -> Block<TxHash> {
+        match block_id {
+            BlockId::Number(BlockNumber::Number(number)) => {
+                let number = number.as_u64();
+                let hash = self
+                    .block_hashes
+                    .get(&number)
+                    .copied()
+                    .unwrap_or_else(|| H256::from_low_u64_be(number));
+                Block {
+                    hash: Some(hash),
+                    number: Some(number.into()),
+                    ..Default::default()
+                }
+            }
+            _ => self.best_block.clone(),
+        }
+    }
 }
 
 impl MockMiddleware {
@@ -143,6 +170,7 @@ impl Default for MockData {
             is_syncing: SyncingStatus::IsFalse,
             logs_batch: Vec::new(),
             logs_batch_index: 0,
+            block_hashes: HashMap::new(),
         }
     }
 }
@@ -220,6 +248,23 @@ impl JsonRpcClient for MockMiddleware {
                     serde_json::from_value(res).map_err(Self::Error::SerdeJson)?;
                 Ok(res)
             }
+            "eth_getBlockByNumber" => {
+                let number: BlockNumber =
+                    match params.serialize(serde_json::value::Serializer).unwrap() {
+                        serde_json::Value::Array(ref arr) => {
+                            let number = arr.first().unwrap();
+                            serde_json::from_value(number.clone()).unwrap()
+                        }
+                        _ => panic!("Expected an array"),
+                    };
+
+                let res = serde_json::to_value(Some(
+                    self.update_data(|data| data.block(BlockId::Number(number))),
+                ))?;
+                let res: R =
+                    serde_json::from_value(res).map_err(Self::Error::SerdeJson)?;
+                Ok(res)
+            }
             "eth_getLogs" => {
                 // Decode the filter if T is a vec and the first element is a filter
                 let filter =
@@ -294,7 +339,7 @@ impl Middleware for MockMiddleware {
         tokio::task::yield_now().await;
         let block_id = block_hash_or_number.into();
         self.before_event(TriggerType::GetBlock(block_id));
-        let r = Ok(Some(self.update_data(|data| data.best_block.clone())));
+        let r = Ok(Some(self.update_data(|data| data.block(block_id))));
         self.after_event(TriggerType::GetBlock(block_id));
         r
     }
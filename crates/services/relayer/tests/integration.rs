@@ -2,6 +2,7 @@
 #![allow(non_snake_case)]
 
 use ethers_core::types::{
+    H256,
     Log,
     U256,
 };
@@ -91,7 +92,9 @@ async fn stop_service_at_the_middle() {
     // Allow several sync iterations.
     const NUMBER_OF_SYNC_ITERATIONS: u64 = 7;
     for _ in 0..NUMBER_OF_SYNC_ITERATIONS {
-        // Each iterations syncs 5 blocks.
+        // Each iterations syncs 5 blocks and requests
+        // the hash of the last block and the logs.
+        tokio::task::yield_now().await;
         tokio::task::yield_now().await;
     }
     relayer.stop();
@@ -250,6 +253,51 @@ async fn relayer__if_a_log_does_not_include_index_then_event_not_included_and_er
     );
 }
 
+#[tokio::test(start_paused = true)]
+async fn relayer__syncs_up_to_confirmation_depth() {
+    // Given
+    let mock_db = MockDb::default();
+    let eth_node = MockMiddleware::default();
+    eth_node.update_data(|data| data.best_block.number = Some(100.into()));
+    let config = Config {
+        confirmation_depth: Some(10),
+        ..Default::default()
+    };
+
+    // When
+    let relayer = new_service_test(eth_node, mock_db.clone(), config);
+    relayer.start_and_await().await.unwrap();
+    relayer.shared.await_synced().await.unwrap();
+
+    // Then
+    assert_eq!(*mock_db.get_finalized_da_height().unwrap(), 90);
+}
+
+#[tokio::test(start_paused = true)]
+async fn relayer__halts_when_da_layer_reorgs_processed_blocks() {
+    let mock_db = MockDb::default();
+    let eth_node = MockMiddleware::default();
+    eth_node.update_data(|data| data.best_block.number = Some(100.into()));
+    let relayer = new_service_test(eth_node.clone(), mock_db.clone(), Default::default());
+    relayer.start_and_await().await.unwrap();
+    relayer.shared.await_synced().await.unwrap();
+    assert_eq!(*mock_db.get_finalized_da_height().unwrap(), 100);
+
+    // Given
+    eth_node.update_data(|data| {
+        data.block_hashes
+            .insert(100, H256::from_low_u64_be(u64::MAX));
+        data.best_block.number = Some(120.into());
+    });
+
+    // When
+    tokio::time::sleep(Config::DEFAULT_SYNC_MINIMUM_DURATION * 3).await;
+
+    // Then
+    assert_eq!(*mock_db.get_finalized_da_height().unwrap(), 100);
+    assert!(!relayer.state().started());
+}
+
 fn message(nonce: u64, block_number: u64, block_index: u64) -> Log {
     let message = MessageSentFilter {
         nonce: U256::from_dec_str(nonce.to_string().as_str())
@@ -17,6 +17,7 @@ use fuel_core_types::{
         SealedBlock,
         SealedBlockHeader,
         block::Block,
+        header::BlockHeader,
     },
     fuel_types::BlockHeight,
     services::p2p::{
@@ -79,6 +80,16 @@ pub struct Config {
     pub block_stream_buffer_size: usize,
     /// The maximum number of headers to request in a single batch.
     pub header_batch_size: usize,
+    /// The maximum number of times a batch of headers or transactions is re-requested
+    /// from other peers after a timeout or a bad response.
+    pub max_batch_retries: usize,
+    /// Request full blocks from peers in one round trip instead of requesting
+    /// the headers and the transactions separately. Falls back to the headers and
+    /// the transactions when no peer supports the full blocks.
+    pub request_full_blocks: bool,
+    /// Sync only the sealed block headers checked by the consensus, without
+    /// requesting the transactions and executing the blocks. Used by light clients.
+    pub headers_only: bool,
 }
 
 impl Default for Config {
@@ -86,6 +97,9 @@ impl Default for Config {
         Self {
             block_stream_buffer_size: 10,
             header_batch_size: 100,
+            max_batch_retries: 2,
+            request_full_blocks: false,
+            headers_only: false,
         }
     }
 }
@@ -116,6 +130,8 @@ enum BlockHeaderData {
     Cached(CachedDataBatch),
     /// The headers has just been fetched from the network.
     Fetched(Batch<SealedBlockHeader>),
+    /// The full blocks have just been fetched from the network and checked.
+    FetchedBlocks(Batch<SealedBlock>),
 }
 
 impl<P, E, C> Import<P, E, C> {
@@ -188,7 +204,11 @@ where
         // If there is a range to process, launch the stream.
         if let Some(range) = self.state.apply(|s| s.process_range()) {
             // Launch the stream to import the range.
-            let count = self.launch_stream(range.clone(), shutdown).await;
+            let count = if self.params.headers_only {
+                self.launch_headers_stream(range.clone(), shutdown).await
+            } else {
+                self.launch_stream(range.clone(), shutdown).await
+            };
 
             // Get the size of the range.
             let range_len = range.size_hint().0;
@@ -234,11 +254,15 @@ where
             let consensus = consensus.clone();
             let cache = cache.clone();
             let block_stream_buffer_size = params.block_stream_buffer_size;
+            let max_batch_retries = params.max_batch_retries;
+            let request_full_blocks = params.request_full_blocks;
             let mut shutdown_signal = shutdown.clone();
             async move {
                 let block_stream = get_block_stream(
                     range.clone(),
                     batch_size,
+                    max_batch_retries,
+                    request_full_blocks,
                     p2p,
                     consensus,
                     cache.clone(),
@@ -387,69 +411,215 @@ where
             .trace_err("Failed to join the fetch batches task");
         result
     }
+
+    #[tracing::instrument(skip(self, shutdown))]
+    /// Launches a stream to import only the headers of a range of blocks.
+    ///
+    /// The headers checked by the consensus are committed without
+    /// the transactions, so the blocks are never executed.
+    /// Returns the number of committed headers.
+    async fn launch_headers_stream(
+        &mut self,
+        range: RangeInclusive<u32>,
+        shutdown: &StateWatcher,
+    ) -> usize {
+        let Self {
+            state,
+            params,
+            p2p,
+            executor,
+            consensus,
+            cache,
+            ..
+        } = &self;
+        let batch_size = u32::try_from(params.header_batch_size)
+            .expect("Header batch size must be less u32::MAX");
+        let batch_size =
+            NonZeroU32::new(batch_size).expect("Header batch size must be non-zero");
+
+        let shutdown_future = {
+            let mut s = shutdown.clone();
+            async move {
+                let _ = s.while_started().await;
+                tracing::info!("In progress header import stream shutting down");
+            }
+        };
+
+        get_header_stream(
+            range,
+            batch_size,
+            params.max_batch_retries,
+            p2p.clone(),
+            consensus.clone(),
+            cache.clone(),
+        )
+        // Request up to `block_stream_buffer_size` batches of headers from the network.
+        .buffered(params.block_stream_buffer_size)
+        .take_until(shutdown_future)
+        .into_scan_err()
+        .scan_err()
+        .then(|batch| {
+            let mut cache = cache.clone();
+            async move {
+                let Batch {
+                    peer,
+                    range,
+                    results,
+                } = batch;
+
+                let mut done = vec![];
+                for header in results {
+                    let height = *header.entity.height();
+                    let res = commit_header(executor.as_ref(), state, header).await;
+                    cache.remove_element(&height);
+                    if res.is_err() {
+                        break;
+                    }
+                    done.push(());
+                }
+
+                let batch = Batch::new(peer.clone(), range, done);
+
+                if !batch.is_err() {
+                    report_peer(p2p, peer, PeerReportReason::SuccessfulBlockImport);
+                }
+
+                batch
+            }
+            .instrument(tracing::debug_span!("commit_headers"))
+            .in_current_span()
+        })
+        .into_scan_err()
+        .scan_err()
+        .fold(0usize, |count, batch| async move {
+            count
+                .checked_add(batch.results.len())
+                .expect("It is impossible to fetch so much data to overflow `usize`")
+        })
+        .await
+    }
 }
 
-fn get_block_stream<
+/// Requests the headers of the range in batches, reusing the headers from the cache.
+fn get_header_stream<
     P: PeerToPeerPort + Send + Sync + 'static,
     C: ConsensusPort + Send + Sync + 'static,
 >(
     range: RangeInclusive<u32>,
     header_batch_size: NonZeroU32,
+    max_batch_retries: usize,
     p2p: Arc<P>,
     consensus: Arc<C>,
     cache: Cache,
-) -> impl Stream<Item = impl Future<Output = SealedBlockBatch>> {
+) -> impl Stream<Item = impl Future<Output = SealedHeaderBatch>> {
     cache
-        .get_chunks(range.clone(), header_batch_size)
-        .map({
+        .get_chunks(range, header_batch_size)
+        .map(move |cached_data_batch| {
             let p2p = p2p.clone();
-            move |cached_data_batch| {
-                let p2p = p2p.clone();
-                async move {
-                    if let CachedDataBatch::None(range) = cached_data_batch {
-                        BlockHeaderData::Fetched(get_headers_batch(range, &p2p).await)
-                    } else {
-                        BlockHeaderData::Cached(cached_data_batch)
+            let consensus = consensus.clone();
+            let mut cache = cache.clone();
+            async move {
+                match cached_data_batch {
+                    CachedDataBatch::Headers(batch) => batch,
+                    CachedDataBatch::Blocks(batch) => {
+                        let Batch {
+                            peer,
+                            range,
+                            results,
+                        } = batch;
+                        let headers = results
+                            .into_iter()
+                            .map(|block| SealedBlockHeader {
+                                entity: block.entity.header().clone(),
+                                consensus: block.consensus,
+                            })
+                            .collect();
+                        Batch::new(peer, range, headers)
+                    }
+                    CachedDataBatch::None(range) => {
+                        let batch = request_with_retries(
+                            &p2p,
+                            max_batch_retries,
+                            |excluded_peers| {
+                                get_headers_batch(
+                                    range.clone(),
+                                    excluded_peers,
+                                    &p2p,
+                                    &consensus,
+                                )
+                            },
+                        )
+                        .await;
+                        if !batch.is_err() {
+                            cache.insert_headers(batch.clone());
+                        }
+                        batch
                     }
                 }
             }
+            .instrument(tracing::debug_span!("consensus"))
+            .in_current_span()
         })
+}
+
+fn get_block_stream<
+    P: PeerToPeerPort + Send + Sync + 'static,
+    C: ConsensusPort + Send + Sync + 'static,
+>(
+    range: RangeInclusive<u32>,
+    header_batch_size: NonZeroU32,
+    max_batch_retries: usize,
+    request_full_blocks: bool,
+    p2p: Arc<P>,
+    consensus: Arc<C>,
+    cache: Cache,
+) -> impl Stream<Item = impl Future<Output = SealedBlockBatch>> {
+    cache
+        .get_chunks(range.clone(), header_batch_size)
         .map({
             let p2p = p2p.clone();
             let consensus = consensus.clone();
             let cache = cache.clone();
-            move |header_batch| {
+            move |cached_data_batch| {
                 let p2p = p2p.clone();
                 let consensus = consensus.clone();
                 let mut cache = cache.clone();
                 async move {
-                    match header_batch.await {
-                        BlockHeaderData::Cached(cached_data) => {
-                            BlockHeaderData::Cached(cached_data)
-                        }
-                        BlockHeaderData::Fetched(fetched_batch) => {
-                            let Batch {
-                                peer,
-                                range,
-                                results,
-                            } = fetched_batch;
-                            let checked_headers = results
-                                .into_iter()
-                                .take_while(|header| {
-                                    check_sealed_header(
-                                        header,
-                                        peer.clone(),
+                    if let CachedDataBatch::None(range) = cached_data_batch {
+                        if request_full_blocks {
+                            let batch =
+                                request_with_retries(&p2p, max_batch_retries, |excluded_peers| {
+                                    get_full_blocks_batch(
+                                        range.clone(),
+                                        excluded_peers,
                                         &p2p,
                                         &consensus,
                                     )
                                 })
-                                .collect::<Vec<_>>();
-                            let batch = Batch::new(peer, range.clone(), checked_headers);
-                            if !batch.is_err() {
-                                cache.insert_headers(batch.clone());
+                                .await;
+                            // Only the peers supporting the full blocks are asked for them.
+                            // Without such a peer, the blocks are requested as the headers
+                            // and the transactions from any peer.
+                            if batch.peer.is_some() {
+                                return BlockHeaderData::FetchedBlocks(batch);
                             }
-                            BlockHeaderData::Fetched(batch)
                         }
+                        let batch =
+                            request_with_retries(&p2p, max_batch_retries, |excluded_peers| {
+                                get_headers_batch(
+                                    range.clone(),
+                                    excluded_peers,
+                                    &p2p,
+                                    &consensus,
+                                )
+                            })
+                            .await;
+                        if !batch.is_err() {
+                            cache.insert_headers(batch.clone());
+                        }
+                        BlockHeaderData::Fetched(batch)
+                    } else {
+                        BlockHeaderData::Cached(cached_data_batch)
                     }
                 }
             }
@@ -464,6 +634,15 @@ fn get_block_stream<
                 async move {
                     match headers.await {
                         BlockHeaderData::Cached(CachedDataBatch::Blocks(batch)) => batch,
+                        BlockHeaderData::FetchedBlocks(batch) => {
+                            if let Some(block) = batch.results.last() {
+                                await_da_height(block.entity.header(), &consensus).await;
+                            }
+                            if !batch.is_err() {
+                                cache.insert_blocks(batch.clone());
+                            }
+                            batch
+                        }
                         BlockHeaderData::Cached(CachedDataBatch::Headers(batch))
                         | BlockHeaderData::Fetched(batch) => {
                             let Batch {
@@ -475,15 +654,23 @@ fn get_block_stream<
                                 SealedBlockBatch::new(peer, range, vec![])
                             } else {
                                 await_da_height(
-                                    results
+                                    &results
                                         .last()
-                                        .expect("We checked headers are not empty above"),
+                                        .expect("We checked headers are not empty above")
+                                        .entity,
                                     &consensus,
                                 )
                                 .await;
                                 let headers =
                                     SealedHeaderBatch::new(peer, range.clone(), results);
-                                let batch = get_blocks(&p2p, headers).await;
+                                let batch = request_with_retries(
+                                    &p2p,
+                                    max_batch_retries,
+                                    |excluded_peers| {
+                                        get_blocks(&p2p, &headers, excluded_peers)
+                                    },
+                                )
+                                .await;
                                 if !batch.is_err() {
                                     cache.insert_blocks(batch.clone());
                                 }
@@ -502,31 +689,22 @@ fn get_block_stream<
         })
 }
 
-fn check_sealed_header<
-    P: PeerToPeerPort + Send + Sync + 'static,
-    C: ConsensusPort + Send + Sync + 'static,
->(
+fn check_sealed_header<C: ConsensusPort + Send + Sync + 'static>(
     header: &SealedBlockHeader,
-    peer_id: Option<PeerId>,
-    p2p: &Arc<P>,
     consensus: &Arc<C>,
 ) -> bool {
-    let validity = consensus
+    consensus
         .check_sealed_header(header)
         .trace_err("Failed to check consensus on header")
-        .unwrap_or(false);
-    if !validity {
-        report_peer(p2p, peer_id.clone(), PeerReportReason::BadBlockHeader);
-    }
-    validity
+        .unwrap_or(false)
 }
 
 async fn await_da_height<C: ConsensusPort + Send + Sync + 'static>(
-    header: &SealedBlockHeader,
+    header: &BlockHeader,
     consensus: &Arc<C>,
 ) {
     let _ = consensus
-        .await_da_height(&header.entity.da_height())
+        .await_da_height(&header.da_height())
         .await
         .trace_err("Failed to wait for DA layer to sync");
 }
@@ -551,6 +729,7 @@ async fn wait_for_notify_or_shutdown(
 
 async fn get_sealed_block_headers<P>(
     range: Range<u32>,
+    excluded_peers: Vec<PeerId>,
     p2p: &Arc<P>,
 ) -> Option<SourcePeer<Vec<SealedBlockHeader>>>
 where
@@ -561,75 +740,123 @@ where
         range.start,
         range.end
     );
-    p2p.get_sealed_block_headers(range)
+    p2p.get_sealed_block_headers(range, excluded_peers)
         .await
         .trace_err("Failed to get headers")
         .ok()
         .map(|res| res.map(|data| data.unwrap_or_default()))
 }
 
+/// Requests the transactions from the `peer_id` that served the headers on the
+/// first attempt, or from any other peer that is not in `excluded_peers` on retries.
+///
+/// Returns `None` if there is no peer to request the transactions from.
 async fn get_transactions<P>(
     range: Range<u32>,
     peer_id: Option<PeerId>,
+    excluded_peers: Vec<PeerId>,
     p2p: &Arc<P>,
-) -> Option<SourcePeer<Vec<Transactions>>>
+) -> Option<SourcePeer<Option<Vec<Transactions>>>>
 where
     P: PeerToPeerPort + Send + Sync + 'static,
 {
     match peer_id {
-        Some(peer_id) => {
+        Some(peer_id) if excluded_peers.is_empty() => {
             let source_peer = peer_id.clone().bind(range.clone());
-            let Ok(Some(txs)) = p2p
+            let txs = p2p
                 .get_transactions_from_peer(source_peer)
                 .await
                 .trace_err("Failed to get transactions")
-            else {
-                report_peer(
-                    p2p,
-                    Some(peer_id.clone()),
-                    PeerReportReason::MissingTransactions,
-                );
-                return None;
-            };
+                .ok()
+                .flatten();
             Some(SourcePeer { peer_id, data: txs })
         }
-        None => {
-            let Ok(SourcePeer { peer_id, data }) = p2p
-                .get_transactions(range.clone())
-                .await
-                .trace_err("Failed to get transactions")
-            else {
-                return None;
-            };
-            let Some(txs) = data else {
-                report_peer(
-                    p2p,
-                    Some(peer_id.clone()),
-                    PeerReportReason::MissingTransactions,
-                );
-                return None;
-            };
-            Some(SourcePeer { peer_id, data: txs })
+        _ => p2p
+            .get_transactions(range.clone(), excluded_peers)
+            .await
+            .trace_err("Failed to get transactions")
+            .ok(),
+    }
+}
+
+/// Requests the batch until it is complete, re-requesting it from another peer
+/// at most `max_batch_retries` times.
+///
+/// The `request` receives the peers that already failed to serve the batch.
+/// These peers are reported only after the retries, and the most complete batch is returned.
+async fn request_with_retries<P, T, F, Fut>(
+    p2p: &Arc<P>,
+    max_batch_retries: usize,
+    mut request: F,
+) -> Batch<T>
+where
+    P: PeerToPeerPort + Send + Sync + 'static,
+    F: FnMut(Vec<PeerId>) -> Fut,
+    Fut: Future<Output = (Batch<T>, Vec<PeerReportReason>)>,
+{
+    let mut failed_peers: Vec<PeerId> = vec![];
+    let mut reports = vec![];
+    let mut best_batch: Option<Batch<T>> = None;
+
+    for _ in 0..=max_batch_retries {
+        let (batch, batch_reports) = request(failed_peers.clone()).await;
+        let is_complete = !batch.is_err();
+        let peer = batch.peer.clone();
+        reports.extend(
+            batch_reports
+                .into_iter()
+                .map(|reason| (peer.clone(), reason)),
+        );
+
+        let is_better = best_batch
+            .as_ref()
+            .is_none_or(|best_batch| batch.results.len() > best_batch.results.len());
+        if is_better {
+            best_batch = Some(batch);
+        }
+
+        // Without the peer there is nobody to exclude, so the retry would fail the same way.
+        let Some(peer) = peer else { break };
+        if is_complete {
+            break
         }
+
+        tracing::debug!("Peer {:?} failed to serve the batch", peer);
+        failed_peers.push(peer);
     }
+
+    for (peer, reason) in reports {
+        report_peer(p2p, peer, reason);
+    }
+
+    best_batch.expect("The batch is requested at least once")
 }
 
-async fn get_headers_batch<P>(range: Range<u32>, p2p: &Arc<P>) -> SealedHeaderBatch
+async fn get_headers_batch<P, C>(
+    range: Range<u32>,
+    excluded_peers: Vec<PeerId>,
+    p2p: &Arc<P>,
+    consensus: &Arc<C>,
+) -> (SealedHeaderBatch, Vec<PeerReportReason>)
 where
     P: PeerToPeerPort + Send + Sync + 'static,
+    C: ConsensusPort + Send + Sync + 'static,
 {
     tracing::debug!(
         "getting header range from {} to {} inclusive",
         range.start,
         range.end
     );
-    let Some(sourced_headers) = get_sealed_block_headers(range.clone(), p2p).await else {
-        return Batch::new(None, range, vec![])
+    let Some(sourced_headers) =
+        get_sealed_block_headers(range.clone(), excluded_peers, p2p).await
+    else {
+        return (Batch::new(None, range, vec![]), vec![])
     };
     let SourcePeer {
         peer_id,
         data: headers,
     } = sourced_headers;
+    let mut reports = vec![];
     let heights = range.clone().map(BlockHeight::from);
     let headers = headers
         .into_iter()
@@ -641,13 +868,89 @@ where
         .map(|(header, _)| header)
         .collect::<Vec<_>>();
     if headers.len() != range.len() {
-        report_peer(
-            p2p,
-            Some(peer_id.clone()),
-            PeerReportReason::MissingBlockHeaders,
-        );
+        reports.push(PeerReportReason::MissingBlockHeaders);
+    }
+    let headers_count = headers.len();
+    let checked_headers = headers
+        .into_iter()
+        .take_while(|header| check_sealed_header(header, consensus))
+        .collect::<Vec<_>>();
+    if checked_headers.len() < headers_count {
+        reports.push(PeerReportReason::BadBlockHeader);
+    }
+    (Batch::new(Some(peer_id), range, checked_headers), reports)
+}
+
+/// Requests the full blocks in one round trip and checks their consensus
+/// and that the transactions match the headers.
+async fn get_full_blocks_batch<P, C>(
+    range: Range<u32>,
+    excluded_peers: Vec<PeerId>,
+    p2p: &Arc<P>,
+    consensus: &Arc<C>,
+) -> (SealedBlockBatch, Vec<PeerReportReason>)
+where
+    P: PeerToPeerPort + Send + Sync + 'static,
+    C: ConsensusPort + Send + Sync + 'static,
+{
+    tracing::debug!(
+        "getting block range from {} to {} inclusive",
+        range.start,
+        range.end
+    );
+    let Some(SourcePeer {
+        peer_id,
+        data: blocks,
+    }) = p2p
+        .get_sealed_blocks(range.clone(), excluded_peers)
+        .await
+        .trace_err("Failed to get blocks")
+        .ok()
+    else {
+        return (Batch::new(None, range, vec![]), vec![])
+    };
+    let mut reports = vec![];
+    let heights = range.clone().map(BlockHeight::from);
+    let blocks = blocks
+        .unwrap_or_default()
+        .into_iter()
+        .zip(heights)
+        .take_while(|(block, expected_height)| {
+            block.entity.header().height() == expected_height
+        })
+        .map(|(block, _)| block)
+        .collect::<Vec<_>>();
+    if blocks.len() != range.len() {
+        reports.push(PeerReportReason::MissingBlockHeaders);
     }
-    Batch::new(Some(peer_id), range, headers)
+    let blocks_count = blocks.len();
+    let checked_blocks = blocks
+        .into_iter()
+        .take_while(|block| {
+            let header = SealedBlockHeader {
+                entity: block.entity.header().clone(),
+                consensus: block.consensus.clone(),
+            };
+            check_sealed_header(&header, consensus)
+        })
+        .collect::<Vec<_>>();
+    if checked_blocks.len() < blocks_count {
+        reports.push(PeerReportReason::BadBlockHeader);
+    }
+    let checked_count = checked_blocks.len();
+    let valid_blocks = checked_blocks
+        .into_iter()
+        .take_while(|block| {
+            block
+                .entity
+                .header()
+                .validate_transactions(block.entity.transactions())
+        })
+        .collect::<Vec<_>>();
+    if valid_blocks.len() < checked_count {
+        reports.push(PeerReportReason::InvalidTransactions);
+    }
+    (Batch::new(Some(peer_id), range, valid_blocks), reports)
 }
 
 fn report_peer<P>(p2p: &Arc<P>, peer_id: Option<PeerId>, reason: PeerReportReason)
@@ -666,7 +969,11 @@ where
 
 /// Get blocks correlating to the headers from a specific peer
 #[tracing::instrument(skip(p2p, headers))]
-async fn get_blocks<P>(p2p: &Arc<P>, headers: SealedHeaderBatch) -> SealedBlockBatch
+async fn get_blocks<P>(
+    p2p: &Arc<P>,
+    headers: &SealedHeaderBatch,
+    excluded_peers: Vec<PeerId>,
+) -> (SealedBlockBatch, Vec<PeerReportReason>)
 where
     P: PeerToPeerPort + Send + Sync + 'static,
 {
@@ -674,18 +981,26 @@ where
         results: headers,
         range,
         peer,
-    } = headers;
+    } = headers.clone();
 
     let Some(SourcePeer {
         peer_id,
         data: transactions,
-    }) = get_transactions(range.clone(), peer.clone(), p2p).await
+    }) = get_transactions(range.clone(), peer, excluded_peers, p2p).await
     else {
-        return Batch::new(peer, range, vec![])
+        return (Batch::new(None, range, vec![]), vec![])
+    };
+
+    let Some(transactions) = transactions else {
+        return (
+            Batch::new(Some(peer_id), range, vec![]),
+            vec![PeerReportReason::MissingTransactions],
+        )
     };
 
     let iter = headers.into_iter().zip(transactions.into_iter());
     let mut blocks = vec![];
+    let mut reports = vec![];
     for (block_header, transactions) in iter {
         let SealedBlockHeader {
             consensus,
@@ -699,15 +1014,11 @@ where
         if let Some(block) = block {
             blocks.push(block);
         } else {
-            report_peer(
-                p2p,
-                Some(peer_id.clone()),
-                PeerReportReason::InvalidTransactions,
-            );
+            reports.push(PeerReportReason::InvalidTransactions);
             break
         }
     }
-    Batch::new(Some(peer_id), range, blocks)
+    (Batch::new(Some(peer_id), range, blocks), reports)
 }
 
 #[tracing::instrument(
@@ -742,6 +1053,41 @@ where
     r
 }
 
+#[tracing::instrument(
+    skip_all,
+    fields(
+        height = **header.entity.height(),
+        id = %header.entity.consensus().generated.application_hash
+    ),
+    err
+)]
+async fn commit_header<E>(
+    executor: &E,
+    state: &SharedMutex<State>,
+    header: SealedBlockHeader,
+) -> anyhow::Result<()>
+where
+    E: BlockImporterPort + Send + Sync + 'static,
+{
+    let height = *header.entity.height();
+    let r = executor.commit_header(header).await;
+
+    // If the header was committed successfully, mark its height as committed.
+    match &r {
+        Err(err) => {
+            tracing::error!(
+                "Commit of the header at height {} failed: {:?}",
+                *height,
+                err
+            );
+        }
+        _ => {
+            state.apply(|s| s.commit(*height));
+        }
+    }
+    r
+}
+
 /// Extra stream utilities.
 trait StreamUtil: Sized {
     /// Scan the stream for `None`.
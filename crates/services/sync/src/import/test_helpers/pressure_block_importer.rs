@@ -7,7 +7,10 @@ use crate::{
 };
 use fuel_core_services::stream::BoxStream;
 use fuel_core_types::{
-    blockchain::SealedBlock,
+    blockchain::{
+        SealedBlock,
+        SealedBlockHeader,
+    },
     fuel_types::BlockHeight,
 };
 use std::time::Duration;
@@ -28,6 +31,10 @@ impl BlockImporterPort for PressureBlockImporter {
         });
         self.0.execute_and_commit(block).await
     }
+
+    async fn commit_header(&self, header: SealedBlockHeader) -> anyhow::Result<()> {
+        self.0.commit_header(header).await
+    }
 }
 
 impl PressureBlockImporter {
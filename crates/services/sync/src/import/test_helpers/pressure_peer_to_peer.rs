@@ -12,9 +12,15 @@ use crate::{
 };
 use fuel_core_services::stream::BoxStream;
 use fuel_core_types::{
-    blockchain::SealedBlockHeader,
+    blockchain::{
+        SealedBlock,
+        SealedBlockHeader,
+    },
     fuel_types::BlockHeight,
     services::p2p::{
+        GossipsubMessageAcceptance,
+        GossipsubMessageInfo,
+        NewBlockGossipData,
         PeerId,
         SourcePeer,
         Transactions,
@@ -37,14 +43,45 @@ impl PeerToPeerPort for PressurePeerToPeer {
         self.p2p.height_stream()
     }
 
+    fn new_block_stream(&self) -> BoxStream<NewBlockGossipData> {
+        self.p2p.new_block_stream()
+    }
+
+    fn notify_gossip_block_validity(
+        &self,
+        _message_info: GossipsubMessageInfo,
+        _validity: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     async fn get_sealed_block_headers(
         &self,
         block_height_range: Range<u32>,
+        excluded_peers: Vec<PeerId>,
     ) -> anyhow::Result<SourcePeer<Option<Vec<SealedBlockHeader>>>> {
         self.counts.apply(|c| c.inc_headers());
         tokio::time::sleep(self.durations[0]).await;
         self.counts.apply(|c| c.dec_headers());
-        self.p2p.get_sealed_block_headers(block_height_range).await
+        self.p2p
+            .get_sealed_block_headers(block_height_range, excluded_peers)
+            .await
+    }
+
+    async fn get_sealed_blocks(
+        &self,
+        block_height_range: Range<u32>,
+        excluded_peers: Vec<PeerId>,
+    ) -> anyhow::Result<SourcePeer<Option<Vec<SealedBlock>>>> {
+        self.counts.apply(|c| c.inc_headers());
+        tokio::time::sleep(self.durations[0]).await;
+        for _height in block_height_range.clone() {
+            self.counts.apply(|c| c.inc_blocks());
+        }
+        self.counts.apply(|c| c.dec_headers());
+        self.p2p
+            .get_sealed_blocks(block_height_range, excluded_peers)
+            .await
     }
 
     async fn get_transactions_from_peer(
@@ -63,6 +100,7 @@ impl PeerToPeerPort for PressurePeerToPeer {
     async fn get_transactions(
         &self,
         block_ids: Range<u32>,
+        excluded_peers: Vec<PeerId>,
     ) -> anyhow::Result<SourcePeer<Option<Vec<Transactions>>>> {
         self.counts.apply(|c| c.inc_transactions());
         tokio::time::sleep(self.durations[1]).await;
@@ -70,7 +108,7 @@ impl PeerToPeerPort for PressurePeerToPeer {
             self.counts.apply(|c| c.inc_blocks());
         }
         self.counts.apply(|c| c.dec_transactions());
-        self.p2p.get_transactions(block_ids).await
+        self.p2p.get_transactions(block_ids, excluded_peers).await
     }
 
     fn report_peer(
@@ -85,18 +123,19 @@ impl PeerToPeerPort for PressurePeerToPeer {
 impl PressurePeerToPeer {
     pub fn new(counts: SharedCounts, delays: [Duration; 2]) -> Self {
         let mut mock = MockPeerToPeerPort::default();
-        mock.expect_get_sealed_block_headers().returning(|range| {
-            Box::pin(async move {
-                let peer = random_peer();
-                let headers = range
-                    .clone()
-                    .map(BlockHeight::from)
-                    .map(empty_header)
-                    .collect();
-                let headers = peer.bind(Some(headers));
-                Ok(headers)
-            })
-        });
+        mock.expect_get_sealed_block_headers()
+            .returning(|range, _| {
+                Box::pin(async move {
+                    let peer = random_peer();
+                    let headers = range
+                        .clone()
+                        .map(BlockHeight::from)
+                        .map(empty_header)
+                        .collect();
+                    let headers = peer.bind(Some(headers));
+                    Ok(headers)
+                })
+            });
         mock.expect_get_transactions_from_peer()
             .returning(|block_ids| {
                 Box::pin(async move {
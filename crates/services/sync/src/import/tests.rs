@@ -13,7 +13,10 @@ use crate::{
         PeerReportReason,
     },
 };
-use fuel_core_types::services::p2p::Transactions;
+use fuel_core_types::{
+    fuel_tx::Transaction,
+    services::p2p::Transactions,
+};
 use mockall::Sequence;
 use std::{
     ops::Deref,
@@ -41,7 +44,7 @@ async fn test_import_0_to_5() {
     let mut p2p = MockPeerToPeerPort::default();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|range| {
+        .returning(|range, _| {
             Box::pin(async move {
                 let peer = random_peer();
                 let headers = Some(range.map(empty_header).collect());
@@ -62,6 +65,9 @@ async fn test_import_0_to_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
+        request_full_blocks: false,
+        headers_only: false,
     };
     let mocks = Mocks {
         consensus_port,
@@ -91,7 +97,7 @@ async fn test_import_3_to_5() {
     let mut p2p = MockPeerToPeerPort::default();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|range| {
+        .returning(|range, _| {
             Box::pin(async move {
                 let peer = random_peer();
                 let headers = Some(range.map(empty_header).collect());
@@ -112,6 +118,9 @@ async fn test_import_3_to_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
+        request_full_blocks: false,
+        headers_only: false,
     };
     let mocks = Mocks {
         consensus_port,
@@ -158,7 +167,7 @@ async fn test_import_0_to_499() {
     let times = div_ceil(n, header_batch_size);
     p2p.expect_get_sealed_block_headers()
         .times(times)
-        .returning(|range| {
+        .returning(|range, _| {
             Box::pin(async move {
                 let peer = random_peer();
                 let headers = Some(range.map(empty_header).collect());
@@ -182,6 +191,9 @@ async fn test_import_0_to_499() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size,
+        max_batch_retries: 0,
+        request_full_blocks: false,
+        headers_only: false,
     };
     let mocks = Mocks {
         consensus_port,
@@ -211,7 +223,7 @@ async fn import__signature_fails_on_header_5_only() {
     let mut p2p = MockPeerToPeerPort::default();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|range| {
+        .returning(|range, _| {
             Box::pin(async move {
                 let peer = random_peer();
                 let headers = Some(range.map(empty_header).collect());
@@ -238,6 +250,9 @@ async fn import__signature_fails_on_header_5_only() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
+        request_full_blocks: false,
+        headers_only: false,
     };
 
     // when
@@ -253,6 +268,9 @@ async fn import__keep_data_asked_in_fail_ask_header_cases() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 1,
+        max_batch_retries: 0,
+        request_full_blocks: false,
+        headers_only: false,
     };
 
     let mut consensus_port = MockConsensusPort::default();
@@ -274,7 +292,7 @@ async fn import__keep_data_asked_in_fail_ask_header_cases() {
     p2p.expect_get_sealed_block_headers()
         .times(1)
         .in_sequence(&mut seq)
-        .returning(|range| {
+        .returning(|range, _| {
             assert_eq!(range, 4..5);
             Box::pin(async move {
                 tokio::time::sleep(Duration::from_millis(300)).await;
@@ -285,7 +303,7 @@ async fn import__keep_data_asked_in_fail_ask_header_cases() {
     p2p.expect_get_sealed_block_headers()
         .times(2)
         .in_sequence(&mut seq)
-        .returning(|range| {
+        .returning(|range, _| {
             Box::pin(async move {
                 let peer = random_peer();
                 let headers = Some(range.map(empty_header).collect());
@@ -298,7 +316,7 @@ async fn import__keep_data_asked_in_fail_ask_header_cases() {
     p2p.expect_get_sealed_block_headers()
         .times(1)
         .in_sequence(&mut seq)
-        .returning(|range| {
+        .returning(|range, _| {
             assert_eq!(range, 4..5);
             Box::pin(async move {
                 let peer = random_peer();
@@ -356,6 +374,9 @@ async fn import__keep_data_asked_in_fail_ask_transactions_cases() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 1,
+        max_batch_retries: 0,
+        request_full_blocks: false,
+        headers_only: false,
     };
 
     let mut consensus_port = MockConsensusPort::default();
@@ -374,7 +395,7 @@ async fn import__keep_data_asked_in_fail_ask_transactions_cases() {
     // Everything goes well on the headers part for all blocks
     p2p.expect_get_sealed_block_headers()
         .times(3)
-        .returning(|range| {
+        .returning(|range, _| {
             Box::pin(async move {
                 let peer = random_peer();
                 let headers = Some(range.map(empty_header).collect());
@@ -412,7 +433,7 @@ async fn import__keep_data_asked_in_fail_ask_transactions_cases() {
     p2p.expect_get_transactions()
         .times(1)
         .in_sequence(&mut seq)
-        .returning(|block_ids| {
+        .returning(|block_ids, _| {
             assert_eq!(block_ids, 4..5);
             Box::pin(async move {
                 let data = block_ids;
@@ -462,6 +483,9 @@ async fn import__keep_data_asked_in_fail_execution() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 1,
+        max_batch_retries: 0,
+        request_full_blocks: false,
+        headers_only: false,
     };
 
     let mut consensus_port = MockConsensusPort::default();
@@ -480,7 +504,7 @@ async fn import__keep_data_asked_in_fail_execution() {
     // Data is re-ask for the block 4 because his execution failed
     p2p.expect_get_sealed_block_headers()
         .times(4)
-        .returning(|range| {
+        .returning(|range, _| {
             Box::pin(async move {
                 let peer = random_peer();
                 let headers = Some(range.map(empty_header).collect());
@@ -568,7 +592,7 @@ async fn import__signature_fails_on_header_4_only() {
     let mut p2p = MockPeerToPeerPort::default();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|range| {
+        .returning(|range, _| {
             Box::pin(async move {
                 let peer = random_peer();
                 let headers = Some(range.map(empty_header).collect());
@@ -595,6 +619,9 @@ async fn import__signature_fails_on_header_4_only() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
+        request_full_blocks: false,
+        headers_only: false,
     };
 
     // when
@@ -610,7 +637,7 @@ async fn import__header_not_found() {
     let mut p2p = MockPeerToPeerPort::default();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|_| {
+        .returning(|_, _| {
             Box::pin(async move {
                 let peer = random_peer();
                 let headers = Some(Vec::new());
@@ -628,6 +655,9 @@ async fn import__header_not_found() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
+        request_full_blocks: false,
+        headers_only: false,
     };
 
     // when
@@ -643,7 +673,7 @@ async fn import__header_response_incomplete() {
     let mut p2p = MockPeerToPeerPort::default();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|_| {
+        .returning(|_, _| {
             Box::pin(async move {
                 let peer = random_peer();
                 let headers = None;
@@ -661,6 +691,9 @@ async fn import__header_response_incomplete() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
+        request_full_blocks: false,
+        headers_only: false,
     };
 
     // when
@@ -676,7 +709,7 @@ async fn import__header_5_not_found() {
     let mut p2p = MockPeerToPeerPort::default();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|_| {
+        .returning(|_, _| {
             Box::pin(async move {
                 let peer = random_peer();
                 let headers = Some(vec![empty_header(4)]);
@@ -704,6 +737,9 @@ async fn import__header_5_not_found() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
+        request_full_blocks: false,
+        headers_only: false,
     };
 
     // when
@@ -719,7 +755,7 @@ async fn import__header_4_not_found() {
     let mut p2p = MockPeerToPeerPort::default();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|_| {
+        .returning(|_, _| {
             Box::pin(async move {
                 let peer = random_peer();
                 let headers = Some(vec![empty_header(5)]);
@@ -738,6 +774,9 @@ async fn import__header_4_not_found() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
+        request_full_blocks: false,
+        headers_only: false,
     };
 
     // when
@@ -763,7 +802,7 @@ async fn import__transactions_not_found() {
     let mut p2p = MockPeerToPeerPort::default();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|range| {
+        .returning(|range, _| {
             Box::pin(async move {
                 let peer = random_peer();
                 let headers = Some(range.map(empty_header).collect());
@@ -784,6 +823,9 @@ async fn import__transactions_not_found() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
+        request_full_blocks: false,
+        headers_only: false,
     };
 
     // when
@@ -809,7 +851,7 @@ async fn import__transactions_not_found_for_header_4() {
     let mut p2p = MockPeerToPeerPort::default();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|range| {
+        .returning(|range, _| {
             Box::pin(async move {
                 let peer = random_peer();
                 let headers = Some(range.map(empty_header).collect());
@@ -842,6 +884,9 @@ async fn import__transactions_not_found_for_header_4() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
+        request_full_blocks: false,
+        headers_only: false,
     };
 
     // when
@@ -867,7 +912,7 @@ async fn import__transactions_not_found_for_header_5() {
     let mut p2p = MockPeerToPeerPort::default();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|range| {
+        .returning(|range, _| {
             Box::pin(async move {
                 let peer = random_peer();
                 let headers = Some(range.map(empty_header).collect());
@@ -893,6 +938,9 @@ async fn import__transactions_not_found_for_header_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
+        request_full_blocks: false,
+        headers_only: false,
     };
 
     // when
@@ -908,7 +956,7 @@ async fn import__p2p_error() {
     let mut p2p = MockPeerToPeerPort::default();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|_| {
+        .returning(|_, _| {
             Box::pin(async move { Err(anyhow::anyhow!("Some network error")) })
         });
     p2p.expect_get_transactions_from_peer().times(0);
@@ -922,6 +970,9 @@ async fn import__p2p_error() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
+        request_full_blocks: false,
+        headers_only: false,
     };
 
     // when
@@ -947,7 +998,7 @@ async fn import__p2p_error_on_4_transactions() {
     let mut p2p = MockPeerToPeerPort::default();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|range| {
+        .returning(|range, _| {
             Box::pin(async move {
                 let peer = random_peer();
                 let headers = Some(range.map(empty_header).collect());
@@ -970,6 +1021,9 @@ async fn import__p2p_error_on_4_transactions() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
+        request_full_blocks: false,
+        headers_only: false,
     };
 
     // when
@@ -1001,7 +1055,7 @@ async fn import__consensus_error_on_4() {
     let mut p2p = MockPeerToPeerPort::default();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|range| {
+        .returning(|range, _| {
             Box::pin(async move {
                 let peer = random_peer();
                 let headers = Some(range.map(empty_header).collect());
@@ -1020,6 +1074,9 @@ async fn import__consensus_error_on_4() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
+        request_full_blocks: false,
+        headers_only: false,
     };
 
     // when
@@ -1051,7 +1108,7 @@ async fn import__consensus_error_on_5() {
     let mut p2p = MockPeerToPeerPort::default();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|range| {
+        .returning(|range, _| {
             Box::pin(async move {
                 let peer = random_peer();
                 let headers = Some(range.map(empty_header).collect());
@@ -1078,6 +1135,9 @@ async fn import__consensus_error_on_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
+        request_full_blocks: false,
+        headers_only: false,
     };
 
     // when
@@ -1103,7 +1163,7 @@ async fn import__execution_error_on_header_4() {
     let mut p2p = MockPeerToPeerPort::default();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|range| {
+        .returning(|range, _| {
             Box::pin(async move {
                 let peer = random_peer();
                 let headers = Some(range.map(empty_header).collect());
@@ -1144,6 +1204,9 @@ async fn import__execution_error_on_header_4() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
+        request_full_blocks: false,
+        headers_only: false,
     };
 
     // when
@@ -1169,7 +1232,7 @@ async fn import__execution_error_on_header_5() {
     let mut p2p = MockPeerToPeerPort::default();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|range| {
+        .returning(|range, _| {
             Box::pin(async move {
                 let peer = random_peer();
                 let headers = Some(range.map(empty_header).collect());
@@ -1210,6 +1273,9 @@ async fn import__execution_error_on_header_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
+        request_full_blocks: false,
+        headers_only: false,
     };
 
     // when
@@ -1238,6 +1304,9 @@ async fn signature_always_fails() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
+        request_full_blocks: false,
+        headers_only: false,
     };
 
     // when
@@ -1266,7 +1335,7 @@ async fn import__can_work_in_two_loops() {
     let mut p2p = MockPeerToPeerPort::default();
     p2p.expect_get_sealed_block_headers()
         .times(2)
-        .returning(move |range| {
+        .returning(move |range, _| {
             let state = state.clone();
             Box::pin(async move {
                 state.apply(|s| s.observe(6));
@@ -1295,6 +1364,9 @@ async fn import__can_work_in_two_loops() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
+        request_full_blocks: false,
+        headers_only: false,
     };
 
     // when
@@ -1304,6 +1376,411 @@ async fn import__can_work_in_two_loops() {
     assert_eq!((State::new(6, None), true), res);
 }
 
+fn peer(id: u8) -> PeerId {
+    PeerId::from(vec![id])
+}
+
+fn retry_consensus_port(headers: usize) -> MockConsensusPort {
+    let mut consensus_port = MockConsensusPort::default();
+    consensus_port
+        .expect_check_sealed_header()
+        .times(headers)
+        .returning(|_| Ok(true));
+    consensus_port
+        .expect_await_da_height()
+        .times(1)
+        .returning(|_| Box::pin(async move { Ok(()) }));
+    consensus_port
+}
+
+#[tokio::test]
+async fn import__missing_headers_are_requested_from_another_peer() {
+    // Given
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_sealed_block_headers()
+        .times(1)
+        .withf(|_, excluded_peers| excluded_peers.is_empty())
+        .returning(|_, _| Box::pin(async move { Ok(peer(1).bind(None)) }));
+    p2p.expect_get_sealed_block_headers()
+        .times(1)
+        .withf(|_, excluded_peers| excluded_peers == &[peer(1)])
+        .returning(|range, _| {
+            Box::pin(async move {
+                let headers = Some(range.map(empty_header).collect());
+                Ok(peer(2).bind(headers))
+            })
+        });
+    p2p.expect_get_transactions_from_peer()
+        .times(1)
+        .withf(|block_ids| block_ids.peer_id == peer(2))
+        .returning(|block_ids| {
+            Box::pin(async move {
+                let data = block_ids.data;
+                let v = data.into_iter().map(|_| Transactions::default()).collect();
+                Ok(Some(v))
+            })
+        });
+    p2p.expect_report_peer()
+        .times(1)
+        .with(
+            mockall::predicate::eq(peer(1)),
+            mockall::predicate::eq(PeerReportReason::MissingBlockHeaders),
+        )
+        .returning(|_, _| Ok(()));
+
+    let state = State::new(3, 5).into();
+    let mocks = Mocks {
+        consensus_port: retry_consensus_port(2),
+        p2p,
+        executor: DefaultMocks::times([2]),
+    };
+    let params = Config {
+        block_stream_buffer_size: 10,
+        header_batch_size: 10,
+        max_batch_retries: 1,
+        request_full_blocks: false,
+        headers_only: false,
+    };
+
+    // When
+    let res = test_import_inner(state, mocks, None, params).await;
+
+    // Then
+    assert_eq!((State::new(5, None), true), res);
+}
+
+#[tokio::test]
+async fn import__missing_transactions_are_requested_from_another_peer() {
+    // Given
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_sealed_block_headers()
+        .times(1)
+        .returning(|range, _| {
+            Box::pin(async move {
+                let headers = Some(range.map(empty_header).collect());
+                Ok(peer(1).bind(headers))
+            })
+        });
+    p2p.expect_get_transactions_from_peer()
+        .times(1)
+        .returning(|_| Box::pin(async move { Ok(None) }));
+    p2p.expect_get_transactions()
+        .times(1)
+        .withf(|_, excluded_peers| excluded_peers == &[peer(1)])
+        .returning(|range, _| {
+            Box::pin(async move {
+                let v = range.map(|_| Transactions::default()).collect();
+                Ok(peer(2).bind(Some(v)))
+            })
+        });
+    p2p.expect_report_peer()
+        .times(1)
+        .with(
+            mockall::predicate::eq(peer(1)),
+            mockall::predicate::eq(PeerReportReason::MissingTransactions),
+        )
+        .returning(|_, _| Ok(()));
+
+    let state = State::new(3, 5).into();
+    let mocks = Mocks {
+        consensus_port: retry_consensus_port(2),
+        p2p,
+        executor: DefaultMocks::times([2]),
+    };
+    let params = Config {
+        block_stream_buffer_size: 10,
+        header_batch_size: 10,
+        max_batch_retries: 1,
+        request_full_blocks: false,
+        headers_only: false,
+    };
+
+    // When
+    let res = test_import_inner(state, mocks, None, params).await;
+
+    // Then
+    assert_eq!((State::new(5, None), true), res);
+}
+
+#[tokio::test]
+async fn import__reports_every_failed_peer_when_retries_are_exhausted() {
+    // Given
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_sealed_block_headers()
+        .times(1)
+        .withf(|_, excluded_peers| excluded_peers.is_empty())
+        .returning(|_, _| Box::pin(async move { Ok(peer(1).bind(None)) }));
+    p2p.expect_get_sealed_block_headers()
+        .times(1)
+        .withf(|_, excluded_peers| excluded_peers == &[peer(1)])
+        .returning(|_, _| Box::pin(async move { Ok(peer(2).bind(None)) }));
+    for failed_peer in [peer(1), peer(2)] {
+        p2p.expect_report_peer()
+            .times(1)
+            .with(
+                mockall::predicate::eq(failed_peer),
+                mockall::predicate::eq(PeerReportReason::MissingBlockHeaders),
+            )
+            .returning(|_, _| Ok(()));
+    }
+
+    let state = State::new(3, 5).into();
+    let mocks = Mocks {
+        consensus_port: DefaultMocks::times([0]),
+        p2p,
+        executor: DefaultMocks::times([0]),
+    };
+    let params = Config {
+        block_stream_buffer_size: 10,
+        header_batch_size: 10,
+        max_batch_retries: 1,
+        request_full_blocks: false,
+        headers_only: false,
+    };
+
+    // When
+    let res = test_import_inner(state, mocks, None, params).await;
+
+    // Then
+    assert_eq!((State::new(3, None), false), res);
+}
+
+fn empty_block(height: u32) -> SealedBlock {
+    let SealedBlockHeader { entity, consensus } = empty_header(height);
+    let entity = Block::try_from_executed(entity, vec![])
+        .expect("The empty header has no transactions");
+    SealedBlock { entity, consensus }
+}
+
+#[tokio::test]
+async fn import__requests_full_blocks_when_enabled() {
+    // Given
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_sealed_blocks()
+        .times(1)
+        .returning(|range, _| {
+            Box::pin(async move {
+                let blocks = Some(range.map(empty_block).collect());
+                Ok(peer(1).bind(blocks))
+            })
+        });
+
+    let state = State::new(3, 5).into();
+    let mocks = Mocks {
+        consensus_port: retry_consensus_port(2),
+        p2p,
+        executor: DefaultMocks::times([2]),
+    };
+    let params = Config {
+        block_stream_buffer_size: 10,
+        header_batch_size: 10,
+        max_batch_retries: 0,
+        request_full_blocks: true,
+        headers_only: false,
+    };
+
+    // When
+    let res = test_import_inner(state, mocks, None, params).await;
+
+    // Then
+    assert_eq!((State::new(5, None), true), res);
+}
+
+#[tokio::test]
+async fn import__full_blocks_with_invalid_transactions_are_requested_from_another_peer() {
+    // Given
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_sealed_blocks()
+        .times(1)
+        .withf(|_, excluded_peers| excluded_peers.is_empty())
+        .returning(|range, _| {
+            Box::pin(async move {
+                let blocks = range
+                    .map(|height| {
+                        let mut block = empty_block(height);
+                        block
+                            .entity
+                            .transactions_mut()
+                            .push(Transaction::default_test_tx());
+                        block
+                    })
+                    .collect();
+                Ok(peer(1).bind(Some(blocks)))
+            })
+        });
+    p2p.expect_get_sealed_blocks()
+        .times(1)
+        .withf(|_, excluded_peers| excluded_peers == &[peer(1)])
+        .returning(|range, _| {
+            Box::pin(async move {
+                let blocks = Some(range.map(empty_block).collect());
+                Ok(peer(2).bind(blocks))
+            })
+        });
+    p2p.expect_report_peer()
+        .times(1)
+        .with(
+            mockall::predicate::eq(peer(1)),
+            mockall::predicate::eq(PeerReportReason::InvalidTransactions),
+        )
+        .returning(|_, _| Ok(()));
+
+    let state = State::new(3, 5).into();
+    let mocks = Mocks {
+        consensus_port: retry_consensus_port(4),
+        p2p,
+        executor: DefaultMocks::times([2]),
+    };
+    let params = Config {
+        block_stream_buffer_size: 10,
+        header_batch_size: 10,
+        max_batch_retries: 1,
+        request_full_blocks: true,
+        headers_only: false,
+    };
+
+    // When
+    let res = test_import_inner(state, mocks, None, params).await;
+
+    // Then
+    assert_eq!((State::new(5, None), true), res);
+}
+
+#[tokio::test]
+async fn import__falls_back_to_headers_and_transactions_without_full_blocks_peer() {
+    // Given
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_sealed_blocks().times(1).returning(|_, _| {
+        Box::pin(async move { Err(anyhow::anyhow!("No peer found to send request to")) })
+    });
+    p2p.expect_get_sealed_block_headers()
+        .times(1)
+        .returning(|range, _| {
+            Box::pin(async move {
+                let headers = Some(range.map(empty_header).collect());
+                Ok(peer(1).bind(headers))
+            })
+        });
+    p2p.expect_get_transactions_from_peer()
+        .times(1)
+        .returning(|block_ids| {
+            Box::pin(async move {
+                let data = block_ids.data;
+                let v = data.into_iter().map(|_| Transactions::default()).collect();
+                Ok(Some(v))
+            })
+        });
+    p2p.expect_report_peer().never();
+
+    let state = State::new(3, 5).into();
+    let mocks = Mocks {
+        consensus_port: retry_consensus_port(2),
+        p2p,
+        executor: DefaultMocks::times([2]),
+    };
+    let params = Config {
+        block_stream_buffer_size: 10,
+        header_batch_size: 10,
+        max_batch_retries: 0,
+        request_full_blocks: true,
+        headers_only: false,
+    };
+
+    // When
+    let res = test_import_inner(state, mocks, None, params).await;
+
+    // Then
+    assert_eq!((State::new(5, None), true), res);
+}
+
+fn headers_only_mocks(executor: MockBlockImporterPort) -> Mocks {
+    let mut consensus_port = MockConsensusPort::default();
+    consensus_port
+        .expect_check_sealed_header()
+        .times(2)
+        .returning(|_| Ok(true));
+    consensus_port.expect_await_da_height().never();
+
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_sealed_block_headers()
+        .times(1)
+        .returning(|range, _| {
+            Box::pin(async move {
+                let headers = Some(range.map(empty_header).collect());
+                Ok(peer(1).bind(headers))
+            })
+        });
+    p2p.expect_get_transactions_from_peer().never();
+    p2p.expect_get_transactions().never();
+
+    Mocks {
+        consensus_port,
+        p2p,
+        executor,
+    }
+}
+
+#[tokio::test]
+async fn import__headers_only_commits_headers_without_transactions() {
+    // Given
+    let mut executor = MockBlockImporterPort::default();
+    executor.expect_execute_and_commit().never();
+    executor
+        .expect_commit_header()
+        .times(2)
+        .returning(|_| Box::pin(async move { Ok(()) }));
+
+    let state = State::new(3, 5).into();
+    let mocks = headers_only_mocks(executor);
+    let params = Config {
+        block_stream_buffer_size: 10,
+        header_batch_size: 10,
+        max_batch_retries: 0,
+        request_full_blocks: false,
+        headers_only: true,
+    };
+
+    // When
+    let res = test_import_inner(state, mocks, None, params).await;
+
+    // Then
+    assert_eq!((State::new(5, None), true), res);
+}
+
+#[tokio::test]
+async fn import__headers_only_stops_at_the_header_that_failed_to_commit() {
+    // Given
+    let mut executor = MockBlockImporterPort::default();
+    executor
+        .expect_commit_header()
+        .times(2)
+        .returning(|header| {
+            Box::pin(async move {
+                if **header.entity.height() == 5 {
+                    Err(anyhow::anyhow!("The previous root doesn't match"))
+                } else {
+                    Ok(())
+                }
+            })
+        });
+
+    let state = State::new(3, 5).into();
+    let mocks = headers_only_mocks(executor);
+    let params = Config {
+        block_stream_buffer_size: 10,
+        header_batch_size: 10,
+        max_batch_retries: 0,
+        request_full_blocks: false,
+        headers_only: true,
+    };
+
+    // When
+    let res = test_import_inner(state, mocks, None, params).await;
+
+    // Then
+    assert_eq!((State::new(4, None), false), res);
+}
+
 async fn test_import_inner(
     state: SharedMutex<State>,
     mocks: Mocks,
@@ -1420,7 +1897,7 @@ async fn import__execution_error_on_header_4_when_awaits_for_1000000_blocks() {
         .returning(|_| Box::pin(async move { Ok(()) }));
 
     let mut p2p = MockPeerToPeerPort::default();
-    p2p.expect_get_sealed_block_headers().returning(|range| {
+    p2p.expect_get_sealed_block_headers().returning(|range, _| {
         Box::pin(async move {
             let peer = random_peer();
             let headers = Some(range.map(empty_header).collect());
@@ -1460,6 +1937,9 @@ async fn import__execution_error_on_header_4_when_awaits_for_1000000_blocks() {
     let params = Config {
         block_stream_buffer_size: 1,
         header_batch_size: 1,
+        max_batch_retries: 0,
+        request_full_blocks: false,
+        headers_only: false,
     };
 
     // when
@@ -1543,6 +2023,9 @@ impl PeerReportTestBuilder {
         let params = Config {
             block_stream_buffer_size: 10,
             header_batch_size: 10,
+            max_batch_retries: 0,
+            request_full_blocks: false,
+            headers_only: false,
         };
 
         let mut import = Import {
@@ -1570,17 +2053,18 @@ impl PeerReportTestBuilder {
 
         let peer_id = self.shared_peer_id.clone();
         if let Some(get_headers) = self.get_sealed_headers.clone() {
-            p2p.expect_get_sealed_block_headers().returning(move |_| {
-                let peer: PeerId = peer_id.clone().into();
-                let get_headers = get_headers.clone();
-                Box::pin(async move {
-                    let headers = peer.bind(get_headers);
-                    Ok(headers)
-                })
-            });
+            p2p.expect_get_sealed_block_headers()
+                .returning(move |_, _| {
+                    let peer: PeerId = peer_id.clone().into();
+                    let get_headers = get_headers.clone();
+                    Box::pin(async move {
+                        let headers = peer.bind(get_headers);
+                        Ok(headers)
+                    })
+                });
         } else {
             p2p.expect_get_sealed_block_headers()
-                .returning(move |range| {
+                .returning(move |range, _| {
                     let peer: PeerId = peer_id.clone().into();
                     Box::pin(async move {
                         let headers = Some(range.map(empty_header).collect());
@@ -1721,7 +2205,7 @@ impl DefaultMocks for MockPeerToPeerPort {
 
         p2p.expect_get_sealed_block_headers()
             .times(1)
-            .returning(|range| {
+            .returning(|range, _| {
                 Box::pin(async move {
                     let peer = random_peer();
                     let headers = Some(range.map(empty_header).collect());
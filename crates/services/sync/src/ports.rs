@@ -9,6 +9,9 @@ use fuel_core_types::{
     },
     fuel_types::BlockHeight,
     services::p2p::{
+        GossipsubMessageAcceptance,
+        GossipsubMessageInfo,
+        NewBlockGossipData,
         PeerId,
         SourcePeer,
         Transactions,
@@ -41,16 +44,38 @@ pub trait PeerToPeerPort {
     /// Stream of newly observed block heights.
     fn height_stream(&self) -> BoxStream<BlockHeight>;
 
+    /// Stream of the headers of new blocks announced by the block producers.
+    fn new_block_stream(&self) -> BoxStream<NewBlockGossipData>;
+
+    /// Report the validity of a new block announcement received from the network.
+    fn notify_gossip_block_validity(
+        &self,
+        message_info: GossipsubMessageInfo,
+        validity: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()>;
+
     /// Request a range of sealed block headers from the network.
+    /// The request is never sent to the `excluded_peers`.
     async fn get_sealed_block_headers(
         &self,
         block_height_range: Range<u32>,
+        excluded_peers: Vec<PeerId>,
     ) -> anyhow::Result<SourcePeer<Option<Vec<SealedBlockHeader>>>>;
 
-    /// Request transactions from the network for the given block range
+    /// Request a range of full sealed blocks from the network.
+    /// The request is never sent to the `excluded_peers`.
+    async fn get_sealed_blocks(
+        &self,
+        block_height_range: Range<u32>,
+        excluded_peers: Vec<PeerId>,
+    ) -> anyhow::Result<SourcePeer<Option<Vec<SealedBlock>>>>;
+
+    /// Request transactions from the network for the given block range.
+    /// The request is never sent to the `excluded_peers`.
     async fn get_transactions(
         &self,
         block_ids: Range<u32>,
+        excluded_peers: Vec<PeerId>,
     ) -> anyhow::Result<SourcePeer<Option<Vec<Transactions>>>>;
 
     /// Request transactions from the network for the given block range
@@ -88,4 +113,11 @@ pub trait BlockImporterPort {
         &self,
         block: SealedBlock,
     ) -> impl core::future::Future<Output = anyhow::Result<()>> + Send;
+
+    /// Commit the sealed block header without the transactions
+    /// and without the execution of the block.
+    fn commit_header(
+        &self,
+        header: SealedBlockHeader,
+    ) -> impl core::future::Future<Output = anyhow::Result<()>> + Send;
 }
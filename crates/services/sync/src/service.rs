@@ -24,12 +24,21 @@ use fuel_core_services::{
     SharedMutex,
     StateWatcher,
     TaskNextAction,
+    TraceErr,
     stream::{
         BoxStream,
         IntoBoxStream,
     },
 };
-use fuel_core_types::fuel_types::BlockHeight;
+use fuel_core_types::{
+    fuel_types::BlockHeight,
+    services::p2p::{
+        GossipData,
+        GossipsubMessageAcceptance,
+        GossipsubMessageInfo,
+        NewBlockGossipData,
+    },
+};
 use futures::StreamExt;
 use tokio::sync::Notify;
 
@@ -50,10 +59,12 @@ where
     C: ports::ConsensusPort + Send + Sync + 'static,
 {
     let height_stream = p2p.height_stream();
+    let new_block_stream = p2p.new_block_stream();
     let committed_height_stream = executor.committed_height_stream();
     let state = State::new(Some(current_fuel_block_height.into()), None);
     Ok(ServiceRunner::new(SyncTask::new(
         height_stream,
+        new_block_stream,
         committed_height_stream,
         state,
         params,
@@ -83,8 +94,10 @@ where
     E: BlockImporterPort + Send + Sync + 'static,
     C: ConsensusPort + Send + Sync + 'static,
 {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         height_stream: BoxStream<BlockHeight>,
+        new_block_stream: BoxStream<NewBlockGossipData>,
         committed_height_stream: BoxStream<BlockHeight>,
         state: State,
         params: Config,
@@ -97,6 +110,11 @@ where
         let p2p = Arc::new(p2p);
         let executor = Arc::new(executor);
         let consensus = Arc::new(consensus);
+        let height_stream = futures::stream::select(
+            height_stream,
+            announced_heights(new_block_stream, p2p.clone(), consensus.clone()),
+        )
+        .into_boxed();
         let sync_heights = SyncHeights::new(
             height_stream,
             committed_height_stream,
@@ -112,6 +130,48 @@ where
     }
 }
 
+/// Validates the consensus of the announced block headers, reports the validity of
+/// the announcements back to the network and yields the heights of the valid ones.
+fn announced_heights<P, C>(
+    new_block_stream: BoxStream<NewBlockGossipData>,
+    p2p: Arc<P>,
+    consensus: Arc<C>,
+) -> BoxStream<BlockHeight>
+where
+    P: PeerToPeerPort + Send + Sync + 'static,
+    C: ConsensusPort + Send + Sync + 'static,
+{
+    new_block_stream
+        .filter_map(move |announcement| {
+            let GossipData {
+                data,
+                peer_id,
+                message_id,
+            } = announcement;
+            let height = data.and_then(|header| {
+                let valid = consensus
+                    .check_sealed_header(&header)
+                    .trace_err("Failed to check consensus on announced header")
+                    .unwrap_or(false);
+                valid.then(|| *header.entity.height())
+            });
+            let acceptance = if height.is_some() {
+                GossipsubMessageAcceptance::Accept
+            } else {
+                GossipsubMessageAcceptance::Reject
+            };
+            let message_info = GossipsubMessageInfo {
+                message_id,
+                peer_id,
+            };
+            let _ = p2p
+                .notify_gossip_block_validity(message_info, acceptance)
+                .trace_err("Failed to notify the validity of the announced block");
+            futures::future::ready(height)
+        })
+        .into_boxed()
+}
+
 impl<P, E, C> RunnableTask for SyncTask<P, E, C>
 where
     P: PeerToPeerPort + Send + Sync + 'static,
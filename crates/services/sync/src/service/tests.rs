@@ -2,7 +2,10 @@ use fuel_core_services::{
     Service,
     stream::IntoBoxStream,
 };
-use fuel_core_types::services::p2p::Transactions;
+use fuel_core_types::services::p2p::{
+    GossipData,
+    Transactions,
+};
 use futures::{
     StreamExt,
     stream,
@@ -38,7 +41,9 @@ async fn test_new_service() {
         })
         .into_boxed()
     });
-    p2p.expect_get_sealed_block_headers().returning(|range| {
+    p2p.expect_new_block_stream()
+        .returning(|| futures::stream::pending().into_boxed());
+    p2p.expect_get_sealed_block_headers().returning(|range, _| {
         Box::pin(async move {
             let peer = random_peer();
             let headers = Some(range.map(empty_header).collect::<Vec<_>>());
@@ -73,6 +78,9 @@ async fn test_new_service() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
+        request_full_blocks: false,
+        headers_only: false,
     };
     let s = new_service(4u32.into(), p2p, importer, consensus, params).unwrap();
 
@@ -94,3 +102,90 @@ async fn test_new_service() {
         fuel_core_services::State::Stopped
     );
 }
+
+#[allow(non_snake_case)]
+#[tokio::test]
+async fn new_service__imports_blocks_up_to_the_announced_height() {
+    // Given
+    let announced_height = 10u32;
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_report_peer().returning(|_, _| Ok(()));
+    p2p.expect_height_stream()
+        .returning(|| futures::stream::pending().into_boxed());
+    p2p.expect_new_block_stream().returning(move || {
+        let invalid = GossipData::new(empty_header(15u32), vec![1], vec![1]);
+        let valid = GossipData::new(empty_header(announced_height), vec![2], vec![2]);
+        stream::iter([invalid, valid])
+            .chain(stream::pending())
+            .into_boxed()
+    });
+    let (validity_sender, mut validity_receiver) = tokio::sync::mpsc::channel(10);
+    p2p.expect_notify_gossip_block_validity()
+        .returning(move |info, acceptance| {
+            validity_sender
+                .try_send((info.message_id, acceptance))
+                .unwrap();
+            Ok(())
+        });
+    p2p.expect_get_sealed_block_headers().returning(|range, _| {
+        Box::pin(async move {
+            let peer = random_peer();
+            let headers = Some(range.map(empty_header).collect::<Vec<_>>());
+            Ok(peer.bind(headers))
+        })
+    });
+    p2p.expect_get_transactions_from_peer()
+        .returning(|block_ids| {
+            Box::pin(async move {
+                let data = block_ids.data;
+                let v = data.into_iter().map(|_| Transactions::default()).collect();
+                Ok(Some(v))
+            })
+        });
+    let mut importer = MockBlockImporterPort::default();
+    importer
+        .expect_committed_height_stream()
+        .returning(|| futures::stream::pending::<BlockHeight>().into_boxed());
+    let (tx, mut rx) = tokio::sync::mpsc::channel(100);
+    importer.expect_execute_and_commit().returning(move |h| {
+        tx.try_send(**h.entity.header().height()).unwrap();
+        Box::pin(async { Ok(()) })
+    });
+    let mut consensus = MockConsensusPort::default();
+    consensus
+        .expect_check_sealed_header()
+        .returning(move |header| Ok(**header.entity.height() <= announced_height));
+    consensus
+        .expect_await_da_height()
+        .returning(|_| Box::pin(async { Ok(()) }));
+    let params = Config {
+        block_stream_buffer_size: 10,
+        header_batch_size: 10,
+        max_batch_retries: 0,
+        request_full_blocks: false,
+        headers_only: false,
+    };
+    let s = new_service(4u32.into(), p2p, importer, consensus, params).unwrap();
+
+    // When
+    s.start_and_await().await.unwrap();
+    let mut last_value = 0;
+    while let Some(h) = rx.recv().await {
+        last_value = h;
+        if h == announced_height {
+            break
+        }
+    }
+
+    // Then
+    assert_eq!(last_value, announced_height);
+    assert_eq!(
+        validity_receiver.recv().await,
+        Some((vec![1], GossipsubMessageAcceptance::Reject))
+    );
+    assert_eq!(
+        validity_receiver.recv().await,
+        Some((vec![2], GossipsubMessageAcceptance::Accept))
+    );
+    s.stop_and_await().await.unwrap();
+}
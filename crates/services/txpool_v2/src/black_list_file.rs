@@ -0,0 +1,225 @@
+//! The file that keeps the updates of the blacklist done at runtime.
+//!
+//! The file stores only the runtime changes, not the blacklist from the config,
+//! so the changes of the config take effect after the restart.
+//! Each line of the file is one entry: the kind of the entry (`owner`, `coin`,
+//! `message` or `contract`) followed by a space and the hex encoded value.
+//! The entries removed at runtime are prefixed with `remove `.
+
+use std::{
+    fmt::LowerHex,
+    fs::File,
+    io::{
+        self,
+        BufWriter,
+        Write,
+    },
+    path::Path,
+    str::FromStr,
+};
+
+use anyhow::Context;
+
+use crate::config::BlackList;
+
+const OWNER: &str = "owner";
+const COIN: &str = "coin";
+const MESSAGE: &str = "message";
+const CONTRACT: &str = "contract";
+const REMOVE: &str = "remove ";
+
+/// The changes of the blacklist done at runtime.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub(crate) struct BlackListChanges {
+    /// The entries added at runtime.
+    pub added: BlackList,
+    /// The entries removed at runtime, including the entries from the config.
+    pub removed: BlackList,
+}
+
+impl BlackListChanges {
+    /// Records the removal of the `remove` entries followed by the addition
+    /// of the `add` entries.
+    pub fn update(&mut self, add: &BlackList, remove: &BlackList) {
+        self.added.remove(remove);
+        self.removed.extend(remove.clone());
+        self.removed.remove(add);
+        self.added.extend(add.clone());
+    }
+
+    /// Applies the changes to the `black_list` from the config.
+    pub fn apply(&self, black_list: &mut BlackList) {
+        black_list.remove(&self.removed);
+        black_list.extend(self.added.clone());
+    }
+}
+
+/// Reads the blacklist changes from the file at `path`.
+/// Returns no changes if the file doesn't exist.
+pub(crate) fn read(path: &Path) -> anyhow::Result<BlackListChanges> {
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            return Ok(BlackListChanges::default())
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut changes = BlackListChanges::default();
+    for (index, line) in content.lines().enumerate() {
+        let number = index.saturating_add(1);
+        let line = line.trim();
+        if line.is_empty() {
+            continue
+        }
+        let (black_list, line) = match line.strip_prefix(REMOVE) {
+            Some(line) => (&mut changes.removed, line),
+            None => (&mut changes.added, line),
+        };
+        let (kind, value) = line
+            .split_once(' ')
+            .with_context(|| format!("Invalid blacklist entry at line {number}"))?;
+
+        match kind {
+            OWNER => {
+                black_list.owners.insert(parse(value, number)?);
+            }
+            COIN => {
+                black_list.coins.insert(parse(value, number)?);
+            }
+            MESSAGE => {
+                black_list.messages.insert(parse(value, number)?);
+            }
+            CONTRACT => {
+                black_list.contracts.insert(parse(value, number)?);
+            }
+            _ => anyhow::bail!("Unknown blacklist entry `{kind}` at line {number}"),
+        }
+    }
+
+    Ok(changes)
+}
+
+/// Replaces the content of the file at `path` with the `changes`.
+pub(crate) fn write(path: &Path, changes: &BlackListChanges) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let tmp_path = path.with_extension("tmp");
+    {
+        let mut writer = BufWriter::new(File::create(&tmp_path)?);
+        for (prefix, black_list) in [("", &changes.added), (REMOVE, &changes.removed)] {
+            write_entries(&mut writer, prefix, OWNER, &black_list.owners)?;
+            write_entries(&mut writer, prefix, COIN, &black_list.coins)?;
+            write_entries(&mut writer, prefix, MESSAGE, &black_list.messages)?;
+            write_entries(&mut writer, prefix, CONTRACT, &black_list.contracts)?;
+        }
+        writer.flush()?;
+        writer.get_ref().sync_all()?;
+    }
+    std::fs::rename(&tmp_path, path)
+}
+
+fn parse<T>(value: &str, number: usize) -> anyhow::Result<T>
+where
+    T: FromStr,
+    T::Err: core::fmt::Display,
+{
+    T::from_str(value.trim()).map_err(|err| {
+        anyhow::anyhow!("Invalid blacklist entry `{value}` at line {number}: {err}")
+    })
+}
+
+fn write_entries<'a, T>(
+    writer: &mut impl Write,
+    prefix: &str,
+    kind: &str,
+    entries: impl IntoIterator<Item = &'a T>,
+) -> io::Result<()>
+where
+    T: LowerHex + Ord + 'a,
+{
+    let mut entries = entries.into_iter().collect::<Vec<_>>();
+    // Sorted entries make the file stable between updates.
+    entries.sort();
+    for entry in entries {
+        writeln!(writer, "{prefix}{kind} {entry:#x}")?;
+    }
+    Ok(())
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::fuel_tx::UtxoId;
+
+    #[test]
+    fn read__returns_written_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("blacklist");
+
+        // Given
+        let changes = BlackListChanges {
+            added: BlackList::new(
+                vec![[1; 32].into(), [2; 32].into()],
+                vec![UtxoId::new([3; 32].into(), 4)],
+                vec![[5; 32].into()],
+                vec![[6; 32].into()],
+            ),
+            removed: BlackList::new(vec![[7; 32].into()], vec![], vec![], vec![]),
+        };
+        write(&path, &changes).unwrap();
+
+        // When
+        let result = read(&path).unwrap();
+
+        // Then
+        assert_eq!(result, changes);
+    }
+
+    #[test]
+    fn apply__keeps_the_latest_change_of_the_entry() {
+        // Given
+        let entry = BlackList::new(vec![[1; 32].into()], vec![], vec![], vec![]);
+        let mut changes = BlackListChanges::default();
+        changes.update(&BlackList::default(), &entry);
+        changes.update(&entry, &BlackList::default());
+
+        // When
+        let mut black_list = BlackList::default();
+        changes.apply(&mut black_list);
+
+        // Then
+        assert_eq!(black_list, entry);
+        assert!(changes.removed.is_empty());
+    }
+
+    #[test]
+    fn read__returns_empty_black_list_when_file_does_not_exist() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("blacklist");
+
+        // When
+        let result = read(&path).unwrap();
+
+        // Then
+        assert_eq!(result, BlackListChanges::default());
+    }
+
+    #[test]
+    fn read__fails_on_unknown_entry() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("blacklist");
+
+        // Given
+        std::fs::write(&path, "peer 0x01\n").unwrap();
+
+        // When
+        let result = read(&path);
+
+        // Then
+        assert!(result.is_err());
+    }
+}
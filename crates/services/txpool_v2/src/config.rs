@@ -1,5 +1,8 @@
 use std::{
     collections::HashSet,
+    fmt,
+    path::PathBuf,
+    sync::Arc,
     time::Duration,
 };
 
@@ -26,7 +29,10 @@ use fuel_core_types::{
     services::txpool::PoolTransaction,
 };
 
-use crate::error::BlacklistedError;
+use crate::{
+    error::BlacklistedError,
+    selection_algorithms::SelectionAlgorithmFactory,
+};
 
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
 pub struct BlackList {
@@ -56,6 +62,32 @@ impl BlackList {
         }
     }
 
+    /// Returns `true` if the blacklist doesn't have any entries.
+    pub fn is_empty(&self) -> bool {
+        self.owners.is_empty()
+            && self.coins.is_empty()
+            && self.messages.is_empty()
+            && self.contracts.is_empty()
+    }
+
+    /// Adds all entries of the `other` blacklist.
+    pub fn extend(&mut self, other: BlackList) {
+        self.owners.extend(other.owners);
+        self.coins.extend(other.coins);
+        self.messages.extend(other.messages);
+        self.contracts.extend(other.contracts);
+    }
+
+    /// Removes all entries of the `other` blacklist.
+    pub fn remove(&mut self, other: &BlackList) {
+        self.owners.retain(|owner| !other.owners.contains(owner));
+        self.coins.retain(|coin| !other.coins.contains(coin));
+        self.messages
+            .retain(|message| !other.messages.contains(message));
+        self.contracts
+            .retain(|contract| !other.contracts.contains(contract));
+    }
+
     /// Check if the transaction has blacklisted inputs.
     pub fn check_blacklisting(
         &self,
@@ -138,14 +170,87 @@ pub struct Config {
     pub heavy_work: HeavyWorkConfig,
     /// Blacklist. Transactions with blacklisted inputs will not be accepted.
     pub black_list: BlackList,
+    /// The path to the file that keeps the blacklist updated at runtime.
+    /// If set, the runtime additions and removals are stored in the file after
+    /// each update and applied on top of the `black_list` when the service starts.
+    pub black_list_path: Option<PathBuf>,
     /// TTL for transactions inside the pending pool.
     pub pending_pool_tx_ttl: Duration,
     /// Maximum percentage of the pool size to be used for the pending pool.
     pub max_pending_pool_size_percentage: u16,
+    /// The algorithm used to select transactions for the block.
+    pub selection_algorithm: TxSelectionAlgorithm,
+    /// Rules for replacing transactions in the pool by colliding ones.
+    pub rbf_policy: RbfPolicy,
+    /// The path to the journal of accepted transactions. If set, the transactions
+    /// from the journal are verified and inserted again when the service starts.
+    pub journal_path: Option<PathBuf>,
     /// Enable metrics when set to true
     pub metrics: bool,
 }
 
+/// The algorithm used to select transactions for the block.
+#[derive(Clone, Default)]
+pub enum TxSelectionAlgorithm {
+    /// Selects transactions with the highest tip/gas ratio first.
+    #[default]
+    RatioTipGas,
+    /// Selects transactions in the order of their arrival to the pool.
+    Fifo,
+    /// Selects transactions in the order of their arrival to the pool,
+    /// but limits the gas that transactions of the same owner can take in one block.
+    FairByOwner {
+        /// Maximum gas that transactions of the same owner can take in one block.
+        max_gas_per_owner: u64,
+    },
+    /// Selects transactions using the algorithm created by the factory.
+    Custom(Arc<dyn SelectionAlgorithmFactory>),
+}
+
+impl fmt::Debug for TxSelectionAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RatioTipGas => f.write_str("RatioTipGas"),
+            Self::Fifo => f.write_str("Fifo"),
+            Self::FairByOwner { max_gas_per_owner } => f
+                .debug_struct("FairByOwner")
+                .field("max_gas_per_owner", max_gas_per_owner)
+                .finish(),
+            Self::Custom(_) => f.write_str("Custom"),
+        }
+    }
+}
+
+/// Replace-by-fee policy.
+///
+/// A new transaction that collides with transactions from the pool (spends the same
+/// coin or message, creates the same contract or blob) replaces them if it is more
+/// profitable than the collided transactions with all their dependents, and satisfies
+/// the rules below. The collided transactions and all their dependents are removed
+/// from the pool and reported as squeezed out.
+#[derive(Clone, Debug)]
+pub struct RbfPolicy {
+    /// Minimum increase of the tip, in percent of the tip of the collided transaction,
+    /// required to replace it. Zero disables the rule.
+    pub min_tip_bump_percentage: u16,
+    /// Maximum number of transactions, including dependents, that can be removed
+    /// from the pool by one replacement.
+    pub max_replaced_subtree_size: usize,
+    /// Require the tip of the new transaction to be higher than the total tip
+    /// of all transactions it replaces.
+    pub require_total_fee_increase: bool,
+}
+
+impl Default for RbfPolicy {
+    fn default() -> Self {
+        Self {
+            min_tip_bump_percentage: 0,
+            max_replaced_subtree_size: 100,
+            require_total_fee_increase: false,
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct PoolLimits {
     /// Maximum number of transactions in the pool.
@@ -185,6 +290,7 @@ impl Default for Config {
             ttl_check_interval: Duration::from_secs(60),
             max_txs_ttl: Duration::from_secs(60 * 10),
             black_list: BlackList::default(),
+            black_list_path: None,
             pool_limits: PoolLimits {
                 max_txs: 10000,
                 max_gas: 100_000_000_000,
@@ -204,6 +310,9 @@ impl Default for Config {
             },
             pending_pool_tx_ttl: Duration::from_secs(3),
             max_pending_pool_size_percentage: 50,
+            selection_algorithm: TxSelectionAlgorithm::default(),
+            rbf_policy: RbfPolicy::default(),
+            journal_path: None,
             metrics: false,
         }
     }
@@ -63,6 +63,8 @@ pub enum Error {
     MessageInputWasAlreadySpent(Nonce),
     #[display(fmt = "The UTXO input {_0:#x} was already spent")]
     UtxoInputWasAlreadySpent(UtxoId),
+    #[display(fmt = "Failed to store the blacklist: {_0}")]
+    BlacklistStorage(String),
 }
 
 impl Error {
@@ -80,10 +82,14 @@ pub enum RemovedReason {
         fmt = "Transaction was removed because it was less worth than a new one (id: {_0}) that has been inserted"
     )]
     LessWorth(TxId),
+    #[display(fmt = "Transaction was replaced by {_0}")]
+    Replaced(TxId),
     #[display(
         fmt = "Transaction expired because it exceeded the configured time to live `tx-pool-ttl`."
     )]
     Ttl,
+    #[display(fmt = "Transaction was removed because of the blacklist update: {_0}")]
+    Blacklisted(BlacklistedError),
 }
 
 #[derive(Clone, Debug, derive_more::Display)]
@@ -200,6 +206,31 @@ pub enum CollisionReason {
         fmt = "This transaction have dependencies and is colliding with multiple transactions"
     )]
     MultipleCollisions,
+    #[display(
+        fmt = "The tip should be at least {required_tip} to replace the transaction (id: {tx_id})"
+    )]
+    NotEnoughTipBump {
+        /// The id of the collided transaction.
+        tx_id: TxId,
+        /// The minimal tip required by the RBF policy.
+        required_tip: Word,
+    },
+    #[display(
+        fmt = "The transaction would replace {replaced} transactions, while the limit is {limit}"
+    )]
+    TooManyReplacedTransactions {
+        /// The number of transactions that would be replaced.
+        replaced: usize,
+        /// The limit from the RBF policy.
+        limit: usize,
+    },
+    #[display(
+        fmt = "The tip should be higher than {replaced_tip}, the total tip of the replaced transactions"
+    )]
+    TotalFeeNotIncreased {
+        /// The total tip of the replaced transactions.
+        replaced_tip: Word,
+    },
 }
 
 impl From<CheckError> for Error {
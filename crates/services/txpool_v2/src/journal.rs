@@ -0,0 +1,454 @@
+//! The append-only on-disk journal of transactions accepted by the `TxPool`.
+//!
+//! Each record is the time when the transaction was accepted for the first time
+//! (seconds since UNIX epoch, little endian `u64`), followed by the length of the
+//! canonically encoded transaction (little endian `u32`) and the transaction itself.
+//!
+//! The journal only grows while the node is running. When it contains too many
+//! records compared to the number of transactions in the pool, it is rewritten
+//! with the content of the pool. A partially written record at the end of the file
+//! (for example, after a crash) is ignored.
+
+use std::{
+    collections::HashMap,
+    fs::{
+        File,
+        OpenOptions,
+    },
+    io::{
+        self,
+        BufReader,
+        BufWriter,
+        Read,
+        Write,
+    },
+    path::{
+        Path,
+        PathBuf,
+    },
+    time::{
+        Duration,
+        SystemTime,
+    },
+};
+
+use fuel_core_types::{
+    fuel_tx::{
+        Transaction,
+        TxId,
+        UniqueIdentifier,
+    },
+    fuel_types::{
+        ChainId,
+        canonical::{
+            Deserialize,
+            Serialize,
+        },
+    },
+};
+
+/// The journal is not compacted until it has at least this number of records.
+const MIN_RECORDS_TO_COMPACT: usize = 1024;
+
+/// The transaction restored from the journal.
+#[derive(Debug, Clone)]
+pub(crate) struct JournalEntry {
+    /// The time when the transaction was accepted by the pool for the first time.
+    pub time: SystemTime,
+    pub tx: Transaction,
+}
+
+pub(crate) struct Journal {
+    path: PathBuf,
+    chain_id: ChainId,
+    file: File,
+    records: usize,
+    /// The time of the first acceptance of the transactions in the journal.
+    /// Re-accepted and compacted transactions keep it, so they can't outlive
+    /// the TTL by restarts or compactions.
+    times: HashMap<TxId, SystemTime>,
+}
+
+impl Journal {
+    /// Opens the journal at `path`, creating it if it doesn't exist,
+    /// and returns the transactions stored in it. The transactions are limited
+    /// by the `max_tx_size` in bytes.
+    pub fn open(
+        path: &Path,
+        chain_id: ChainId,
+        max_tx_size: u64,
+    ) -> anyhow::Result<(Self, Vec<JournalEntry>)> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let entries = match File::open(path) {
+            Ok(file) => read_entries(file, max_tx_size)?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => vec![],
+            Err(err) => return Err(err.into()),
+        };
+        let records = entries.len();
+
+        let mut times = HashMap::with_capacity(entries.len());
+        let mut unique_entries = Vec::with_capacity(entries.len());
+        for entry in entries {
+            let tx_id = entry.tx.id(&chain_id);
+            if times.insert(tx_id, entry.time).is_none() {
+                unique_entries.push(entry);
+            }
+        }
+
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+
+        let journal = Self {
+            path: path.to_path_buf(),
+            chain_id,
+            file,
+            records,
+            times,
+        };
+
+        Ok((journal, unique_entries))
+    }
+
+    /// Appends the accepted transaction to the journal.
+    pub fn append(&mut self, tx_id: &TxId, tx: &Transaction) -> io::Result<()> {
+        let time = *self.times.entry(*tx_id).or_insert_with(SystemTime::now);
+        self.file.write_all(&encode_record(time, tx)?)?;
+        self.records = self.records.saturating_add(1);
+        Ok(())
+    }
+
+    /// Returns `true` if the journal has too many records
+    /// compared to the number of `alive_txs`.
+    pub fn should_compact(&self, alive_txs: usize) -> bool {
+        self.records >= MIN_RECORDS_TO_COMPACT
+            && self.records > alive_txs.saturating_mul(2)
+    }
+
+    /// Rewrites the journal to contain only the `alive_txs`.
+    /// The transactions keep the time of their first acceptance.
+    pub fn compact<'a>(
+        &mut self,
+        alive_txs: impl Iterator<Item = &'a Transaction>,
+    ) -> io::Result<()> {
+        let tmp_path = self.path.with_extension("tmp");
+        let mut times = HashMap::new();
+        let mut records = 0usize;
+        {
+            let mut writer = BufWriter::new(File::create(&tmp_path)?);
+            for tx in alive_txs {
+                let tx_id = tx.id(&self.chain_id);
+                let time = self
+                    .times
+                    .get(&tx_id)
+                    .copied()
+                    .unwrap_or_else(SystemTime::now);
+                times.insert(tx_id, time);
+                writer.write_all(&encode_record(time, tx)?)?;
+                records = records.saturating_add(1);
+            }
+            writer.flush()?;
+            writer.get_ref().sync_all()?;
+        }
+        std::fs::rename(&tmp_path, &self.path)?;
+
+        self.file = OpenOptions::new().append(true).open(&self.path)?;
+        self.records = records;
+        self.times = times;
+        Ok(())
+    }
+}
+
+/// Removes the entries that are older than the `ttl`.
+pub(crate) fn remove_expired_entries(
+    entries: Vec<JournalEntry>,
+    ttl: Duration,
+) -> Vec<JournalEntry> {
+    let now = SystemTime::now();
+    entries
+        .into_iter()
+        .filter(|entry| match now.duration_since(entry.time) {
+            Ok(age) => age < ttl,
+            // The entry is from the future, keep it.
+            Err(_) => true,
+        })
+        .collect()
+}
+
+fn encode_record(time: SystemTime, tx: &Transaction) -> io::Result<Vec<u8>> {
+    let secs = time
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default();
+    let tx_bytes = tx.to_bytes();
+    let len = u32::try_from(tx_bytes.len()).map_err(|_| {
+        io::Error::new(io::ErrorKind::InvalidInput, "Too big transaction")
+    })?;
+
+    let mut record = Vec::with_capacity(tx_bytes.len().saturating_add(12));
+    record.extend_from_slice(&secs.to_le_bytes());
+    record.extend_from_slice(&len.to_le_bytes());
+    record.extend_from_slice(&tx_bytes);
+    Ok(record)
+}
+
+fn read_entries(file: File, max_tx_size: u64) -> io::Result<Vec<JournalEntry>> {
+    let mut reader = BufReader::new(file);
+    let mut entries = vec![];
+
+    loop {
+        let mut header = [0u8; 12];
+        match read_exact_or_eof(&mut reader, &mut header)? {
+            ReadResult::Complete => {}
+            ReadResult::Eof => break,
+            ReadResult::Partial => {
+                tracing::warn!("Ignoring the partially written record in the journal");
+                break
+            }
+        }
+
+        let (secs, len) = header.split_at(8);
+        let secs = u64::from_le_bytes(secs.try_into().expect("8 bytes; qed"));
+        let len = u32::from_le_bytes(len.try_into().expect("4 bytes; qed"));
+        if u64::from(len) > max_tx_size {
+            tracing::warn!(
+                "Ignoring the rest of the journal after the record of {len} bytes, \
+                it exceeds the maximum transaction size of {max_tx_size} bytes"
+            );
+            break
+        }
+
+        let mut tx_bytes = vec![0u8; len as usize];
+        if !matches!(
+            read_exact_or_eof(&mut reader, &mut tx_bytes)?,
+            ReadResult::Complete
+        ) {
+            tracing::warn!("Ignoring the partially written record in the journal");
+            break
+        }
+
+        match Transaction::from_bytes(&tx_bytes) {
+            Ok(tx) => entries.push(JournalEntry {
+                time: SystemTime::UNIX_EPOCH
+                    .checked_add(Duration::from_secs(secs))
+                    .unwrap_or(SystemTime::UNIX_EPOCH),
+                tx,
+            }),
+            Err(err) => {
+                tracing::warn!(
+                    "Skipping undecodable transaction in the journal: {err:?}"
+                );
+            }
+        }
+    }
+
+    Ok(entries)
+}
+
+enum ReadResult {
+    Complete,
+    Partial,
+    Eof,
+}
+
+fn read_exact_or_eof(reader: &mut impl Read, buf: &mut [u8]) -> io::Result<ReadResult> {
+    let mut read = 0;
+    while read < buf.len() {
+        match reader.read(&mut buf[read..]) {
+            Ok(0) if read == 0 => return Ok(ReadResult::Eof),
+            Ok(0) => return Ok(ReadResult::Partial),
+            Ok(n) => read = read.saturating_add(n),
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(ReadResult::Complete)
+}
+
+#[allow(non_snake_case)]
+#[allow(clippy::arithmetic_side_effects)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::fuel_tx::TransactionBuilder;
+
+    const MAX_TX_SIZE: u64 = 1024 * 1024;
+
+    fn script(tip: u64) -> Transaction {
+        TransactionBuilder::script(vec![], vec![])
+            .tip(tip)
+            .max_fee_limit(tip)
+            .finalize_as_transaction()
+    }
+
+    #[test]
+    fn open__returns_appended_transactions() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("journal");
+        let chain_id = ChainId::default();
+
+        // Given
+        let (mut journal, entries) = Journal::open(&path, chain_id, MAX_TX_SIZE).unwrap();
+        assert!(entries.is_empty());
+        let tx1 = script(1);
+        let tx2 = script(2);
+        journal.append(&tx1.id(&chain_id), &tx1).unwrap();
+        journal.append(&tx2.id(&chain_id), &tx2).unwrap();
+        drop(journal);
+
+        // When
+        let (_, entries) = Journal::open(&path, chain_id, MAX_TX_SIZE).unwrap();
+
+        // Then
+        let txs = entries
+            .into_iter()
+            .map(|entry| entry.tx)
+            .collect::<Vec<_>>();
+        assert_eq!(txs, vec![tx1, tx2]);
+    }
+
+    #[test]
+    fn open__ignores_partially_written_record() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("journal");
+        let chain_id = ChainId::default();
+
+        // Given
+        let (mut journal, _) = Journal::open(&path, chain_id, MAX_TX_SIZE).unwrap();
+        let tx = script(1);
+        journal.append(&tx.id(&chain_id), &tx).unwrap();
+        let record = encode_record(SystemTime::now(), &script(2)).unwrap();
+        journal.file.write_all(&record[..record.len() - 1]).unwrap();
+        drop(journal);
+
+        // When
+        let (_, entries) = Journal::open(&path, chain_id, MAX_TX_SIZE).unwrap();
+
+        // Then
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].tx, tx);
+    }
+
+    #[test]
+    fn open__stops_at_record_longer_than_max_tx_size() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("journal");
+        let chain_id = ChainId::default();
+
+        // Given
+        let (mut journal, _) = Journal::open(&path, chain_id, MAX_TX_SIZE).unwrap();
+        let tx = script(1);
+        journal.append(&tx.id(&chain_id), &tx).unwrap();
+        let mut header = 0u64.to_le_bytes().to_vec();
+        header.extend_from_slice(&u32::MAX.to_le_bytes());
+        journal.file.write_all(&header).unwrap();
+        journal
+            .append(&script(2).id(&chain_id), &script(2))
+            .unwrap();
+        drop(journal);
+
+        // When
+        let (_, entries) = Journal::open(&path, chain_id, MAX_TX_SIZE).unwrap();
+
+        // Then
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].tx, tx);
+    }
+
+    #[test]
+    fn append__keeps_time_of_restored_transaction() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("journal");
+        let chain_id = ChainId::default();
+        let tx = script(1);
+        let tx_id = tx.id(&chain_id);
+
+        // Given
+        let (mut journal, _) = Journal::open(&path, chain_id, MAX_TX_SIZE).unwrap();
+        journal.append(&tx_id, &tx).unwrap();
+        drop(journal);
+        let (mut journal, entries) = Journal::open(&path, chain_id, MAX_TX_SIZE).unwrap();
+        let first_time = entries[0].time;
+
+        // When
+        journal.append(&tx_id, &tx).unwrap();
+        journal.compact([&tx].into_iter()).unwrap();
+        drop(journal);
+
+        // Then
+        let (_, entries) = Journal::open(&path, chain_id, MAX_TX_SIZE).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].time, first_time);
+    }
+
+    #[test]
+    fn compact__removes_not_alive_transactions() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("journal");
+        let chain_id = ChainId::default();
+
+        // Given
+        let (mut journal, _) = Journal::open(&path, chain_id, MAX_TX_SIZE).unwrap();
+        let txs = (0..10).map(script).collect::<Vec<_>>();
+        for tx in &txs {
+            journal.append(&tx.id(&chain_id), tx).unwrap();
+        }
+
+        // When
+        journal.compact(txs.iter().take(2)).unwrap();
+        drop(journal);
+
+        // Then
+        let (_, entries) = Journal::open(&path, chain_id, MAX_TX_SIZE).unwrap();
+        let restored = entries
+            .into_iter()
+            .map(|entry| entry.tx)
+            .collect::<Vec<_>>();
+        assert_eq!(restored, txs[..2].to_vec());
+    }
+
+    #[test]
+    fn compact__keeps_time_of_appended_transaction() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("journal");
+        let chain_id = ChainId::default();
+        let tx = script(1);
+
+        // Given
+        let (mut journal, _) = Journal::open(&path, chain_id, MAX_TX_SIZE).unwrap();
+        journal.append(&tx.id(&chain_id), &tx).unwrap();
+        let appended_before = SystemTime::now();
+        std::thread::sleep(Duration::from_millis(10));
+
+        // When
+        journal.compact([&tx].into_iter()).unwrap();
+        drop(journal);
+
+        // Then
+        let (_, entries) = Journal::open(&path, chain_id, MAX_TX_SIZE).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert!(entries[0].time <= appended_before);
+    }
+
+    #[test]
+    fn remove_expired_entries__drops_entries_older_than_ttl() {
+        // Given
+        let ttl = Duration::from_secs(60);
+        let fresh = JournalEntry {
+            time: SystemTime::now(),
+            tx: script(1),
+        };
+        let expired = JournalEntry {
+            time: SystemTime::now() - Duration::from_secs(120),
+            tx: script(2),
+        };
+
+        // When
+        let entries = remove_expired_entries(vec![fresh.clone(), expired], ttl);
+
+        // Then
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].tx, fresh.tx);
+    }
+}
@@ -41,15 +41,17 @@
 #![deny(unused_crate_dependencies)]
 #![deny(warnings)]
 
+mod black_list_file;
 mod collision_manager;
 pub mod config;
 pub mod error;
 mod extracted_outputs;
+mod journal;
 mod pending_pool;
 mod pool;
 mod pool_worker;
 pub mod ports;
-mod selection_algorithms;
+pub mod selection_algorithms;
 mod service;
 mod shared_state;
 mod storage;
@@ -70,3 +72,8 @@ pub use service::{
     new_service,
 };
 pub use shared_state::SharedState;
+pub use storage::{
+    RemovedTransactions,
+    StorageData,
+    graph::GraphStorage,
+};
@@ -109,6 +109,12 @@ impl PendingPool {
         }
     }
 
+    pub fn iter_transactions(&self) -> impl Iterator<Item = &ArcPoolTx> {
+        self.pending_inputs_by_tx
+            .values()
+            .map(|pending| &pending.tx)
+    }
+
     pub fn insert_transaction(
         &mut self,
         transaction: ArcPoolTx,
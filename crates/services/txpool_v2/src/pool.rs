@@ -191,13 +191,14 @@ where
             removed_transactions.extend(removed);
         }
 
+        let mut replaced_transactions = vec![];
         for collided_tx in collisions.keys() {
             let removed = self
                 .storage
                 .remove_transaction_and_dependents_subtree(*collided_tx);
             self.update_components_and_caches_on_removal(removed.iter());
 
-            removed_transactions.extend(removed);
+            replaced_transactions.extend(removed);
         }
 
         let tx = checked_transaction.tx();
@@ -241,13 +242,22 @@ where
             self.new_executable_txs_notifier.send_replace(());
         }
 
-        let status = statuses::SqueezedOut {
+        let less_worth_status = statuses::SqueezedOut {
             reason: Error::Removed(RemovedReason::LessWorth(tx_id)).to_string(),
         };
+        let replaced_status = statuses::SqueezedOut {
+            reason: Error::Removed(RemovedReason::Replaced(tx_id)).to_string(),
+        };
 
         let removed_transactions = removed_transactions
             .into_iter()
-            .map(|data| {
+            .map(|data| (data, &less_worth_status))
+            .chain(
+                replaced_transactions
+                    .into_iter()
+                    .map(|data| (data, &replaced_status)),
+            )
+            .map(|(data, status)| {
                 let removed_tx_id = data.transaction.id();
 
                 (removed_tx_id, status.clone())
@@ -321,6 +331,7 @@ where
                 checked_transaction.tx(),
                 has_dependencies,
                 &self.storage,
+                &self.config.rbf_policy,
             )
             .map_err(Error::Collided)?;
 
@@ -618,11 +629,13 @@ where
     }
 
     /// Remove transaction and its dependents.
+    /// Returns the ids of all removed transactions.
     pub fn remove_transactions_and_dependents<I>(
         &mut self,
         tx_ids: I,
         tx_status: statuses::SqueezedOut,
-    ) where
+    ) -> Vec<TxId>
+    where
         I: IntoIterator<Item = TxId>,
     {
         let mut removed_transactions = vec![];
@@ -639,11 +652,16 @@ where
                 }));
             }
         }
+        let removed_tx_ids = removed_transactions
+            .iter()
+            .map(|(tx_id, _)| *tx_id)
+            .collect();
         if !removed_transactions.is_empty() {
             self.tx_status_manager
                 .squeezed_out_txs(removed_transactions);
         }
         self.update_stats();
+        removed_tx_ids
     }
 
     pub fn remove_skipped_transaction(&mut self, tx_id: TxId, reason: String) {
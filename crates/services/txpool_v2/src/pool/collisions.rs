@@ -1,5 +1,6 @@
 use crate::{
     collision_manager::Collisions,
+    config::RbfPolicy,
     error::CollisionReason,
     storage::Storage,
 };
@@ -15,6 +16,7 @@ pub trait CollisionsExt<S> {
         tx: &PoolTransaction,
         has_dependencies: bool,
         storage: &S,
+        rbf_policy: &RbfPolicy,
     ) -> Result<(), CollisionReason>;
 }
 
@@ -32,16 +34,23 @@ where
     /// - A transaction doesn't have dependencies:
     ///     - A new transaction can be accepted if its profitability is higher
     ///         than the collided subtrees'.
+    /// - In both cases, the replacement should satisfy the `RbfPolicy`:
+    ///     - The tip is bumped enough compared to each collided transaction.
+    ///     - The collided subtrees are not bigger than allowed.
+    ///     - The tip is higher than the total tip of the collided subtrees, if required.
     fn check_collision_requirements(
         &self,
         tx: &PoolTransaction,
         has_dependencies: bool,
         storage: &S,
+        rbf_policy: &RbfPolicy,
     ) -> Result<(), CollisionReason> {
         if has_dependencies && self.len() > 1 {
             return Err(CollisionReason::MultipleCollisions);
         }
 
+        let mut replaced_txs: usize = 0;
+        let mut replaced_tip: u64 = 0;
         for (collision, reason) in self.iter() {
             if !is_better_than_collision(tx, collision, storage)? {
                 tracing::info!(
@@ -59,6 +68,24 @@ where
                     return Err(CollisionReason::Unknown);
                 }
             }
+
+            let colliding_tx = storage.get(collision).ok_or(CollisionReason::Unknown)?;
+            check_tip_bump(tx, colliding_tx.transaction.as_ref(), rbf_policy)?;
+            replaced_txs =
+                replaced_txs.saturating_add(colliding_tx.number_dependents_in_chain);
+            replaced_tip =
+                replaced_tip.saturating_add(colliding_tx.dependents_cumulative_tip);
+        }
+
+        if replaced_txs > rbf_policy.max_replaced_subtree_size {
+            return Err(CollisionReason::TooManyReplacedTransactions {
+                replaced: replaced_txs,
+                limit: rbf_policy.max_replaced_subtree_size,
+            });
+        }
+
+        if rbf_policy.require_total_fee_increase && tx.tip() <= replaced_tip {
+            return Err(CollisionReason::TotalFeeNotIncreased { replaced_tip });
         }
 
         Ok(())
@@ -81,3 +108,29 @@ where
     );
     Ok(new_tx_ratio > colliding_tx_ratio)
 }
+
+fn check_tip_bump(
+    tx: &PoolTransaction,
+    colliding_tx: &PoolTransaction,
+    rbf_policy: &RbfPolicy,
+) -> Result<(), CollisionReason> {
+    if rbf_policy.min_tip_bump_percentage == 0 {
+        return Ok(());
+    }
+
+    let colliding_tip = colliding_tx.tip();
+    let bump = u128::from(colliding_tip)
+        .saturating_mul(u128::from(rbf_policy.min_tip_bump_percentage))
+        .div_ceil(100);
+    let required_tip =
+        u64::try_from(u128::from(colliding_tip).saturating_add(bump)).unwrap_or(u64::MAX);
+
+    if tx.tip() < required_tip {
+        return Err(CollisionReason::NotEnoughTipBump {
+            tx_id: colliding_tx.id(),
+            required_tip,
+        });
+    }
+
+    Ok(())
+}
@@ -42,11 +42,16 @@ use tokio::{
 
 use crate::{
     Constraints,
-    config::ServiceChannelLimits,
+    config::{
+        BlackList,
+        ServiceChannelLimits,
+    },
     error::{
         Error,
         InsertionErrorType,
+        RemovedReason,
     },
+    journal::Journal,
     pending_pool::PendingPool,
     ports::{
         TxPoolPersistentStorage,
@@ -94,6 +99,7 @@ impl PoolWorkerInterface {
         tx_pool: TxPool<TxStatusManager>,
         view_provider: Arc<dyn AtomicView<LatestView = View>>,
         limits: &ServiceChannelLimits,
+        journal: Option<Journal>,
     ) -> Self
     where
         View: TxPoolPersistentStorage,
@@ -137,6 +143,7 @@ impl PoolWorkerInterface {
                     pending_pool: PendingPool::new(tx_pool.config.pending_pool_tx_ttl),
                     pool: tx_pool,
                     view_provider,
+                    journal,
                 };
 
                 tokio_runtime.block_on(async {
@@ -216,8 +223,16 @@ pub(super) enum PoolExtractBlockTransactions {
 }
 
 pub(super) enum PoolUpdateRequest {
-    ProcessBlock { block_result: SharedImportResult },
-    ExpiredTransactions { expired_txs: Vec<TxId> },
+    ProcessBlock {
+        block_result: SharedImportResult,
+    },
+    ExpiredTransactions {
+        expired_txs: Vec<TxId>,
+    },
+    UpdateBlackList {
+        black_list: BlackList,
+        response_channel: oneshot::Sender<Vec<TxId>>,
+    },
 }
 pub(super) enum PoolReadRequest {
     NonExistingTxs {
@@ -271,6 +286,7 @@ pub(super) struct PoolWorker<View, TxStatusManager> {
     pending_pool: PendingPool,
     view_provider: Arc<dyn AtomicView<LatestView = View>>,
     notification_sender: Sender<PoolNotification>,
+    journal: Option<Journal>,
 }
 
 impl<View, TxStatusManager> PoolWorker<View, TxStatusManager>
@@ -322,6 +338,9 @@ where
                         PoolUpdateRequest::ExpiredTransactions { expired_txs } => {
                             self.remove_expired_transactions(expired_txs);
                         }
+                        PoolUpdateRequest::UpdateBlackList { black_list, response_channel } => {
+                            self.update_black_list(black_list, response_channel);
+                        }
                     }
                 }
             }
@@ -409,6 +428,7 @@ where
                         }
                     }
                 };
+                self.write_to_journal(&tx);
                 if let Err(e) =
                     self.notification_sender
                         .try_send(PoolNotification::Inserted {
@@ -457,6 +477,7 @@ where
                         );
                     }
                 } else {
+                    self.write_to_journal(&tx);
                     self.pending_pool
                         .insert_transaction(tx, source, missing_inputs);
                 }
@@ -476,6 +497,41 @@ where
         }
     }
 
+    fn write_to_journal(&mut self, tx: &ArcPoolTx) {
+        let Some(journal) = self.journal.as_mut() else {
+            return
+        };
+
+        let transaction: Transaction = tx.deref().into();
+        if let Err(err) = journal.append(&tx.id(), &transaction) {
+            tracing::error!("Failed to write the transaction to the journal: {err}");
+            return
+        }
+
+        let alive_txs = self
+            .pool
+            .tx_count()
+            .saturating_add(self.pending_pool.current_txs);
+        if journal.should_compact(alive_txs) {
+            let pool_txs = self
+                .pool
+                .iter_tx_ids()
+                .filter_map(|tx_id| self.pool.get(tx_id))
+                .map(|data| Transaction::from(data.transaction.deref()));
+            let pending_txs = self
+                .pending_pool
+                .iter_transactions()
+                .map(|tx| Transaction::from(tx.deref()));
+            let alive_txs = pool_txs.chain(pending_txs).collect::<Vec<_>>();
+
+            // The journal keeps the time of the first acceptance of each transaction,
+            // so the compaction doesn't extend their TTL.
+            if let Err(err) = journal.compact(alive_txs.iter()) {
+                tracing::error!("Failed to compact the journal: {err}");
+            }
+        }
+    }
+
     fn extract_block_transactions(
         &mut self,
         constraints: Constraints,
@@ -519,11 +575,45 @@ where
         self.pool.remove_transactions_and_dependents(
             tx_ids,
             statuses::SqueezedOut {
-                reason: Error::Removed(crate::error::RemovedReason::Ttl).to_string(),
+                reason: Error::Removed(RemovedReason::Ttl).to_string(),
             },
         );
     }
 
+    fn update_black_list(
+        &mut self,
+        black_list: BlackList,
+        response_channel: oneshot::Sender<Vec<TxId>>,
+    ) {
+        let blacklisted_txs = self
+            .pool
+            .iter_tx_ids()
+            .filter_map(|tx_id| {
+                let data = self.pool.get(tx_id)?;
+                let error = black_list.check_blacklisting(&data.transaction).err()?;
+                Some((*tx_id, error))
+            })
+            .collect::<Vec<_>>();
+        // Transactions from the pending pool are checked against
+        // the new blacklist when they are inserted into the pool.
+        self.pool.config.black_list = black_list;
+
+        let mut removed_txs = vec![];
+        for (tx_id, error) in blacklisted_txs {
+            let removed = self.pool.remove_transactions_and_dependents(
+                iter::once(tx_id),
+                statuses::SqueezedOut {
+                    reason: Error::Removed(RemovedReason::Blacklisted(error)).to_string(),
+                },
+            );
+            removed_txs.extend(removed);
+        }
+
+        if response_channel.send(removed_txs).is_err() {
+            tracing::error!("Failed to send the result of the blacklist update");
+        }
+    }
+
     fn process_preconfirmed_transaction(
         &mut self,
         tx_id: TxId,
@@ -0,0 +1,150 @@
+use crate::{
+    config::TxSelectionAlgorithm,
+    storage::{
+        RemovedTransactions,
+        StorageData,
+        graph::GraphStorage,
+    },
+};
+
+use super::{
+    Constraints,
+    SelectionAlgorithm,
+    SelectionAlgorithmStorage,
+    fifo::FifoSelection,
+    ratio_tip_gas::RatioTipGasSelection,
+};
+
+#[cfg(test)]
+use fuel_core_types::services::txpool::ArcPoolTx;
+
+/// The selection algorithm chosen by the configuration of the `TxPool` service.
+pub enum ConfigurableSelection<S>
+where
+    S: SelectionAlgorithmStorage,
+{
+    RatioTipGas(RatioTipGasSelection<S>),
+    Fifo(FifoSelection<S>),
+    Custom(
+        Box<
+            dyn SelectionAlgorithm<Storage = S, StorageIndex = S::StorageIndex>
+                + Send
+                + Sync,
+        >,
+    ),
+}
+
+impl ConfigurableSelection<GraphStorage> {
+    pub fn new(
+        config: &TxSelectionAlgorithm,
+        new_executable_txs_notifier: tokio::sync::watch::Sender<()>,
+    ) -> Self {
+        match config {
+            TxSelectionAlgorithm::RatioTipGas => {
+                Self::RatioTipGas(RatioTipGasSelection::new(new_executable_txs_notifier))
+            }
+            TxSelectionAlgorithm::Fifo => {
+                Self::Fifo(FifoSelection::new(new_executable_txs_notifier))
+            }
+            TxSelectionAlgorithm::FairByOwner { max_gas_per_owner } => Self::Fifo(
+                FifoSelection::new(new_executable_txs_notifier)
+                    .with_max_gas_per_owner(*max_gas_per_owner),
+            ),
+            TxSelectionAlgorithm::Custom(factory) => {
+                Self::Custom(factory.create(new_executable_txs_notifier))
+            }
+        }
+    }
+}
+
+impl<S> ConfigurableSelection<S>
+where
+    S: SelectionAlgorithmStorage,
+{
+    #[cfg(test)]
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Self::RatioTipGas(selection) => selection.is_empty(),
+            Self::Fifo(selection) => selection.is_empty(),
+            Self::Custom(selection) => selection.number_of_executable_transactions() == 0,
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn assert_integrity(&self, expected_txs: &[ArcPoolTx]) {
+        match self {
+            Self::RatioTipGas(selection) => selection.assert_integrity(expected_txs),
+            Self::Fifo(selection) => selection.assert_integrity(expected_txs),
+            Self::Custom(selection) => assert_eq!(
+                selection.number_of_executable_transactions(),
+                expected_txs.len()
+            ),
+        }
+    }
+}
+
+impl<S> SelectionAlgorithm for ConfigurableSelection<S>
+where
+    S: SelectionAlgorithmStorage,
+{
+    type Storage = S;
+    type StorageIndex = S::StorageIndex;
+
+    fn gather_best_txs(
+        &mut self,
+        constraints: Constraints,
+        storage: &mut S,
+    ) -> RemovedTransactions {
+        match self {
+            Self::RatioTipGas(selection) => {
+                selection.gather_best_txs(constraints, storage)
+            }
+            Self::Fifo(selection) => selection.gather_best_txs(constraints, storage),
+            Self::Custom(selection) => selection.gather_best_txs(constraints, storage),
+        }
+    }
+
+    fn new_executable_transaction(
+        &mut self,
+        storage_id: Self::StorageIndex,
+        store_entry: &StorageData,
+    ) {
+        match self {
+            Self::RatioTipGas(selection) => {
+                selection.new_executable_transaction(storage_id, store_entry)
+            }
+            Self::Fifo(selection) => {
+                selection.new_executable_transaction(storage_id, store_entry)
+            }
+            Self::Custom(selection) => {
+                selection.new_executable_transaction(storage_id, store_entry)
+            }
+        }
+    }
+
+    fn number_of_executable_transactions(&self) -> usize {
+        match self {
+            Self::RatioTipGas(selection) => selection.number_of_executable_transactions(),
+            Self::Fifo(selection) => selection.number_of_executable_transactions(),
+            Self::Custom(selection) => selection.number_of_executable_transactions(),
+        }
+    }
+
+    fn get_less_worth_txs(&self) -> Box<dyn Iterator<Item = &Self::StorageIndex> + '_> {
+        match self {
+            Self::RatioTipGas(selection) => selection.get_less_worth_txs(),
+            Self::Fifo(selection) => selection.get_less_worth_txs(),
+            Self::Custom(selection) => selection.get_less_worth_txs(),
+        }
+    }
+
+    fn on_removed_transaction(&mut self, storage_entry: &StorageData) {
+        match self {
+            Self::RatioTipGas(selection) => {
+                selection.on_removed_transaction(storage_entry)
+            }
+            Self::Fifo(selection) => selection.on_removed_transaction(storage_entry),
+            Self::Custom(selection) => selection.on_removed_transaction(storage_entry),
+        }
+    }
+}
@@ -1,12 +1,19 @@
-use std::collections::HashSet;
+use std::{
+    collections::HashSet,
+    fmt::Debug,
+};
 
 use fuel_core_types::fuel_tx::ContractId;
 
 use crate::storage::{
     RemovedTransactions,
     StorageData,
+    graph::GraphStorage,
 };
 
+pub mod configurable;
+pub mod fifo;
+pub mod ordered;
 pub mod ratio_tip_gas;
 
 /// Constraints that the selection algorithm has to respect.
@@ -47,8 +54,46 @@ pub trait SelectionAlgorithm {
     fn number_of_executable_transactions(&self) -> usize;
 
     /// Get less worth transactions iterator
-    fn get_less_worth_txs(&self) -> impl Iterator<Item = &Self::StorageIndex>;
+    fn get_less_worth_txs(&self) -> Box<dyn Iterator<Item = &Self::StorageIndex> + '_>;
 
     /// Inform the selection algorithm that a transaction was removed from the pool.
     fn on_removed_transaction(&mut self, storage_entry: &StorageData);
 }
+
+/// The view of the storage required by the selection algorithms.
+pub trait SelectionAlgorithmStorage {
+    type StorageIndex: Debug;
+
+    fn get(&self, index: &Self::StorageIndex) -> Option<&StorageData>;
+
+    fn get_dependents(
+        &self,
+        index: &Self::StorageIndex,
+    ) -> impl Iterator<Item = Self::StorageIndex>;
+
+    fn has_dependencies(&self, index: &Self::StorageIndex) -> bool;
+
+    fn remove(&mut self, index: &Self::StorageIndex) -> Option<StorageData>;
+}
+
+/// The selection algorithm that works on top of the storage used by the `TxPool` service.
+pub type BoxedSelectionAlgorithm = Box<
+    dyn SelectionAlgorithm<
+            Storage = GraphStorage,
+            StorageIndex = <GraphStorage as SelectionAlgorithmStorage>::StorageIndex,
+        > + Send
+        + Sync,
+>;
+
+/// Creates a custom selection algorithm for the `TxPool` service.
+/// It allows plugging in an algorithm that is not part of the crate
+/// when the node is embedded as a library.
+pub trait SelectionAlgorithmFactory: Send + Sync {
+    /// Creates a new instance of the selection algorithm.
+    /// The algorithm should notify the `new_executable_txs_notifier`
+    /// when dependent transactions become executable during `gather_best_txs`.
+    fn create(
+        &self,
+        new_executable_txs_notifier: tokio::sync::watch::Sender<()>,
+    ) -> BoxedSelectionAlgorithm;
+}
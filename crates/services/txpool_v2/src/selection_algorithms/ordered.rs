@@ -0,0 +1,280 @@
+use std::{
+    cmp::Reverse,
+    collections::{
+        BTreeMap,
+        HashMap,
+    },
+    fmt::Debug,
+};
+
+use fuel_core_types::fuel_tx::{
+    Address,
+    TxId,
+};
+
+use crate::storage::{
+    RemovedTransactions,
+    StorageData,
+};
+
+use super::{
+    Constraints,
+    SelectionAlgorithm,
+    SelectionAlgorithmStorage,
+};
+
+#[cfg(test)]
+use fuel_core_types::services::txpool::ArcPoolTx;
+
+/// The key that defines the order of the executable transactions.
+/// Transactions with the greatest key are selected first.
+pub trait OrderingKey: Ord + Copy + Debug {
+    /// Creates the key for the stored transaction.
+    fn new(store_entry: &StorageData) -> Self;
+
+    /// Returns the id of the transaction the key was created for.
+    fn tx_id(&self) -> TxId;
+}
+
+/// The selection algorithm that selects executable transactions in the order defined by the key.
+/// Optionally, it limits the gas that transactions of the same owner can take in one block.
+pub struct OrderedSelection<S, K>
+where
+    S: SelectionAlgorithmStorage,
+{
+    executable_transactions_sorted: BTreeMap<Reverse<K>, S::StorageIndex>,
+    new_executable_txs_notifier: tokio::sync::watch::Sender<()>,
+    max_gas_per_owner: Option<u64>,
+}
+
+impl<S, K> OrderedSelection<S, K>
+where
+    S: SelectionAlgorithmStorage,
+    K: OrderingKey,
+{
+    pub fn new(new_executable_txs_notifier: tokio::sync::watch::Sender<()>) -> Self {
+        Self {
+            executable_transactions_sorted: BTreeMap::new(),
+            new_executable_txs_notifier,
+            max_gas_per_owner: None,
+        }
+    }
+
+    /// Limits the gas that transactions of the same owner can take in one block.
+    /// The owner of the transaction is the owner of its first coin or message input.
+    pub fn with_max_gas_per_owner(mut self, max_gas_per_owner: u64) -> Self {
+        self.max_gas_per_owner = Some(max_gas_per_owner);
+        self
+    }
+
+    #[cfg(test)]
+    pub fn is_empty(&self) -> bool {
+        self.executable_transactions_sorted.is_empty()
+    }
+
+    fn on_removed_transaction_inner(&mut self, key: K) {
+        self.executable_transactions_sorted.remove(&Reverse(key));
+    }
+
+    #[cfg(test)]
+    pub(crate) fn assert_integrity(&self, expected_txs: &[ArcPoolTx]) {
+        let mut expected_txs: HashMap<TxId, ArcPoolTx> = expected_txs
+            .iter()
+            .map(|tx| (tx.id(), tx.clone()))
+            .collect();
+        for key in self.executable_transactions_sorted.keys() {
+            let tx_id = key.0.tx_id();
+            expected_txs.remove(&tx_id).unwrap_or_else(|| {
+                panic!(
+                    "Transaction with id {:?} is not in the expected transactions.",
+                    tx_id
+                )
+            });
+        }
+        assert!(
+            expected_txs.is_empty(),
+            "Some transactions are missing from the selection algorithm: {:?}",
+            expected_txs.keys().collect::<Vec<_>>()
+        );
+    }
+}
+
+fn owner_of(store_entry: &StorageData) -> Option<Address> {
+    store_entry
+        .transaction
+        .inputs()
+        .iter()
+        .find_map(|input| input.input_owner())
+        .copied()
+}
+
+impl<S, K> SelectionAlgorithm for OrderedSelection<S, K>
+where
+    S: SelectionAlgorithmStorage,
+    K: OrderingKey,
+{
+    type Storage = S;
+    type StorageIndex = S::StorageIndex;
+
+    fn gather_best_txs(
+        &mut self,
+        constraints: Constraints,
+        storage: &mut S,
+    ) -> RemovedTransactions {
+        let mut gas_left = constraints.max_gas;
+        let mut space_left = constraints.maximum_block_size as usize;
+        let mut nb_left = constraints.maximum_txs;
+        let mut gas_used_by_owner = HashMap::<Address, u64>::new();
+        let mut result = Vec::new();
+
+        // Take iterate over all transactions in the order of the key. If transaction
+        // fits in the gas limit select it and mark all its dependents to be promoted.
+        // Do that until end of the list or gas limit is reached. If gas limit is not
+        // reached, but we have promoted transactions we can start again from the beginning.
+        // Otherwise, we can break the loop.
+        // It is done in this way to minimize number of iteration of the list of executable
+        // transactions.
+        let mut add_new_executable = false;
+        while gas_left > 0
+            && nb_left > 0
+            && space_left > 0
+            && !self.executable_transactions_sorted.is_empty()
+        {
+            let mut clean_up_list = Vec::new();
+            let mut transactions_to_remove = Vec::new();
+            let mut transactions_to_promote = Vec::new();
+
+            'outer: for (key, storage_id) in &self.executable_transactions_sorted {
+                if nb_left == 0 || gas_left == 0 || space_left == 0 {
+                    break;
+                }
+
+                let Some(stored_transaction) = storage.get(storage_id) else {
+                    debug_assert!(
+                        false,
+                        "Transaction not found in the storage during `gather_best_txs`."
+                    );
+                    tracing::warn!(
+                        "Transaction not found in the storage during `gather_best_txs`."
+                    );
+                    transactions_to_remove.push(*key);
+                    continue
+                };
+
+                for input in stored_transaction.transaction.inputs() {
+                    if let fuel_core_types::fuel_tx::Input::Contract(contract) = input {
+                        if constraints
+                            .excluded_contracts
+                            .contains(&contract.contract_id)
+                        {
+                            continue 'outer;
+                        }
+                    }
+                }
+
+                let less_price = stored_transaction.transaction.max_gas_price()
+                    < constraints.minimal_gas_price;
+
+                if less_price {
+                    continue;
+                }
+
+                let max_gas = stored_transaction.transaction.max_gas();
+                let not_enough_gas = max_gas > gas_left;
+                let too_big_tx =
+                    stored_transaction.transaction.metered_bytes_size() > space_left;
+
+                if not_enough_gas || too_big_tx {
+                    continue;
+                }
+
+                let owner = self.max_gas_per_owner.and_then(|max_gas_per_owner| {
+                    owner_of(stored_transaction).map(|owner| (owner, max_gas_per_owner))
+                });
+                if let Some((owner, max_gas_per_owner)) = owner {
+                    let used = gas_used_by_owner.entry(owner).or_default();
+                    let owner_gas_left = max_gas_per_owner.saturating_sub(*used);
+                    if max_gas > owner_gas_left {
+                        continue;
+                    }
+                    *used = used.saturating_add(max_gas);
+                }
+
+                gas_left = gas_left.saturating_sub(max_gas);
+                space_left = space_left
+                    .saturating_sub(stored_transaction.transaction.metered_bytes_size());
+                nb_left = nb_left.saturating_sub(1);
+
+                let dependents = storage.get_dependents(storage_id).collect::<Vec<_>>();
+                debug_assert!(!storage.has_dependencies(storage_id));
+                let removed = storage.remove(storage_id).expect(
+                    "We just get the transaction from the storage above, it should exist.",
+                );
+                clean_up_list.push(*key);
+                result.push(removed);
+
+                for dependent in dependents {
+                    if !storage.has_dependencies(&dependent) {
+                        transactions_to_promote.push(dependent);
+                    }
+                }
+            }
+
+            for remove in transactions_to_remove {
+                let key = remove.0;
+                self.on_removed_transaction_inner(key);
+            }
+
+            // If no transaction fits in the gas limit and no one to promote, we can break the loop
+            if clean_up_list.is_empty() && transactions_to_promote.is_empty() {
+                break;
+            }
+
+            for key in clean_up_list {
+                let key = key.0;
+                // Remove selected transactions from the sorted list
+                self.on_removed_transaction_inner(key);
+            }
+
+            if transactions_to_promote.is_empty() {
+                continue;
+            }
+            for promote in transactions_to_promote {
+                let storage = storage.get(&promote).expect(
+                    "We just get the dependent from the storage, it should exist.",
+                );
+                self.new_executable_transaction(promote, storage);
+            }
+            add_new_executable = true;
+        }
+
+        if add_new_executable {
+            self.new_executable_txs_notifier.send_replace(());
+        }
+
+        result
+    }
+
+    fn new_executable_transaction(
+        &mut self,
+        storage_id: Self::StorageIndex,
+        store_entry: &StorageData,
+    ) {
+        let key = K::new(store_entry);
+        self.executable_transactions_sorted
+            .insert(Reverse(key), storage_id);
+    }
+
+    fn get_less_worth_txs(&self) -> Box<dyn Iterator<Item = &Self::StorageIndex> + '_> {
+        Box::new(self.executable_transactions_sorted.values().rev())
+    }
+
+    fn on_removed_transaction(&mut self, storage_entry: &StorageData) {
+        let key = K::new(storage_entry);
+        self.on_removed_transaction_inner(key)
+    }
+
+    fn number_of_executable_transactions(&self) -> usize {
+        self.executable_transactions_sorted.len()
+    }
+}
@@ -29,9 +29,17 @@ use fuel_core_services::{
     },
 };
 use fuel_core_txpool::{
+    black_list_file::{
+        self,
+        BlackListChanges,
+    },
     collision_manager::basic::BasicCollisionManager,
     config::Config,
     error::Error,
+    journal::{
+        Journal,
+        remove_expired_entries,
+    },
     pool::Pool,
     ports::{
         AtomicView,
@@ -44,7 +52,7 @@ use fuel_core_txpool::{
         TxStatusManager as TxStatusManagerTrait,
         WasmChecker as WasmCheckerTrait,
     },
-    selection_algorithms::ratio_tip_gas::RatioTipGasSelection,
+    selection_algorithms::configurable::ConfigurableSelection,
     service::{
         memory::MemoryPool,
         pruner::TransactionPruner,
@@ -93,13 +101,17 @@ use std::{
     },
     sync::Arc,
     time::{
+        Duration,
         SystemTime,
         SystemTimeError,
     },
 };
 use tokio::{
     sync::{
-        mpsc,
+        mpsc::{
+            self,
+            error::TrySendError,
+        },
         oneshot,
         watch,
     },
@@ -115,12 +127,18 @@ pub type TxPool<TxStatusManager> = Pool<
     GraphStorage,
     <GraphStorage as Storage>::StorageIndex,
     BasicCollisionManager<<GraphStorage as Storage>::StorageIndex>,
-    RatioTipGasSelection<GraphStorage>,
+    ConfigurableSelection<GraphStorage>,
     TxStatusManager,
 >;
 
 pub(crate) type Shared<T> = Arc<RwLock<T>>;
 
+/// The interval between attempts to queue the transaction restored from the journal.
+const RESTORE_RETRY_INTERVAL: Duration = Duration::from_millis(10);
+
+/// The number of attempts to send the restored transaction to the full pool worker.
+const RESTORE_INSERT_ATTEMPTS: usize = 500;
+
 pub type Service<View, P2P, TxStatusManager> =
     ServiceRunner<Task<View, P2P, TxStatusManager>>;
 
@@ -187,6 +205,8 @@ where
     tx_sync_history: Shared<HashSet<PeerId>>,
     shared_state: SharedState,
     metrics: bool,
+    /// Transactions from the journal to insert again when the service starts.
+    restored_txs: Vec<Arc<Transaction>>,
 }
 
 #[async_trait::async_trait]
@@ -213,6 +233,31 @@ where
         _: &StateWatcher,
         _: Self::TaskParams,
     ) -> anyhow::Result<Self::Task> {
+        let restored_txs = core::mem::take(&mut self.restored_txs);
+        if !restored_txs.is_empty() {
+            tracing::info!(
+                "Restoring {} transactions from the journal",
+                restored_txs.len()
+            );
+        }
+        // Transactions are verified again, because the state could change since
+        // they were accepted. Invalid transactions are dropped by the verification.
+        for transaction in restored_txs {
+            let reservation = loop {
+                match self.transaction_verifier_process.reserve() {
+                    Ok(reservation) => break reservation,
+                    Err(_) => tokio::time::sleep(RESTORE_RETRY_INTERVAL).await,
+                }
+            };
+            let op = self.verify_and_insert_transaction(
+                transaction,
+                None,
+                None,
+                RESTORE_INSERT_ATTEMPTS,
+            );
+            self.transaction_verifier_process
+                .spawn_reserved(reservation, op);
+        }
         Ok(self)
     }
 }
@@ -450,6 +495,24 @@ where
         transaction: Arc<Transaction>,
         from_peer_info: Option<GossipsubMessageInfo>,
         response_channel: Option<oneshot::Sender<Result<(), Error>>>,
+    ) -> impl FnOnce() + Send + 'static + use<View, P2P, TxStatusManager> {
+        self.verify_and_insert_transaction(
+            transaction,
+            from_peer_info,
+            response_channel,
+            1,
+        )
+    }
+
+    /// Returns the operation that verifies the transaction and sends it to the pool
+    /// worker. If the worker is busy, the sending is attempted up to `send_attempts`
+    /// times before the transaction is dropped.
+    fn verify_and_insert_transaction(
+        &self,
+        transaction: Arc<Transaction>,
+        from_peer_info: Option<GossipsubMessageInfo>,
+        response_channel: Option<oneshot::Sender<Result<(), Error>>>,
+        send_attempts: usize,
     ) -> impl FnOnce() + Send + 'static + use<View, P2P, TxStatusManager> {
         let metrics = self.metrics;
         if metrics {
@@ -525,10 +588,19 @@ where
             };
             let tx = Arc::new(checked_tx);
 
-            if let Err(e) = pool_insert_request_sender
-                .try_send(PoolInsertRequest::Insert { tx, source })
-            {
-                tracing::error!("Failed to send the insert request: {}", e);
+            let mut request = PoolInsertRequest::Insert { tx, source };
+            for attempt in 1..=send_attempts {
+                match pool_insert_request_sender.try_send(request) {
+                    Err(TrySendError::Full(returned)) if attempt < send_attempts => {
+                        request = returned;
+                        std::thread::sleep(RESTORE_RETRY_INTERVAL);
+                    }
+                    Err(e) => {
+                        tracing::error!("Failed to send the insert request: {}", e);
+                        break
+                    }
+                    Ok(()) => break,
+                }
             }
         };
 
@@ -748,6 +820,32 @@ where
         write_pool: write_pool_requests_receiver,
     };
 
+    let mut config = config;
+    let mut black_list_path = config.black_list_path.clone();
+    let mut black_list_changes = BlackListChanges::default();
+    if let Some(path) = &black_list_path {
+        match black_list_file::read(path) {
+            Ok(changes) => {
+                changes.apply(&mut config.black_list);
+                black_list_changes = changes;
+            }
+            Err(err) => {
+                // Don't overwrite the file that can't be read with the updates.
+                tracing::error!(
+                    "Failed to read the blacklist from {path:?}, \
+                    updates of the blacklist will not be stored: {err}"
+                );
+                black_list_path = None;
+            }
+        }
+    }
+    let black_list = Arc::new(RwLock::new(config.black_list.clone()));
+
+    let max_tx_size = chain_state_info_provider
+        .latest_consensus_parameters()
+        .1
+        .tx_params()
+        .max_size();
     let storage_provider = Arc::new(ps_provider);
     let verification = Verification {
         persistent_storage_provider: storage_provider.clone(),
@@ -755,7 +853,7 @@ where
         gas_price_provider: Arc::new(gas_price_provider),
         wasm_checker: Arc::new(wasm_checker),
         memory_pool: MemoryPool::new(),
-        blacklist: config.black_list.clone(),
+        blacklist: black_list.clone(),
     };
 
     let pruner = TransactionPruner {
@@ -784,12 +882,28 @@ where
     let service_channel_limits = config.service_channel_limits;
     let utxo_validation = config.utxo_validation;
     let tx_status_manager = Arc::new(tx_status_manager);
+    let (journal, restored_txs) = match &config.journal_path {
+        Some(path) => match Journal::open(path, chain_id, max_tx_size) {
+            Ok((journal, entries)) => {
+                let restored_txs = remove_expired_entries(entries, config.max_txs_ttl)
+                    .into_iter()
+                    .map(|entry| Arc::new(entry.tx))
+                    .collect();
+                (Some(journal), restored_txs)
+            }
+            Err(err) => {
+                tracing::error!("Failed to open the journal at {path:?}: {err}");
+                (None, vec![])
+            }
+        },
+        None => (None, vec![]),
+    };
     let txpool = Pool::new(
         GraphStorage::new(GraphConfig {
             max_txs_chain_count: config.max_txs_chain_count,
         }),
         BasicCollisionManager::new(),
-        RatioTipGasSelection::new(new_txs_notifier.clone()),
+        ConfigurableSelection::new(&config.selection_algorithm, new_txs_notifier.clone()),
         config,
         pool_stats_sender,
         new_txs_notifier.clone(),
@@ -800,11 +914,19 @@ where
     let (current_height_writer, current_height_reader) =
         unsafe { SeqLock::new(current_height) };
 
-    let pool_worker =
-        PoolWorkerInterface::new(txpool, storage_provider, &service_channel_limits);
+    let pool_worker = PoolWorkerInterface::new(
+        txpool,
+        storage_provider,
+        &service_channel_limits,
+        journal,
+    );
 
     let shared_state = SharedState {
         request_read_sender: pool_worker.request_read_sender.clone(),
+        request_update_sender: pool_worker.request_update_sender.clone(),
+        black_list,
+        black_list_path,
+        black_list_changes: Arc::new(tokio::sync::Mutex::new(black_list_changes)),
         write_pool_requests_sender,
         select_transactions_requests_sender: pool_worker
             .extract_block_transactions_sender
@@ -829,5 +951,6 @@ where
         metrics,
         tx_sync_history: Default::default(),
         tx_status_manager,
+        restored_txs,
     })
 }
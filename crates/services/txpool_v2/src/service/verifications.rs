@@ -10,7 +10,10 @@ use crate::{
         TxPoolPersistentStorage,
         WasmChecker,
     },
-    service::memory::MemoryPool,
+    service::{
+        Shared,
+        memory::MemoryPool,
+    },
 };
 use fuel_core_storage::transactional::AtomicView;
 use fuel_core_types::{
@@ -54,7 +57,7 @@ pub(crate) struct Verification<View> {
     pub gas_price_provider: Arc<dyn GasPriceProvider>,
     pub wasm_checker: Arc<dyn WasmChecker>,
     pub memory_pool: MemoryPool,
-    pub blacklist: BlackList,
+    pub blacklist: Shared<BlackList>,
 }
 
 impl<View> Verification<View>
@@ -90,7 +93,7 @@ where
             .map_err(|e| Error::Database(format!("{:?}", e)))?;
 
         let inputs_verified_tx = gas_price_verified_tx
-            .perform_inputs_verifications(&self.blacklist, metadata)?;
+            .perform_inputs_verifications(&self.blacklist.read(), metadata)?;
 
         let fully_verified_tx = inputs_verified_tx
             .perform_input_computation_verifications(
@@ -1,4 +1,7 @@
-use std::sync::Arc;
+use std::{
+    path::PathBuf,
+    sync::Arc,
+};
 
 use fuel_core_types::{
     fuel_tx::{
@@ -18,13 +21,20 @@ use tokio::sync::{
 
 use crate::{
     Constraints,
+    black_list_file::{
+        self,
+        BlackListChanges,
+    },
+    config::BlackList,
     error::Error,
     pool::TxPoolStats,
     pool_worker::{
         self,
         PoolReadRequest,
+        PoolUpdateRequest,
     },
     service::{
+        Shared,
         TxInfo,
         WritePoolRequest,
     },
@@ -36,6 +46,13 @@ pub struct SharedState {
     pub(crate) select_transactions_requests_sender:
         mpsc::Sender<pool_worker::PoolExtractBlockTransactions>,
     pub(crate) request_read_sender: mpsc::Sender<PoolReadRequest>,
+    pub(crate) request_update_sender: mpsc::Sender<PoolUpdateRequest>,
+    pub(crate) black_list: Shared<BlackList>,
+    pub(crate) black_list_path: Option<PathBuf>,
+    /// The runtime changes of the blacklist stored in the file at `black_list_path`.
+    /// The lock serializes updates of the blacklist, so the pool receives them
+    /// in the same order as they are applied to the `black_list`.
+    pub(crate) black_list_changes: Arc<tokio::sync::Mutex<BlackListChanges>>,
     pub(crate) new_executable_txs_notifier: tokio::sync::watch::Sender<()>,
     pub(crate) latest_stats: tokio::sync::watch::Receiver<TxPoolStats>,
 }
@@ -137,6 +154,55 @@ impl SharedState {
             .map_err(|_| Error::ServiceCommunicationFailed)
     }
 
+    /// Returns the current blacklist.
+    pub fn black_list(&self) -> BlackList {
+        self.black_list.read().clone()
+    }
+
+    /// Removes the `remove` entries from the blacklist and adds the `add` entries to it.
+    /// Transactions from the pool that have newly blacklisted inputs are removed
+    /// with all their dependents. Returns the ids of the removed transactions.
+    pub async fn update_black_list(
+        &self,
+        add: BlackList,
+        remove: BlackList,
+    ) -> Result<Vec<TxId>, Error> {
+        let mut changes = self.black_list_changes.lock().await;
+
+        let mut black_list = self.black_list();
+        black_list.remove(&remove);
+        black_list.extend(add.clone());
+
+        // Only the runtime changes are stored, so the changes of the config
+        // take effect after the restart.
+        let mut updated_changes = changes.clone();
+        updated_changes.update(&add, &remove);
+        if let Some(path) = self.black_list_path.clone() {
+            let file_changes = updated_changes.clone();
+            tokio::task::spawn_blocking(move || {
+                black_list_file::write(&path, &file_changes)
+            })
+            .await
+            .map_err(|e| Error::BlacklistStorage(e.to_string()))?
+            .map_err(|e| Error::BlacklistStorage(e.to_string()))?;
+        }
+        *changes = updated_changes;
+        *self.black_list.write() = black_list.clone();
+
+        let (response_channel, result_receiver) = oneshot::channel();
+        self.request_update_sender
+            .send(PoolUpdateRequest::UpdateBlackList {
+                black_list,
+                response_channel,
+            })
+            .await
+            .map_err(|_| Error::ServiceCommunicationFailed)?;
+
+        result_receiver
+            .await
+            .map_err(|_| Error::ServiceCommunicationFailed)
+    }
+
     /// Get a notifier that is notified when new executable transactions are added to the pool.
     pub fn get_new_executable_txs_notifier(&self) -> watch::Receiver<()> {
         self.new_executable_txs_notifier.subscribe()
@@ -48,7 +48,7 @@ use crate::{
     extracted_outputs::ExtractedOutputs,
     pending_pool::MissingInput,
     ports::TxPoolPersistentStorage,
-    selection_algorithms::ratio_tip_gas::RatioTipGasSelectionAlgorithmStorage,
+    selection_algorithms::SelectionAlgorithmStorage,
     spent_inputs::SpentInputs,
     storage::checked_collision::CheckedTransaction,
 };
@@ -782,7 +782,7 @@ impl Storage for GraphStorage {
     }
 }
 
-impl RatioTipGasSelectionAlgorithmStorage for GraphStorage {
+impl SelectionAlgorithmStorage for GraphStorage {
     type StorageIndex = NodeIndex;
 
     fn get(&self, index: &Self::StorageIndex) -> Option<&StorageData> {
@@ -144,9 +144,9 @@ fn stability_test(limits: Limits, config: Config) {
     use rand::RngCore;
     let seed = rand::thread_rng().next_u64();
 
-    let result = std::panic::catch_unwind(|| {
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
         stability_test_with_seed(seed, limits, config);
-    });
+    }));
 
     if let Err(err) = result {
         tracing::error!("Stability test failed with seed: {}; err: {:?}", seed, err);
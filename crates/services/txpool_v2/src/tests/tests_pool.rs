@@ -1,9 +1,13 @@
-use std::collections::HashSet;
+use std::{
+    collections::HashSet,
+    sync::Arc,
+};
 
 use crate::{
     config::{
         Config,
         PoolLimits,
+        TxSelectionAlgorithm,
     },
     error::{
         BlacklistedError,
@@ -11,9 +15,15 @@ use crate::{
         DependencyError,
         Error,
         InputValidationError,
+        RemovedReason,
     },
     ports::WasmValidityError,
-    selection_algorithms::Constraints,
+    selection_algorithms::{
+        BoxedSelectionAlgorithm,
+        Constraints,
+        SelectionAlgorithmFactory,
+        fifo::FifoSelection,
+    },
     tests::{
         mocks::MockWasmChecker,
         universe::{
@@ -47,6 +57,7 @@ use fuel_core_types::{
         PanicReason,
         PredicateParameters,
         TransactionBuilder,
+        TxId,
         TxParameters,
         UniqueIdentifier,
         UpgradePurpose,
@@ -63,6 +74,7 @@ use fuel_core_types::{
             IntoChecked,
         },
     },
+    services::transaction_status::TransactionStatus,
 };
 
 #[test]
@@ -352,6 +364,144 @@ async fn insert__higher_priced_tx_removes_lower_priced_tx() {
     universe.assert_pool_integrity(&[result]);
 }
 
+#[tokio::test]
+async fn insert__replaced_tx_is_squeezed_out_with_replaced_reason() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let common_coin = universe.setup_coin().1;
+    let tx1 =
+        universe.build_script_transaction(Some(vec![common_coin.clone()]), None, 10);
+    let tx1_id = tx1.id(&ChainId::default());
+    let tx2 = universe.build_script_transaction(Some(vec![common_coin]), None, 20);
+    let tx2_id = tx2.id(&ChainId::default());
+    universe.verify_and_insert(tx1).unwrap();
+
+    // When
+    let result = universe.verify_and_insert(tx2).unwrap();
+
+    // Then
+    let expected_reason = Error::Removed(RemovedReason::Replaced(tx2_id)).to_string();
+    universe
+        .await_expected_tx_statuses(vec![tx1_id], |status| {
+            matches!(
+                status,
+                TransactionStatus::SqueezedOut(status) if status.reason == expected_reason
+            )
+        })
+        .await
+        .unwrap();
+    universe.assert_pool_integrity(&[result]);
+}
+
+#[test]
+fn insert__rbf_tip_bump_below_minimum_returns_error() {
+    let mut universe = TestPoolUniverse::default();
+    universe.config.rbf_policy.min_tip_bump_percentage = 10;
+    universe.build_pool();
+
+    // Given
+    let common_coin = universe.setup_coin().1;
+    let tx1 =
+        universe.build_script_transaction(Some(vec![common_coin.clone()]), None, 100);
+    let tx1_id = tx1.id(&ChainId::default());
+    let tx2 =
+        universe.build_script_transaction(Some(vec![common_coin.clone()]), None, 105);
+    let tx3 = universe.build_script_transaction(Some(vec![common_coin]), None, 110);
+    universe.verify_and_insert(tx1).unwrap();
+
+    // When
+    let result2 = universe.verify_and_insert(tx2);
+    let result3 = universe.verify_and_insert(tx3);
+
+    // Then
+    let err = result2.unwrap_err();
+    assert!(
+        matches!(
+            err,
+            Error::Collided(CollisionReason::NotEnoughTipBump { tx_id, required_tip: 110 })
+                if tx_id == tx1_id
+        ),
+        "{err:?}"
+    );
+    universe.assert_pool_integrity(&[result3.unwrap()]);
+}
+
+#[test]
+fn insert__rbf_too_many_replaced_transactions_returns_error() {
+    let mut universe = TestPoolUniverse::default();
+    universe.config.rbf_policy.max_replaced_subtree_size = 1;
+    universe.build_pool();
+
+    // Given
+    let common_coin = universe.setup_coin().1;
+    let (output, unset_input) = universe.create_output_and_input();
+    let tx1 = universe.build_script_transaction(
+        Some(vec![common_coin.clone()]),
+        Some(vec![output]),
+        10,
+    );
+    let input = unset_input.into_input(UtxoId::new(tx1.id(&ChainId::default()), 0));
+    let tx2 = universe.build_script_transaction(Some(vec![input]), None, 10);
+    let tx3 = universe.build_script_transaction(Some(vec![common_coin]), None, 100);
+    let tx1 = universe.verify_and_insert(tx1).unwrap();
+    let tx2 = universe.verify_and_insert(tx2).unwrap();
+
+    // When
+    let result = universe.verify_and_insert(tx3);
+
+    // Then
+    let err = result.unwrap_err();
+    assert!(
+        matches!(
+            err,
+            Error::Collided(CollisionReason::TooManyReplacedTransactions {
+                replaced: 2,
+                limit: 1
+            })
+        ),
+        "{err:?}"
+    );
+    universe.assert_pool_integrity(&[tx1, tx2]);
+}
+
+#[test]
+fn insert__rbf_total_fee_not_increased_returns_error() {
+    let mut universe = TestPoolUniverse::default();
+    universe.config.rbf_policy.require_total_fee_increase = true;
+    universe.build_pool();
+
+    // Given
+    let common_coin = universe.setup_coin().1;
+    let (output, unset_input) = universe.create_output_and_input();
+    let tx1 = universe.build_script_transaction(
+        Some(vec![common_coin.clone()]),
+        Some(vec![output]),
+        10,
+    );
+    let input = unset_input.into_input(UtxoId::new(tx1.id(&ChainId::default()), 0));
+    let tx2 = universe.build_script_transaction(Some(vec![input]), None, 10);
+    // More profitable than tx1 with tx2, but pays less in total
+    let tx3 = universe.build_script_transaction(Some(vec![common_coin]), None, 15);
+    let tx1 = universe.verify_and_insert(tx1).unwrap();
+    let tx2 = universe.verify_and_insert(tx2).unwrap();
+
+    // When
+    let result = universe.verify_and_insert(tx3);
+
+    // Then
+    let err = result.unwrap_err();
+    assert!(
+        matches!(
+            err,
+            Error::Collided(CollisionReason::TotalFeeNotIncreased { replaced_tip: 20 })
+        ),
+        "{err:?}"
+    );
+    universe.assert_pool_integrity(&[tx1, tx2]);
+}
+
 #[test]
 fn insert__colliding_dependent_and_underpriced_returns_error() {
     let mut universe = TestPoolUniverse::default();
@@ -874,6 +1024,114 @@ fn get_sorted_out_tx_by_creation_instant() {
     universe.assert_pool_integrity(&[]);
 }
 
+fn extract_all_transactions(universe: &TestPoolUniverse) -> Vec<TxId> {
+    universe
+        .get_pool()
+        .write()
+        .extract_transactions_for_block(Constraints {
+            minimal_gas_price: 0,
+            max_gas: u64::MAX,
+            maximum_txs: u16::MAX,
+            maximum_block_size: u32::MAX,
+            excluded_contracts: Default::default(),
+        })
+        .iter()
+        .map(|tx| tx.id())
+        .collect()
+}
+
+#[test]
+fn get_sorted_out_tx__fifo_ignores_tip() {
+    let mut universe = TestPoolUniverse::default();
+    universe.config.selection_algorithm = TxSelectionAlgorithm::Fifo;
+    universe.build_pool();
+
+    // Given
+    let tx1 = universe.build_script_transaction(None, None, 1);
+    let tx2 = universe.build_script_transaction(None, None, 100);
+    let tx3 = universe.build_script_transaction(None, None, 50);
+    let tx1_id = tx1.id(&ChainId::default());
+    let tx2_id = tx2.id(&ChainId::default());
+    let tx3_id = tx3.id(&ChainId::default());
+
+    universe.verify_and_insert(tx1).unwrap();
+    universe.verify_and_insert(tx2).unwrap();
+    universe.verify_and_insert(tx3).unwrap();
+
+    // When
+    let txs = extract_all_transactions(&universe);
+
+    // Then
+    assert_eq!(txs, vec![tx1_id, tx2_id, tx3_id]);
+    universe.assert_pool_integrity(&[]);
+}
+
+#[test]
+fn get_sorted_out_tx__fair_by_owner_limits_gas_of_one_owner() {
+    let mut universe = TestPoolUniverse::default();
+    // Enough for one transaction of the owner, but not for two.
+    universe.config.selection_algorithm = TxSelectionAlgorithm::FairByOwner {
+        max_gas_per_owner: GAS_LIMIT * 3 / 2,
+    };
+    universe.build_pool();
+
+    // Given
+    let predicate: Vec<u8> = vec![op::ret(1)].into_iter().collect();
+    let (_, owner_coin_1) = universe.setup_coin_with_predicate(predicate.clone());
+    let (_, owner_coin_2) = universe.setup_coin_with_predicate(predicate);
+    let tx1 = universe.build_script_transaction(Some(vec![owner_coin_1]), None, 0);
+    let tx2 = universe.build_script_transaction(Some(vec![owner_coin_2]), None, 0);
+    let tx3 = universe.build_script_transaction(None, None, 0);
+    let tx1_id = tx1.id(&ChainId::default());
+    let tx3_id = tx3.id(&ChainId::default());
+
+    universe.verify_and_insert(tx1).unwrap();
+    let tx2 = universe.verify_and_insert(tx2).unwrap();
+    universe.verify_and_insert(tx3).unwrap();
+
+    // When
+    let txs = extract_all_transactions(&universe);
+
+    // Then
+    assert_eq!(txs, vec![tx1_id, tx3_id]);
+    universe.assert_pool_integrity(&[tx2]);
+}
+
+struct CustomFifoFactory;
+
+impl SelectionAlgorithmFactory for CustomFifoFactory {
+    fn create(
+        &self,
+        new_executable_txs_notifier: tokio::sync::watch::Sender<()>,
+    ) -> BoxedSelectionAlgorithm {
+        Box::new(FifoSelection::new(new_executable_txs_notifier))
+    }
+}
+
+#[test]
+fn get_sorted_out_tx__custom_algorithm_is_used() {
+    let mut universe = TestPoolUniverse::default();
+    universe.config.selection_algorithm =
+        TxSelectionAlgorithm::Custom(Arc::new(CustomFifoFactory));
+    universe.build_pool();
+
+    // Given
+    let tx1 = universe.build_script_transaction(None, None, 1);
+    let tx2 = universe.build_script_transaction(None, None, 100);
+    let tx1_id = tx1.id(&ChainId::default());
+    let tx2_id = tx2.id(&ChainId::default());
+
+    universe.verify_and_insert(tx1).unwrap();
+    universe.verify_and_insert(tx2).unwrap();
+
+    // When
+    let txs = extract_all_transactions(&universe);
+
+    // Then
+    assert_eq!(txs, vec![tx1_id, tx2_id]);
+    universe.assert_pool_integrity(&[]);
+}
+
 #[test]
 fn insert_tx_at_least_min_gas_price() {
     // Given
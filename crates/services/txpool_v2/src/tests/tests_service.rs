@@ -21,7 +21,11 @@ use std::{
 
 use crate::{
     Constraints,
-    config::Config,
+    config::{
+        BlackList,
+        Config,
+    },
+    error::Error,
     tests::{
         mocks::MockImporter,
         universe::{
@@ -618,3 +622,211 @@ async fn pending_pool__returns_error_after_timeout_for_transaction_that_spends_u
 
     service.stop_and_await().await.unwrap();
 }
+
+#[tokio::test]
+async fn journal__restores_transactions_after_restart() {
+    let journal_dir = tempfile::tempdir().unwrap();
+    let mut universe = TestPoolUniverse::default().config(Config {
+        journal_path: Some(journal_dir.path().join("txpool.journal")),
+        ..Default::default()
+    });
+
+    // Given
+    let tx = universe.build_script_transaction(None, None, 10);
+    let tx_id = tx.id(&Default::default());
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+    service.shared.try_insert(vec![tx]).unwrap();
+    universe
+        .await_expected_tx_statuses_submitted(vec![tx_id])
+        .await;
+    service.stop_and_await().await.unwrap();
+    drop(service);
+
+    // When
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+
+    // Then
+    universe
+        .await_expected_tx_statuses_submitted(vec![tx_id])
+        .await;
+    let out = service.shared.find(vec![tx_id]).await.unwrap();
+    assert!(out[0].is_some(), "Transaction should be restored");
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn update_black_list__removes_blacklisted_transactions_from_pool() {
+    let mut universe = TestPoolUniverse::default();
+
+    // Given
+    let (_, blacklisted_input) = universe.setup_coin();
+    let owner = *blacklisted_input.input_owner().unwrap();
+    let blacklisted_tx =
+        universe.build_script_transaction(Some(vec![blacklisted_input]), None, 10);
+    let blacklisted_tx_id = blacklisted_tx.id(&Default::default());
+    let tx = universe.build_script_transaction(None, None, 20);
+    let tx_id = tx.id(&Default::default());
+
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+    service.shared.try_insert(vec![blacklisted_tx, tx]).unwrap();
+    universe
+        .await_expected_tx_statuses_submitted(vec![blacklisted_tx_id, tx_id])
+        .await;
+
+    // When
+    let removed = service
+        .shared
+        .update_black_list(
+            BlackList::new(vec![owner], vec![], vec![], vec![]),
+            BlackList::default(),
+        )
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(removed, vec![blacklisted_tx_id]);
+    universe
+        .await_expected_tx_statuses(vec![blacklisted_tx_id], |status| {
+            matches!(status, TransactionStatus::SqueezedOut(s)
+                if s.reason.contains("because of the blacklist update"))
+        })
+        .await
+        .unwrap();
+    let out = service
+        .shared
+        .find(vec![blacklisted_tx_id, tx_id])
+        .await
+        .unwrap();
+    assert!(
+        out[0].is_none(),
+        "Blacklisted transaction should be removed"
+    );
+    assert!(
+        out[1].is_some(),
+        "Other transaction should stay in the pool"
+    );
+    assert!(service.shared.black_list().owners.contains(&owner));
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn update_black_list__removed_entries_are_not_blacklisted() {
+    let mut universe = TestPoolUniverse::default();
+    let (_, input) = universe.setup_coin();
+    let owner = *input.input_owner().unwrap();
+    let tx = universe.build_script_transaction(Some(vec![input]), None, 10);
+    let tx_id = tx.id(&Default::default());
+
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+
+    // Given
+    let black_list = BlackList::new(vec![owner], vec![], vec![], vec![]);
+    service
+        .shared
+        .update_black_list(black_list.clone(), BlackList::default())
+        .await
+        .unwrap();
+    let result = service.shared.insert(tx.clone()).await;
+    assert!(matches!(result, Err(Error::Blacklisted(_))));
+
+    // When
+    service
+        .shared
+        .update_black_list(BlackList::default(), black_list)
+        .await
+        .unwrap();
+
+    // Then
+    service.shared.insert(tx).await.unwrap();
+    universe
+        .await_expected_tx_statuses_submitted(vec![tx_id])
+        .await;
+    assert!(service.shared.black_list().is_empty());
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn update_black_list__config_changes_take_effect_after_restart() {
+    let black_list_dir = tempfile::tempdir().unwrap();
+    let black_list_path = black_list_dir.path().join("txpool.blacklist");
+    let config_black_list = |owners: Vec<[u8; 32]>| Config {
+        black_list: BlackList::new(
+            owners.into_iter().map(Into::into).collect(),
+            vec![],
+            vec![],
+            vec![],
+        ),
+        black_list_path: Some(black_list_path.clone()),
+        ..Default::default()
+    };
+
+    // Given
+    let universe = TestPoolUniverse::default()
+        .config(config_black_list(vec![[1; 32], [2; 32], [3; 32]]));
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+    let added = BlackList::new(vec![[4; 32].into()], vec![], vec![], vec![]);
+    let removed = BlackList::new(vec![[3; 32].into()], vec![], vec![], vec![]);
+    service
+        .shared
+        .update_black_list(added, removed)
+        .await
+        .unwrap();
+    service.stop_and_await().await.unwrap();
+    drop(service);
+
+    // When
+    let universe =
+        TestPoolUniverse::default().config(config_black_list(vec![[1; 32], [3; 32]]));
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+
+    // Then
+    let expected =
+        BlackList::new(vec![[1; 32].into(), [4; 32].into()], vec![], vec![], vec![]);
+    assert_eq!(service.shared.black_list(), expected);
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn update_black_list__black_list_is_restored_after_restart() {
+    let black_list_dir = tempfile::tempdir().unwrap();
+    let universe = TestPoolUniverse::default().config(Config {
+        black_list: BlackList::new(vec![[1; 32].into()], vec![], vec![], vec![]),
+        black_list_path: Some(black_list_dir.path().join("txpool.blacklist")),
+        ..Default::default()
+    });
+
+    // Given
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+    let added =
+        BlackList::new(vec![[2; 32].into()], vec![], vec![], vec![[3; 32].into()]);
+    service
+        .shared
+        .update_black_list(added, BlackList::default())
+        .await
+        .unwrap();
+    let expected = service.shared.black_list();
+    service.stop_and_await().await.unwrap();
+    drop(service);
+
+    // When
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+
+    // Then
+    assert_eq!(service.shared.black_list(), expected);
+    assert_eq!(expected.owners.len(), 2);
+    assert_eq!(expected.contracts.len(), 1);
+
+    service.stop_and_await().await.unwrap();
+}
@@ -67,10 +67,7 @@ use crate::{
     GasPrice,
     Service,
     collision_manager::basic::BasicCollisionManager,
-    config::{
-        BlackList,
-        Config,
-    },
+    config::Config,
     error::{
         Error,
         InsertionErrorType,
@@ -80,7 +77,7 @@ use crate::{
         Pool,
         TxPoolStats,
     },
-    selection_algorithms::ratio_tip_gas::RatioTipGasSelection,
+    selection_algorithms::configurable::ConfigurableSelection,
     service::{
         Shared,
         TxPool,
@@ -183,7 +180,10 @@ impl TestPoolUniverse {
                 max_txs_chain_count: self.config.max_txs_chain_count,
             }),
             BasicCollisionManager::new(),
-            RatioTipGasSelection::new(tx_new_executable_txs.clone()),
+            ConfigurableSelection::new(
+                &self.config.selection_algorithm,
+                tx_new_executable_txs.clone(),
+            ),
             self.config.clone(),
             tx,
             tx_new_executable_txs,
@@ -310,7 +310,7 @@ impl TestPoolUniverse {
                     chain_state_info_provider: Arc::new(mock_chain_state_info_provider),
                     wasm_checker: Arc::new(MockWasmChecker::new(Ok(()))),
                     memory_pool: MemoryPool::new(),
-                    blacklist: BlackList::default(),
+                    blacklist: Default::default(),
                 };
                 let tx = verification.perform_all_verifications(
                     tx,
@@ -352,7 +352,7 @@ impl TestPoolUniverse {
                     chain_state_info_provider: Arc::new(mock_chain_state_info),
                     wasm_checker: Arc::new(MockWasmChecker::new(Ok(()))),
                     memory_pool: MemoryPool::new(),
-                    blacklist: BlackList::default(),
+                    blacklist: Default::default(),
                 };
                 let tx = verification.perform_all_verifications(
                     tx,
@@ -393,7 +393,7 @@ impl TestPoolUniverse {
                     chain_state_info_provider: Arc::new(mock_chain_state_info_provider),
                     wasm_checker: Arc::new(wasm_checker),
                     memory_pool: MemoryPool::new(),
-                    blacklist: BlackList::default(),
+                    blacklist: Default::default(),
                 };
                 let tx = verification.perform_all_verifications(
                     tx,
@@ -453,8 +453,18 @@ impl TestPoolUniverse {
 
     pub fn setup_coin(&mut self) -> (Coin, Input) {
         let input = self.random_predicate(AssetId::BASE, TEST_COIN_AMOUNT, None);
+        self.add_coin_to_state(input)
+    }
+
+    /// Same as `setup_coin`, but the coin is owned by the predicate with the `code`.
+    pub fn setup_coin_with_predicate(&mut self, code: Vec<u8>) -> (Coin, Input) {
+        let input = self
+            .custom_predicate(AssetId::BASE, TEST_COIN_AMOUNT, code, None)
+            .into_default_estimated();
+        self.add_coin_to_state(input)
+    }
 
-        // add coin to the state
+    fn add_coin_to_state(&mut self, input: Input) -> (Coin, Input) {
         let mut coin = CompressedCoin::default();
         coin.set_owner(*input.input_owner().unwrap());
         coin.set_amount(TEST_COIN_AMOUNT);
@@ -152,7 +152,17 @@ pub mod merkle {
 
     /// The key for the corresponding `DenseMerkleMetadata` type.
     /// The `Latest` variant is used to have the access to the latest dense Merkle tree.
-    #[derive(Default, Debug, Clone, serde::Serialize, serde::Deserialize)]
+    #[derive(
+        Default,
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        serde::Serialize,
+        serde::Deserialize,
+    )]
     pub enum DenseMetadataKey<PrimaryKey> {
         /// The primary key of the `DenseMerkleMetadata`.
         Primary(PrimaryKey),
@@ -157,6 +157,15 @@ impl<T> Block<T> {
 }
 
 impl CompressedBlock {
+    /// Create a compressed block that contains only the header, without the
+    /// transaction ids. Used by light clients that store only the header chain.
+    pub fn from_header(header: BlockHeader) -> Self {
+        Block::V1(BlockV1 {
+            header,
+            transactions: Vec::new(),
+        })
+    }
+
     /// Convert from a compressed block back to a the full block.
     pub fn uncompress(self, transactions: Vec<Transaction>) -> Block<Transaction> {
         // TODO: should we perform an extra validation step to ensure the provided
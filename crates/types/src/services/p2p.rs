@@ -15,6 +15,7 @@ use super::{
 #[cfg(feature = "test-helpers")]
 use crate::services::preconfirmation::PreconfirmationStatus;
 use crate::{
+    blockchain::SealedBlockHeader,
     fuel_tx::Transaction,
     fuel_types::BlockHeight,
 };
@@ -25,8 +26,12 @@ use std::{
         Display,
         Formatter,
     },
+    net::IpAddr,
     str::FromStr,
-    time::SystemTime,
+    time::{
+        Duration,
+        SystemTime,
+    },
 };
 
 pub use tai64::Tai64;
@@ -83,6 +88,9 @@ pub struct GossipData<T> {
 /// Transactions gossiped by peers for inclusion into a block
 pub type TransactionGossipData = GossipData<Transaction>;
 
+/// Headers of the newly sealed blocks announced by the block producers
+pub type NewBlockGossipData = GossipData<SealedBlockHeader>;
+
 /// A value and an associated signature
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -255,6 +263,45 @@ impl PeerId {
     }
 }
 
+/// The subject of a ban in the p2p network.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BanTarget {
+    /// The single peer
+    Peer(PeerId),
+    /// All peers connecting from the IP address
+    Ip(IpAddr),
+}
+
+impl Display for BanTarget {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BanTarget::Peer(peer_id) => Display::fmt(peer_id, f),
+            BanTarget::Ip(ip) => Display::fmt(ip, f),
+        }
+    }
+}
+
+impl FromStr for BanTarget {
+    type Err = String;
+
+    /// Parses the IP address, or the peer id if the string is not an IP address.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match IpAddr::from_str(s) {
+            Ok(ip) => Ok(Self::Ip(ip)),
+            Err(_) => PeerId::from_str(s).map(Self::Peer),
+        }
+    }
+}
+
+/// The active ban of a peer or an IP address
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PeerBan {
+    /// The banned peer or IP address
+    pub target: BanTarget,
+    /// The time when the ban is lifted. `None` means the ban lasts until it is removed.
+    pub expires_at: Option<SystemTime>,
+}
+
 /// Contains metadata about a connected peer
 pub struct PeerInfo {
     /// The libp2p peer id
@@ -267,6 +314,21 @@ pub struct PeerInfo {
     pub heartbeat_data: HeartbeatData,
     /// the current application reputation score of the peer
     pub app_score: f64,
+    /// statistics of the requests sent to the peer
+    pub request_stats: PeerRequestStats,
+}
+
+/// Statistics of the request-response interactions with the peer
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct PeerRequestStats {
+    /// The number of requests answered with the expected data
+    pub successful_requests: u64,
+    /// The number of requests that failed or were answered with an error
+    pub failed_requests: u64,
+    /// The moving average of the response latency
+    pub avg_latency: Option<Duration>,
+    /// The total size of the responses received from the peer in bytes
+    pub bytes_served: u64,
 }
 
 /// Contains information from the most recent heartbeat received by the peer
@@ -6,7 +6,15 @@ use crate::{
         Message,
         RelayedTransaction,
     },
-    fuel_types::Bytes32,
+    fuel_crypto::Hasher,
+    fuel_types::{
+        Bytes20,
+        Bytes32,
+    },
+};
+use alloc::{
+    string::String,
+    vec::Vec,
 };
 use core::ops::Deref;
 
@@ -18,6 +26,8 @@ pub enum Event {
     Message(Message),
     /// A transaction that was forcibly included from L1
     Transaction(RelayedTransaction),
+    /// The event decoded by a custom decoder of the relayer.
+    Custom(RelayedEvent),
 }
 
 impl Event {
@@ -26,6 +36,7 @@ impl Event {
         match self {
             Event::Message(message) => message.da_height(),
             Event::Transaction(transaction) => transaction.da_height(),
+            Event::Custom(event) => event.da_height,
         }
     }
 
@@ -34,6 +45,7 @@ impl Event {
         match self {
             Event::Message(message) => (*message.message_id().deref()).into(),
             Event::Transaction(transaction) => transaction.id().into(),
+            Event::Custom(event) => event.id(),
         }
     }
 
@@ -42,8 +54,15 @@ impl Event {
         match self {
             Event::Message(_message) => 0,
             Event::Transaction(transaction) => transaction.max_gas(),
+            Event::Custom(_event) => 0,
         }
     }
+
+    /// Returns `true` if the event affects the state of the Fuel chain,
+    /// and should be processed by the executor.
+    pub fn is_consensus_event(&self) -> bool {
+        !matches!(self, Event::Custom(_))
+    }
 }
 
 impl From<Message> for Event {
@@ -57,3 +76,41 @@ impl From<RelayedTransaction> for Event {
         Event::Transaction(transaction)
     }
 }
+
+impl From<RelayedEvent> for Event {
+    fn from(event: RelayedEvent) -> Self {
+        Event::Custom(event)
+    }
+}
+
+/// The event emitted by a DA contract and decoded by a custom decoder of the relayer.
+///
+/// Unlike messages and forced transactions, custom events don't affect the state
+/// of the Fuel chain. The relayer only stores them for downstream consumers.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelayedEvent {
+    /// The name of the decoder that produced the event.
+    pub kind: String,
+    /// The address of the DA contract that emitted the event.
+    pub contract: Bytes20,
+    /// The DA height when the event happened.
+    pub da_height: DaBlockHeight,
+    /// The index of the event log within the DA block.
+    pub log_index: u64,
+    /// The parameters of the event encoded by the decoder.
+    pub payload: Vec<u8>,
+}
+
+impl RelayedEvent {
+    /// Returns the unique identifier of the event.
+    pub fn id(&self) -> Bytes32 {
+        let mut hasher = Hasher::default();
+        hasher.input(self.kind.as_bytes());
+        hasher.input(self.contract);
+        hasher.input(self.da_height.to_be_bytes());
+        hasher.input(self.log_index.to_be_bytes());
+        hasher.input(&self.payload);
+        hasher.digest()
+    }
+}
@@ -1,4 +1,4 @@
-//! Block and generic data signing using a secret key or AWS KMS
+//! Block and generic data signing using a secret key, AWS KMS or a remote signer
 
 use crate::{
     blockchain::primitives::SecretKeyWrapper,
@@ -27,6 +27,9 @@ use aws_sdk_kms::{
 };
 use core::ops::Deref;
 
+#[cfg(feature = "remote-signer")]
+pub mod remote;
+
 /// How the block is signed
 #[derive(Clone, Debug)]
 pub enum SignMode {
@@ -44,6 +47,9 @@ pub enum SignMode {
         /// The cached public key bytes.
         cached_public_key_bytes: Vec<u8>,
     },
+    /// Sign using an external signer process
+    #[cfg(feature = "remote-signer")]
+    Remote(remote::RemoteSigner),
 }
 
 impl SignMode {
@@ -66,6 +72,8 @@ impl SignMode {
                 client,
                 cached_public_key_bytes,
             } => sign_with_kms(client, key_id, cached_public_key_bytes, message).await?,
+            #[cfg(feature = "remote-signer")]
+            SignMode::Remote(signer) => signer.sign_message(message).await?,
         };
         Ok(signature)
     }
@@ -94,6 +102,9 @@ impl SignMode {
                     k256::PublicKey::from_public_key_der(cached_public_key_bytes)?;
                 Ok(Some(PublicKey::from(k256_public_key)))
             }
+
+            #[cfg(feature = "remote-signer")]
+            SignMode::Remote(signer) => Ok(Some(signer.public_key())),
         }
     }
 
@@ -119,6 +130,17 @@ impl SignMode {
                     k256::PublicKey::from_public_key_der(cached_public_key_bytes)?;
                 Ok(Some(k256_public_key.into()))
             }
+
+            #[cfg(feature = "remote-signer")]
+            SignMode::Remote(signer) => {
+                let mut sec1_bytes = [0u8; 65];
+                // The uncompressed SEC1 encoding is the `0x04` tag followed by `x` and `y`.
+                sec1_bytes[0] = 0x04;
+                sec1_bytes[1..].copy_from_slice(signer.public_key().as_ref());
+                let verifying_key =
+                    k256::ecdsa::VerifyingKey::from_sec1_bytes(&sec1_bytes)?;
+                Ok(Some(verifying_key))
+            }
         }
     }
 
@@ -0,0 +1,411 @@
+//! Client side of the remote signer protocol.
+//!
+//! The remote signer is an external process holding the producer key.
+//! The node connects to it over a Unix socket or TCP and sends one request per
+//! connection, encoded as a single line of JSON. The signer answers with a single
+//! line of JSON. Every request carries a shared token that the signer checks
+//! before answering. The connection isn't encrypted, so TCP is allowed only
+//! for the signer listening on the loopback address.
+//!
+//! Request examples:
+//! ```json
+//! {"token":"secret","method":"public_key"}
+//! {"token":"secret","method":"sign","message":"<32 bytes in hex>"}
+//! ```
+//!
+//! Response examples:
+//! ```json
+//! {"result":"public_key","public_key":"<64 bytes in hex>"}
+//! {"result":"signature","signature":"<64 bytes in hex>"}
+//! {"result":"error","reason":"invalid token"}
+//! ```
+
+use crate::{
+    fuel_crypto::{
+        Message,
+        PublicKey,
+    },
+    fuel_vm::Signature,
+    secrecy::{
+        ExposeSecret,
+        Secret,
+    },
+};
+use anyhow::{
+    Context,
+    anyhow,
+};
+use core::{
+    fmt,
+    str::FromStr,
+    time::Duration,
+};
+#[cfg(unix)]
+use std::path::PathBuf;
+use std::{
+    net::SocketAddr,
+    sync::Arc,
+};
+use tokio::io::{
+    AsyncBufReadExt,
+    AsyncRead,
+    AsyncWrite,
+    AsyncWriteExt,
+    BufReader,
+};
+
+/// How long the node waits for the remote signer to answer a request.
+pub const REMOTE_SIGNER_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The address of the remote signer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RemoteSignerEndpoint {
+    /// The signer listens on the TCP address, e.g. `tcp://127.0.0.1:4100`.
+    Tcp(String),
+    /// The signer listens on the Unix socket, e.g. `unix:///run/fuel/signer.sock`.
+    #[cfg(unix)]
+    Unix(PathBuf),
+}
+
+impl FromStr for RemoteSignerEndpoint {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(address) = s.strip_prefix("tcp://") {
+            if !is_loopback(address) {
+                return Err(anyhow!(
+                    "The remote signer endpoint `{s}` is not a loopback address, \
+                    the token and the messages are sent unencrypted over TCP. \
+                    Use `unix://<path>` for the signer on another address"
+                ))
+            }
+            return Ok(Self::Tcp(address.to_string()))
+        }
+        #[cfg(unix)]
+        if let Some(path) = s.strip_prefix("unix://") {
+            return Ok(Self::Unix(PathBuf::from(path)))
+        }
+        Err(anyhow!(
+            "Unsupported remote signer endpoint `{s}`, expected `tcp://<address>` or `unix://<path>`"
+        ))
+    }
+}
+
+/// Returns `true` if the TCP `address` points to the local machine.
+fn is_loopback(address: &str) -> bool {
+    if let Ok(address) = address.parse::<SocketAddr>() {
+        return address.ip().is_loopback()
+    }
+    address
+        .rsplit_once(':')
+        .is_some_and(|(host, _)| host.eq_ignore_ascii_case("localhost"))
+}
+
+impl fmt::Display for RemoteSignerEndpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Tcp(address) => write!(f, "tcp://{address}"),
+            #[cfg(unix)]
+            Self::Unix(path) => write!(f, "unix://{}", path.display()),
+        }
+    }
+}
+
+/// The request sent to the remote signer.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RemoteSignerRequest {
+    /// The shared token authenticating the node.
+    pub token: String,
+    /// The requested operation.
+    #[serde(flatten)]
+    pub method: RemoteSignerMethod,
+}
+
+/// The operation requested from the remote signer.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "method", rename_all = "snake_case")]
+pub enum RemoteSignerMethod {
+    /// Returns the public key of the signer.
+    PublicKey,
+    /// Signs the prehashed message.
+    Sign {
+        /// The message to sign.
+        message: Message,
+    },
+}
+
+/// The response of the remote signer.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "result", rename_all = "snake_case")]
+pub enum RemoteSignerResponse {
+    /// The public key of the signer.
+    PublicKey {
+        /// The public key.
+        public_key: PublicKey,
+    },
+    /// The signature of the requested message.
+    Signature {
+        /// The signature.
+        signature: Signature,
+    },
+    /// The signer refused or failed to serve the request.
+    Error {
+        /// The description of the failure.
+        reason: String,
+    },
+}
+
+/// The client of the remote signer.
+#[derive(Clone)]
+pub struct RemoteSigner {
+    endpoint: RemoteSignerEndpoint,
+    token: Arc<Secret<String>>,
+    public_key: PublicKey,
+}
+
+impl fmt::Debug for RemoteSigner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RemoteSigner")
+            .field("endpoint", &self.endpoint)
+            .field("public_key", &self.public_key)
+            .finish_non_exhaustive()
+    }
+}
+
+impl RemoteSigner {
+    /// Connects to the remote signer and caches its public key.
+    pub async fn connect(
+        endpoint: RemoteSignerEndpoint,
+        token: String,
+    ) -> anyhow::Result<Self> {
+        let token = Arc::new(Secret::new(token));
+        let response = request(&endpoint, &token, RemoteSignerMethod::PublicKey)
+            .await
+            .with_context(|| {
+                format!("Failed to get the public key from the remote signer {endpoint}")
+            })?;
+        let public_key = match response {
+            RemoteSignerResponse::PublicKey { public_key } => public_key,
+            response => return Err(unexpected_response(response)),
+        };
+        Ok(Self {
+            endpoint,
+            token,
+            public_key,
+        })
+    }
+
+    /// Returns the endpoint of the remote signer.
+    pub fn endpoint(&self) -> &RemoteSignerEndpoint {
+        &self.endpoint
+    }
+
+    /// Returns the cached public key of the remote signer.
+    pub fn public_key(&self) -> PublicKey {
+        self.public_key
+    }
+
+    /// Asks the remote signer to sign the prehashed message.
+    ///
+    /// The signature is rejected if it wasn't produced by the cached public key.
+    pub async fn sign_message(&self, message: Message) -> anyhow::Result<Signature> {
+        let response = request(
+            &self.endpoint,
+            &self.token,
+            RemoteSignerMethod::Sign { message },
+        )
+        .await
+        .with_context(|| {
+            format!("Failed to sign with the remote signer {}", self.endpoint)
+        })?;
+        let signature = match response {
+            RemoteSignerResponse::Signature { signature } => signature,
+            response => return Err(unexpected_response(response)),
+        };
+        signature.verify(&self.public_key, &message).map_err(|_| {
+            anyhow!("The remote signer returned a signature of another key")
+        })?;
+        Ok(signature)
+    }
+}
+
+fn unexpected_response(response: RemoteSignerResponse) -> anyhow::Error {
+    match response {
+        RemoteSignerResponse::Error { reason } => {
+            anyhow!("The remote signer refused the request: {reason}")
+        }
+        response => anyhow!("Unexpected response from the remote signer: {response:?}"),
+    }
+}
+
+async fn request(
+    endpoint: &RemoteSignerEndpoint,
+    token: &Secret<String>,
+    method: RemoteSignerMethod,
+) -> anyhow::Result<RemoteSignerResponse> {
+    let request = RemoteSignerRequest {
+        token: token.expose_secret().clone(),
+        method,
+    };
+    let mut payload = serde_json::to_vec(&request)?;
+    payload.push(b'\n');
+
+    let response = async {
+        match endpoint {
+            RemoteSignerEndpoint::Tcp(address) => {
+                let stream = tokio::net::TcpStream::connect(address).await?;
+                exchange(stream, &payload).await
+            }
+            #[cfg(unix)]
+            RemoteSignerEndpoint::Unix(path) => {
+                let stream = tokio::net::UnixStream::connect(path).await?;
+                exchange(stream, &payload).await
+            }
+        }
+    };
+    tokio::time::timeout(REMOTE_SIGNER_TIMEOUT, response)
+        .await
+        .map_err(|_| anyhow!("The remote signer didn't answer in time"))?
+}
+
+async fn exchange<S>(stream: S, payload: &[u8]) -> anyhow::Result<RemoteSignerResponse>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let mut stream = BufReader::new(stream);
+    stream.get_mut().write_all(payload).await?;
+    stream.get_mut().flush().await?;
+
+    let mut line = String::new();
+    stream.read_line(&mut line).await?;
+    let response = serde_json::from_str(&line)
+        .context("The remote signer returned a malformed response")?;
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+    use crate::fuel_crypto::SecretKey;
+    use rand::{
+        SeedableRng,
+        rngs::StdRng,
+    };
+    use tokio::net::TcpListener;
+
+    const TOKEN: &str = "token";
+
+    /// Serves requests one by one, signing with `secret_key`.
+    async fn spawn_signer(secret_key: SecretKey) -> RemoteSignerEndpoint {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let mut stream = BufReader::new(stream);
+                let mut line = String::new();
+                stream.read_line(&mut line).await.unwrap();
+                let request: RemoteSignerRequest = serde_json::from_str(&line).unwrap();
+                let response = if request.token != TOKEN {
+                    RemoteSignerResponse::Error {
+                        reason: "invalid token".to_string(),
+                    }
+                } else {
+                    match request.method {
+                        RemoteSignerMethod::PublicKey => {
+                            RemoteSignerResponse::PublicKey {
+                                public_key: secret_key.public_key(),
+                            }
+                        }
+                        RemoteSignerMethod::Sign { message } => {
+                            RemoteSignerResponse::Signature {
+                                signature: Signature::sign(&secret_key, &message),
+                            }
+                        }
+                    }
+                };
+                let mut payload = serde_json::to_vec(&response).unwrap();
+                payload.push(b'\n');
+                stream.get_mut().write_all(&payload).await.unwrap();
+            }
+        });
+        RemoteSignerEndpoint::Tcp(address.to_string())
+    }
+
+    #[test]
+    fn endpoint__parses_supported_schemes() {
+        assert_eq!(
+            "tcp://127.0.0.1:4100"
+                .parse::<RemoteSignerEndpoint>()
+                .unwrap(),
+            RemoteSignerEndpoint::Tcp("127.0.0.1:4100".to_string())
+        );
+        #[cfg(unix)]
+        assert_eq!(
+            "unix:///run/signer.sock"
+                .parse::<RemoteSignerEndpoint>()
+                .unwrap(),
+            RemoteSignerEndpoint::Unix(PathBuf::from("/run/signer.sock"))
+        );
+        assert!(
+            "http://127.0.0.1:4100"
+                .parse::<RemoteSignerEndpoint>()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn endpoint__allows_tcp_only_on_loopback() {
+        assert!("tcp://[::1]:4100".parse::<RemoteSignerEndpoint>().is_ok());
+        assert!(
+            "tcp://localhost:4100"
+                .parse::<RemoteSignerEndpoint>()
+                .is_ok()
+        );
+        assert!(
+            "tcp://10.0.0.1:4100"
+                .parse::<RemoteSignerEndpoint>()
+                .is_err()
+        );
+        assert!(
+            "tcp://signer.example.com:4100"
+                .parse::<RemoteSignerEndpoint>()
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn sign_message__returns_signature_of_remote_key() {
+        // Given
+        let secret_key = SecretKey::random(&mut StdRng::seed_from_u64(2322));
+        let endpoint = spawn_signer(secret_key).await;
+        let signer = RemoteSigner::connect(endpoint, TOKEN.to_string())
+            .await
+            .unwrap();
+        let message = Message::new(b"block");
+
+        // When
+        let signature = signer.sign_message(message).await.unwrap();
+
+        // Then
+        assert_eq!(signer.public_key(), secret_key.public_key());
+        assert_eq!(
+            signature.recover(&message).unwrap(),
+            secret_key.public_key()
+        );
+    }
+
+    #[tokio::test]
+    async fn connect__fails_with_invalid_token() {
+        // Given
+        let secret_key = SecretKey::random(&mut StdRng::seed_from_u64(2322));
+        let endpoint = spawn_signer(secret_key).await;
+
+        // When
+        let result = RemoteSigner::connect(endpoint, "wrong".to_string()).await;
+
+        // Then
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,120 @@
+use fuel_core::{
+    combined_database::{
+        CombinedDatabase,
+        backup::{
+            Backup,
+            BackupStore,
+        },
+    },
+    state::{
+        historical_rocksdb::StateRewindPolicy,
+        rocks_db::DatabaseConfig,
+    },
+};
+use fuel_core_storage::transactional::HistoricalView;
+use fuel_core_types::fuel_types::BlockHeight;
+use std::time::Duration;
+use tempfile::TempDir;
+use test_helpers::fuel_core_driver::FuelCoreDriver;
+
+const HEIGHT: u32 = 10;
+
+async fn wait_for_backups(
+    store: &BackupStore,
+    predicate: impl Fn(&[Backup]) -> bool,
+) -> Vec<Backup> {
+    tokio::time::timeout(Duration::from_secs(10), async {
+        loop {
+            let backups = store.list().unwrap();
+            if predicate(&backups) {
+                return backups
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    })
+    .await
+    .expect("The backups were not created in time")
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn backup_scheduler__creates_backups_of_the_running_node() -> anyhow::Result<()> {
+    // Given
+    let backup_dir = TempDir::new()?;
+    let driver = FuelCoreDriver::spawn(&[
+        "--debug",
+        "--poa-instant",
+        "true",
+        "--backup-dir",
+        backup_dir.path().to_str().unwrap(),
+        "--backup-interval",
+        "100ms",
+        "--backup-retention",
+        "2",
+    ])
+    .await?;
+    let store = BackupStore::new(backup_dir.path());
+
+    // When
+    driver.client.produce_blocks(HEIGHT, None).await?;
+    let backups = wait_for_backups(&store, |backups| {
+        backups
+            .last()
+            .is_some_and(|backup| backup.height() == Some(BlockHeight::new(HEIGHT)))
+    })
+    .await;
+    driver.kill().await;
+
+    // Then
+    assert!(store.list()?.len() <= 2);
+    let latest = backups.last().unwrap();
+    store.verify(Some(latest.id))?;
+
+    let restore_dir = TempDir::new()?;
+    let restore_to = restore_dir.path().join("db");
+    store.restore(Some(latest.id), &restore_to)?;
+    let restored = CombinedDatabase::open(
+        &restore_to,
+        StateRewindPolicy::NoRewind,
+        DatabaseConfig::config_for_tests(),
+    )?;
+    assert_eq!(
+        restored.on_chain().latest_height(),
+        Some(BlockHeight::new(HEIGHT))
+    );
+    assert!(restored.off_chain().latest_height() <= restored.on_chain().latest_height());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn backup_store__restored_backup_starts_the_node() -> anyhow::Result<()> {
+    // Given
+    let driver = FuelCoreDriver::spawn(&["--debug", "--poa-instant", "true"]).await?;
+    driver.client.produce_blocks(HEIGHT, None).await?;
+    let backup_dir = TempDir::new()?;
+    let store = BackupStore::new(backup_dir.path());
+    let backup = store.create(&driver.node.shared.database)?;
+    driver.kill().await;
+
+    // When
+    let restore_dir = TempDir::new()?;
+    std::fs::remove_dir(restore_dir.path())?;
+    store.restore(Some(backup.id), restore_dir.path())?;
+    let restored_driver = FuelCoreDriver::spawn_with_directory(
+        restore_dir,
+        &["--debug", "--poa-instant", "true"],
+    )
+    .await?;
+
+    // Then
+    let database = &restored_driver.node.shared.database;
+    assert_eq!(
+        database.on_chain().latest_height(),
+        Some(BlockHeight::new(HEIGHT))
+    );
+    assert_eq!(
+        database.off_chain().latest_height(),
+        Some(BlockHeight::new(HEIGHT))
+    );
+    restored_driver.kill().await;
+    Ok(())
+}
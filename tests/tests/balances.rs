@@ -596,3 +596,98 @@ async fn contract_balances_in_the_past() {
         contract_id.asset_id(&sub_asset_id)
     );
 }
+
+fn state_config_with_coins(owner: Address, asset_id: AssetId) -> StateConfig {
+    let mut coin_generator = CoinConfigGenerator::new();
+    StateConfig {
+        coins: [50, 100, 150]
+            .into_iter()
+            .map(|amount| CoinConfig {
+                owner,
+                amount,
+                asset_id,
+                ..coin_generator.generate()
+            })
+            .collect(),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn balance_at_height__returns_the_balance_in_the_past() {
+    let wallet = default_signing_wallet();
+    let owner = wallet.owner();
+    let asset_id = AssetId::BASE;
+    let config =
+        Config::local_node_with_state_config(state_config_with_coins(owner, asset_id));
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    let height_before_transfer = client
+        .chain_info()
+        .await
+        .unwrap()
+        .latest_block
+        .header
+        .height;
+    client
+        .run_transfer(wallet, vec![(Address::new([1u8; 32]), asset_id, 200)])
+        .await
+        .unwrap();
+
+    // When
+    let balance_before_transfer = client
+        .balance_at(&owner, Some(&asset_id), height_before_transfer.into())
+        .await
+        .unwrap();
+    let latest_balance = client.balance(&owner, Some(&asset_id)).await.unwrap();
+
+    // Then
+    assert_eq!(balance_before_transfer, 300);
+    assert!(latest_balance <= 100);
+}
+
+#[tokio::test]
+async fn balance_at_height__fails_outside_of_the_retained_history() {
+    let mut config = Config::local_node();
+    config.combined_db_config.state_rewind_policy = StateRewindPolicy::NoRewind;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let owner = Address::new([1u8; 32]);
+
+    // Given
+    client.produce_blocks(2, None).await.unwrap();
+
+    // When
+    let result = client
+        .balance_at(&owner, Some(&AssetId::BASE), 1u32.into())
+        .await;
+
+    // Then
+    let err = result.expect_err("The history is not retained");
+    assert!(
+        err.to_string()
+            .contains("outside the retained history range"),
+        "{err}"
+    );
+}
+
+#[tokio::test]
+async fn balance_at_height__fails_above_the_latest_height() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let owner = Address::new([1u8; 32]);
+
+    // When
+    let result = client
+        .balance_at(&owner, Some(&AssetId::BASE), 100u32.into())
+        .await;
+
+    // Then
+    let err = result.expect_err("The height is above the latest height");
+    assert!(
+        err.to_string().contains("is above the latest block height"),
+        "{err}"
+    );
+}
@@ -37,6 +37,7 @@ use fuel_core_types::{
     signer::SignMode,
     tai64::Tai64,
 };
+use futures::StreamExt;
 use itertools::{
     Itertools,
     rev,
@@ -346,6 +347,61 @@ async fn missing_first_and_last_parameters_returns_an_error() {
     assert!(result.contains("The queries for the whole range is not supported"));
 }
 
+/// Produces a new block every 100ms, until the future is dropped.
+async fn produce_blocks_forever(client: &FuelClient) {
+    loop {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        client.produce_blocks(1, None).await.unwrap();
+    }
+}
+
+#[tokio::test]
+async fn subscribe_blocks__replays_existing_blocks_and_streams_new_ones() {
+    // Given
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.produce_blocks(3, None).await.unwrap();
+
+    // When
+    let mut stream = client.subscribe_blocks(Some(1.into())).await.unwrap();
+    let mut heights = vec![];
+    for _ in 0..3 {
+        let block = stream.next().await.unwrap().unwrap();
+        heights.push(block.header.height);
+    }
+    let new_block = tokio::select! {
+        block = stream.next() => block.unwrap().unwrap(),
+        _ = produce_blocks_forever(&client) => unreachable!(),
+    };
+
+    // Then
+    assert_eq!(heights, vec![1, 2, 3]);
+    assert_eq!(new_block.header.height, 4);
+}
+
+#[tokio::test]
+async fn subscribe_new_blocks__streams_only_new_blocks() {
+    // Given
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.produce_blocks(2, None).await.unwrap();
+
+    // When
+    let mut stream = client.subscribe_new_blocks().await.unwrap();
+    let first = tokio::select! {
+        block = stream.next() => block.unwrap().unwrap(),
+        _ = produce_blocks_forever(&client) => unreachable!(),
+    };
+    let second = tokio::select! {
+        block = stream.next() => block.unwrap().unwrap(),
+        _ = produce_blocks_forever(&client) => unreachable!(),
+    };
+
+    // Then
+    assert!(first.header.height > 2);
+    assert_eq!(second.header.height, first.header.height + 1);
+}
+
 mod full_block {
     use super::*;
     use cynic::QueryBuilder;
@@ -0,0 +1,232 @@
+use fuel_core::service::{
+    Config,
+    FuelService,
+};
+use fuel_core_client::client::{
+    FuelClient,
+    pagination::{
+        PageDirection,
+        PaginationRequest,
+    },
+    schema::tx::transparent_receipt::ReceiptType,
+    types::{
+        ContractReceiptsFilter,
+        TransactionStatus,
+    },
+};
+use fuel_core_types::{
+    fuel_asm::{
+        GTFArgs,
+        RegId,
+        op,
+    },
+    fuel_tx::{
+        Bytes32,
+        ContractId,
+        Input,
+        Output,
+        Receipt,
+        Transaction,
+        TransactionBuilder,
+        TxPointer,
+        UtxoId,
+        Witness,
+    },
+    fuel_types::canonical::Serialize,
+    fuel_vm::{
+        Call,
+        Contract,
+        Salt,
+    },
+};
+use futures::StreamExt;
+
+/// Deploys a contract that emits a `Log` receipt with `ra` set to `log_value`.
+async fn deploy_logging_contract(
+    client: &FuelClient,
+    log_value: u16,
+) -> (ContractId, Bytes32) {
+    let reg_value: u8 = 0x10;
+    let bytecode: Witness = [
+        op::movi(reg_value, log_value.into()),
+        op::log(reg_value, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect::<Vec<u8>>()
+    .into();
+
+    let salt = Salt::zeroed();
+    let contract = Contract::from(bytecode.as_ref());
+    let root = contract.root();
+    let state_root = Contract::initial_state_root(std::iter::empty());
+    let contract_id = contract.id(&salt, &root, &state_root);
+    let output = Output::contract_created(contract_id, state_root);
+
+    let contract_deploy = TransactionBuilder::create(bytecode, salt, vec![])
+        .add_fee_input()
+        .add_output(output)
+        .finalize_as_transaction();
+    let status = client
+        .submit_and_await_commit(&contract_deploy)
+        .await
+        .unwrap();
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+
+    (contract_id, state_root)
+}
+
+fn call_contract(contract_id: ContractId, state_root: Bytes32) -> Transaction {
+    let script_ops = vec![
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+    let script_data = Call::new(contract_id, 0, 0).to_bytes();
+
+    TransactionBuilder::script(script_ops.into_iter().collect::<Vec<u8>>(), script_data)
+        .add_input(Input::contract(
+            UtxoId::new(Bytes32::zeroed(), 0),
+            Bytes32::zeroed(),
+            state_root,
+            TxPointer::default(),
+            contract_id,
+        ))
+        .script_gas_limit(1_000_000)
+        .add_fee_input()
+        .add_output(Output::contract(0, Bytes32::zeroed(), Bytes32::zeroed()))
+        .finalize_as_transaction()
+}
+
+#[tokio::test]
+async fn contract_receipts__returns_receipts_of_requested_contracts() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    let (contract_a, state_root_a) = deploy_logging_contract(&client, 1).await;
+    let (contract_b, state_root_b) = deploy_logging_contract(&client, 2).await;
+    for tx in [
+        call_contract(contract_a, state_root_a),
+        call_contract(contract_b, state_root_b),
+        call_contract(contract_a, state_root_a),
+    ] {
+        let status = client.submit_and_await_commit(&tx).await.unwrap();
+        assert!(matches!(status, TransactionStatus::Success { .. }));
+    }
+
+    // When
+    let filter = ContractReceiptsFilter {
+        contract_ids: vec![contract_a, contract_b],
+        receipt_types: Some(vec![ReceiptType::Log]),
+        from_height: None,
+    };
+    let request = PaginationRequest {
+        cursor: None,
+        results: 10,
+        direction: PageDirection::Forward,
+    };
+    let receipts = client.contract_receipts(filter, request).await.unwrap();
+
+    // Then
+    let logged = receipts
+        .results
+        .iter()
+        .map(|receipt| match receipt.receipt {
+            Receipt::Log { id, ra, .. } => (id, ra),
+            ref receipt => panic!("Unexpected receipt: {receipt:?}"),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        logged,
+        vec![(contract_a, 1), (contract_b, 2), (contract_a, 1)]
+    );
+    assert!(!receipts.has_next_page);
+}
+
+#[tokio::test]
+async fn contract_receipts__paginates_backward() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    let (contract_id, state_root) = deploy_logging_contract(&client, 1).await;
+    for _ in 0..3 {
+        let tx = call_contract(contract_id, state_root);
+        let status = client.submit_and_await_commit(&tx).await.unwrap();
+        assert!(matches!(status, TransactionStatus::Success { .. }));
+    }
+    let filter = ContractReceiptsFilter {
+        contract_ids: vec![contract_id],
+        receipt_types: Some(vec![ReceiptType::Log]),
+        from_height: None,
+    };
+
+    // When
+    let first_page = client
+        .contract_receipts(
+            filter.clone(),
+            PaginationRequest {
+                cursor: None,
+                results: 2,
+                direction: PageDirection::Backward,
+            },
+        )
+        .await
+        .unwrap();
+    let second_page = client
+        .contract_receipts(
+            filter,
+            PaginationRequest {
+                cursor: first_page.cursor.clone(),
+                results: 2,
+                direction: PageDirection::Backward,
+            },
+        )
+        .await
+        .unwrap();
+
+    // Then
+    let heights = first_page
+        .results
+        .iter()
+        .chain(second_page.results.iter())
+        .map(|receipt| *receipt.block_height)
+        .collect::<Vec<_>>();
+    assert_eq!(heights.len(), 3);
+    assert!(heights.windows(2).all(|pair| pair[0] > pair[1]));
+    assert!(first_page.has_next_page);
+    assert!(!second_page.has_next_page);
+}
+
+#[tokio::test]
+async fn subscribe_contract_receipts__replays_and_streams_new_receipts() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    let (contract_id, state_root) = deploy_logging_contract(&client, 7).await;
+    let tx = call_contract(contract_id, state_root);
+    client.submit_and_await_commit(&tx).await.unwrap();
+    let filter = ContractReceiptsFilter {
+        contract_ids: vec![contract_id],
+        receipt_types: Some(vec![ReceiptType::Log]),
+        from_height: Some(1.into()),
+    };
+    let mut stream = client.subscribe_contract_receipts(filter).await.unwrap();
+
+    // When
+    let tx = call_contract(contract_id, state_root);
+    client.submit_and_await_commit(&tx).await.unwrap();
+
+    // Then
+    let replayed = stream.next().await.unwrap().unwrap();
+    let streamed = stream.next().await.unwrap().unwrap();
+    assert!(replayed.block_height < streamed.block_height);
+    for receipt in [replayed, streamed] {
+        assert!(matches!(
+            receipt.receipt,
+            Receipt::Log { id, ra: 7, .. } if id == contract_id
+        ));
+    }
+}
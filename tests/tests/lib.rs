@@ -7,6 +7,8 @@ mod assemble_tx;
 #[cfg(not(feature = "only-p2p"))]
 mod assets;
 #[cfg(not(feature = "only-p2p"))]
+mod backup;
+#[cfg(not(feature = "only-p2p"))]
 mod balances;
 #[cfg(not(feature = "only-p2p"))]
 mod blob;
@@ -21,6 +23,8 @@ mod coins;
 #[cfg(not(feature = "only-p2p"))]
 mod contract;
 #[cfg(not(feature = "only-p2p"))]
+mod contract_receipts;
+#[cfg(not(feature = "only-p2p"))]
 mod da_compression;
 #[cfg(not(feature = "only-p2p"))]
 mod dap;
@@ -51,12 +55,16 @@ mod poa;
 #[cfg(not(feature = "only-p2p"))]
 mod preconfirmations;
 #[cfg(not(feature = "only-p2p"))]
+mod pruning;
+#[cfg(not(feature = "only-p2p"))]
 mod recovery;
 #[cfg(not(feature = "only-p2p"))]
 mod regenesis;
 #[cfg(not(feature = "only-p2p"))]
 mod relayer;
 #[cfg(not(feature = "only-p2p"))]
+mod remote_signer;
+#[cfg(not(feature = "only-p2p"))]
 mod required_fuel_block_height_extension;
 #[cfg(not(feature = "only-p2p"))]
 mod snapshot;
@@ -307,6 +307,9 @@ mod p2p {
             ConsensusConfig::PoAV2(poa) => {
                 poa.set_genesis_signing_key(key);
             }
+            ConsensusConfig::RoundRobin(round_robin) => {
+                round_robin.set_producers(vec![key]);
+            }
         }
         config.snapshot_reader = snapshot_reader.clone().with_chain_config(chain_config)
     }
@@ -0,0 +1,101 @@
+use fuel_core::service::{
+    Config,
+    FuelService,
+};
+use fuel_core_client::client::{
+    FuelClient,
+    types::TransactionStatus,
+};
+use fuel_core_types::{
+    fuel_asm::{
+        RegId,
+        op,
+    },
+    fuel_tx::UniqueIdentifier,
+    fuel_types::BlockHeight,
+};
+use std::time::Duration;
+use test_helpers::{
+    assemble_tx::AssembleAndRunTx,
+    default_signing_wallet,
+};
+
+const RETENTION: u32 = 2;
+
+async fn wait_for_pruning(srv: &FuelService, height: BlockHeight) {
+    tokio::time::timeout(Duration::from_secs(10), async {
+        loop {
+            let pruned_height = srv.shared.database.pruned_height().unwrap();
+            if pruned_height >= Some(height) {
+                return
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    })
+    .await
+    .expect("The blocks were not pruned in time")
+}
+
+#[tokio::test]
+async fn block_retention__prunes_transactions_but_keeps_the_headers() {
+    // Given
+    let mut config = Config::local_node();
+    config.block_retention = Some(RETENTION);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let script = vec![op::ret(RegId::ONE)];
+    let tx = client
+        .assemble_script(script, vec![], default_signing_wallet())
+        .await
+        .unwrap();
+    let tx_id = tx.id(&Default::default());
+    let status = client.submit_and_await_commit(&tx).await.unwrap();
+    let TransactionStatus::Success { block_height, .. } = status else {
+        panic!("The transaction should be included into the block: {status:?}")
+    };
+
+    // When
+    client.produce_blocks(RETENTION + 1, None).await.unwrap();
+    wait_for_pruning(&srv, block_height).await;
+
+    // Then
+    let transaction = client.transaction(&tx_id).await;
+    let err = transaction.expect_err("The transaction should be pruned");
+    assert!(err.to_string().contains("pruned"), "{err}");
+
+    let status = client.transaction_status(&tx_id).await;
+    let err = status.expect_err("The status should be pruned");
+    assert!(err.to_string().contains("pruned"), "{err}");
+
+    let block = client
+        .block_by_height(block_height)
+        .await
+        .unwrap()
+        .expect("The header of the block should be kept");
+    assert!(block.transactions.contains(&tx_id));
+}
+
+#[tokio::test]
+async fn block_retention__keeps_the_latest_blocks() {
+    // Given
+    let mut config = Config::local_node();
+    config.block_retention = Some(RETENTION);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.produce_blocks(RETENTION, None).await.unwrap();
+
+    // When
+    let script = vec![op::ret(RegId::ONE)];
+    let tx = client
+        .assemble_script(script, vec![], default_signing_wallet())
+        .await
+        .unwrap();
+    let tx_id = tx.id(&Default::default());
+    client.submit_and_await_commit(&tx).await.unwrap();
+    client.produce_blocks(RETENTION - 1, None).await.unwrap();
+
+    // Then
+    let transaction = client.transaction(&tx_id).await.unwrap();
+    assert!(transaction.is_some());
+}
@@ -12,7 +12,10 @@ use fuel_core_client::client::{
         PageDirection,
         PaginationRequest,
     },
-    types::message::MessageStatus,
+    types::{
+        Consensus,
+        message::MessageStatus,
+    },
 };
 use fuel_core_types::{
     blockchain::header::LATEST_STATE_TRANSITION_VERSION,
@@ -61,6 +64,30 @@ async fn take_snapshot(db_dir: &TempDir, snapshot_dir: &TempDir) -> anyhow::Resu
     Ok(())
 }
 
+async fn take_diff_snapshot(
+    db_dir: &TempDir,
+    snapshot_dir: &TempDir,
+    from: u32,
+    to: u32,
+) -> anyhow::Result<()> {
+    snapshot::exec(snapshot::Command::parse_from([
+        "_IGNORED_",
+        "--db-path",
+        db_dir.path().to_str().unwrap(),
+        "--output-directory",
+        snapshot_dir.path().to_str().unwrap(),
+        "diff",
+        "--from",
+        &from.to_string(),
+        "--to",
+        &to.to_string(),
+        "encoding",
+        "parquet",
+    ]))
+    .await?;
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_regenesis_old_blocks_are_preserved() -> anyhow::Result<()> {
     const BLOCKS_QUERY: i32 = 50;
@@ -899,3 +926,72 @@ async fn starting_node_with_overwritten_new_poa_key_from_the_future_doesnt_rollb
     core.kill().await;
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn regenesis_from_snapshot_with_diff_matches_full_snapshot() -> anyhow::Result<()> {
+    let mut rng = StdRng::seed_from_u64(1234);
+    const BASE_HEIGHT: u32 = 2;
+    const HEIGHT: u32 = 4;
+
+    // Given
+    let core =
+        FuelCoreDriver::spawn_feeless(&["--debug", "--poa-instant", "true"]).await?;
+    produce_block_with_tx(&mut rng, &core.client).await;
+    produce_block_with_tx(&mut rng, &core.client).await;
+    let db_dir = core.kill().await;
+    let base_snapshot_dir = tempdir()?;
+    take_snapshot(&db_dir, &base_snapshot_dir).await?;
+
+    let core = FuelCoreDriver::spawn_feeless_with_directory(
+        db_dir,
+        &["--debug", "--poa-instant", "true"],
+    )
+    .await?;
+    produce_block_with_tx(&mut rng, &core.client).await;
+    produce_block_with_tx(&mut rng, &core.client).await;
+    let db_dir = core.kill().await;
+    let diff_snapshot_dir = tempdir()?;
+    take_diff_snapshot(&db_dir, &diff_snapshot_dir, BASE_HEIGHT, HEIGHT).await?;
+    let full_snapshot_dir = tempdir()?;
+    take_snapshot(&db_dir, &full_snapshot_dir).await?;
+
+    // When
+    let core = FuelCoreDriver::spawn_feeless(&[
+        "--debug",
+        "--poa-instant",
+        "true",
+        "--snapshot",
+        base_snapshot_dir.path().to_str().unwrap(),
+        "--snapshot-diff",
+        diff_snapshot_dir.path().to_str().unwrap(),
+    ])
+    .await?;
+    let regenesis_height = (HEIGHT + 1).into();
+    let diff_block = core
+        .client
+        .block_by_height(regenesis_height)
+        .await?
+        .expect("The regenesis block should exist");
+    core.kill().await;
+
+    // Then
+    let core = FuelCoreDriver::spawn_feeless(&[
+        "--debug",
+        "--poa-instant",
+        "true",
+        "--snapshot",
+        full_snapshot_dir.path().to_str().unwrap(),
+    ])
+    .await?;
+    let full_block = core
+        .client
+        .block_by_height(regenesis_height)
+        .await?
+        .expect("The regenesis block should exist");
+    core.kill().await;
+
+    assert!(matches!(diff_block.consensus, Consensus::Genesis(_)));
+    assert_eq!(diff_block.consensus, full_block.consensus);
+    assert_eq!(diff_block.header.prev_root, full_block.header.prev_root);
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use ethers::{
     providers::Middleware,
     types::{
+        BlockNumber,
         Log,
         SyncingStatus,
         U256,
@@ -461,7 +462,10 @@ async fn handle(
     let method = o.get("method").unwrap().as_str().unwrap();
     let r = match method {
         "eth_getBlockByNumber" => {
-            let r = mock.get_block(id).await.unwrap().unwrap();
+            let params = o.remove("params").unwrap();
+            let params: Vec<serde_json::Value> = serde_json::from_value(params).unwrap();
+            let number: BlockNumber = serde_json::from_value(params[0].clone()).unwrap();
+            let r = mock.get_block(number).await.unwrap().unwrap();
             json!({ "id": id, "jsonrpc": "2.0", "result": r })
         }
         "eth_syncing" => {
@@ -0,0 +1,94 @@
+use fuel_core::{
+    combined_database::CombinedDatabase,
+    state::rocks_db::DatabaseConfig,
+};
+use fuel_core_remote_signer::Signer;
+use fuel_core_storage::transactional::AtomicView;
+use fuel_core_types::{
+    blockchain::consensus::Consensus,
+    fuel_crypto::SecretKey,
+};
+use rand::{
+    SeedableRng,
+    rngs::StdRng,
+};
+use test_helpers::fuel_core_driver::FuelCoreDriver;
+
+#[tokio::test]
+async fn can_get_sealed_block_from_poa_produced_block_when_signing_with_remote_signer() {
+    // Given
+    let secret_key = SecretKey::random(&mut StdRng::seed_from_u64(2322));
+    let socket_dir = tempfile::tempdir().unwrap();
+    let endpoint = format!("unix://{}", socket_dir.path().join("signer.sock").display());
+    let _signer = Signer::new(secret_key, "token".to_string())
+        .spawn(endpoint.parse().unwrap())
+        .await
+        .unwrap();
+    let args = vec![
+        "--debug",
+        "--poa-instant",
+        "true",
+        "--consensus-remote-signer",
+        endpoint.as_str(),
+        "--consensus-remote-signer-token",
+        "token",
+    ];
+    let driver = FuelCoreDriver::spawn(&args).await.unwrap();
+
+    // When
+    let num_blocks = 10;
+    let _ = driver
+        .client
+        .produce_blocks(num_blocks, None)
+        .await
+        .unwrap();
+
+    // Then
+    let db_path = driver.kill().await;
+    let db = CombinedDatabase::open(
+        db_path.path(),
+        Default::default(),
+        DatabaseConfig::config_for_tests(),
+    )
+    .unwrap();
+    let view = db.on_chain().latest_view().unwrap();
+    for height in 1..=num_blocks {
+        let sealed_block = view
+            .get_sealed_block_by_height(&height.into())
+            .unwrap()
+            .expect("expected sealed block to be available");
+        let block_id = sealed_block.entity.id();
+        let signature = match sealed_block.consensus {
+            Consensus::PoA(ref poa) => poa.signature,
+            _ => panic!("Not expected consensus"),
+        };
+        signature
+            .verify(&secret_key.public_key(), &block_id.into_message())
+            .expect("failed to verify signature");
+    }
+}
+
+#[tokio::test]
+async fn node_fails_to_start_with_invalid_remote_signer_token() {
+    // Given
+    let secret_key = SecretKey::random(&mut StdRng::seed_from_u64(2322));
+    let socket_dir = tempfile::tempdir().unwrap();
+    let endpoint = format!("unix://{}", socket_dir.path().join("signer.sock").display());
+    let _signer = Signer::new(secret_key, "token".to_string())
+        .spawn(endpoint.parse().unwrap())
+        .await
+        .unwrap();
+    let args = vec![
+        "--debug",
+        "--consensus-remote-signer",
+        endpoint.as_str(),
+        "--consensus-remote-signer-token",
+        "wrong",
+    ];
+
+    // When
+    let result = FuelCoreDriver::spawn(&args).await;
+
+    // Then
+    assert!(result.is_err());
+}
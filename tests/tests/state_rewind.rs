@@ -320,6 +320,97 @@ async fn rollback_to__should_work_with_empty_gas_price_database() -> anyhow::Res
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn rollback__dry_run_does_not_change_databases() -> anyhow::Result<()> {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let driver = FuelCoreDriver::spawn_feeless(&[
+        "--debug",
+        "--poa-instant",
+        "true",
+        "--state-rewind-duration",
+        "7d",
+    ])
+    .await?;
+
+    // Given
+    const TOTAL_BLOCKS: u32 = 10;
+    for _ in 0..TOTAL_BLOCKS {
+        produce_block_with_tx(&mut rng, &driver.client).await;
+    }
+    let temp_dir = driver.kill().await;
+
+    // When
+    let args = [
+        "_IGNORED_",
+        "--db-path",
+        temp_dir.path().to_str().unwrap(),
+        "--target-block-height",
+        "5",
+        "--dry-run",
+    ];
+    let command = fuel_core_bin::cli::rollback::Command::parse_from(args);
+    fuel_core_bin::cli::rollback::exec(command).await?;
+
+    // Then
+    let db = open_combined_database(temp_dir.path());
+    assert_eq!(
+        db.on_chain().latest_height_from_metadata()?,
+        Some(TOTAL_BLOCKS.into())
+    );
+    let plan = db.rollback_plan(5u32.into())?;
+    assert_eq!(plan.on_chain.blocks(), TOTAL_BLOCKS - 5);
+    assert_eq!(plan.off_chain.blocks(), TOTAL_BLOCKS - 5);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn rollback__all_databases_agree_on_the_new_tip() -> anyhow::Result<()> {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let driver = FuelCoreDriver::spawn_feeless(&[
+        "--debug",
+        "--poa-instant",
+        "true",
+        "--state-rewind-duration",
+        "7d",
+    ])
+    .await?;
+
+    // Given
+    const TOTAL_BLOCKS: u32 = 10;
+    for _ in 0..TOTAL_BLOCKS {
+        produce_block_with_tx(&mut rng, &driver.client).await;
+    }
+    let temp_dir = driver.kill().await;
+
+    // When
+    let args = [
+        "_IGNORED_",
+        "--db-path",
+        temp_dir.path().to_str().unwrap(),
+        "--target-block-height",
+        "5",
+    ];
+    let command = fuel_core_bin::cli::rollback::Command::parse_from(args);
+    fuel_core_bin::cli::rollback::exec(command).await?;
+
+    // Then
+    let db = open_combined_database(temp_dir.path());
+    db.verify_tip(5u32.into())?;
+    assert!(db.rollback_plan(5u32.into())?.is_done());
+
+    Ok(())
+}
+
+fn open_combined_database(path: &std::path::Path) -> CombinedDatabase {
+    CombinedDatabase::open(
+        path,
+        fuel_core::state::historical_rocksdb::StateRewindPolicy::RewindFullRange,
+        fuel_core::state::rocks_db::DatabaseConfig::config_for_tests(),
+    )
+    .unwrap()
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn backup_and_restore__should_work_with_state_rewind() -> anyhow::Result<()> {
     let mut rng = StdRng::seed_from_u64(1234);
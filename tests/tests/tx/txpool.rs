@@ -7,14 +7,20 @@ use crate::helpers::{
 use fuel_core::service::FuelService;
 use fuel_core_client::client::{
     FuelClient,
-    types::TransactionStatus,
+    types::{
+        TransactionStatus,
+        TxPoolBlacklist,
+    },
 };
 use fuel_core_poa::Trigger;
 use fuel_core_types::{
     fuel_asm::*,
     fuel_crypto::*,
     fuel_tx,
-    fuel_tx::*,
+    fuel_tx::{
+        field::Inputs,
+        *,
+    },
 };
 use futures::StreamExt;
 use itertools::Itertools;
@@ -185,3 +191,65 @@ async fn informs_immediately_if_the_input_was_spent_during_previous_blocks_witho
     let err = status.expect_err("Should receive error that transaction squeezed out");
     assert!(err.to_string().contains("was already spent"))
 }
+
+#[tokio::test]
+async fn update_tx_pool_blacklist__removes_transactions_of_blacklisted_owner() {
+    const ADMIN_TOKEN: &str = "admin-token";
+    let mut config = config_with_fee();
+    config.block_production = Trigger::Never;
+    config.graphql_config.admin_api_token = Some(ADMIN_TOKEN.to_string());
+
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    let script = vec![op::ret(RegId::ONE)];
+    let tx = client
+        .assemble_script(script, vec![], default_signing_wallet())
+        .await
+        .unwrap();
+    let owner = *tx
+        .as_script()
+        .unwrap()
+        .inputs()
+        .iter()
+        .find_map(|input| input.input_owner())
+        .unwrap();
+    let tx_id = client.submit(&tx).await.unwrap();
+
+    // When
+    let removed = client
+        .update_tx_pool_blacklist(
+            ADMIN_TOKEN,
+            TxPoolBlacklist {
+                owners: vec![owner],
+                ..Default::default()
+            },
+            Default::default(),
+        )
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(removed, vec![tx_id]);
+    let black_list = client.tx_pool_blacklist(ADMIN_TOKEN).await.unwrap();
+    assert_eq!(black_list.owners, vec![owner]);
+    let result = client.submit(&tx).await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn tx_pool_blacklist__rejects_wrong_admin_token() {
+    let mut config = config_with_fee();
+    config.graphql_config.admin_api_token = Some("admin-token".to_string());
+
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let result = client.tx_pool_blacklist("wrong-token").await;
+
+    // Then
+    let err = result.expect_err("The request should be rejected");
+    assert!(err.to_string().contains("not authorized"));
+}